@@ -543,6 +543,7 @@ pdf-perf-sub-resources = Ressourcen & Datenmenge
 pdf-perf-sub-bottlenecks = Lade-Engpässe & Rendering
 pdf-perf-throttled-title = Performance unter gedrosselten Bedingungen
 pdf-perf-throttled-profile = Profil
+pdf-perf-runs-title = Messreihe — Median aus { $runs } Läufen (Lighthouse Mobile)
 pdf-perf-cls-title = CLS — Layout-Verschiebungen
 pdf-perf-cls-value = Wert
 pdf-perf-cls-time = Zeitpunkt
@@ -970,3 +971,33 @@ sr-state-disabled = deaktiviert
 sr-state-pressed = gedrückt
 sr-state-not-pressed = nicht gedrückt
 sr-state-tab-stop = fokussierbar
+
+# Entwurf Erklärung zur Barrierefreiheit (`auditmysite statement`)
+statement-title = Erklärung zur Barrierefreiheit
+statement-draft-marker = ENTWURF – aus automatisierten Prüfergebnissen erzeugt. Vor der Veröffentlichung ist eine menschliche Prüfung erforderlich.
+statement-placeholder-body = [Name der Organisation]
+statement-placeholder-legislation = [anwendbare Rechtsvorschrift, z. B. BFSG oder BITV 2.0]
+statement-intro = { $body } ist bemüht, die Website im Einklang mit { $legislation } barrierefrei zugänglich zu machen. Diese Erklärung zur Barrierefreiheit gilt für { $website }.
+statement-compliance-heading = Stand der Vereinbarkeit mit den Anforderungen
+statement-compliance-review-required = [Nicht bewertet – Prüfung erforderlich] Die automatisierten Prüfungen haben auf den geprüften Seiten keine Unvereinbarkeiten mit { $standard } gefunden. Ob die Website vollständig vereinbar ist, muss eine manuelle Prüfung feststellen.
+statement-compliance-partially = Diese Website ist wegen der folgenden Unvereinbarkeiten teilweise mit { $standard } vereinbar.
+statement-compliance-not = Diese Website ist nicht mit { $standard } vereinbar. Die Unvereinbarkeiten sind nachstehend aufgeführt.
+statement-compliance-review = Vor der Veröffentlichung prüfen: Dieser Stand beruht ausschließlich auf automatisierten Prüfungen. Kriterien mit manuellem Prüfbedarf wurden nicht bewertet.
+statement-nonaccessible-heading = Nicht barrierefreie Inhalte
+statement-nonaccessible-intro = Die nachstehend aufgeführten Inhalte sind aus folgenden Gründen nicht barrierefrei:
+statement-noncompliance-heading = a) Unvereinbarkeit mit { $legislation }
+statement-noncompliance-none = Die automatisierten Prüfungen haben keine Unvereinbarkeiten festgestellt.
+statement-noncompliance-review = Vor der Veröffentlichung prüfen: Jeden Punkt verständlich beschreiben und barrierefreie Alternativen sowie den geplanten Behebungstermin ergänzen.
+statement-issue = { $title } ({ $occurrences } Vorkommen auf { $pages } Seite(n))
+statement-burden-heading = b) Unverhältnismäßige Belastung
+statement-burden-placeholder = [Gegebenenfalls: Inhalte, für die eine unverhältnismäßige Belastung geltend gemacht wird, mit Begründung und barrierefreien Alternativen.]
+statement-scope-heading = c) Inhalte, die nicht in den Anwendungsbereich der anwendbaren Rechtsvorschriften fallen
+statement-scope-placeholder = [Gegebenenfalls: Inhalte außerhalb des Anwendungsbereichs, z. B. Inhalte Dritter oder archivierte Dokumente.]
+statement-preparation-heading = Erstellung dieser Erklärung zur Barrierefreiheit
+statement-preparation-date = Diese Erklärung wurde am { $date } erstellt.
+statement-preparation-method = Die Bewertung beruht auf einer Selbstbewertung mit automatisierten Prüfungen ({ $tool }) von { $pages } Seite(n).
+statement-preparation-review = Die Erklärung wurde zuletzt am [Datum] überprüft.
+statement-feedback-heading = Feedback und Kontaktangaben
+statement-feedback-placeholder = [Beschreibung des Feedback-Mechanismus und Kontaktangaben: E-Mail-Adresse, Telefonnummer, Kontaktformular.]
+statement-enforcement-heading = Durchsetzungsverfahren
+statement-enforcement-placeholder = [Link zum Durchsetzungsverfahren und Kontaktangaben der zuständigen Durchsetzungs- bzw. Schlichtungsstelle.]
@@ -543,6 +543,7 @@ pdf-perf-sub-resources = Resources & Payload Size
 pdf-perf-sub-bottlenecks = Loading Bottlenecks & Rendering
 pdf-perf-throttled-title = Performance under throttled conditions
 pdf-perf-throttled-profile = Profile
+pdf-perf-runs-title = Repeated measurement — median of { $runs } runs (Lighthouse Mobile)
 pdf-perf-cls-title = CLS — Layout Shifts
 pdf-perf-cls-value = Shift
 pdf-perf-cls-time = Time
@@ -970,3 +971,33 @@ sr-state-disabled = disabled
 sr-state-pressed = pressed
 sr-state-not-pressed = not pressed
 sr-state-tab-stop = focusable
+
+# Accessibility statement draft (`auditmysite statement`)
+statement-title = Accessibility statement
+statement-draft-marker = DRAFT — generated from automated test results. Human review required before publication.
+statement-placeholder-body = [Name of the organisation]
+statement-placeholder-legislation = [applicable legislation, e.g. BFSG or national transposition of Directive (EU) 2016/2102]
+statement-intro = { $body } is committed to making its website accessible in accordance with { $legislation }. This accessibility statement applies to { $website }.
+statement-compliance-heading = Compliance status
+statement-compliance-review-required = [Not assessed – review required] Automated checks found no non-compliances with { $standard } on the audited pages. A manual audit has to establish whether the website is fully compliant.
+statement-compliance-partially = This website is partially compliant with { $standard } due to the non-compliances listed below.
+statement-compliance-not = This website is not compliant with { $standard }. The non-compliances are listed below.
+statement-compliance-review = Check before publishing: this status is based on automated checks only. Criteria that require manual review were not assessed.
+statement-nonaccessible-heading = Non-accessible content
+statement-nonaccessible-intro = The content listed below is non-accessible for the following reasons:
+statement-noncompliance-heading = a) Non-compliance with { $legislation }
+statement-noncompliance-none = The automated checks found no non-compliances.
+statement-noncompliance-review = Check before publishing: describe each item in plain language and add the accessible alternatives and the planned fix date.
+statement-issue = { $title } ({ $occurrences } occurrence(s) on { $pages } page(s))
+statement-burden-heading = b) Disproportionate burden
+statement-burden-placeholder = [If applicable: content for which a disproportionate burden is claimed, with the reason and any accessible alternatives.]
+statement-scope-heading = c) Content not within the scope of the applicable legislation
+statement-scope-placeholder = [If applicable: content outside the scope of the legislation, e.g. third-party content or archived documents.]
+statement-preparation-heading = Preparation of this accessibility statement
+statement-preparation-date = This statement was prepared on { $date }.
+statement-preparation-method = The assessment is a self-assessment based on automated testing ({ $tool }) of { $pages } page(s).
+statement-preparation-review = The statement was last reviewed on [date].
+statement-feedback-heading = Feedback and contact information
+statement-feedback-placeholder = [Description of the feedback mechanism and contact details: e-mail address, phone number, contact form.]
+statement-enforcement-heading = Enforcement procedure
+statement-enforcement-placeholder = [Link to the enforcement procedure and contact details of the responsible enforcement or conciliation body.]
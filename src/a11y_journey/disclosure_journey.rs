@@ -7,7 +7,7 @@ use crate::audit::normalized::{
     InteractiveFinding, InteractiveFindingKind, InteractiveFindingValues, JourneyStep, JourneyTrace,
 };
 use crate::error::Result;
-use crate::interaction::{pointer, stability};
+use crate::interaction::{focus, pointer, stability};
 use crate::patterns::JourneyCandidate;
 use crate::taxonomy::Severity;
 
@@ -54,6 +54,10 @@ pub async fn test(
         None => return Ok((trace, findings)),
     };
 
+    let trigger_target = focus::selector_for_backend_node(page, trigger_id)
+        .await
+        .unwrap_or_else(|| format!("backend_node:{trigger_id}"));
+
     // Capture state before first click.
     let before_states = collect_expanded_states(page).await;
 
@@ -64,7 +68,7 @@ pub async fn test(
     }
     trace.steps.push(JourneyStep {
         action: "synthetic_click".to_string(),
-        target: Some(format!("backend_node:{trigger_id}")),
+        target: Some(trigger_target.clone()),
         focus: None,
         result: None,
         snapshot_label: Some("after_open_click".to_string()),
@@ -118,7 +122,7 @@ pub async fn test(
     }
     trace.steps.push(JourneyStep {
         action: "synthetic_click".to_string(),
-        target: Some(format!("backend_node:{trigger_id}")),
+        target: Some(trigger_target),
         focus: None,
         result: None,
         snapshot_label: Some("after_close_click".to_string()),
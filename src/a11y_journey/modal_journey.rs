@@ -42,6 +42,10 @@ pub async fn test(
     let trigger_snap = focus::capture_focus(page).await?;
     let trigger_selector = trigger_snap.selector.clone();
 
+    let trigger_target = focus::selector_for_backend_node(page, trigger_id)
+        .await
+        .unwrap_or_else(|| format!("backend_node:{trigger_id}"));
+
     // Click trigger to open the modal.
     if let Err(e) = pointer::synthetic_click_backend(page, trigger_id).await {
         tracing::warn!("modal: click on backend node {trigger_id} failed: {e}");
@@ -49,7 +53,7 @@ pub async fn test(
     }
     trace.steps.push(JourneyStep {
         action: "synthetic_click".to_string(),
-        target: Some(format!("backend_node:{trigger_id}")),
+        target: Some(trigger_target),
         focus: None,
         result: None,
         snapshot_label: Some("after_open_click".to_string()),
@@ -0,0 +1,371 @@
+//! Inline suppression annotations (`data-auditmysite-ignore`).
+//!
+//! Authors can mark a component whose findings are known and accepted —
+//! a third-party widget, a legacy iframe — directly in the markup:
+//!
+//! ```html
+//! <div class="chat-widget" data-auditmysite-ignore="color-contrast,region">…</div>
+//! ```
+//!
+//! Every violation or warning whose element is the annotated element or lies
+//! inside it is moved to `WcagResults::suppressed` when one of the listed
+//! entries matches its axe rule ID or WCAG criterion. An empty value or `*`
+//! matches every rule. Suppressed findings keep the annotation value and the
+//! location of the annotated element so the JSON shows why they are missing
+//! from `findings`.
+
+use chromiumoxide::cdp::browser_protocol::dom::{BackendNodeId, ResolveNodeParams};
+use chromiumoxide::cdp::js_protocol::runtime::{CallFunctionOnParams, EvaluateParams};
+use chromiumoxide::Page;
+use serde::Deserialize;
+use tracing::info;
+
+use super::tree::AXTree;
+use crate::wcag::types::{RuleOutcomeStatus, SuppressedViolation, Violation, WcagResults};
+
+/// Attribute that carries the suppression list.
+pub const IGNORE_ATTRIBUTE: &str = "data-auditmysite-ignore";
+
+/// Cheap pre-check so pages without annotations cost one evaluate call.
+const HAS_ANNOTATIONS_JS: &str = "document.querySelector('[data-auditmysite-ignore]') !== null";
+
+/// Collects `{ value, selector }` for the element and every annotated
+/// ancestor, crossing shadow-root boundaries.
+const ANNOTATED_ANCESTORS_JS: &str = r#"function () {
+    function seg(el) {
+        var tag = el.tagName.toLowerCase();
+        if (el.id) return tag + '#' + el.id;
+        var cls = (typeof el.className === 'string' && el.className.trim())
+            ? '.' + el.className.trim().split(/\s+/)[0]
+            : '';
+        return tag + cls;
+    }
+    function path(el) {
+        var parts = [];
+        for (var cur = el; cur && cur.nodeType === 1 && parts.length < 3; cur = cur.parentElement) {
+            parts.unshift(seg(cur));
+            if (cur.id) break;
+        }
+        return parts.join(' > ');
+    }
+    var out = [];
+    var el = this.nodeType === 1 ? this : this.parentElement;
+    while (el) {
+        if (el.hasAttribute && el.hasAttribute('data-auditmysite-ignore')) {
+            out.push({ value: el.getAttribute('data-auditmysite-ignore'), selector: path(el) });
+        }
+        if (el.parentElement) {
+            el = el.parentElement;
+        } else {
+            var root = el.getRootNode && el.getRootNode();
+            el = root && root.host ? root.host : null;
+        }
+    }
+    return out;
+}"#;
+
+/// One annotated element on the path from a violation's element to the root.
+#[derive(Debug, Clone, Deserialize)]
+struct Annotation {
+    value: String,
+    selector: String,
+}
+
+/// Move violations and warnings inside matching `data-auditmysite-ignore`
+/// subtrees to `results.suppressed` and update the affected rule outcomes.
+pub async fn apply_ignore_annotations(page: &Page, results: &mut WcagResults, ax_tree: &AXTree) {
+    if !page_has_annotations(page).await {
+        return;
+    }
+    let violations = suppress_annotated(page, &mut results.violations, ax_tree).await;
+    let warnings = suppress_annotated(page, &mut results.warnings, ax_tree).await;
+    if violations.is_empty() && warnings.is_empty() {
+        return;
+    }
+    info!(
+        "Suppressed {} finding(s) via {IGNORE_ATTRIBUTE}",
+        violations.len() + warnings.len()
+    );
+    reconcile_rule_outcomes(results, &violations, &warnings);
+    results.suppressed.extend(violations);
+    results.suppressed.extend(warnings);
+}
+
+/// Recount the rule outcomes touched by suppression, so a rule whose every
+/// violation was suppressed no longer reports `ViolationsFound` (and fails
+/// JUnit/EARL output). The new status follows the rule's remaining warnings
+/// and not-testable findings, as when the rule first recorded its outcome.
+pub fn reconcile_rule_outcomes(
+    results: &mut WcagResults,
+    suppressed_violations: &[SuppressedViolation],
+    suppressed_warnings: &[SuppressedViolation],
+) {
+    let belongs_to =
+        |finding: &Violation, rule_id: &str| finding.rule_id.as_deref() == Some(rule_id);
+    for outcome in &mut results.rule_outcomes {
+        let removed = suppressed_violations
+            .iter()
+            .filter(|s| s.rule_id == outcome.rule_id)
+            .count();
+        let warnings_removed = suppressed_warnings
+            .iter()
+            .any(|s| s.rule_id == outcome.rule_id);
+        if removed == 0 && !warnings_removed {
+            continue;
+        }
+        outcome.finding_count = outcome.finding_count.saturating_sub(removed);
+        if outcome.finding_count > 0
+            || !matches!(
+                outcome.status,
+                RuleOutcomeStatus::ViolationsFound | RuleOutcomeStatus::Warning
+            )
+        {
+            continue;
+        }
+        outcome.status = if results
+            .warnings
+            .iter()
+            .any(|w| belongs_to(w, &outcome.rule_id))
+        {
+            RuleOutcomeStatus::Warning
+        } else if results
+            .not_testables
+            .iter()
+            .any(|n| belongs_to(n, &outcome.rule_id))
+        {
+            RuleOutcomeStatus::ManualReviewRequired
+        } else {
+            RuleOutcomeStatus::NoViolationDetected
+        };
+    }
+}
+
+/// Remove the findings covered by an annotation from `findings` and return
+/// them as suppressed. Findings that cannot be located in the DOM
+/// (page-level checks) are never suppressed.
+pub async fn suppress_annotated(
+    page: &Page,
+    findings: &mut Vec<Violation>,
+    ax_tree: &AXTree,
+) -> Vec<SuppressedViolation> {
+    let mut suppressed = Vec::new();
+    let mut kept = Vec::with_capacity(findings.len());
+    for violation in findings.drain(..) {
+        let backend_id = ax_tree
+            .get_node(&violation.node_id)
+            .and_then(|n| n.backend_dom_node_id);
+        let annotations = match (backend_id, violation.selector.as_deref()) {
+            (Some(backend_id), _) => annotated_ancestors(page, backend_id).await,
+            // Style-based checks (contrast) carry a CSS path instead of an
+            // AX node.
+            (None, Some(selector)) => annotated_ancestors_of_selector(page, selector).await,
+            (None, None) => Vec::new(),
+        };
+        match annotations
+            .into_iter()
+            .find(|a| annotation_matches(&a.value, &violation))
+        {
+            Some(annotation) => {
+                suppressed.push(suppress(violation, annotation.value, annotation.selector))
+            }
+            None => kept.push(violation),
+        }
+    }
+    *findings = kept;
+    suppressed
+}
+
+/// Whether the comma-separated `annotation` covers `violation`.
+pub fn annotation_matches(annotation: &str, violation: &Violation) -> bool {
+    let entries: Vec<&str> = annotation
+        .split(',')
+        .map(str::trim)
+        .filter(|e| !e.is_empty())
+        .collect();
+    entries.is_empty()
+        || entries.iter().any(|entry| {
+            *entry == "*"
+                || *entry == violation.rule
+                || violation
+                    .rule_id
+                    .as_deref()
+                    .is_some_and(|id| id.eq_ignore_ascii_case(entry))
+        })
+}
+
+fn suppress(
+    violation: Violation,
+    annotation: String,
+    annotated_element: String,
+) -> SuppressedViolation {
+    SuppressedViolation {
+        rule_id: violation.rule_id.unwrap_or_default(),
+        rule: violation.rule,
+        node_id: violation.node_id,
+        selector: violation.selector,
+        severity: violation.severity,
+        message: violation.message,
+        annotation,
+        annotated_element,
+    }
+}
+
+/// Whether any element on the page carries [`IGNORE_ATTRIBUTE`].
+pub async fn page_has_annotations(page: &Page) -> bool {
+    let Ok(params) = EvaluateParams::builder()
+        .expression(HAS_ANNOTATIONS_JS)
+        .return_by_value(true)
+        .build()
+    else {
+        return false;
+    };
+    page.execute(params)
+        .await
+        .ok()
+        .and_then(|r| r.result.result.value.clone())
+        .and_then(|v| v.as_bool())
+        .unwrap_or(false)
+}
+
+async fn annotated_ancestors(page: &Page, backend_node_id: i64) -> Vec<Annotation> {
+    let resolve = ResolveNodeParams::builder()
+        .backend_node_id(BackendNodeId::new(backend_node_id))
+        .build();
+    let Some(object_id) = page
+        .execute(resolve)
+        .await
+        .ok()
+        .and_then(|r| r.result.object.object_id.clone())
+    else {
+        return Vec::new();
+    };
+    let Ok(call) = CallFunctionOnParams::builder()
+        .function_declaration(ANNOTATED_ANCESTORS_JS)
+        .object_id(object_id)
+        .return_by_value(true)
+        .build()
+    else {
+        return Vec::new();
+    };
+    page.execute(call)
+        .await
+        .ok()
+        .and_then(|r| r.result.result.value.clone())
+        .and_then(|v| serde_json::from_value(v).ok())
+        .unwrap_or_default()
+}
+
+async fn annotated_ancestors_of_selector(page: &Page, selector: &str) -> Vec<Annotation> {
+    let Ok(selector) = serde_json::to_string(selector) else {
+        return Vec::new();
+    };
+    // Invalid selectors (descriptive ones like `img [src: …]`) throw and
+    // simply match nothing.
+    let expression = format!(
+        "(function () {{ var el = null; try {{ el = document.querySelector({selector}); }} catch (e) {{}} return el ? ({ANNOTATED_ANCESTORS_JS}).call(el) : []; }})()"
+    );
+    let Ok(params) = EvaluateParams::builder()
+        .expression(expression)
+        .return_by_value(true)
+        .build()
+    else {
+        return Vec::new();
+    };
+    page.execute(params)
+        .await
+        .ok()
+        .and_then(|r| r.result.result.value.clone())
+        .and_then(|v| serde_json::from_value(v).ok())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::WcagLevel;
+    use crate::wcag::Severity;
+
+    #[test]
+    fn matches_axe_ids_criteria_and_wildcards() {
+        let violation = Violation::new(
+            "1.4.3",
+            "Contrast (Minimum)",
+            WcagLevel::AA,
+            Severity::Medium,
+            "Low contrast",
+            "n1",
+        )
+        .with_rule_id("color-contrast");
+
+        assert!(annotation_matches("color-contrast,region", &violation));
+        assert!(annotation_matches(" region , 1.4.3 ", &violation));
+        assert!(annotation_matches("*", &violation));
+        assert!(annotation_matches("", &violation));
+        assert!(!annotation_matches("region,image-alt", &violation));
+    }
+
+    #[test]
+    fn fully_suppressed_rule_outcome_flips_to_passed() {
+        let contrast = |node: &str| {
+            Violation::new(
+                "1.4.3",
+                "Contrast (Minimum)",
+                WcagLevel::AA,
+                Severity::Medium,
+                "Low contrast",
+                node,
+            )
+            .with_rule_id("color-contrast")
+        };
+        let outcome = |rule_id: &str, finding_count: usize| crate::wcag::RuleOutcome {
+            rule_id: rule_id.to_string(),
+            status: RuleOutcomeStatus::ViolationsFound,
+            wcag_criterion: None,
+            viewport: None,
+            reason_code: None,
+            finding_count,
+        };
+        let mut results = WcagResults::new();
+        results.add_violation(contrast("n1"));
+        results.add_violation(
+            Violation::new(
+                "1.1.1",
+                "Non-text Content",
+                WcagLevel::A,
+                Severity::High,
+                "Image without alt",
+                "n2",
+            )
+            .with_rule_id("image-alt"),
+        );
+        results.rule_outcomes = vec![outcome("color-contrast", 2), outcome("image-alt", 1)];
+        let widget = |node: &str| {
+            vec![suppress(
+                contrast(node),
+                "color-contrast".to_string(),
+                "div.chat-widget".to_string(),
+            )]
+        };
+
+        // n3 is already gone from `violations`; n1 is still reported.
+        reconcile_rule_outcomes(&mut results, &widget("n3"), &[]);
+        assert_eq!(results.rule_outcomes[0].finding_count, 1);
+        assert_eq!(
+            results.rule_outcomes[0].status,
+            RuleOutcomeStatus::ViolationsFound
+        );
+
+        results.violations.retain(|v| v.node_id != "n1");
+        reconcile_rule_outcomes(&mut results, &widget("n1"), &[]);
+        let contrast_outcome = &results.rule_outcomes[0];
+        assert_eq!(contrast_outcome.finding_count, 0);
+        assert_eq!(
+            contrast_outcome.status,
+            RuleOutcomeStatus::NoViolationDetected
+        );
+        assert_eq!(
+            results.rule_outcomes[1].status,
+            RuleOutcomeStatus::ViolationsFound
+        );
+    }
+}
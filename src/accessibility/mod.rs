@@ -2,6 +2,7 @@
 //!
 //! Provides AXTree extraction and accessibility-related utilities.
 
+pub mod annotations;
 pub(crate) mod code_gen;
 pub mod diff;
 mod element_capture;
@@ -9,13 +10,16 @@ mod enrichment;
 mod extractor;
 pub(crate) mod js_helpers;
 pub mod snapshot;
+pub mod source_patch;
 mod styles;
 mod tree;
 
+pub use annotations::{apply_ignore_annotations, IGNORE_ATTRIBUTE};
 pub use diff::{AXTreeDiff, FocusMove, PropertyChange};
 pub use element_capture::{capture_element_evidence, ElementEvidenceBudget, MAX_ELEMENT_CROPS};
 pub use enrichment::enrich_violations_with_page;
 pub use extractor::extract_ax_tree;
 pub use snapshot::{AXSnapshot, FocusIndicatorStatus, FocusSnapshot, Rect};
+pub use source_patch::{suggest_source_patches, PatchSuggestion, SourcePatchSet};
 pub use styles::{extract_text_styles, ComputedStyles};
 pub use tree::{AXNode, AXProperty, AXTree, AXValue, NameSource, RelatedNode};
@@ -0,0 +1,648 @@
+//! Fix patches against local template sources (`--source-root`).
+//!
+//! [`code_gen`](super::code_gen) fixes that only add an attribute to the
+//! element's opening tag (`alt`, `aria-label`, `lang`, `autocomplete`, …) are
+//! mapped back to the markup that rendered the element: every HTML, JSX,
+//! Vue, Svelte and Astro file under the source root is scanned for opening
+//! tags with the same name, and the tag sharing the most literal attributes
+//! with the rendered `html_snippet` wins. Ties and tags without any shared
+//! attribute are left alone — a wrong patch is worse than none. The result
+//! renders as a unified diff that `git apply` accepts; inserted attribute
+//! values are the same placeholders `suggested_code` uses and must be
+//! reviewed before the patch is applied.
+
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::path::{Path, PathBuf};
+
+use super::code_gen::generate_suggested_code;
+use crate::audit::normalized::NormalizedReport;
+use crate::error::{AuditError, Result};
+
+/// Template file extensions that are scanned.
+const SOURCE_EXTENSIONS: &[&str] = &["html", "htm", "jsx", "tsx", "js", "vue", "svelte", "astro"];
+
+/// Directories never scanned (dependencies, build output, VCS metadata).
+const SKIPPED_DIRS: &[&str] = &[
+    "node_modules",
+    "dist",
+    "build",
+    "target",
+    "vendor",
+    "coverage",
+];
+
+/// Files larger than this are assumed to be bundles, not templates.
+const MAX_SOURCE_BYTES: u64 = 1024 * 1024;
+
+/// Lines of context around each hunk, as in `git diff`.
+const CONTEXT_LINES: usize = 3;
+
+/// Rendered attributes that never appear literally in templates.
+const IGNORED_ATTRIBUTES: &[&str] = &["style"];
+
+/// One attribute insertion located in a source file.
+#[derive(Debug, Clone)]
+pub struct PatchSuggestion {
+    pub rule_id: String,
+    pub wcag_criterion: String,
+    pub title: String,
+    /// Path relative to the source root.
+    pub file: PathBuf,
+    /// 1-based line of the opening tag.
+    pub line: usize,
+    /// Inserted `name="value"` text.
+    pub attribute: String,
+    /// Audited pages the element appeared on.
+    pub pages: Vec<String>,
+    offset: usize,
+}
+
+/// Patch suggestions for an audit, plus how many fixable occurrences could
+/// not be located unambiguously.
+#[derive(Debug, Clone, Default)]
+pub struct SourcePatchSet {
+    /// Source root as given on the command line.
+    pub source_root: PathBuf,
+    pub suggestions: Vec<PatchSuggestion>,
+    pub unmatched: usize,
+    sources: BTreeMap<PathBuf, String>,
+    /// Source root relative to the directory `git apply` runs in; prefixed
+    /// to the diff paths.
+    diff_prefix: PathBuf,
+}
+
+impl SourcePatchSet {
+    pub fn is_empty(&self) -> bool {
+        self.suggestions.is_empty()
+    }
+
+    /// Render all suggestions as one unified diff, one file section per
+    /// source file. The leading `#` comment lines are ignored by `git apply`.
+    pub fn to_unified_diff(&self) -> String {
+        let mut out = String::new();
+        out.push_str("# auditmysite fix suggestions — generated, not verified.\n");
+        out.push_str("# Inserted attribute values are placeholders: replace them with real text\n");
+        out.push_str("# (or drop the hunk) before running `git apply`.\n");
+
+        let mut by_file: BTreeMap<&Path, Vec<&PatchSuggestion>> = BTreeMap::new();
+        for suggestion in &self.suggestions {
+            by_file
+                .entry(suggestion.file.as_path())
+                .or_default()
+                .push(suggestion);
+        }
+        for (file, suggestions) in by_file {
+            let Some(source) = self.sources.get(file) else {
+                continue;
+            };
+            out.push('\n');
+            for s in &suggestions {
+                let _ = writeln!(
+                    out,
+                    "# {}:{} — WCAG {} {}: add {} ({})",
+                    file.display(),
+                    s.line,
+                    s.wcag_criterion,
+                    s.title,
+                    s.attribute,
+                    s.pages.join(", ")
+                );
+            }
+            let path = diff_path(&self.diff_prefix, file);
+            let _ = writeln!(out, "diff --git a/{path} b/{path}");
+            let _ = writeln!(out, "--- a/{path}");
+            let _ = writeln!(out, "+++ b/{path}");
+            let edits: Vec<(usize, String)> = suggestions
+                .iter()
+                .map(|s| (s.offset, format!(" {}", s.attribute)))
+                .collect();
+            out.push_str(&render_hunks(source, &edits));
+        }
+        out
+    }
+}
+
+/// Locate the attribute fixes of `reports` in the templates under
+/// `source_root`.
+pub fn suggest_source_patches(
+    reports: &[&NormalizedReport],
+    source_root: &Path,
+) -> Result<SourcePatchSet> {
+    let mut files = Vec::new();
+    collect_source_files(source_root, source_root, &mut files)?;
+    let sources: Vec<(PathBuf, String)> = files
+        .into_iter()
+        .filter_map(|rel| {
+            let text = std::fs::read_to_string(source_root.join(&rel)).ok()?;
+            Some((rel, text))
+        })
+        .collect();
+
+    let mut set = SourcePatchSet {
+        source_root: source_root.to_path_buf(),
+        diff_prefix: diff_prefix(source_root, std::env::current_dir().ok().as_deref()),
+        ..Default::default()
+    };
+    for report in reports {
+        for finding in report.findings.iter().filter(|f| f.category == "wcag") {
+            for occurrence in &finding.occurrences {
+                let Some(snippet) = occurrence.html_snippet.as_deref() else {
+                    continue;
+                };
+                let suggested = occurrence.suggested_code.clone().or_else(|| {
+                    generate_suggested_code(&finding.wcag_criterion, Some(snippet), None, None)
+                });
+                let Some((name, value)) = suggested
+                    .as_deref()
+                    .and_then(|code| added_attribute(snippet, code))
+                else {
+                    continue;
+                };
+                let Some(rendered) = parse_open_tag(snippet) else {
+                    continue;
+                };
+                let Some((file_idx, offset)) = locate_tag(&sources, &rendered, &name) else {
+                    set.unmatched += 1;
+                    continue;
+                };
+                let (file, text) = &sources[file_idx];
+                if let Some(existing) = set.suggestions.iter_mut().find(|s| {
+                    &s.file == file
+                        && s.offset == offset
+                        && s.attribute.starts_with(&attribute_name_for(file, &name))
+                }) {
+                    if !existing.pages.contains(&report.url) {
+                        existing.pages.push(report.url.clone());
+                    }
+                    continue;
+                }
+                let attribute = format!("{}=\"{}\"", attribute_name_for(file, &name), value);
+                set.suggestions.push(PatchSuggestion {
+                    rule_id: finding.rule_id.clone(),
+                    wcag_criterion: finding.wcag_criterion.clone(),
+                    title: finding.title.clone(),
+                    file: file.clone(),
+                    line: text[..offset].matches('\n').count() + 1,
+                    attribute,
+                    pages: vec![report.url.clone()],
+                    offset,
+                });
+            }
+        }
+    }
+    set.suggestions
+        .sort_by(|a, b| a.file.cmp(&b.file).then(a.offset.cmp(&b.offset)));
+    for suggestion in &set.suggestions {
+        if let Some((file, text)) = sources.iter().find(|(f, _)| *f == suggestion.file) {
+            set.sources.insert(file.clone(), text.clone());
+        }
+    }
+    Ok(set)
+}
+
+fn collect_source_files(root: &Path, dir: &Path, out: &mut Vec<PathBuf>) -> Result<()> {
+    let entries = std::fs::read_dir(dir).map_err(|e| AuditError::FileError {
+        path: dir.to_path_buf(),
+        reason: e.to_string(),
+    })?;
+    let mut paths: Vec<PathBuf> = entries.filter_map(|e| e.ok().map(|e| e.path())).collect();
+    paths.sort();
+    for path in paths {
+        let name = path
+            .file_name()
+            .and_then(|n| n.to_str())
+            .unwrap_or_default();
+        if path.is_dir() {
+            if !name.starts_with('.') && !SKIPPED_DIRS.contains(&name) {
+                collect_source_files(root, &path, out)?;
+            }
+            continue;
+        }
+        let ext = path
+            .extension()
+            .and_then(|e| e.to_str())
+            .unwrap_or_default();
+        let small = path
+            .metadata()
+            .map(|m| m.len() <= MAX_SOURCE_BYTES)
+            .unwrap_or(false);
+        if SOURCE_EXTENSIONS.contains(&ext) && !name.contains(".min.") && small {
+            if let Ok(rel) = path.strip_prefix(root) {
+                out.push(rel.to_path_buf());
+            }
+        }
+    }
+    Ok(())
+}
+
+/// The single `name="value"` that `suggested` adds to the opening tag of
+/// `snippet`, if that is the only change.
+fn added_attribute(snippet: &str, suggested: &str) -> Option<(String, String)> {
+    let snippet_end = snippet.find('>')?;
+    let suggested_end = suggested.find('>')?;
+    if snippet[snippet_end..] != suggested[suggested_end..] {
+        return None;
+    }
+    let before = &snippet[..snippet_end];
+    let after = &suggested[..suggested_end];
+    let prefix = before
+        .bytes()
+        .zip(after.bytes())
+        .take_while(|(a, b)| a == b)
+        .count();
+    let suffix = before[prefix..]
+        .bytes()
+        .rev()
+        .zip(after[prefix..].bytes().rev())
+        .take_while(|(a, b)| a == b)
+        .count();
+    if prefix + suffix != before.len() {
+        return None;
+    }
+    let inserted = after[prefix..after.len() - suffix].trim();
+    let (name, value) = inserted.split_once("=\"")?;
+    let value = value.strip_suffix('"')?;
+    let valid_name =
+        !name.is_empty() && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '-');
+    (valid_name && !value.contains('"')).then(|| (name.to_string(), value.to_string()))
+}
+
+/// Tag name and attributes of a rendered opening tag.
+struct OpenTag {
+    name: String,
+    attributes: Vec<(String, String)>,
+}
+
+fn parse_open_tag(html: &str) -> Option<OpenTag> {
+    let rest = html.trim_start().strip_prefix('<')?;
+    let end = rest.find('>')?;
+    let inner = rest[..end].trim_end_matches('/');
+    let name_len = inner
+        .find(|c: char| c.is_whitespace())
+        .unwrap_or(inner.len());
+    let name = inner[..name_len].to_ascii_lowercase();
+    if name.is_empty() {
+        return None;
+    }
+    let mut attributes = Vec::new();
+    let mut chars = inner[name_len..].trim_start();
+    while !chars.is_empty() {
+        let key_len = chars
+            .find(|c: char| c == '=' || c.is_whitespace())
+            .unwrap_or(chars.len());
+        let key = chars[..key_len].to_ascii_lowercase();
+        chars = chars[key_len..].trim_start();
+        let mut value = String::new();
+        if let Some(after_eq) = chars.strip_prefix('=') {
+            let after_eq = after_eq.trim_start();
+            let quote = after_eq.chars().next().filter(|c| *c == '"' || *c == '\'');
+            let (val, rest) = match quote {
+                Some(q) => {
+                    let body = &after_eq[1..];
+                    let close = body.find(q).unwrap_or(body.len());
+                    (&body[..close], body.get(close + 1..).unwrap_or_default())
+                }
+                None => {
+                    let close = after_eq.find(char::is_whitespace).unwrap_or(after_eq.len());
+                    (&after_eq[..close], &after_eq[close..])
+                }
+            };
+            value = val.to_string();
+            chars = rest.trim_start();
+        }
+        if !key.is_empty() {
+            attributes.push((key, value));
+        }
+    }
+    Some(OpenTag { name, attributes })
+}
+
+/// Best-matching opening tag across `sources` as `(file index, offset just
+/// after the tag name)`. `None` when nothing matches or the best score is
+/// shared by several tags.
+fn locate_tag(
+    sources: &[(PathBuf, String)],
+    rendered: &OpenTag,
+    adding: &str,
+) -> Option<(usize, usize)> {
+    let distinctive: Vec<&(String, String)> = rendered
+        .attributes
+        .iter()
+        .filter(|(k, v)| !v.is_empty() && !IGNORED_ATTRIBUTES.contains(&k.as_str()))
+        .collect();
+    // Without distinctive attributes only a page-unique tag (`<html>`) can be
+    // matched safely.
+    let min_score = if distinctive.is_empty() {
+        if rendered.name != "html" {
+            return None;
+        }
+        0
+    } else {
+        1
+    };
+
+    let mut best: Option<(usize, usize, usize)> = None;
+    let mut tied = false;
+    for (idx, (file, text)) in sources.iter().enumerate() {
+        for (start, tag) in open_tags(text, &rendered.name) {
+            if has_attribute(tag, adding) || has_attribute(tag, &attribute_name_for(file, adding)) {
+                continue;
+            }
+            let score = distinctive
+                .iter()
+                .filter(|(k, v)| source_has_literal(tag, k, v))
+                .count();
+            if score < min_score {
+                continue;
+            }
+            match best {
+                Some((best_score, _, _)) if score < best_score => {}
+                Some((best_score, _, _)) if score == best_score => tied = true,
+                _ => {
+                    best = Some((score, idx, start + 1 + rendered.name.len()));
+                    tied = false;
+                }
+            }
+        }
+    }
+    if tied {
+        return None;
+    }
+    best.map(|(_, idx, offset)| (idx, offset))
+}
+
+/// Opening tags named `name` in `text` as `(offset of '<', tag text)`.
+/// Quotes and JSX `{…}` expressions are skipped when looking for the
+/// closing `>`.
+fn open_tags<'a>(text: &'a str, name: &str) -> Vec<(usize, &'a str)> {
+    let needle = format!("<{name}");
+    let mut tags = Vec::new();
+    let mut from = 0;
+    while let Some(pos) = text[from..].find(&needle) {
+        let start = from + pos;
+        from = start + needle.len();
+        let boundary = text[from..].chars().next();
+        if !matches!(boundary, Some(c) if c.is_whitespace() || c == '>' || c == '/') {
+            continue;
+        }
+        let mut quote = None;
+        let mut depth = 0usize;
+        let mut end = None;
+        for (i, c) in text[from..].char_indices() {
+            match (quote, c) {
+                (Some(q), c) if c == q => quote = None,
+                (Some(_), _) => {}
+                (None, '"' | '\'' | '`') => quote = Some(c),
+                (None, '{') => depth += 1,
+                (None, '}') => depth = depth.saturating_sub(1),
+                (None, '>') if depth == 0 => {
+                    end = Some(from + i + 1);
+                    break;
+                }
+                _ => {}
+            }
+        }
+        if let Some(end) = end {
+            tags.push((start, &text[start..end]));
+        }
+    }
+    tags
+}
+
+/// Whether the source tag sets `attr` in any syntax (`alt=`, `:alt=`,
+/// `v-bind:alt=`, `alt={…}`, bare `alt`).
+fn has_attribute(tag: &str, attr: &str) -> bool {
+    let lower = tag.to_ascii_lowercase();
+    let attr = attr.to_ascii_lowercase();
+    lower.match_indices(&attr).any(|(i, _)| {
+        let before = lower[..i].chars().next_back();
+        let after = lower[i + attr.len()..].chars().next();
+        matches!(before, Some(c) if c.is_whitespace() || c == ':')
+            && matches!(after, Some(c) if c == '=' || c == '>' || c == '/' || c.is_whitespace())
+    })
+}
+
+fn source_has_literal(tag: &str, key: &str, value: &str) -> bool {
+    let keys: &[&str] = match key {
+        "class" => &["class", "className"],
+        "for" => &["for", "htmlFor"],
+        _ => &[key],
+    };
+    keys.iter().any(|k| {
+        tag.contains(&format!("{k}=\"{value}\"")) || tag.contains(&format!("{k}='{value}'"))
+    })
+}
+
+/// JSX spells some DOM attributes in camelCase.
+fn attribute_name_for(file: &Path, name: &str) -> String {
+    let jsx = matches!(
+        file.extension().and_then(|e| e.to_str()),
+        Some("jsx" | "tsx" | "js")
+    );
+    match (jsx, name) {
+        (true, "tabindex") => "tabIndex".to_string(),
+        (true, "autocomplete") => "autoComplete".to_string(),
+        _ => name.to_string(),
+    }
+}
+
+/// Where the source root sits relative to the enclosing git worktree, or
+/// else to `cwd`, so the patch applies from there whether `--source-root`
+/// was absolute or relative. A root outside both keeps paths relative to
+/// itself (`git apply --directory` can re-anchor them).
+fn diff_prefix(source_root: &Path, cwd: Option<&Path>) -> PathBuf {
+    let absolute = match cwd {
+        Some(cwd) if source_root.is_relative() => cwd.join(source_root),
+        _ => source_root.to_path_buf(),
+    };
+    let absolute = absolute.canonicalize().unwrap_or(absolute);
+    let worktree = absolute
+        .ancestors()
+        .find(|dir| dir.join(".git").exists())
+        .map(Path::to_path_buf);
+    let cwd = cwd.map(|cwd| cwd.canonicalize().unwrap_or_else(|_| cwd.to_path_buf()));
+    worktree
+        .into_iter()
+        .chain(cwd)
+        .find_map(|base| absolute.strip_prefix(&base).ok().map(Path::to_path_buf))
+        .unwrap_or_default()
+}
+
+fn diff_path(prefix: &Path, file: &Path) -> String {
+    prefix.join(file).to_string_lossy().replace('\\', "/")
+}
+
+/// Unified-diff hunks inserting `edits` (`(byte offset, text)`) into
+/// `source`. Edits closer than two context windows share a hunk.
+fn render_hunks(source: &str, edits: &[(usize, String)]) -> String {
+    let lines: Vec<&str> = source.split_inclusive('\n').collect();
+    let mut line_starts = Vec::with_capacity(lines.len());
+    let mut pos = 0;
+    for line in &lines {
+        line_starts.push(pos);
+        pos += line.len();
+    }
+
+    let mut changed: BTreeMap<usize, Vec<(usize, &str)>> = BTreeMap::new();
+    for (offset, text) in edits {
+        let line = line_starts.partition_point(|start| start <= offset) - 1;
+        changed
+            .entry(line)
+            .or_default()
+            .push((offset - line_starts[line], text.as_str()));
+    }
+
+    let mut groups: Vec<Vec<usize>> = Vec::new();
+    for &line in changed.keys() {
+        match groups.last_mut() {
+            Some(group) if line - group[group.len() - 1] <= 2 * CONTEXT_LINES => group.push(line),
+            _ => groups.push(vec![line]),
+        }
+    }
+
+    let mut out = String::new();
+    for group in groups {
+        let first = group[0].saturating_sub(CONTEXT_LINES);
+        let last = (group[group.len() - 1] + CONTEXT_LINES).min(lines.len() - 1);
+        let count = last - first + 1;
+        let _ = writeln!(out, "@@ -{},{count} +{},{count} @@", first + 1, first + 1);
+        for (idx, line) in lines.iter().enumerate().take(last + 1).skip(first) {
+            match changed.get(&idx) {
+                Some(inserts) => {
+                    let mut new_line = line.to_string();
+                    let mut inserts = inserts.clone();
+                    inserts.sort_by_key(|(col, _)| std::cmp::Reverse(*col));
+                    for (col, text) in inserts {
+                        new_line.insert_str(col, text);
+                    }
+                    push_diff_line(&mut out, '-', line);
+                    push_diff_line(&mut out, '+', &new_line);
+                }
+                None => push_diff_line(&mut out, ' ', line),
+            }
+        }
+    }
+    out
+}
+
+fn push_diff_line(out: &mut String, marker: char, line: &str) {
+    out.push(marker);
+    out.push_str(line);
+    if !line.ends_with('\n') {
+        out.push_str("\n\\ No newline at end of file\n");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::audit::{normalize, AuditReport};
+    use crate::cli::WcagLevel;
+    use crate::wcag::{Severity, Violation, WcagResults};
+
+    fn report_with(violations: &[(&str, &str, &str)]) -> NormalizedReport {
+        let mut results = WcagResults::new();
+        for (i, (criterion, rule_id, snippet)) in violations.iter().enumerate() {
+            results.add_violation(
+                Violation::new(
+                    *criterion,
+                    "Issue",
+                    WcagLevel::A,
+                    Severity::High,
+                    "Issue",
+                    format!("node-{i}"),
+                )
+                .with_rule_id(*rule_id)
+                .with_selector(format!("#el-{i}"))
+                .with_html_snippet(*snippet),
+            );
+        }
+        let report = AuditReport::new(
+            "https://example.com/".to_string(),
+            WcagLevel::AA,
+            results,
+            1,
+        );
+        normalize(&report).normalized
+    }
+
+    #[test]
+    fn extracts_single_added_attribute() {
+        assert_eq!(
+            added_attribute(
+                r#"<img src="/hero.jpg">"#,
+                r#"<img src="/hero.jpg" alt="Image description">"#
+            ),
+            Some(("alt".to_string(), "Image description".to_string()))
+        );
+        // Label wrappers and comments are not attribute-only fixes.
+        assert_eq!(
+            added_attribute(
+                r#"<input id="q">"#,
+                "<label for=\"q\">Field label</label>\n<input id=\"q\">"
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn patches_matching_templates_and_skips_ambiguous_tags() {
+        let root = tempfile::tempdir().unwrap();
+        std::fs::create_dir_all(root.path().join("components")).unwrap();
+        std::fs::write(
+            root.path().join("components/Hero.jsx"),
+            "export function Hero() {\n  return (\n    <div className=\"hero\">\n      <img src=\"/hero.jpg\" className=\"hero-img\" />\n    </div>\n  );\n}\n",
+        )
+        .unwrap();
+        std::fs::write(
+            root.path().join("index.html"),
+            "<!doctype html>\n<html>\n<body><button class=\"icon\"></button><button class=\"icon\"></button></body>\n</html>",
+        )
+        .unwrap();
+        std::fs::create_dir_all(root.path().join("node_modules/lib")).unwrap();
+        std::fs::write(
+            root.path().join("node_modules/lib/x.html"),
+            "<img src=\"/hero.jpg\">",
+        )
+        .unwrap();
+
+        let report = report_with(&[
+            (
+                "1.1.1",
+                "image-alt",
+                r#"<img src="/hero.jpg" class="hero-img">"#,
+            ),
+            ("3.1.1", "html-has-lang", "<html>"),
+            ("4.1.2", "button-name", r#"<button class="icon"></button>"#),
+        ]);
+        let set = suggest_source_patches(&[&report], root.path()).unwrap();
+
+        assert_eq!(set.suggestions.len(), 2);
+        assert_eq!(set.unmatched, 1, "two identical buttons are ambiguous");
+        let diff = set.to_unified_diff();
+        assert!(diff.contains("+      <img alt=\"Image description\" src=\"/hero.jpg\""));
+        assert!(diff.contains("+<html lang=\"de\">\n"));
+        assert!(diff.contains(" </html>\n\\ No newline at end of file\n"));
+        assert!(!diff.contains("node_modules"));
+    }
+
+    #[test]
+    fn diff_paths_are_relative_to_the_worktree_for_absolute_and_relative_roots() {
+        let repo = tempfile::tempdir().unwrap();
+        std::fs::create_dir_all(repo.path().join(".git")).unwrap();
+        std::fs::create_dir_all(repo.path().join("web/src")).unwrap();
+        let file = Path::new("pages/index.html");
+
+        let absolute = diff_prefix(&repo.path().join("web/src"), None);
+        assert_eq!(diff_path(&absolute, file), "web/src/pages/index.html");
+
+        let relative = diff_prefix(Path::new("src"), Some(&repo.path().join("web")));
+        assert_eq!(diff_path(&relative, file), "web/src/pages/index.html");
+
+        let elsewhere = tempfile::tempdir().unwrap();
+        std::fs::create_dir_all(elsewhere.path().join("site")).unwrap();
+        let cwd_relative = diff_prefix(&elsewhere.path().join("site"), Some(elsewhere.path()));
+        assert_eq!(diff_path(&cwd_relative, file), "site/pages/index.html");
+    }
+}
@@ -18,9 +18,18 @@
 //!
 //! Hash stability: FNV-1a 64-bit (deterministic across processes and platforms).
 //! DefaultHasher is explicitly NOT used — it is non-deterministic by design.
+//!
+//! Batch run checkpoints reuse the `{domain}/{url_hash}/` layout under a run
+//! directory instead of the cache root:
+//!   {run_dir}/run.json                  — RunManifest (signature + URL list)
+//!   {run_dir}/{domain}/{url_hash}/
+//!     audit.json          — NormalizedReport
+//!     screen_reader.json  — SrAuditReport (not part of the serialized report)
+//!     dual_viewport.json  — DualViewportResults without screenshots (ditto)
+//!     report.json         — AuditReport; written last, marks the URL complete
 
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
@@ -31,7 +40,7 @@ use crate::audit::normalized::NormalizedReport;
 use crate::audit::PerformanceResults;
 use crate::audit::{AccessibilityScorer, AuditReport};
 use crate::cli::WcagLevel;
-use crate::error::Result;
+use crate::error::{AuditError, Result};
 use crate::mobile::MobileFriendliness;
 use crate::security::SecurityAnalysis;
 use crate::seo::SeoAnalysis;
@@ -249,6 +258,7 @@ pub fn content_hash(snapshot: &SnapshotArtifact) -> String {
 pub fn to_audit_report(artifacts: &AuditArtifacts, locale: &str) -> AuditReport {
     let mut wcag_results = WcagResults::new();
     wcag_results.nodes_checked = artifacts.snapshot.ax_tree.len();
+    wcag_results.suppressed = artifacts.audit.suppressed_findings.clone();
 
     for finding in &artifacts.audit.findings {
         let level = parse_wcag_level(&finding.wcag_level);
@@ -284,6 +294,7 @@ pub fn to_audit_report(artifacts: &AuditArtifacts, locale: &str) -> AuditReport
             statistics: stats,
             nodes_analyzed: artifacts.audit.nodes_analyzed,
             execution: artifacts.audit.execution.clone(),
+            waived: artifacts.audit.waived_findings.clone(),
         },
         duration_ms: artifacts.audit.duration_ms,
         performance: artifacts.snapshot.performance.clone(),
@@ -304,6 +315,7 @@ pub fn to_audit_report(artifacts: &AuditArtifacts, locale: &str) -> AuditReport
         page_screenshots: None,
         dual_viewport: None,
         viewport_scores: None,
+        device_results: artifacts.audit.device_results.clone(),
         throttled_performance: Vec::new(),
         patterns: None,
         screenshot_status: Default::default(),
@@ -322,6 +334,7 @@ pub fn to_audit_report(artifacts: &AuditArtifacts, locale: &str) -> AuditReport
             locale,
             None,
         )),
+        rendered_links: Vec::new(),
     };
 
     if report.wcag_level != artifacts.audit.wcag_level {
@@ -348,12 +361,133 @@ pub fn hydrate_cached_report(report: &mut AuditReport, snapshot: &SnapshotArtifa
     }
 }
 
+// ─── Batch run checkpoints ────────────────────────────────────────────────────
+
+/// Written once at the root of a batch run directory.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RunManifest {
+    pub auditmysite_version: String,
+    /// `PipelineConfig::audit_signature` of the run — a resume with a
+    /// different audit scope is rejected.
+    pub audit_signature: String,
+    pub created_at: DateTime<Utc>,
+    /// URLs the run was started with, in input order.
+    pub urls: Vec<String>,
+}
+
+/// Default checkpoint directory for a new batch run:
+/// `~/.auditmysite/runs/{YYYYmmdd-HHMMSS}-{pid}/`.
+pub fn default_run_dir() -> PathBuf {
+    dirs::home_dir()
+        .unwrap_or_else(|| PathBuf::from("."))
+        .join(".auditmysite")
+        .join("runs")
+        .join(format!(
+            "{}-{}",
+            Utc::now().format("%Y%m%d-%H%M%S"),
+            std::process::id()
+        ))
+}
+
+/// Create a run directory, or reopen an existing one for resuming.
+///
+/// Fails when the directory belongs to a run with a different audit
+/// signature, since mixing its pages with fresh ones would aggregate
+/// incomparable results.
+pub fn open_run_dir(dir: &Path, audit_signature: &str, urls: &[String]) -> Result<RunManifest> {
+    let manifest_path = dir.join("run.json");
+    if manifest_path.exists() {
+        let manifest: RunManifest = serde_json::from_slice(&fs::read(&manifest_path)?)?;
+        if manifest.audit_signature != audit_signature {
+            return Err(AuditError::ConfigError(format!(
+                "Run directory {} was created with a different audit configuration ({}); \
+                 resume with the original flags or start a new run",
+                dir.display(),
+                manifest.audit_signature
+            )));
+        }
+        if manifest.urls != urls {
+            warn!(
+                "URL list differs from the one {} was started with; completed URLs are still reused",
+                dir.display()
+            );
+        }
+        return Ok(manifest);
+    }
+
+    fs::create_dir_all(dir)?;
+    let manifest = RunManifest {
+        auditmysite_version: env!("CARGO_PKG_VERSION").to_string(),
+        audit_signature: audit_signature.to_string(),
+        created_at: Utc::now(),
+        urls: urls.to_vec(),
+    };
+    fs::write(&manifest_path, serde_json::to_vec_pretty(&manifest)?)?;
+    Ok(manifest)
+}
+
+/// Checkpoint one completed page of a batch run.
+pub fn save_run_result(dir: &Path, url: &str, report: &AuditReport) -> Result<PathBuf> {
+    let entry = url_entry_dir(dir, url)?;
+    fs::create_dir_all(&entry)?;
+
+    fs::write(
+        entry.join("audit.json"),
+        serde_json::to_vec_pretty(&crate::audit::normalize(report).normalized)?,
+    )?;
+    if let Some(ref sr) = report.screen_reader_audit {
+        fs::write(entry.join("screen_reader.json"), serde_json::to_vec(sr)?)?;
+    }
+    if let Some(ref dual) = report.dual_viewport {
+        fs::write(entry.join("dual_viewport.json"), serde_json::to_vec(dual)?)?;
+    }
+    // report.json marks the page as complete, so it goes last and is renamed
+    // into place — a run killed mid-write leaves no half-written marker.
+    let tmp = entry.join("report.json.tmp");
+    fs::write(&tmp, serde_json::to_vec(report)?)?;
+    fs::rename(&tmp, entry.join("report.json"))?;
+
+    Ok(entry)
+}
+
+/// The checkpointed report for `url`, or `None` when the page has not been
+/// completed (or its checkpoint is unreadable) and must be audited again.
+pub fn load_run_result(dir: &Path, url: &str) -> Option<AuditReport> {
+    let entry = url_entry_dir(dir, url).ok()?;
+    let report_path = entry.join("report.json");
+    if !report_path.exists() {
+        return None;
+    }
+    let mut report: AuditReport = match fs::read(&report_path)
+        .map_err(|e| e.to_string())
+        .and_then(|bytes| serde_json::from_slice(&bytes).map_err(|e| e.to_string()))
+    {
+        Ok(report) => report,
+        Err(e) => {
+            warn!(
+                "Checkpoint {} is unreadable ({}); auditing {} again",
+                report_path.display(),
+                e,
+                url
+            );
+            return None;
+        }
+    };
+    report.screen_reader_audit = fs::read(entry.join("screen_reader.json"))
+        .ok()
+        .and_then(|bytes| serde_json::from_slice(&bytes).ok());
+    report.dual_viewport = fs::read(entry.join("dual_viewport.json"))
+        .ok()
+        .and_then(|bytes| serde_json::from_slice(&bytes).ok());
+    Some(report)
+}
+
 // ─── Internal helpers ─────────────────────────────────────────────────────────
 
 /// FNV-1a 64-bit hash. Deterministic across processes and platforms.
 ///
 /// Reference: <https://datatracker.ietf.org/doc/html/draft-eastlake-fnv>
-fn fnv1a(data: &[u8]) -> u64 {
+pub(crate) fn fnv1a(data: &[u8]) -> u64 {
     const OFFSET_BASIS: u64 = 14695981039346656037;
     const PRIME: u64 = 1099511628211;
     data.iter().fold(OFFSET_BASIS, |hash, &byte| {
@@ -375,18 +509,22 @@ fn parse_wcag_level(level: &str) -> WcagLevel {
 /// The VERSION subdirectory provides automatic cache invalidation on binary upgrades.
 /// Old entries are silently ignored — they remain on disk until manually cleared.
 fn artifact_dir(url: &str) -> Result<PathBuf> {
+    let cache_root = dirs::home_dir()
+        .unwrap_or_else(|| PathBuf::from("."))
+        .join(".auditmysite")
+        .join("cache");
+    let version = format!("v{}", env!("CARGO_PKG_VERSION"));
+
+    Ok(url_entry_dir(&cache_root, url)?.join(version))
+}
+
+/// `{root}/{domain}/{url_hash}` — shared by the cache and run checkpoints.
+fn url_entry_dir(root: &Path, url: &str) -> Result<PathBuf> {
     let parsed = url::Url::parse(url)?;
     let domain = parsed.host_str().unwrap_or("unknown");
     let url_hash = format!("{:016x}", fnv1a(url.as_bytes()));
-    let version = format!("v{}", env!("CARGO_PKG_VERSION"));
 
-    Ok(dirs::home_dir()
-        .unwrap_or_else(|| PathBuf::from("."))
-        .join(".auditmysite")
-        .join("cache")
-        .join(domain)
-        .join(url_hash)
-        .join(version))
+    Ok(root.join(domain).join(url_hash))
 }
 
 #[cfg(test)]
@@ -432,4 +570,43 @@ mod tests {
             "level=AA;perf=1;seo=1;sec=0;mobile=1;dark=1;stack=0;consent=0"
         ));
     }
+
+    #[test]
+    fn run_checkpoints_round_trip_and_reject_other_signatures() {
+        let dir = tempfile::tempdir().unwrap();
+        let urls = vec![
+            "https://example.com/a".to_string(),
+            "https://example.com/b".to_string(),
+        ];
+        open_run_dir(dir.path(), "level=AA", &urls).unwrap();
+
+        let mut report = AuditReport::new(urls[0].clone(), WcagLevel::AA, WcagResults::new(), 42);
+        let pass = |score| crate::audit::ViewportAuditData {
+            wcag_results: WcagResults::new(),
+            accessibility_score: score,
+            performance: None,
+            seo: None,
+            mobile: None,
+            ux: None,
+            journey: None,
+            screenshot: None,
+            module_runs: Vec::new(),
+        };
+        report.dual_viewport = Some(crate::audit::DualViewportResults {
+            desktop: pass(91.0),
+            mobile: pass(77.0),
+        });
+        save_run_result(dir.path(), &urls[0], &report).unwrap();
+
+        let restored = load_run_result(dir.path(), &urls[0]).unwrap();
+        assert_eq!(restored.url, urls[0]);
+        assert_eq!(restored.duration_ms, 42);
+        let dual = restored.dual_viewport.expect("dual-viewport data restored");
+        assert_eq!(dual.desktop.accessibility_score, 91.0);
+        assert_eq!(dual.mobile.accessibility_score, 77.0);
+        assert!(load_run_result(dir.path(), &urls[1]).is_none());
+
+        assert!(open_run_dir(dir.path(), "level=AA", &urls).is_ok());
+        assert!(open_run_dir(dir.path(), "level=AAA", &urls).is_err());
+    }
 }
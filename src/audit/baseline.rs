@@ -1,12 +1,32 @@
 //! Baseline / Waiver model for CI diff tracking
 //!
 //! Provides types and logic for storing a baseline snapshot of violations
-//! and comparing future audit runs against it to identify regressions.
+//! and comparing future audit runs against it to identify regressions, plus
+//! the waivers file (`[waivers] file = "…"` in `auditmysite.toml`) that moves
+//! accepted violations out of findings, risk and the verdict.
 
+use std::path::Path;
+
+use chrono::NaiveDate;
 use serde::{Deserialize, Serialize};
 
+use crate::cli::WcagLevel;
+use crate::error::{AuditError, Result};
+use crate::taxonomy::{RuleLookup, Severity};
+use crate::util::glob_match;
 use crate::wcag::types::Violation;
 
+/// Rule ID of the finding raised when a waiver has expired, whether or not the
+/// violation it covered is still present.
+pub const WAIVER_EXPIRED_RULE_ID: &str = "waiver-expired";
+
+/// Tag prefix identifying which waiver a `waiver-expired` finding belongs to,
+/// so a batch can report each expired waiver once.
+const WAIVER_TAG_PREFIX: &str = "waiver:";
+
+/// Tag on `waiver-expired` findings whose waiver still matches a violation.
+const WAIVER_STILL_MATCHES_TAG: &str = "waiver-still-matches";
+
 /// A waived/accepted violation
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct WaivedViolation {
@@ -18,8 +38,288 @@ pub struct WaivedViolation {
     pub node_id: Option<String>,
     /// Human-readable reason for the waiver
     pub reason: Option<String>,
-    /// ISO date string when the waiver was created
-    pub waived_at: String,
+    /// ISO date the waiver was agreed (`created` in the waivers file), if
+    /// the file records one
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub waived_at: Option<String>,
+    /// CSS selector of the waived element (if known)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub selector: Option<String>,
+    /// Severity the violation would have carried
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub severity: Option<Severity>,
+    /// Original violation message
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    /// Person or team accountable for the waiver
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub owner: Option<String>,
+    /// ISO date after which the waiver no longer applies
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expires: Option<String>,
+}
+
+/// One entry of the waivers file.
+///
+/// `rule_id` matches either the axe-style rule ID (`color-contrast`) or the
+/// WCAG criterion (`1.4.3`). `url` and `selector` are `*`/`?` globs; an absent
+/// pattern matches every page or element. The waiver applies up to and
+/// including `expires`; `created` is the optional date it was agreed.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Waiver {
+    pub rule_id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub selector: Option<String>,
+    pub reason: String,
+    pub owner: String,
+    pub expires: NaiveDate,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created: Option<NaiveDate>,
+}
+
+impl Waiver {
+    fn matches(&self, url: &str, violation: &Violation) -> bool {
+        let rule_matches = self.rule_id == violation.rule
+            || violation.rule_id.as_deref() == Some(self.rule_id.as_str());
+        rule_matches
+            && self.applies_to_url(url)
+            && self.selector.as_deref().is_none_or(|pattern| {
+                violation
+                    .selector
+                    .as_deref()
+                    .is_some_and(|selector| glob_match(pattern, selector))
+            })
+    }
+
+    fn is_expired(&self, today: NaiveDate) -> bool {
+        today > self.expires
+    }
+
+    /// Tag identifying this waiver on its `waiver-expired` findings.
+    fn tag(&self) -> String {
+        format!(
+            "{WAIVER_TAG_PREFIX}{}|{}|{}|{}",
+            self.rule_id,
+            self.url.as_deref().unwrap_or("*"),
+            self.selector.as_deref().unwrap_or("*"),
+            self.expires
+        )
+    }
+
+    /// The finding raised for an expired waiver. `covered` is the violation it
+    /// still matches on this page; `None` means it matches nothing any more.
+    fn expired_finding(&self, covered: Option<&Violation>) -> Violation {
+        let Some(covered) = covered else {
+            let rule = RuleLookup::by_legacy_wcag_id(&self.rule_id)
+                .or_else(|| {
+                    RuleLookup::all()
+                        .into_iter()
+                        .find(|rule| rule.axe_id == Some(self.rule_id.as_str()))
+                })
+                .and_then(|rule| rule.external_ref)
+                .and_then(|reference| reference.strip_prefix("WCAG "))
+                .unwrap_or(&self.rule_id);
+            let mut finding = Violation::new(
+                rule,
+                "Expired waiver",
+                WcagLevel::A,
+                Severity::Low,
+                format!(
+                    "Waiver for {} expired on {} (owner: {}, reason: {}) and no longer matches any violation",
+                    self.rule_id, self.expires, self.owner, self.reason
+                ),
+                "document",
+            )
+            .with_rule_id(WAIVER_EXPIRED_RULE_ID);
+            finding.fix_suggestion = Some(format!(
+                "Remove the {} waiver from the waivers file",
+                self.rule_id
+            ));
+            finding.tags.push(self.tag());
+            return finding;
+        };
+
+        let mut finding = Violation::new(
+            covered.rule.clone(),
+            "Expired waiver",
+            covered.level,
+            Severity::Medium,
+            format!(
+                "Waiver for {} expired on {} (owner: {}, reason: {}); the violation is reported again",
+                self.rule_id, self.expires, self.owner, self.reason
+            ),
+            covered.node_id.clone(),
+        )
+        .with_rule_id(WAIVER_EXPIRED_RULE_ID);
+        finding.selector = covered.selector.clone();
+        finding.fix_suggestion = Some(format!(
+            "Fix the {} violation or renew the waiver with a new expiry date",
+            self.rule_id
+        ));
+        finding.tags.push(self.tag());
+        finding.tags.push(WAIVER_STILL_MATCHES_TAG.to_string());
+        finding
+    }
+
+    fn applies_to_url(&self, url: &str) -> bool {
+        self.url
+            .as_deref()
+            .is_none_or(|pattern| glob_match(pattern, url))
+    }
+}
+
+/// The parsed waivers file: `[[waiver]]` tables in TOML, or
+/// `{"waivers": [...]}` in JSON.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct WaiverSet {
+    #[serde(default, rename = "waiver", alias = "waivers")]
+    pub waivers: Vec<Waiver>,
+}
+
+impl WaiverSet {
+    /// Load a waivers file; `.json` files are parsed as JSON, anything else as TOML.
+    pub fn load(path: &Path) -> Result<Self> {
+        let content = std::fs::read_to_string(path).map_err(|e| AuditError::FileError {
+            path: path.to_path_buf(),
+            reason: e.to_string(),
+        })?;
+        let parsed = if path.extension().is_some_and(|ext| ext == "json") {
+            serde_json::from_str(&content).map_err(|e| e.to_string())
+        } else {
+            toml::from_str(&content).map_err(|e| e.to_string())
+        };
+        parsed.map_err(|e| {
+            AuditError::ConfigError(format!("Invalid waivers file {}: {}", path.display(), e))
+        })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.waivers.is_empty()
+    }
+
+    /// Move violations covered by an active waiver out of `violations` and
+    /// return them as [`WaivedViolation`]s.
+    ///
+    /// An expired waiver no longer suppresses anything; instead every expired
+    /// waiver whose URL pattern covers this page adds one `waiver-expired`
+    /// finding: next to the violation it used to cover when it still matches
+    /// one, or as a low-severity clean-up reminder when it matches nothing.
+    /// Batches keep only one of these per waiver, see
+    /// [`report_expired_waivers_once`].
+    pub fn apply(
+        &self,
+        url: &str,
+        violations: &mut Vec<Violation>,
+        today: NaiveDate,
+    ) -> Vec<WaivedViolation> {
+        let mut waived = Vec::new();
+        let mut kept = Vec::with_capacity(violations.len());
+        let mut expired: Vec<(usize, Violation)> = Vec::new();
+
+        for violation in violations.drain(..) {
+            let mut active = None;
+            for (idx, waiver) in self.waivers.iter().enumerate() {
+                if !waiver.matches(url, &violation) {
+                    continue;
+                }
+                if !waiver.is_expired(today) {
+                    active = Some(waiver);
+                    break;
+                }
+                if !expired.iter().any(|(seen, _)| *seen == idx) {
+                    expired.push((idx, waiver.expired_finding(Some(&violation))));
+                }
+            }
+            match active {
+                Some(waiver) => waived.push(WaivedViolation {
+                    rule_id: violation
+                        .rule_id
+                        .clone()
+                        .unwrap_or_else(|| violation.rule.clone()),
+                    rule: violation.rule.clone(),
+                    node_id: (!violation.node_id.is_empty()).then(|| violation.node_id.clone()),
+                    reason: Some(waiver.reason.clone()),
+                    waived_at: waiver.created.map(|date| date.to_string()),
+                    selector: violation.selector.clone(),
+                    severity: Some(violation.severity),
+                    message: Some(violation.message.clone()),
+                    owner: Some(waiver.owner.clone()),
+                    expires: Some(waiver.expires.to_string()),
+                }),
+                None => kept.push(violation),
+            }
+        }
+
+        for (idx, waiver) in self.waivers.iter().enumerate() {
+            if waiver.is_expired(today)
+                && waiver.applies_to_url(url)
+                && !expired.iter().any(|(seen, _)| *seen == idx)
+            {
+                expired.push((idx, waiver.expired_finding(None)));
+            }
+        }
+
+        kept.extend(expired.into_iter().map(|(_, finding)| finding));
+        *violations = kept;
+        waived
+    }
+}
+
+/// Keep one `waiver-expired` finding per expired waiver across a batch.
+///
+/// `WaiverSet::apply` runs per page, so an expired waiver would otherwise be
+/// reported on every page its URL pattern covers. The kept finding is the
+/// first one, in page order, that still matches a violation; only when the
+/// waiver matches nothing anywhere is the first clean-up reminder kept.
+/// Returns the indices of the pages whose violations changed.
+pub fn report_expired_waivers_once(pages: &mut [&mut Vec<Violation>]) -> Vec<usize> {
+    fn waiver_tag(violation: &Violation) -> Option<&str> {
+        if violation.rule_id.as_deref() != Some(WAIVER_EXPIRED_RULE_ID) {
+            return None;
+        }
+        violation
+            .tags
+            .iter()
+            .find(|tag| tag.starts_with(WAIVER_TAG_PREFIX))
+            .map(String::as_str)
+    }
+
+    // Waiver tag -> (page, position, still matches) of the finding to keep.
+    let mut keep: std::collections::HashMap<String, (usize, usize, bool)> =
+        std::collections::HashMap::new();
+    for (page, violations) in pages.iter().enumerate() {
+        for (position, violation) in violations.iter().enumerate() {
+            let Some(tag) = waiver_tag(violation) else {
+                continue;
+            };
+            let matches = violation.tags.iter().any(|t| t == WAIVER_STILL_MATCHES_TAG);
+            match keep.get(tag) {
+                Some((_, _, true)) => {}
+                Some((_, _, false)) if !matches => {}
+                _ => {
+                    keep.insert(tag.to_string(), (page, position, matches));
+                }
+            }
+        }
+    }
+
+    let mut changed = Vec::new();
+    for (page, violations) in pages.iter_mut().enumerate() {
+        let before = violations.len();
+        let mut position = 0;
+        violations.retain(|violation| {
+            let current = position;
+            position += 1;
+            waiver_tag(violation)
+                .is_none_or(|tag| keep.get(tag).is_some_and(|k| (k.0, k.1) == (page, current)))
+        });
+        if violations.len() != before {
+            changed.push(page);
+        }
+    }
+    changed
 }
 
 /// A stored baseline snapshot for a single URL
@@ -121,7 +421,10 @@ impl Baseline {
     }
 
     /// Save to a JSON file.
-    pub fn save(&self, path: &std::path::Path) -> Result<(), Box<dyn std::error::Error>> {
+    pub fn save(
+        &self,
+        path: &std::path::Path,
+    ) -> std::result::Result<(), Box<dyn std::error::Error>> {
         if let Some(parent) = path.parent() {
             std::fs::create_dir_all(parent)?;
         }
@@ -233,6 +536,176 @@ mod tests {
         assert!(diff.resolved_violations.is_empty());
     }
 
+    fn waiver(rule_id: &str, url: Option<&str>, selector: Option<&str>, expires: &str) -> Waiver {
+        Waiver {
+            rule_id: rule_id.to_string(),
+            url: url.map(str::to_string),
+            selector: selector.map(str::to_string),
+            reason: "Third-party widget".to_string(),
+            owner: "web-team".to_string(),
+            expires: expires.parse().unwrap(),
+            created: None,
+        }
+    }
+
+    fn today() -> NaiveDate {
+        "2026-03-01".parse().unwrap()
+    }
+
+    #[test]
+    fn test_waivers_move_matching_violations() {
+        let mut contrast = make_violation("1.4.3", "node-1").with_rule_id("color-contrast");
+        contrast.selector = Some("footer > a.legal".to_string());
+        let mut other_contrast = make_violation("1.4.3", "node-2").with_rule_id("color-contrast");
+        other_contrast.selector = Some("main p".to_string());
+        let mut violations = vec![contrast, other_contrast, make_violation("1.1.1", "node-3")];
+        let set = WaiverSet {
+            waivers: vec![waiver(
+                "color-contrast",
+                Some("https://example.com/*"),
+                Some("footer *"),
+                "2026-12-31",
+            )],
+        };
+
+        let waived = set.apply("https://example.com/about", &mut violations, today());
+        assert_eq!(waived.len(), 1);
+        assert_eq!(waived[0].selector.as_deref(), Some("footer > a.legal"));
+        assert_eq!(waived[0].owner.as_deref(), Some("web-team"));
+        assert_eq!(violations.len(), 2);
+
+        let mut violations = vec![make_violation("1.4.3", "node-1").with_rule_id("color-contrast")];
+        let waived = set.apply("https://other.test/", &mut violations, today());
+        assert!(waived.is_empty(), "URL glob must match");
+    }
+
+    #[test]
+    fn test_expired_waiver_surfaces_as_finding() {
+        let mut violations = vec![
+            make_violation("1.1.1", "node-1"),
+            make_violation("1.1.1", "node-2"),
+        ];
+        let set = WaiverSet {
+            waivers: vec![waiver("1.1.1", None, None, "2026-02-28")],
+        };
+
+        let waived = set.apply("https://example.com/", &mut violations, today());
+        assert!(waived.is_empty());
+        assert_eq!(violations.len(), 3);
+        let expired: Vec<_> = violations
+            .iter()
+            .filter(|v| v.rule_id.as_deref() == Some(WAIVER_EXPIRED_RULE_ID))
+            .collect();
+        assert_eq!(expired.len(), 1, "one finding per expired waiver");
+        assert!(expired[0].message.contains("2026-02-28"));
+    }
+
+    #[test]
+    fn test_expired_waiver_without_match_is_still_reported() {
+        let mut violations = vec![make_violation("1.1.1", "node-1")];
+        let set = WaiverSet {
+            waivers: vec![
+                waiver("color-contrast", None, None, "2026-02-28"),
+                waiver("1.4.3", Some("https://other.test/*"), None, "2026-02-28"),
+                waiver("1.3.1", None, None, "2026-12-31"),
+            ],
+        };
+
+        set.apply("https://example.com/", &mut violations, today());
+        let expired: Vec<_> = violations
+            .iter()
+            .filter(|v| v.rule_id.as_deref() == Some(WAIVER_EXPIRED_RULE_ID))
+            .collect();
+        assert_eq!(
+            expired.len(),
+            1,
+            "only expired waivers scoped to this page are reported"
+        );
+        assert_eq!(expired[0].rule, "1.4.3");
+        assert_eq!(expired[0].severity, Severity::Low);
+        assert!(expired[0].message.contains("no longer matches"));
+    }
+
+    #[test]
+    fn test_waived_at_carries_the_waiver_creation_date() {
+        let mut with_date = waiver("1.1.1", None, None, "2026-12-31");
+        with_date.created = Some("2026-01-15".parse().unwrap());
+        let set = WaiverSet {
+            waivers: vec![with_date],
+        };
+        let mut violations = vec![make_violation("1.1.1", "node-1")];
+        let waived = set.apply("https://example.com/", &mut violations, today());
+        assert_eq!(waived[0].waived_at.as_deref(), Some("2026-01-15"));
+
+        let set = WaiverSet {
+            waivers: vec![waiver("1.1.1", None, None, "2026-12-31")],
+        };
+        let mut violations = vec![make_violation("1.1.1", "node-1")];
+        let waived = set.apply("https://example.com/", &mut violations, today());
+        assert_eq!(waived[0].waived_at, None, "never stamped with the run date");
+    }
+
+    #[test]
+    fn test_expired_waivers_are_reported_once_per_run() {
+        let set = WaiverSet {
+            waivers: vec![
+                waiver("1.1.1", None, None, "2026-02-28"),
+                waiver("color-contrast", None, None, "2026-02-28"),
+            ],
+        };
+        let mut first = vec![make_violation("4.1.2", "node-1")];
+        let mut second = vec![make_violation("1.1.1", "node-2")];
+        let mut third = vec![make_violation("1.1.1", "node-3")];
+        for (url, violations) in [
+            ("https://example.com/a", &mut first),
+            ("https://example.com/b", &mut second),
+            ("https://example.com/c", &mut third),
+        ] {
+            set.apply(url, violations, today());
+        }
+
+        let mut pages = vec![&mut first, &mut second, &mut third];
+        let changed = report_expired_waivers_once(&mut pages);
+        assert_eq!(changed, vec![0, 1, 2]);
+
+        let expired = |violations: &[Violation]| -> Vec<String> {
+            violations
+                .iter()
+                .filter(|v| v.rule_id.as_deref() == Some(WAIVER_EXPIRED_RULE_ID))
+                .map(|v| v.rule.clone())
+                .collect()
+        };
+        // The unmatched color-contrast waiver stays on the first page; the
+        // 1.1.1 waiver moves to the first page where it still matches.
+        assert_eq!(expired(&first), vec!["1.4.3".to_string()]);
+        assert_eq!(expired(&second), vec!["1.1.1".to_string()]);
+        assert!(expired(&third).is_empty());
+        assert_eq!(third.len(), 1, "the violation itself is kept");
+    }
+
+    #[test]
+    fn test_waiver_file_parses_toml_and_json() {
+        let toml_set: WaiverSet = toml::from_str(
+            r#"
+            [[waiver]]
+            rule_id = "color-contrast"
+            url = "https://example.com/legal/*"
+            reason = "Brand colours under review"
+            owner = "design"
+            expires = "2026-06-30"
+            "#,
+        )
+        .unwrap();
+        assert_eq!(toml_set.waivers.len(), 1);
+        assert_eq!(toml_set.waivers[0].selector, None);
+
+        let json_set: WaiverSet = serde_json::from_str(
+            r#"{"waivers": [{"rule_id": "1.1.1", "reason": "r", "owner": "o", "expires": "2026-01-01"}]}"#,
+        )
+        .unwrap();
+        assert_eq!(json_set.waivers[0].rule_id, "1.1.1");
+    }
+
     #[test]
     fn test_baseline_save_load() {
         let violations = vec![make_violation("1.1.1", "node-1")];
@@ -7,6 +7,7 @@
 //! - Progress reporting
 
 use std::collections::{HashSet, VecDeque};
+use std::path::PathBuf;
 use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Arc;
 use std::time::{Duration, Instant};
@@ -16,8 +17,15 @@ use reqwest::{redirect::Policy, Client};
 use tracing::{info, warn};
 use url::Url;
 
+use super::artifacts::{load_run_result, open_run_dir, save_run_result};
+use super::crawl::{
+    fetch_static_html, normalize_discovered_url, rendered_crawl_node, CrawlNode, CrawlResult,
+};
+use super::crawl_policy::{CrawlPolicy, RateLimiter};
 use super::pipeline::{audit_page, PipelineConfig};
-use super::report::{AuditReport, BatchError, BatchReport, SitemapDiagnostics, SitemapHttpIssue};
+use super::report::{
+    AuditReport, BatchError, BatchReport, LinkDiscovery, SitemapDiagnostics, SitemapHttpIssue,
+};
 use crate::browser::{BrowserOptions, BrowserPool, PoolConfig};
 use crate::cli::{Args, RequestMode};
 use crate::error::{AuditError, Result};
@@ -34,10 +42,16 @@ pub struct BatchConfig {
     pub max_urls: usize,
     /// Pool configuration
     pub pool_config: PoolConfig,
+    /// Checkpoint directory for `run_concurrent_batch` (`--checkpoint`).
+    /// Completed pages found there are restored instead of audited again
+    /// (`--resume`).
+    pub run_dir: Option<PathBuf>,
 }
 
-impl From<&Args> for BatchConfig {
-    fn from(args: &Args) -> Self {
+impl TryFrom<&Args> for BatchConfig {
+    type Error = AuditError;
+
+    fn try_from(args: &Args) -> Result<Self> {
         let pool_config = PoolConfig {
             max_pages: args.effective_concurrency(),
             browser_options: BrowserOptions {
@@ -59,12 +73,18 @@ impl From<&Args> for BatchConfig {
             ..PoolConfig::default()
         };
 
-        Self {
-            pipeline: PipelineConfig::from(args),
+        Ok(Self {
+            pipeline: PipelineConfig::try_from(args)?,
             concurrency: args.effective_concurrency(),
             max_urls: args.max_pages,
             pool_config,
-        }
+            run_dir: args.resume.clone().or_else(|| {
+                args.checkpoint.as_ref().map(|dir| {
+                    dir.clone()
+                        .unwrap_or_else(super::artifacts::default_run_dir)
+                })
+            }),
+        })
     }
 }
 
@@ -104,6 +124,12 @@ pub type ProgressCallback = Arc<dyn Fn(usize, usize, &str, Option<&str>) + Send
 
 /// Run concurrent batch audit on multiple URLs
 ///
+/// With `config.run_dir` set, every successful page is checkpointed there as
+/// soon as it completes, and pages already checkpointed by an earlier run are
+/// restored instead of audited. Failed URLs are never checkpointed, so a
+/// resumed run retries them. Reports are returned in input order either way,
+/// which makes a resumed run aggregate exactly like an uninterrupted one.
+///
 /// # Arguments
 /// * `urls` - URLs to audit
 /// * `config` - Batch configuration
@@ -123,77 +149,87 @@ pub async fn run_concurrent_batch(
     } else {
         urls.len()
     };
+    let urls: Vec<String> = urls.into_iter().take(total_urls).collect();
+
+    // Reports keyed by input position, so the output order does not depend
+    // on completion order or on which pages came from a checkpoint.
+    let mut reports: Vec<(usize, AuditReport)> = Vec::with_capacity(total_urls);
+    let mut pending: Vec<(usize, String)> = Vec::with_capacity(total_urls);
+    if let Some(dir) = &config.run_dir {
+        open_run_dir(dir, &config.pipeline.run_signature(), &urls)?;
+        for (index, url) in urls.into_iter().enumerate() {
+            match load_run_result(dir, &url) {
+                Some(report) => reports.push((index, report)),
+                None => pending.push((index, url)),
+            }
+        }
+        if !reports.is_empty() {
+            info!(
+                "Resuming from {}: {} of {} URLs already completed",
+                dir.display(),
+                reports.len(),
+                total_urls
+            );
+        }
+    } else {
+        pending.extend(urls.into_iter().enumerate());
+    }
 
     info!(
         "Starting batch audit of {} URLs with {} concurrent workers",
-        total_urls, config.concurrency
+        pending.len(),
+        config.concurrency
     );
 
-    // Create browser pool
-    let pool = Arc::new(BrowserPool::new(config.pool_config.clone()).await?);
-    let pipeline_config = Arc::new(config.pipeline.clone());
-
-    let completed = Arc::new(AtomicUsize::new(0));
+    let completed = Arc::new(AtomicUsize::new(reports.len()));
+    let mut errors = Vec::new();
 
-    // Bounded work queue: at most `concurrency` futures in flight at any time.
-    // No unbounded spawn — tasks are only created as slots free up.
-    let mut in_flight: FuturesUnordered<_> = FuturesUnordered::new();
-    let mut url_iter = urls.into_iter().take(total_urls);
-
-    let make_task = |url: String,
-                     pool: Arc<BrowserPool>,
-                     config: Arc<PipelineConfig>,
-                     completed: Arc<AtomicUsize>,
-                     progress: Option<ProgressCallback>,
-                     total: usize| {
-        async move {
-            let result = audit_url_with_pool(&pool, &url, &config).await;
-            let current = completed.fetch_add(1, Ordering::SeqCst) + 1;
-            match &result.outcome {
-                Ok(report) => {
-                    info!(
-                        "[{}/{}] Completed: {} (score: {})",
-                        current, total, url, report.accessibility.score
-                    );
-                    if let Some(ref cb) = progress {
-                        cb(current, total, &url, None);
+    if !pending.is_empty() {
+        // Create browser pool
+        let pool = Arc::new(BrowserPool::new(config.pool_config.clone()).await?);
+        let pipeline_config = Arc::new(config.pipeline.clone());
+
+        // Bounded work queue: at most `concurrency` futures in flight at any time.
+        // No unbounded spawn — tasks are only created as slots free up.
+        let mut in_flight: FuturesUnordered<_> = FuturesUnordered::new();
+        let mut url_iter = pending.into_iter();
+
+        let make_task = |index: usize,
+                         url: String,
+                         pool: Arc<BrowserPool>,
+                         config: Arc<PipelineConfig>,
+                         completed: Arc<AtomicUsize>,
+                         progress: Option<ProgressCallback>,
+                         total: usize| {
+            async move {
+                let result = audit_url_with_pool(&pool, &url, &config).await;
+                let current = completed.fetch_add(1, Ordering::SeqCst) + 1;
+                match &result.outcome {
+                    Ok(report) => {
+                        info!(
+                            "[{}/{}] Completed: {} (score: {})",
+                            current, total, url, report.accessibility.score
+                        );
+                        if let Some(ref cb) = progress {
+                            cb(current, total, &url, None);
+                        }
                     }
-                }
-                Err(e) => {
-                    let msg = e.to_string();
-                    warn!("[{}/{}] Failed: {} - {}", current, total, url, msg);
-                    if let Some(ref cb) = progress {
-                        cb(current, total, &url, Some(&msg));
+                    Err(e) => {
+                        let msg = e.to_string();
+                        warn!("[{}/{}] Failed: {} - {}", current, total, url, msg);
+                        if let Some(ref cb) = progress {
+                            cb(current, total, &url, Some(&msg));
+                        }
                     }
                 }
+                (index, result)
             }
-            result
-        }
-    };
-
-    // Fill up to concurrency limit before starting the drain loop
-    for url in url_iter.by_ref().take(config.concurrency) {
-        in_flight.push(make_task(
-            url,
-            Arc::clone(&pool),
-            Arc::clone(&pipeline_config),
-            Arc::clone(&completed),
-            progress.clone(),
-            total_urls,
-        ));
-    }
-
-    // Collect results, feeding new work in as slots free up
-    let mut reports = Vec::with_capacity(total_urls);
-    let mut errors = Vec::new();
+        };
 
-    while let Some(batch_result) = in_flight.next().await {
-        match batch_result.outcome {
-            Ok(report) => reports.push(report),
-            Err(e) => errors.push((batch_result.url, e)),
-        }
-        if let Some(url) = url_iter.next() {
+        // Fill up to concurrency limit before starting the drain loop
+        for (index, url) in url_iter.by_ref().take(config.concurrency) {
             in_flight.push(make_task(
+                index,
                 url,
                 Arc::clone(&pool),
                 Arc::clone(&pipeline_config),
@@ -202,10 +238,36 @@ pub async fn run_concurrent_batch(
                 total_urls,
             ));
         }
-    }
 
-    // Close pool - need to unwrap Arc
-    // Note: Pool will be dropped when all Arc references are dropped
+        // Collect results, feeding new work in as slots free up
+        while let Some((index, batch_result)) = in_flight.next().await {
+            match batch_result.outcome {
+                Ok(report) => {
+                    if let Some(dir) = &config.run_dir {
+                        if let Err(e) = save_run_result(dir, &batch_result.url, &report) {
+                            warn!("Checkpoint failed for {}: {}", batch_result.url, e);
+                        }
+                    }
+                    reports.push((index, report));
+                }
+                Err(e) => errors.push((index, batch_result.url, e)),
+            }
+            if let Some((index, url)) = url_iter.next() {
+                in_flight.push(make_task(
+                    index,
+                    url,
+                    Arc::clone(&pool),
+                    Arc::clone(&pipeline_config),
+                    Arc::clone(&completed),
+                    progress.clone(),
+                    total_urls,
+                ));
+            }
+        }
+
+        // Close pool - need to unwrap Arc
+        // Note: Pool will be dropped when all Arc references are dropped
+    }
 
     let total_duration_ms = start_time.elapsed().as_millis() as u64;
 
@@ -217,21 +279,187 @@ pub async fn run_concurrent_batch(
         total_duration_ms
     );
 
+    reports.sort_by_key(|(index, _)| *index);
+    errors.sort_by_key(|(index, _, _)| *index);
     let batch_errors = errors
         .into_iter()
-        .map(|(url, error)| BatchError {
+        .map(|(_, url, error)| BatchError {
             url,
             error: error.to_string(),
         })
         .collect();
 
     Ok(BatchReport::from_reports(
-        reports,
+        reports.into_iter().map(|(_, report)| report).collect(),
         batch_errors,
         total_duration_ms,
     ))
 }
 
+/// Crawl from `seed_url` through the browser pool, auditing every page as
+/// it is discovered.
+///
+/// Unlike [`crawl_site`](super::crawl::crawl_site) there is no separate
+/// HTML-only discovery pass: each audit records the rendered DOM's links and
+/// History API targets, and unseen same-domain URLs are queued breadth-first
+/// up to `max_depth` and `config.max_urls`. The static HTML of every audited
+/// page is fetched as well, so each crawl node can tell links present in the
+/// markup from those that only appear after rendering.
+///
+/// `policy` filters and normalises discovered URLs and rate-limits both the
+/// page audits and the static fetches.
+pub async fn run_rendered_crawl_batch(
+    seed_url: &str,
+    max_depth: usize,
+    config: &BatchConfig,
+    policy: &CrawlPolicy,
+    progress: Option<ProgressCallback>,
+) -> Result<(BatchReport, CrawlResult)> {
+    let start_time = Instant::now();
+    let seed = Url::parse(seed_url)?;
+    let host = seed
+        .host_str()
+        .ok_or_else(|| AuditError::InvalidUrl {
+            url: seed_url.to_string(),
+            reason: "Missing host".to_string(),
+        })?
+        .to_string();
+    let max_urls = if config.max_urls == 0 {
+        usize::MAX
+    } else {
+        config.max_urls
+    };
+
+    info!(
+        "Starting rendered crawl from {} (max_urls={}, max_depth={}) with {} concurrent workers",
+        seed_url, max_urls, max_depth, config.concurrency
+    );
+
+    let pool = Arc::new(BrowserPool::new(config.pool_config.clone()).await?);
+    let mut pipeline_config = config.pipeline.clone();
+    pipeline_config.capture_rendered_links = true;
+    let pipeline_config = Arc::new(pipeline_config);
+
+    let seed = normalize_discovered_url(seed)?;
+    let mut seen = HashSet::from([seed.clone()]);
+    // URLs the policy turned down, so each is evaluated (and logged) once.
+    let mut rejected = HashSet::new();
+    let mut queue = VecDeque::from([(seed, 0usize, LinkDiscovery::Seed)]);
+    let mut started = 0usize;
+    let mut completed = 0usize;
+    let mut in_flight: FuturesUnordered<_> = FuturesUnordered::new();
+
+    let mut reports = Vec::new();
+    let mut errors = Vec::new();
+    let mut pages: Vec<CrawlNode> = Vec::new();
+
+    loop {
+        while in_flight.len() < config.concurrency && started < max_urls {
+            let Some((url, depth, discovered_via)) = queue.pop_front() else {
+                break;
+            };
+            started += 1;
+            let pool = Arc::clone(&pool);
+            let pipeline_config = Arc::clone(&pipeline_config);
+            let limiter = policy.limiter.clone();
+            in_flight.push(async move {
+                limiter.wait(&url).await;
+                let result = audit_url_with_pool(&pool, &url, &pipeline_config).await;
+                let static_html = match result.outcome {
+                    Ok(_) => fetch_static_html(&url, &limiter).await,
+                    Err(_) => None,
+                };
+                (result, depth, discovered_via, static_html)
+            });
+        }
+
+        let Some((result, depth, discovered_via, static_html)) = in_flight.next().await else {
+            break;
+        };
+        completed += 1;
+
+        match result.outcome {
+            Ok(report) => {
+                let node = rendered_crawl_node(
+                    &result.url,
+                    depth,
+                    discovered_via,
+                    &host,
+                    static_html.as_deref(),
+                    &report.rendered_links,
+                    policy,
+                );
+                if depth < max_depth {
+                    for link in &node.links_out {
+                        if seen.contains(link) || rejected.contains(link) {
+                            continue;
+                        }
+                        if !policy.allows(link) {
+                            rejected.insert(link.clone());
+                            continue;
+                        }
+                        seen.insert(link.clone());
+                        let via = if node.rendered_only_links.contains(link) {
+                            LinkDiscovery::Rendered
+                        } else {
+                            LinkDiscovery::Static
+                        };
+                        queue.push_back((link.clone(), depth + 1, via));
+                    }
+                }
+                let total = seen.len().min(max_urls);
+                info!(
+                    "[{}/{}] Completed: {} (score: {}, {} rendered-only links)",
+                    completed,
+                    total,
+                    result.url,
+                    report.accessibility.score,
+                    node.rendered_only_links.len()
+                );
+                if let Some(ref cb) = progress {
+                    cb(completed, total, &result.url, None);
+                }
+                pages.push(node);
+                reports.push(report);
+            }
+            Err(e) => {
+                let total = seen.len().min(max_urls);
+                let msg = e.to_string();
+                warn!("[{}/{}] Failed: {} - {}", completed, total, result.url, msg);
+                if let Some(ref cb) = progress {
+                    cb(completed, total, &result.url, Some(&msg));
+                }
+                errors.push(BatchError {
+                    url: result.url,
+                    error: msg,
+                });
+            }
+        }
+    }
+
+    pages.sort_by(|a, b| a.depth.cmp(&b.depth).then_with(|| a.url.cmp(&b.url)));
+    let total_duration_ms = start_time.elapsed().as_millis() as u64;
+    info!(
+        "Rendered crawl completed: {} pages audited, {} failed, {} rendered-only links in {}ms",
+        reports.len(),
+        errors.len(),
+        pages
+            .iter()
+            .map(|p| p.rendered_only_links.len())
+            .sum::<usize>(),
+        total_duration_ms
+    );
+
+    Ok((
+        BatchReport::from_reports(reports, errors, total_duration_ms),
+        CrawlResult {
+            seed_url: seed_url.to_string(),
+            pages,
+            rendered: true,
+        },
+    ))
+}
+
 /// Validate sitemap entries that were selected for a sitemap-driven batch.
 ///
 /// The checks intentionally stay sitemap-specific: a sitemap should list only
@@ -245,9 +473,12 @@ pub async fn run_concurrent_batch(
 /// the full sitemap floods `linked_not_in_sitemap` with pages that are
 /// genuinely in the sitemap, just outside the sample. `reports` stays
 /// audited-only: that's the only source of link-graph evidence.
+///
+/// Every check waits for `limiter`, the run's shared per-host rate cap.
 pub async fn analyze_sitemap_diagnostics(
     sitemap_urls: &[String],
     reports: &[AuditReport],
+    limiter: &RateLimiter,
 ) -> SitemapDiagnostics {
     let client = Client::builder()
         .redirect(Policy::none())
@@ -256,12 +487,35 @@ pub async fn analyze_sitemap_diagnostics(
         .unwrap_or_else(|_| Client::new());
 
     let http_issues: Vec<SitemapHttpIssue> = futures::stream::iter(sitemap_urls.iter())
-        .map(|url| check_sitemap_url(&client, url))
+        .map(|url| {
+            let client = &client;
+            async move {
+                limiter.wait(url).await;
+                check_sitemap_url(client, url).await
+            }
+        })
         .buffer_unordered(8)
         .filter_map(|issue| async move { issue })
         .collect()
         .await;
 
+    let (orphan_sitemap_urls, linked_not_in_sitemap) = sitemap_link_graph(sitemap_urls, reports);
+
+    SitemapDiagnostics {
+        checked_urls: sitemap_urls.len(),
+        http_issues,
+        orphan_sitemap_urls,
+        linked_not_in_sitemap,
+    }
+}
+
+/// The link-graph half of [`analyze_sitemap_diagnostics`], without HTTP
+/// checks: audited sitemap URLs no audited page links to, and internal link
+/// targets missing from the sitemap.
+pub(crate) fn sitemap_link_graph(
+    sitemap_urls: &[String],
+    reports: &[AuditReport],
+) -> (Vec<String>, Vec<String>) {
     let sitemap_set: HashSet<String> = sitemap_urls
         .iter()
         .filter_map(|u| normalize_url(u))
@@ -283,12 +537,7 @@ pub async fn analyze_sitemap_diagnostics(
         linked_set.difference(&sitemap_set).cloned().collect();
     linked_not_in_sitemap.sort();
 
-    SitemapDiagnostics {
-        checked_urls: sitemap_urls.len(),
-        http_issues,
-        orphan_sitemap_urls,
-        linked_not_in_sitemap,
-    }
+    (orphan_sitemap_urls, linked_not_in_sitemap)
 }
 
 async fn check_sitemap_url(client: &Client, url: &str) -> Option<SitemapHttpIssue> {
@@ -418,7 +667,7 @@ fn normalize_url(url: &str) -> Option<String> {
 }
 
 /// Audit a single URL using a page from the pool
-async fn audit_url_with_pool(
+pub(crate) async fn audit_url_with_pool(
     pool: &BrowserPool,
     url: &str,
     config: &PipelineConfig,
@@ -2,6 +2,9 @@
 //!
 //! Evaluates an AuditReport against the configured budget limits and
 //! returns a list of violations, sorted by severity then metric name.
+//!
+//! With `--runs N` the report vitals are per-metric medians, so timing
+//! budgets are checked against the median instead of a single sample.
 
 use serde::{Deserialize, Serialize};
 
@@ -93,13 +96,19 @@ pub fn evaluate_budgets(report: &AuditReport, config: &BudgetConfig) -> Vec<Budg
         None => return Vec::new(),
     };
 
+    // Timing labels name the median when the vitals aggregate several runs.
+    let sample_note = match perf.vitals.run_stats.as_ref() {
+        Some(stats) if stats.runs > 1 => format!(" (median of {} runs)", stats.runs),
+        _ => String::new(),
+    };
+
     // ── Core Web Vitals ────────────────────────────────────────────────────
     if let (Some(limit), Some(lcp)) = (config.max_lcp_ms, perf.vitals.lcp.as_ref()) {
         if lcp.value > limit {
             violations.push(BudgetViolation::new(
                 "LCP",
                 format!("≤ {limit:.0} ms"),
-                format!("{:.0} ms", lcp.value),
+                format!("{:.0} ms{sample_note}", lcp.value),
                 limit,
                 lcp.value,
             ));
@@ -111,7 +120,7 @@ pub fn evaluate_budgets(report: &AuditReport, config: &BudgetConfig) -> Vec<Budg
             violations.push(BudgetViolation::new(
                 "FCP",
                 format!("≤ {limit:.0} ms"),
-                format!("{:.0} ms", fcp.value),
+                format!("{:.0} ms{sample_note}", fcp.value),
                 limit,
                 fcp.value,
             ));
@@ -123,7 +132,7 @@ pub fn evaluate_budgets(report: &AuditReport, config: &BudgetConfig) -> Vec<Budg
             violations.push(BudgetViolation::new(
                 "CLS",
                 format!("≤ {limit:.3}"),
-                format!("{:.3}", cls.value),
+                format!("{:.3}{sample_note}", cls.value),
                 limit,
                 cls.value,
             ));
@@ -135,7 +144,7 @@ pub fn evaluate_budgets(report: &AuditReport, config: &BudgetConfig) -> Vec<Budg
             violations.push(BudgetViolation::new(
                 "TBT",
                 format!("≤ {limit:.0} ms"),
-                format!("{:.0} ms", tbt.value),
+                format!("{:.0} ms{sample_note}", tbt.value),
                 limit,
                 tbt.value,
             ));
@@ -269,6 +278,41 @@ mod tests {
         assert!(evaluate_budgets(&report, &config).is_empty());
     }
 
+    #[test]
+    fn test_timing_budgets_use_median_of_runs() {
+        use crate::audit::report::PerformanceResults;
+        use crate::performance::{
+            aggregate_vitals_runs, calculate_performance_score, VitalMetric, WebVitals,
+        };
+        let run = |lcp: f64| WebVitals {
+            lcp: Some(VitalMetric::new(lcp, 2500.0, 4000.0)),
+            ..Default::default()
+        };
+        // One outlier run (6000 ms) would fail the budget on its own.
+        let vitals = aggregate_vitals_runs(&[run(2000.0), run(6000.0), run(2600.0)]).unwrap();
+        let mut report = empty_report();
+        report.performance = Some(PerformanceResults {
+            score: calculate_performance_score(&vitals, None),
+            vitals,
+            render_blocking: None,
+            content_weight: None,
+            third_party: None,
+            critical_chain: None,
+            minification: None,
+            animations: None,
+            coverage: None,
+            measurement_warnings: Vec::new(),
+        });
+        let config = BudgetConfig {
+            max_lcp_ms: Some(2500.0),
+            ..Default::default()
+        };
+        let violations = evaluate_budgets(&report, &config);
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].actual_value, 2600.0);
+        assert_eq!(violations[0].actual_label, "2600 ms (median of 3 runs)");
+    }
+
     #[test]
     fn test_budget_severity_error_when_exceeds_50_pct() {
         let v = BudgetViolation::new("LCP", "≤ 2500 ms", "4000 ms", 2500.0, 4000.0);
@@ -336,7 +336,7 @@ mod tests {
     }
 
     fn cfg() -> PipelineConfig {
-        PipelineConfig::from(&Args::parse_from(["auditmysite", "https://example.com"]))
+        PipelineConfig::try_from(&Args::parse_from(["auditmysite", "https://example.com"])).unwrap()
     }
 
     fn ids(modules: &[&dyn AuditModule]) -> Vec<&'static str> {
@@ -4,16 +4,28 @@
 //! - start from a seed URL
 //! - follow same-domain links
 //! - breadth-first with depth and page limits
+//!
+//! `crawl_site` reads server-delivered HTML only. For client-rendered sites
+//! the batch runner can instead discover links while auditing
+//! (`--crawl-render`): the browser records every rendered link plus the
+//! targets passed to `history.pushState`/`replaceState`, and
+//! [`rendered_crawl_node`] classifies them against the static HTML.
 
 use std::collections::{HashMap, HashSet, VecDeque};
 
+use chromiumoxide::cdp::browser_protocol::page::AddScriptToEvaluateOnNewDocumentParams;
+use chromiumoxide::Page;
 use html5ever::{parse_document, tendril::TendrilSink};
 use markup5ever_rcdom::{Handle, NodeData, RcDom};
 use reqwest::{redirect::Policy, Client};
 use tracing::{debug, info, warn};
 use url::Url;
 
-use crate::audit::report::{BrokenLink, BrokenLinkSeverity, CrawlDiagnostics, RedirectChain};
+use crate::audit::crawl_policy::{CrawlPolicy, RateLimiter};
+use crate::audit::report::{
+    BrokenLink, BrokenLinkSeverity, CrawlDiagnostics, CrawlPageDiscovery, LinkDiscovery,
+    RedirectChain,
+};
 use crate::error::{AuditError, Result};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -22,12 +34,19 @@ pub struct CrawlNode {
     pub depth: usize,
     pub links_out: Vec<String>,
     pub external_links_out: Vec<String>,
+    /// How this page was first reached.
+    pub discovered_via: LinkDiscovery,
+    /// Same-domain entries of `links_out` missing from the static HTML.
+    /// Always empty for HTML-only crawls.
+    pub rendered_only_links: Vec<String>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct CrawlResult {
     pub seed_url: String,
     pub pages: Vec<CrawlNode>,
+    /// Whether links were read from the rendered DOM (`--crawl-render`).
+    pub rendered: bool,
 }
 
 impl CrawlResult {
@@ -43,12 +62,13 @@ struct LinkCheckResult {
     error: Option<String>,
 }
 
-async fn check_link(client: &Client, url: &str) -> LinkCheckResult {
+async fn check_link(client: &Client, limiter: &RateLimiter, url: &str) -> LinkCheckResult {
     let mut current = url.to_string();
     let mut hops = 0u8;
     const MAX_HOPS: u8 = 6;
 
     loop {
+        limiter.wait(&current).await;
         let result = client
             .head(&current)
             .header("User-Agent", "auditmysite-link-checker/1.0")
@@ -91,6 +111,7 @@ async fn check_link(client: &Client, url: &str) -> LinkCheckResult {
             Err(e) => {
                 // HEAD not supported by some servers — try GET for the initial request only
                 if hops == 0 {
+                    limiter.wait(url).await;
                     match client
                         .get(url)
                         .header("User-Agent", "auditmysite-link-checker/1.0")
@@ -168,7 +189,10 @@ fn unique_external_targets(crawl: &CrawlResult) -> Vec<String> {
     targets
 }
 
-pub async fn analyze_crawl_links(crawl: &CrawlResult) -> CrawlDiagnostics {
+/// Status-check every link found by the crawl. Requests go through the
+/// policy's rate limiter; internal targets disallowed by robots.txt are not
+/// checked.
+pub async fn analyze_crawl_links(crawl: &CrawlResult, policy: &CrawlPolicy) -> CrawlDiagnostics {
     let client = Client::builder()
         .redirect(Policy::none())
         .timeout(std::time::Duration::from_secs(10))
@@ -176,10 +200,16 @@ pub async fn analyze_crawl_links(crawl: &CrawlResult) -> CrawlDiagnostics {
         .unwrap_or_else(|_| Client::new());
 
     // ── Internal links ──────────────────────────────────────────────────────
-    let unique_internal = unique_internal_targets(crawl);
+    let unique_internal: Vec<String> = unique_internal_targets(crawl)
+        .into_iter()
+        .filter(|target| policy.robots_allows(target))
+        .collect();
     let mut internal_results: HashMap<String, LinkCheckResult> = HashMap::new();
     for target in &unique_internal {
-        internal_results.insert(target.clone(), check_link(&client, target).await);
+        internal_results.insert(
+            target.clone(),
+            check_link(&client, &policy.limiter, target).await,
+        );
     }
 
     let mut broken_internal_links = Vec::new();
@@ -227,7 +257,10 @@ pub async fn analyze_crawl_links(crawl: &CrawlResult) -> CrawlDiagnostics {
     let unique_external = unique_external_targets(crawl);
     let mut external_results: HashMap<String, LinkCheckResult> = HashMap::new();
     for target in &unique_external {
-        external_results.insert(target.clone(), check_link(&client, target).await);
+        external_results.insert(
+            target.clone(),
+            check_link(&client, &policy.limiter, target).await,
+        );
     }
 
     let mut broken_external_links = Vec::new();
@@ -292,10 +325,33 @@ pub async fn analyze_crawl_links(crawl: &CrawlResult) -> CrawlDiagnostics {
         checked_external_links: unique_external.len(),
         broken_external_links,
         redirect_chains,
+        discovery: if crawl.rendered {
+            crawl.pages.iter().map(page_discovery).collect()
+        } else {
+            Vec::new()
+        },
+    }
+}
+
+fn page_discovery(page: &CrawlNode) -> CrawlPageDiscovery {
+    CrawlPageDiscovery {
+        url: page.url.clone(),
+        depth: page.depth,
+        discovered_via: page.discovered_via,
+        static_links: page.links_out.len() - page.rendered_only_links.len(),
+        rendered_only_links: page.rendered_only_links.clone(),
     }
 }
 
-pub async fn crawl_site(seed_url: &str, max_urls: usize, max_depth: usize) -> Result<CrawlResult> {
+/// Breadth-first HTML crawl from `seed_url`. Discovered links are
+/// normalised by `policy` and only followed when it allows them; every fetch
+/// waits for the policy's rate limiter. The seed itself is always fetched.
+pub async fn crawl_site(
+    seed_url: &str,
+    max_urls: usize,
+    max_depth: usize,
+    policy: &CrawlPolicy,
+) -> Result<CrawlResult> {
     let seed = Url::parse(seed_url)?;
     let host = seed
         .host_str()
@@ -314,6 +370,8 @@ pub async fn crawl_site(seed_url: &str, max_urls: usize, max_depth: usize) -> Re
 
     let mut queue = VecDeque::new();
     let mut visited = HashSet::new();
+    // Links the policy turned down, so each is evaluated (and logged) once.
+    let mut rejected = HashSet::new();
     let mut pages = Vec::new();
 
     queue.push_back((normalize_discovered_url(seed.clone())?, 0usize));
@@ -326,6 +384,7 @@ pub async fn crawl_site(seed_url: &str, max_urls: usize, max_depth: usize) -> Re
             continue;
         }
 
+        policy.limiter.wait(&current).await;
         let html = match fetch_html(current.as_str()).await {
             Ok(html) => html,
             Err(err) => {
@@ -335,7 +394,10 @@ pub async fn crawl_site(seed_url: &str, max_urls: usize, max_depth: usize) -> Re
         };
 
         let (same_domain_links, external_links) = extract_links(&current, &host, &html);
-        let mut links_out = same_domain_links;
+        let mut links_out: Vec<String> = same_domain_links
+            .iter()
+            .map(|link| policy.normalize(link))
+            .collect();
         links_out.sort();
         links_out.dedup();
         let mut external_links_out = external_links;
@@ -352,8 +414,13 @@ pub async fn crawl_site(seed_url: &str, max_urls: usize, max_depth: usize) -> Re
 
         if depth < max_depth {
             for link in &links_out {
-                if !visited.contains(link) {
+                if visited.contains(link) || rejected.contains(link) {
+                    continue;
+                }
+                if policy.allows(link) {
                     queue.push_back((link.clone(), depth + 1));
+                } else {
+                    rejected.insert(link.clone());
                 }
             }
         }
@@ -363,15 +430,152 @@ pub async fn crawl_site(seed_url: &str, max_urls: usize, max_depth: usize) -> Re
             depth,
             links_out,
             external_links_out,
+            discovered_via: if depth == 0 {
+                LinkDiscovery::Seed
+            } else {
+                LinkDiscovery::Static
+            },
+            rendered_only_links: Vec::new(),
         });
     }
 
     Ok(CrawlResult {
         seed_url: seed_url.to_string(),
         pages,
+        rendered: false,
     })
 }
 
+/// Records every URL passed to `history.pushState`/`replaceState` in
+/// `window.__ams_route_targets`. Installed before navigation so routes the
+/// app pushes while booting are captured too.
+const ROUTE_OBSERVER_JS: &str = r#"
+(function() {
+    if (window.__ams_route_targets) return;
+    window.__ams_route_targets = [];
+    ['pushState', 'replaceState'].forEach(function(name) {
+        var orig = history[name];
+        history[name] = function(state, title, url) {
+            if (url !== undefined && url !== null) {
+                try {
+                    window.__ams_route_targets.push(new URL(String(url), location.href).href);
+                } catch (e) {}
+            }
+            return orig.apply(this, arguments);
+        };
+    });
+})();
+"#;
+
+/// Collects absolute link targets from the rendered DOM: anchors, image-map
+/// areas and router link attributes (`routerLink`, `data-href`) that
+/// frameworks resolve in JavaScript, plus the recorded History API targets.
+const COLLECT_RENDERED_LINKS_JS: &str = r#"
+(function() {
+    var out = [];
+    function add(value) {
+        if (!value) return;
+        try { out.push(new URL(String(value), document.baseURI).href); } catch (e) {}
+    }
+    document.querySelectorAll('a[href], area[href]').forEach(function(el) {
+        add(el.getAttribute('href'));
+    });
+    document.querySelectorAll('[routerlink], [ng-reflect-router-link], [data-href]').forEach(function(el) {
+        add(el.getAttribute('routerlink') || el.getAttribute('ng-reflect-router-link') || el.getAttribute('data-href'));
+    });
+    (window.__ams_route_targets || []).forEach(add);
+    return out;
+})()
+"#;
+
+/// Start recording History API navigation targets on `page`.
+pub async fn install_route_observer(page: &Page) -> Result<()> {
+    page.execute(AddScriptToEvaluateOnNewDocumentParams::new(
+        ROUTE_OBSERVER_JS,
+    ))
+    .await
+    .map_err(|e| AuditError::CdpError(format!("Route observer setup failed: {e}")))?;
+    Ok(())
+}
+
+/// Read the link targets of the current rendered document, including the
+/// History API targets recorded by [`install_route_observer`].
+pub async fn collect_rendered_links(page: &Page) -> Result<Vec<String>> {
+    let result = page
+        .evaluate(COLLECT_RENDERED_LINKS_JS)
+        .await
+        .map_err(|e| AuditError::CdpError(format!("Rendered link collection failed: {e}")))?;
+    Ok(result.into_value().unwrap_or_default())
+}
+
+/// Server-delivered HTML of `url`, or `None` when it cannot be fetched.
+pub async fn fetch_static_html(url: &str, limiter: &RateLimiter) -> Option<String> {
+    limiter.wait(url).await;
+    match fetch_html(url).await {
+        Ok(html) => Some(html),
+        Err(err) => {
+            debug!("Static HTML unavailable for {}: {}", url, err);
+            None
+        }
+    }
+}
+
+/// Build the crawl node for an audited page from its static HTML (if it
+/// could be fetched) and the links collected in the browser. A link counts
+/// as rendered-only when the static HTML does not contain it. Same-domain
+/// links are normalised by `policy`.
+pub fn rendered_crawl_node(
+    url: &str,
+    depth: usize,
+    discovered_via: LinkDiscovery,
+    expected_host: &str,
+    static_html: Option<&str>,
+    rendered_links: &[String],
+    policy: &CrawlPolicy,
+) -> CrawlNode {
+    let (static_links, static_external) = static_html
+        .map(|html| extract_links(url, expected_host, html))
+        .unwrap_or_default();
+    let static_links: Vec<String> = static_links
+        .iter()
+        .map(|link| policy.normalize(link))
+        .collect();
+    let static_set: HashSet<&str> = static_links.iter().map(String::as_str).collect();
+
+    let mut links_out = static_links.clone();
+    let mut rendered_only_links = Vec::new();
+    let mut external_links_out = static_external;
+    for href in rendered_links {
+        if let Some(link) = normalize_link(url, expected_host, href) {
+            let link = policy.normalize(&link);
+            if !static_set.contains(link.as_str()) {
+                rendered_only_links.push(link.clone());
+            }
+            links_out.push(link);
+        } else if let Some(link) = normalize_external_link(url, expected_host, href) {
+            external_links_out.push(link);
+        }
+    }
+    for list in [
+        &mut links_out,
+        &mut rendered_only_links,
+        &mut external_links_out,
+    ] {
+        list.sort();
+        list.dedup();
+    }
+    external_links_out.truncate(50);
+
+    CrawlNode {
+        url: url.to_string(),
+        depth,
+        links_out,
+        external_links_out,
+        discovered_via,
+        rendered_only_links,
+    }
+}
+
 async fn fetch_html(url: &str) -> Result<String> {
     let response = reqwest::get(url).await?;
     let content_type = response
@@ -507,7 +711,7 @@ fn normalize_external_link(base_url: &str, expected_host: &str, href: &str) -> O
     Some(u.to_string())
 }
 
-fn normalize_discovered_url(mut url: Url) -> Result<String> {
+pub(crate) fn normalize_discovered_url(mut url: Url) -> Result<String> {
     url.set_fragment(None);
 
     let path = url.path().to_string();
@@ -665,14 +869,19 @@ mod tests {
                         "https://www.casoon.de/contact".to_string(),
                     ],
                     external_links_out: vec![],
+                    discovered_via: LinkDiscovery::Static,
+                    rendered_only_links: vec![],
                 },
                 CrawlNode {
                     url: "https://www.casoon.de/about".to_string(),
                     depth: 1,
                     links_out: vec!["https://www.casoon.de/contact".to_string()],
                     external_links_out: vec![],
+                    discovered_via: LinkDiscovery::Static,
+                    rendered_only_links: vec![],
                 },
             ],
+            rendered: false,
         };
 
         let targets = unique_internal_targets(&crawl);
@@ -684,4 +893,48 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn test_rendered_crawl_node_separates_rendered_only_links() {
+        let html = r##"<a href="/about">About</a><div id="app"></div>"##;
+        let rendered = vec![
+            "https://www.casoon.de/about".to_string(),
+            "https://www.casoon.de/dashboard/".to_string(),
+            "https://www.casoon.de/settings#profile".to_string(),
+            "https://cdn.example.org/docs".to_string(),
+        ];
+
+        let node = rendered_crawl_node(
+            "https://www.casoon.de/",
+            1,
+            LinkDiscovery::Rendered,
+            "www.casoon.de",
+            Some(html),
+            &rendered,
+            &CrawlPolicy::default(),
+        );
+        assert_eq!(
+            node.links_out,
+            vec![
+                "https://www.casoon.de/about".to_string(),
+                "https://www.casoon.de/dashboard".to_string(),
+                "https://www.casoon.de/settings".to_string(),
+            ]
+        );
+        assert_eq!(
+            node.rendered_only_links,
+            vec![
+                "https://www.casoon.de/dashboard".to_string(),
+                "https://www.casoon.de/settings".to_string(),
+            ]
+        );
+        assert_eq!(
+            node.external_links_out,
+            vec!["https://cdn.example.org/docs".to_string()]
+        );
+
+        let discovery = page_discovery(&node);
+        assert_eq!(discovery.static_links, 1);
+        assert_eq!(discovery.discovered_via, LinkDiscovery::Rendered);
+    }
 }
@@ -0,0 +1,339 @@
+//! Crawl politeness and URL scope.
+//!
+//! [`CrawlPolicy`] decides which discovered URLs the crawler may visit and
+//! how they are normalised:
+//! - robots.txt rules for the request-mode user agent (`auditmysite` in bot
+//!   mode, the `*` group when simulating a browser), including `Crawl-delay`
+//! - include/exclude patterns from `[crawl]` in `auditmysite.toml` — globs
+//!   matched against path and query, or regexes with a `re:` prefix
+//! - query-parameter rules (strip tracking parameters, sort the rest)
+//!
+//! [`RateLimiter`] spaces requests per host. One instance is shared by the
+//! crawl, the link checks and the sitemap diagnostics of a run, so the
+//! configured requests-per-second cap holds across all three.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use regex::Regex;
+use tokio::time::Instant;
+use tracing::{info, warn};
+use url::Url;
+
+use crate::cli::config::CrawlConfig;
+use crate::cli::RequestMode;
+use crate::error::{AuditError, Result};
+use crate::seo::robots::{audit_robots_txt, RobotsAudit};
+use crate::util::glob_match;
+
+/// robots.txt product token used in `--request-mode bot`.
+pub const ROBOTS_AGENT: &str = "auditmysite";
+
+/// An include/exclude entry from `[crawl]`.
+#[derive(Debug, Clone)]
+enum UrlPattern {
+    Glob(String),
+    Regex(Regex),
+}
+
+impl UrlPattern {
+    fn parse(raw: &str) -> Result<Self> {
+        match raw.strip_prefix("re:") {
+            Some(expr) => Regex::new(expr).map(UrlPattern::Regex).map_err(|e| {
+                AuditError::ConfigError(format!("Invalid crawl pattern '{raw}': {e}"))
+            }),
+            None => Ok(UrlPattern::Glob(raw.to_string())),
+        }
+    }
+
+    fn matches(&self, target: &str) -> bool {
+        match self {
+            UrlPattern::Glob(glob) => glob_match(glob, target),
+            UrlPattern::Regex(re) => re.is_match(target),
+        }
+    }
+}
+
+/// Which URLs a crawl may visit, and how discovered URLs are normalised.
+#[derive(Debug, Clone, Default)]
+pub struct CrawlPolicy {
+    include: Vec<UrlPattern>,
+    exclude: Vec<UrlPattern>,
+    strip_query_params: Vec<String>,
+    sort_query_params: bool,
+    respect_robots: bool,
+    agent: String,
+    robots: Option<RobotsAudit>,
+    /// Per-host request spacing shared with link checks and sitemap checks.
+    pub limiter: RateLimiter,
+}
+
+impl CrawlPolicy {
+    /// Build the policy from `[crawl]`; `None` means no filters, robots.txt
+    /// respected and no rate cap beyond `Crawl-delay`.
+    pub fn new(config: Option<&CrawlConfig>, request_mode: RequestMode) -> Result<Self> {
+        let default = CrawlConfig::default();
+        let config = config.unwrap_or(&default);
+        let parse_all = |raw: &[String]| -> Result<Vec<UrlPattern>> {
+            raw.iter().map(|p| UrlPattern::parse(p)).collect()
+        };
+        Ok(Self {
+            include: parse_all(&config.include)?,
+            exclude: parse_all(&config.exclude)?,
+            strip_query_params: config.strip_query_params.clone(),
+            sort_query_params: config.sort_query_params,
+            respect_robots: config.respect_robots.unwrap_or(true),
+            agent: match request_mode {
+                RequestMode::Bot => ROBOTS_AGENT.to_string(),
+                RequestMode::Browser => "*".to_string(),
+            },
+            robots: None,
+            limiter: RateLimiter::new(config.requests_per_second),
+        })
+    }
+
+    /// Fetch robots.txt for the seed's host and apply its `Crawl-delay` to
+    /// the rate limiter. A missing or unreadable robots.txt allows everything.
+    pub async fn load_robots(&mut self, seed_url: &str) {
+        if !self.respect_robots {
+            return;
+        }
+        let robots = audit_robots_txt(seed_url, None, false, "en").await;
+        if !robots.fetched {
+            info!(
+                "No robots.txt rules applied for {}: {}",
+                seed_url,
+                robots.error.as_deref().unwrap_or("not found")
+            );
+            return;
+        }
+        if let (Some(delay), Some(host)) = (
+            robots.crawl_delay_for(&self.agent),
+            Url::parse(seed_url)
+                .ok()
+                .and_then(|u| u.host_str().map(str::to_string)),
+        ) {
+            info!(
+                "Honouring robots.txt Crawl-delay of {}s for {}",
+                delay, host
+            );
+            self.limiter
+                .set_host_interval(&host, Duration::from_secs(u64::from(delay)));
+        }
+        self.robots = Some(robots);
+    }
+
+    /// Whether robots.txt lets the request-mode agent fetch `url`.
+    pub fn robots_allows(&self, url: &str) -> bool {
+        let Some(robots) = &self.robots else {
+            return true;
+        };
+        Url::parse(url)
+            .map(|u| robots.is_allowed(&self.agent, &path_and_query(&u)))
+            .unwrap_or(true)
+    }
+
+    /// Whether the crawl should visit `url`: robots.txt allows it, it
+    /// matches an include pattern (when any are set) and no exclude pattern.
+    pub fn allows(&self, url: &str) -> bool {
+        let Ok(parsed) = Url::parse(url) else {
+            return false;
+        };
+        let target = path_and_query(&parsed);
+        let included = self.include.is_empty() || self.include.iter().any(|p| p.matches(&target));
+        let excluded = self.exclude.iter().any(|p| p.matches(&target));
+        if !included || excluded {
+            return false;
+        }
+        if !self.robots_allows(url) {
+            info!("Skipping {} (disallowed by robots.txt)", url);
+            return false;
+        }
+        true
+    }
+
+    /// Apply the query-parameter rules to a discovered URL.
+    pub fn normalize(&self, url: &str) -> String {
+        if self.strip_query_params.is_empty() && !self.sort_query_params {
+            return url.to_string();
+        }
+        let Ok(mut parsed) = Url::parse(url) else {
+            return url.to_string();
+        };
+        if parsed.query().is_none() {
+            return url.to_string();
+        }
+        let mut pairs: Vec<(String, String)> = parsed
+            .query_pairs()
+            .filter(|(name, _)| {
+                !self
+                    .strip_query_params
+                    .iter()
+                    .any(|pattern| glob_match(pattern, name))
+            })
+            .map(|(name, value)| (name.into_owned(), value.into_owned()))
+            .collect();
+        if self.sort_query_params {
+            pairs.sort();
+        }
+        if pairs.is_empty() {
+            parsed.set_query(None);
+        } else {
+            parsed.query_pairs_mut().clear().extend_pairs(pairs);
+        }
+        parsed.to_string()
+    }
+}
+
+fn path_and_query(url: &Url) -> String {
+    match url.query() {
+        Some(query) => format!("{}?{}", url.path(), query),
+        None => url.path().to_string(),
+    }
+}
+
+/// Per-host request spacing. Cloning shares the schedule.
+#[derive(Debug, Clone, Default)]
+pub struct RateLimiter {
+    inner: Arc<RateLimiterInner>,
+}
+
+#[derive(Debug, Default)]
+struct RateLimiterInner {
+    /// Spacing derived from the requests-per-second cap.
+    interval: Duration,
+    /// Longer spacing for hosts with a robots.txt `Crawl-delay`.
+    host_intervals: Mutex<HashMap<String, Duration>>,
+    /// Earliest start of the next request per host.
+    next_slot: Mutex<HashMap<String, Instant>>,
+}
+
+impl RateLimiter {
+    /// A limiter allowing `requests_per_second` per host; `None` or a
+    /// non-positive value leaves hosts unthrottled unless a `Crawl-delay`
+    /// is set.
+    pub fn new(requests_per_second: Option<f64>) -> Self {
+        let interval = match requests_per_second {
+            Some(rps) if rps > 0.0 && rps.is_finite() => Duration::from_secs_f64(1.0 / rps),
+            Some(rps) => {
+                warn!("Ignoring requests_per_second = {}", rps);
+                Duration::ZERO
+            }
+            None => Duration::ZERO,
+        };
+        Self {
+            inner: Arc::new(RateLimiterInner {
+                interval,
+                ..Default::default()
+            }),
+        }
+    }
+
+    /// Space requests to `host` by at least `interval`.
+    pub fn set_host_interval(&self, host: &str, interval: Duration) {
+        if let Ok(mut hosts) = self.inner.host_intervals.lock() {
+            hosts.insert(host.to_string(), interval);
+        }
+    }
+
+    fn interval_for(&self, host: &str) -> Duration {
+        let host_interval = self
+            .inner
+            .host_intervals
+            .lock()
+            .ok()
+            .and_then(|hosts| hosts.get(host).copied())
+            .unwrap_or_default();
+        host_interval.max(self.inner.interval)
+    }
+
+    /// Wait until a request to `url`'s host may start, then reserve the slot.
+    pub async fn wait(&self, url: &str) {
+        let Some(host) = Url::parse(url)
+            .ok()
+            .and_then(|u| u.host_str().map(str::to_string))
+        else {
+            return;
+        };
+        let interval = self.interval_for(&host);
+        if interval.is_zero() {
+            return;
+        }
+        let slot = {
+            let Ok(mut slots) = self.inner.next_slot.lock() else {
+                return;
+            };
+            let now = Instant::now();
+            let slot = slots.get(&host).copied().unwrap_or(now).max(now);
+            slots.insert(host, slot + interval);
+            slot
+        };
+        tokio::time::sleep_until(slot).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn policy(config: CrawlConfig) -> CrawlPolicy {
+        CrawlPolicy::new(Some(&config), RequestMode::Browser).unwrap()
+    }
+
+    #[test]
+    fn include_and_exclude_patterns_filter_urls() {
+        let policy = policy(CrawlConfig {
+            include: vec!["/docs/*".to_string(), r"re:^/blog/\d+$".to_string()],
+            exclude: vec!["*/draft*".to_string()],
+            ..Default::default()
+        });
+
+        assert!(policy.allows("https://example.com/docs/intro"));
+        assert!(policy.allows("https://example.com/blog/42"));
+        assert!(!policy.allows("https://example.com/blog/latest"));
+        assert!(!policy.allows("https://example.com/docs/drafts/x"));
+        assert!(!policy.allows("https://example.com/pricing"));
+
+        let invalid = CrawlConfig {
+            exclude: vec!["re:(".to_string()],
+            ..Default::default()
+        };
+        assert!(CrawlPolicy::new(Some(&invalid), RequestMode::Bot).is_err());
+    }
+
+    #[test]
+    fn query_rules_strip_tracking_and_sort() {
+        let policy = policy(CrawlConfig {
+            strip_query_params: vec!["utm_*".to_string(), "fbclid".to_string()],
+            sort_query_params: true,
+            ..Default::default()
+        });
+
+        assert_eq!(
+            policy.normalize("https://example.com/p?utm_source=x&b=2&fbclid=y&a=1"),
+            "https://example.com/p?a=1&b=2"
+        );
+        assert_eq!(
+            policy.normalize("https://example.com/p?utm_campaign=spring"),
+            "https://example.com/p"
+        );
+    }
+
+    #[tokio::test]
+    async fn rate_limiter_spaces_requests_per_host() {
+        let limiter = RateLimiter::new(Some(20.0));
+        let start = Instant::now();
+        for _ in 0..3 {
+            limiter.wait("https://example.com/page").await;
+        }
+        assert!(start.elapsed() >= Duration::from_millis(100));
+
+        // A Crawl-delay longer than the cap wins for its host.
+        limiter.set_host_interval("slow.example", Duration::from_millis(150));
+        let start = Instant::now();
+        limiter.wait("https://slow.example/a").await;
+        limiter.wait("https://slow.example/b").await;
+        assert!(start.elapsed() >= Duration::from_millis(150));
+    }
+}
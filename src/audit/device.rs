@@ -0,0 +1,470 @@
+//! Device profiles for the viewport matrix.
+//!
+//! Every audit runs a desktop and a mobile pass. `[[devices]]` tables in
+//! `auditmysite.toml` can redefine those two (`name = "desktop"` /
+//! `name = "mobile"`) and add further profiles — tablet, small Android, 4K
+//! desktop, landscape phone. Each additional profile gets its own WCAG and
+//! contrast pass (plus mobile friendliness for touch/mobile profiles) after
+//! the primary passes.
+//!
+//! Findings are matched across devices on rule + selector (rule + message for
+//! page-level findings). A finding that is not seen on every audited device
+//! is tagged `breakpoint-specific`, and each device lists the findings that
+//! occur on it alone.
+
+use std::collections::BTreeSet;
+
+use serde::{Deserialize, Serialize};
+
+use crate::audit::module::Viewport;
+use crate::error::{AuditError, Result};
+use crate::wcag::{Severity, Violation};
+
+pub const DESKTOP_DEVICE: &str = "desktop";
+pub const MOBILE_DEVICE: &str = "mobile";
+
+/// Profiles narrower than this (CSS pixels) default to mobile emulation.
+const MOBILE_MAX_WIDTH: u32 = 768;
+
+/// Tag on findings seen on some, but not all, audited devices.
+pub const BREAKPOINT_SPECIFIC_TAG: &str = "breakpoint-specific";
+/// Prefix of the per-device presence tags (`device:tablet`).
+pub const DEVICE_TAG_PREFIX: &str = "device:";
+
+/// One emulated device: viewport metrics, touch support and user agent.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DeviceProfile {
+    pub name: String,
+    /// CSS pixels.
+    pub width: u32,
+    /// CSS pixels.
+    pub height: u32,
+    #[serde(default = "default_device_scale_factor", alias = "dpr")]
+    pub device_scale_factor: f64,
+    #[serde(default)]
+    pub touch: bool,
+    /// Mobile emulation (meta viewport, overlay scrollbars). When absent,
+    /// `desktop` / `mobile` inherit the built-in pass, other profiles count as
+    /// mobile below 768 CSS pixels or with `touch`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mobile: Option<bool>,
+    /// User agent for this device; the browser default when absent.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_agent: Option<String>,
+}
+
+fn default_device_scale_factor() -> f64 {
+    1.0
+}
+
+impl DeviceProfile {
+    /// Built-in desktop pass: 1280×800 @1x.
+    pub fn desktop() -> Self {
+        Self {
+            name: DESKTOP_DEVICE.to_string(),
+            width: 1280,
+            height: 800,
+            device_scale_factor: 1.0,
+            touch: false,
+            mobile: Some(false),
+            user_agent: None,
+        }
+    }
+
+    /// Built-in mobile pass: 390×844 @2x.
+    pub fn mobile() -> Self {
+        Self {
+            name: MOBILE_DEVICE.to_string(),
+            width: 390,
+            height: 844,
+            device_scale_factor: 2.0,
+            touch: false,
+            mobile: Some(true),
+            user_agent: None,
+        }
+    }
+
+    pub fn is_mobile(&self) -> bool {
+        self.mobile.unwrap_or(match self.name.as_str() {
+            DESKTOP_DEVICE => false,
+            MOBILE_DEVICE => true,
+            _ => self.width < MOBILE_MAX_WIDTH || self.touch,
+        })
+    }
+
+    /// Layout class the audit modules see for this device.
+    pub fn viewport(&self) -> Viewport {
+        if self.is_mobile() {
+            Viewport::Mobile
+        } else {
+            Viewport::Desktop
+        }
+    }
+
+    /// Short metrics label, e.g. `820×1180 @2x, touch`.
+    pub fn metrics_label(&self) -> String {
+        let mut label = format!(
+            "{}×{} @{}x",
+            self.width, self.height, self.device_scale_factor
+        );
+        if self.touch {
+            label.push_str(", touch");
+        }
+        label
+    }
+
+    fn validate(&self) -> Result<()> {
+        if self.name.trim().is_empty() {
+            return Err(AuditError::ConfigError(
+                "device profile without a name".to_string(),
+            ));
+        }
+        if self.width == 0 || self.height == 0 || self.device_scale_factor <= 0.0 {
+            return Err(AuditError::ConfigError(format!(
+                "device '{}': width, height and device_scale_factor must be positive",
+                self.name
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// The devices one audit runs on: the two primary passes plus any extras.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DeviceMatrix {
+    pub desktop: DeviceProfile,
+    pub mobile: DeviceProfile,
+    pub extra: Vec<DeviceProfile>,
+}
+
+impl Default for DeviceMatrix {
+    fn default() -> Self {
+        Self {
+            desktop: DeviceProfile::desktop(),
+            mobile: DeviceProfile::mobile(),
+            extra: Vec::new(),
+        }
+    }
+}
+
+impl DeviceMatrix {
+    /// Build the matrix from `[[devices]]`: `desktop` / `mobile` replace the
+    /// built-in passes, every other profile is added.
+    pub fn from_profiles(profiles: &[DeviceProfile]) -> Result<Self> {
+        let mut matrix = Self::default();
+        let mut seen = BTreeSet::new();
+        for profile in profiles {
+            profile.validate()?;
+            if !seen.insert(profile.name.as_str()) {
+                return Err(AuditError::ConfigError(format!(
+                    "device '{}' is defined twice",
+                    profile.name
+                )));
+            }
+            match profile.name.as_str() {
+                DESKTOP_DEVICE => matrix.desktop = profile.clone(),
+                MOBILE_DEVICE => matrix.mobile = profile.clone(),
+                _ => matrix.extra.push(profile.clone()),
+            }
+        }
+        Ok(matrix)
+    }
+
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Desktop, mobile, then the extra profiles in configuration order.
+    pub fn all(&self) -> impl Iterator<Item = &DeviceProfile> {
+        [&self.desktop, &self.mobile].into_iter().chain(&self.extra)
+    }
+
+    /// Whether any profile needs touch emulation, so passes without touch
+    /// must switch it off again.
+    pub fn uses_touch(&self) -> bool {
+        self.all().any(|p| p.touch)
+    }
+
+    /// Whether any profile overrides the user agent, so passes without an
+    /// override must restore the browser default.
+    pub fn uses_user_agent(&self) -> bool {
+        self.all().any(|p| p.user_agent.is_some())
+    }
+
+    /// Stable text for the audit cache signature.
+    pub fn signature(&self) -> String {
+        self.all()
+            .map(|p| {
+                format!(
+                    "{}:{}x{}@{}:{}:{}:{}",
+                    p.name,
+                    p.width,
+                    p.height,
+                    p.device_scale_factor,
+                    p.touch,
+                    p.is_mobile(),
+                    p.user_agent.as_deref().unwrap_or("")
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+}
+
+/// Per-device outcome shown in the device matrix of JSON/PDF/CLI reports.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeviceResult {
+    pub name: String,
+    pub width: u32,
+    pub height: u32,
+    pub device_scale_factor: f64,
+    #[serde(default)]
+    pub touch: bool,
+    pub accessibility_score: u32,
+    pub violation_count: usize,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mobile_score: Option<u32>,
+    /// Findings that occur on this device and no other.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub exclusive_findings: Vec<DeviceFinding>,
+}
+
+/// A finding reported only on one device.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeviceFinding {
+    pub rule: String,
+    pub rule_name: String,
+    pub severity: Severity,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub selector: Option<String>,
+}
+
+impl DeviceResult {
+    /// Summarize one device pass; `merged` is the final, device-tagged
+    /// finding list of the report.
+    pub fn new(
+        profile: &DeviceProfile,
+        accessibility_score: u32,
+        violation_count: usize,
+        mobile_score: Option<u32>,
+        merged: &[Violation],
+    ) -> Self {
+        let exclusive_findings = merged
+            .iter()
+            .filter(|v| {
+                let devices = devices_of(v);
+                devices.len() == 1 && devices[0] == profile.name
+            })
+            .map(|v| DeviceFinding {
+                rule: v.rule.clone(),
+                rule_name: v.rule_name.clone(),
+                severity: v.severity,
+                selector: v.selector.clone(),
+            })
+            .collect();
+        Self {
+            name: profile.name.clone(),
+            width: profile.width,
+            height: profile.height,
+            device_scale_factor: profile.device_scale_factor,
+            touch: profile.touch,
+            accessibility_score,
+            violation_count,
+            mobile_score,
+            exclusive_findings,
+        }
+    }
+}
+
+fn match_key(v: &Violation) -> (&str, &str) {
+    let location = v
+        .selector
+        .as_deref()
+        .filter(|s| !s.is_empty())
+        .unwrap_or(v.message.as_str());
+    (v.rule.as_str(), location)
+}
+
+/// Fold one extra device pass into the merged desktop/mobile findings: known
+/// findings gain a `device:<name>` tag, unknown ones are appended with only
+/// that tag.
+pub fn merge_device_findings(merged: &mut Vec<Violation>, device: &str, violations: &[Violation]) {
+    let tag = format!("{DEVICE_TAG_PREFIX}{device}");
+    let known = merged.len();
+    for violation in violations {
+        let key = match_key(violation);
+        if let Some(existing) = merged[..known]
+            .iter_mut()
+            .find(|candidate| match_key(candidate) == key)
+        {
+            if !existing.tags.contains(&tag) {
+                existing.tags.push(tag.clone());
+            }
+            continue;
+        }
+        let mut finding = violation.clone();
+        finding.tags.push(tag.clone());
+        merged.push(finding);
+    }
+}
+
+/// Devices a merged finding was seen on. Empty for findings that carry no
+/// viewport information (e.g. structural pattern findings).
+pub fn devices_of(v: &Violation) -> Vec<&str> {
+    let mut devices = Vec::new();
+    for tag in &v.tags {
+        match tag.as_str() {
+            "desktop-only" => devices.push(DESKTOP_DEVICE),
+            "mobile-only" => devices.push(MOBILE_DEVICE),
+            "both-viewports" => devices.extend([DESKTOP_DEVICE, MOBILE_DEVICE]),
+            other => {
+                if let Some(device) = other.strip_prefix(DEVICE_TAG_PREFIX) {
+                    devices.push(device);
+                }
+            }
+        }
+    }
+    devices
+}
+
+/// Tag findings seen on only part of the device matrix.
+pub fn tag_breakpoint_specific(merged: &mut [Violation], matrix: &DeviceMatrix) {
+    let total = matrix.all().count();
+    for violation in merged {
+        let seen = devices_of(violation).len();
+        if seen > 0 && seen < total && !violation.tags.iter().any(|t| t == BREAKPOINT_SPECIFIC_TAG)
+        {
+            violation.tags.push(BREAKPOINT_SPECIFIC_TAG.to_string());
+        }
+    }
+}
+
+/// Accessibility score across the whole device matrix: the 70/30
+/// mobile/desktop blend of [`ViewportScores::weighted_accessibility`], where
+/// each side is the mean of its primary viewport and the extra devices of
+/// that layout class.
+///
+/// [`ViewportScores::weighted_accessibility`]: crate::audit::ViewportScores::weighted_accessibility
+pub fn weighted_device_accessibility(
+    desktop: u32,
+    mobile: u32,
+    extra: &[(&DeviceProfile, u32)],
+) -> u32 {
+    let mean = |primary: u32, is_mobile: bool| {
+        let scores: Vec<u32> = std::iter::once(primary)
+            .chain(
+                extra
+                    .iter()
+                    .filter(|(profile, _)| profile.is_mobile() == is_mobile)
+                    .map(|(_, score)| *score),
+            )
+            .collect();
+        scores.iter().map(|&s| s as f64).sum::<f64>() / scores.len() as f64
+    };
+    (mean(mobile, true) * 0.7 + mean(desktop, false) * 0.3).round() as u32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::WcagLevel;
+
+    fn violation(rule: &str, selector: &str, tags: &[&str]) -> Violation {
+        let mut v = Violation::new(rule, "Rule", WcagLevel::AA, Severity::High, "msg", "1")
+            .with_selector(selector);
+        v.tags = tags.iter().map(|t| t.to_string()).collect();
+        v
+    }
+
+    fn tablet() -> DeviceProfile {
+        DeviceProfile {
+            name: "tablet".to_string(),
+            width: 820,
+            height: 1180,
+            device_scale_factor: 2.0,
+            touch: true,
+            mobile: None,
+            user_agent: None,
+        }
+    }
+
+    #[test]
+    fn named_profiles_replace_builtin_passes() {
+        let mut desktop = DeviceProfile::desktop();
+        desktop.width = 3840;
+        desktop.height = 2160;
+        let matrix = DeviceMatrix::from_profiles(&[desktop, tablet()]).unwrap();
+        assert_eq!(matrix.desktop.width, 3840);
+        assert_eq!(matrix.mobile, DeviceProfile::mobile());
+        assert_eq!(matrix.extra.len(), 1);
+        assert!(matrix.extra[0].is_mobile(), "touch implies mobile");
+        assert!(matrix.uses_touch());
+        assert!(DeviceMatrix::from_profiles(&[tablet(), tablet()]).is_err());
+    }
+
+    #[test]
+    fn mobile_emulation_defaults_follow_name_then_width() {
+        let configured = |name: &str, width: u32| DeviceProfile {
+            name: name.to_string(),
+            width,
+            height: 800,
+            device_scale_factor: 2.0,
+            touch: false,
+            mobile: None,
+            user_agent: None,
+        };
+        assert!(configured(MOBILE_DEVICE, 412).is_mobile());
+        assert!(
+            configured(MOBILE_DEVICE, 900).is_mobile(),
+            "redefined mobile pass stays mobile without touch"
+        );
+        assert!(!configured(DESKTOP_DEVICE, 600).is_mobile());
+        assert!(configured("small-android", 360).is_mobile());
+        assert!(!configured("laptop", 1440).is_mobile());
+
+        let mut forced = configured("small-android", 360);
+        forced.mobile = Some(false);
+        assert!(!forced.is_mobile());
+    }
+
+    #[test]
+    fn device_only_findings_are_breakpoint_specific() {
+        let matrix = DeviceMatrix::from_profiles(&[tablet()]).unwrap();
+        let mut merged = vec![
+            violation("1.4.3", "p.lead", &["both-viewports"]),
+            violation("2.5.8", "a.nav", &["mobile-only"]),
+        ];
+        merge_device_findings(
+            &mut merged,
+            "tablet",
+            &[
+                violation("1.4.3", "p.lead", &[]),
+                violation("1.4.10", "table.prices", &[]),
+            ],
+        );
+        tag_breakpoint_specific(&mut merged, &matrix);
+
+        assert_eq!(merged.len(), 3);
+        assert!(!merged[0].tags.iter().any(|t| t == BREAKPOINT_SPECIFIC_TAG));
+        assert!(merged[1].tags.iter().any(|t| t == BREAKPOINT_SPECIFIC_TAG));
+        assert_eq!(devices_of(&merged[2]), vec!["tablet"]);
+
+        let result = DeviceResult::new(&matrix.extra[0], 90, 2, None, &merged);
+        assert_eq!(result.exclusive_findings.len(), 1);
+        assert_eq!(result.exclusive_findings[0].rule, "1.4.10");
+    }
+
+    #[test]
+    fn extra_devices_count_towards_their_layout_class() {
+        assert_eq!(weighted_device_accessibility(90, 80, &[]), 83);
+
+        let tablet = tablet();
+        let mut wide = DeviceProfile::desktop();
+        wide.name = "wide".to_string();
+        // Mobile side: (80 + 60) / 2 = 70; desktop side: (90 + 70) / 2 = 80.
+        assert_eq!(
+            weighted_device_accessibility(90, 80, &[(&tablet, 60), (&wide, 70)]),
+            73
+        );
+    }
+}
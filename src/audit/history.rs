@@ -0,0 +1,490 @@
+//! Local audit history for trend reports.
+//!
+//! Every fresh audit appends one compact [`HistoryEntry`] per URL and keeps
+//! the normalized report it was derived from. Batch runs additionally append
+//! a site-level entry per host. Layout below the store root:
+//!
+//! ```text
+//! <host>/site.jsonl                      batch runs (site-level entries)
+//! <host>/pages/<url-hash>/index.jsonl    one entry per audit of that URL
+//! <host>/pages/<url-hash>/<millis>.json  normalized report of that audit
+//! ```
+//!
+//! Each index keeps at most [`DEFAULT_HISTORY_KEEP`] entries unless the store
+//! is opened with a different retention; older entries and their stored
+//! reports are pruned on the next write.
+
+use std::collections::BTreeMap;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::audit::normalized::{NormalizedReport, RiskLevel, SeverityCounts};
+use crate::error::{AuditError, Result};
+
+/// Entries kept per page and per site when no retention is configured.
+pub const DEFAULT_HISTORY_KEEP: usize = 30;
+
+/// One point of a score/risk/finding trend.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HistoryEntry {
+    /// Audited URL, or the host for site-level entries
+    pub url: String,
+    pub timestamp: DateTime<Utc>,
+    pub accessibility_score: u32,
+    pub overall_score: u32,
+    pub risk_level: RiskLevel,
+    /// Distinct finding rows (one per rule)
+    pub finding_count: usize,
+    /// Findings by severity
+    pub severity_counts: SeverityCounts,
+    /// Module name → score
+    #[serde(default)]
+    pub module_scores: BTreeMap<String, u32>,
+    /// Pages covered: 1 for a page entry, the audited page count for a site entry
+    #[serde(default = "one")]
+    pub pages: usize,
+    /// File name of the stored normalized report (page entries only)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub report_file: Option<String>,
+    pub cli_version: String,
+}
+
+fn one() -> usize {
+    1
+}
+
+impl HistoryEntry {
+    pub fn from_normalized(report: &NormalizedReport) -> Self {
+        Self {
+            url: report.url.clone(),
+            timestamp: report.timestamp,
+            accessibility_score: report.score,
+            overall_score: report.overall_score,
+            risk_level: report.risk.level,
+            finding_count: report.findings.len(),
+            severity_counts: report.severity_counts.clone(),
+            module_scores: report
+                .module_scores
+                .iter()
+                .map(|m| (m.name.clone(), m.score))
+                .collect(),
+            pages: 1,
+            report_file: None,
+            cli_version: env!("CARGO_PKG_VERSION").to_string(),
+        }
+    }
+
+    /// Site-level entry: averaged scores, worst risk and summed findings.
+    fn from_pages(host: &str, timestamp: DateTime<Utc>, pages: &[&NormalizedReport]) -> Self {
+        let n = pages.len().max(1);
+        let average = |score: fn(&&NormalizedReport) -> u32| {
+            (pages.iter().map(|p| score(p) as f64).sum::<f64>() / n as f64).round() as u32
+        };
+        let mut severity_counts = SeverityCounts::default();
+        let mut module_totals: BTreeMap<String, (u32, u32)> = BTreeMap::new();
+        for page in pages {
+            let counts = &page.severity_counts;
+            severity_counts.critical += counts.critical;
+            severity_counts.high += counts.high;
+            severity_counts.medium += counts.medium;
+            severity_counts.low += counts.low;
+            severity_counts.total += counts.total;
+            for module in &page.module_scores {
+                let slot = module_totals.entry(module.name.clone()).or_default();
+                slot.0 += module.score;
+                slot.1 += 1;
+            }
+        }
+        Self {
+            url: host.to_string(),
+            timestamp,
+            accessibility_score: average(|p| p.score),
+            overall_score: average(|p| p.overall_score),
+            risk_level: pages.iter().map(|p| p.risk.level).max().unwrap_or_default(),
+            finding_count: pages.iter().map(|p| p.findings.len()).sum(),
+            severity_counts,
+            module_scores: module_totals
+                .into_iter()
+                .map(|(name, (sum, count))| (name, (sum as f64 / count as f64).round() as u32))
+                .collect(),
+            pages: pages.len(),
+            report_file: None,
+            cli_version: env!("CARGO_PKG_VERSION").to_string(),
+        }
+    }
+}
+
+/// Whether a history query resolved to one page or a whole site.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum HistoryScope {
+    Page,
+    Site,
+}
+
+/// Entries for one `history` query, oldest first.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HistoryTrend {
+    pub target: String,
+    pub scope: HistoryScope,
+    pub entries: Vec<HistoryEntry>,
+}
+
+/// File-backed history store.
+#[derive(Debug, Clone)]
+pub struct HistoryStore {
+    root: PathBuf,
+    keep: usize,
+}
+
+impl HistoryStore {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self {
+            root: root.into(),
+            keep: DEFAULT_HISTORY_KEEP,
+        }
+    }
+
+    /// Keep at most `keep` entries per page and per site; `0` keeps everything.
+    pub fn with_retention(mut self, keep: usize) -> Self {
+        self.keep = keep;
+        self
+    }
+
+    /// Store rooted at `dir`, or `~/.auditmysite/history` when `None`.
+    pub fn open(dir: Option<&Path>) -> Option<Self> {
+        match dir {
+            Some(dir) => Some(Self::new(dir)),
+            None => Some(Self::new(
+                dirs::home_dir()?.join(".auditmysite").join("history"),
+            )),
+        }
+    }
+
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// Record one audited page: store the normalized report and append its entry.
+    pub fn record(&self, report: &NormalizedReport) -> Result<HistoryEntry> {
+        let dir = self.page_dir(&report.url);
+        create_dir(&dir)?;
+        let file_name = format!("{}.json", report.timestamp.timestamp_millis());
+        let json = serde_json::to_string(report)?;
+        write_file(&dir.join(&file_name), json.as_bytes())?;
+
+        let mut entry = HistoryEntry::from_normalized(report);
+        entry.report_file = Some(file_name);
+        let index = dir.join("index.jsonl");
+        append_line(&index, &entry)?;
+        self.prune(&index)?;
+        Ok(entry)
+    }
+
+    /// Record a batch run from its normalized pages: every page individually
+    /// plus one site entry per host.
+    pub fn record_batch(&self, pages: &[NormalizedReport]) -> Result<()> {
+        let mut by_host: BTreeMap<String, Vec<&NormalizedReport>> = BTreeMap::new();
+        for page in pages {
+            self.record(page)?;
+            by_host.entry(host_key(&page.url)).or_default().push(page);
+        }
+        let timestamp = Utc::now();
+        for (host, pages) in by_host {
+            let dir = self.root.join(&host);
+            create_dir(&dir)?;
+            let index = dir.join("site.jsonl");
+            append_line(&index, &HistoryEntry::from_pages(&host, timestamp, &pages))?;
+            self.prune(&index)?;
+        }
+        Ok(())
+    }
+
+    /// Drop the oldest entries of `index` beyond the retention limit, together
+    /// with the reports they reference.
+    fn prune(&self, index: &Path) -> Result<()> {
+        if self.keep == 0 {
+            return Ok(());
+        }
+        let mut entries = read_entries(index)?;
+        if entries.len() <= self.keep {
+            return Ok(());
+        }
+        entries.sort_by_key(|e| e.timestamp);
+        let dropped: Vec<HistoryEntry> = entries.drain(..entries.len() - self.keep).collect();
+        let dir = index.parent().unwrap_or(&self.root);
+        for file in dropped.iter().filter_map(|e| e.report_file.as_deref()) {
+            let path = dir.join(file);
+            if let Err(e) = std::fs::remove_file(&path) {
+                if e.kind() != std::io::ErrorKind::NotFound {
+                    return Err(AuditError::FileError {
+                        path,
+                        reason: e.to_string(),
+                    });
+                }
+            }
+        }
+        let mut content = String::new();
+        for entry in &entries {
+            content.push_str(&serde_json::to_string(entry)?);
+            content.push('\n');
+        }
+        write_file(index, content.as_bytes())
+    }
+
+    /// Trend for a URL (page entries) or a bare host (`example.com`, site
+    /// entries from batch runs). Returns at most `limit` newest entries,
+    /// oldest first.
+    pub fn trend(&self, target: &str, limit: usize) -> Result<HistoryTrend> {
+        let (scope, path) = if target.contains("://") {
+            (
+                HistoryScope::Page,
+                self.page_dir(target).join("index.jsonl"),
+            )
+        } else {
+            (
+                HistoryScope::Site,
+                self.root
+                    .join(host_key(&format!("https://{target}")))
+                    .join("site.jsonl"),
+            )
+        };
+        let mut entries = read_entries(&path)?;
+        entries.sort_by_key(|e| e.timestamp);
+        if limit > 0 && entries.len() > limit {
+            entries.drain(..entries.len() - limit);
+        }
+        Ok(HistoryTrend {
+            target: target.to_string(),
+            scope,
+            entries,
+        })
+    }
+
+    /// Load the normalized report stored for an entry.
+    pub fn load_report(&self, entry: &HistoryEntry) -> Result<Option<NormalizedReport>> {
+        let Some(ref file) = entry.report_file else {
+            return Ok(None);
+        };
+        let path = self.page_dir(&entry.url).join(file);
+        let content = std::fs::read_to_string(&path).map_err(|e| AuditError::FileError {
+            path: path.clone(),
+            reason: e.to_string(),
+        })?;
+        Ok(Some(serde_json::from_str(&content)?))
+    }
+
+    fn page_dir(&self, url: &str) -> PathBuf {
+        // URL paths are case-sensitive; only scheme and host are normalized.
+        let key = url::Url::parse(url).map_or_else(|_| url.to_string(), |u| u.to_string());
+        let key = key.trim_end_matches('/');
+        self.root.join(host_key(url)).join("pages").join(format!(
+            "{:016x}",
+            crate::audit::artifacts::fnv1a(key.as_bytes())
+        ))
+    }
+}
+
+/// Directory name for a URL's host (`www.example.com`, port kept as `_8080`).
+fn host_key(url: &str) -> String {
+    let host = url::Url::parse(url)
+        .ok()
+        .and_then(|u| {
+            u.host_str().map(|h| match u.port() {
+                Some(port) => format!("{h}_{port}"),
+                None => h.to_string(),
+            })
+        })
+        .unwrap_or_else(|| "unknown".to_string());
+    host.to_ascii_lowercase()
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || matches!(c, '.' | '-' | '_') {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
+
+fn read_entries(path: &Path) -> Result<Vec<HistoryEntry>> {
+    let content = match std::fs::read_to_string(path) {
+        Ok(content) => content,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => {
+            return Err(AuditError::FileError {
+                path: path.to_path_buf(),
+                reason: e.to_string(),
+            })
+        }
+    };
+    // Skip unreadable lines (e.g. a write interrupted mid-line) rather than
+    // losing the whole trend.
+    Ok(content
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .filter_map(|line| serde_json::from_str(line).ok())
+        .collect())
+}
+
+fn append_line(path: &Path, entry: &HistoryEntry) -> Result<()> {
+    let mut line = serde_json::to_string(entry)?;
+    line.push('\n');
+    std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .and_then(|mut file| file.write_all(line.as_bytes()))
+        .map_err(|e| AuditError::FileError {
+            path: path.to_path_buf(),
+            reason: e.to_string(),
+        })
+}
+
+fn create_dir(dir: &Path) -> Result<()> {
+    std::fs::create_dir_all(dir).map_err(|e| AuditError::FileError {
+        path: dir.to_path_buf(),
+        reason: e.to_string(),
+    })
+}
+
+fn write_file(path: &Path, bytes: &[u8]) -> Result<()> {
+    std::fs::write(path, bytes).map_err(|e| AuditError::FileError {
+        path: path.to_path_buf(),
+        reason: e.to_string(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::audit::normalized::normalize;
+    use crate::audit::AuditReport;
+    use crate::cli::WcagLevel;
+    use crate::taxonomy::Severity;
+    use crate::wcag::{Violation, WcagResults};
+
+    fn report(url: &str, violations: usize, millis: i64) -> AuditReport {
+        let mut results = WcagResults::new();
+        for i in 0..violations {
+            results.add_violation(Violation::new(
+                "1.1.1",
+                "Non-text Content",
+                WcagLevel::A,
+                Severity::High,
+                "Image without alt",
+                format!("node-{i}"),
+            ));
+        }
+        let mut report = AuditReport::new(url.to_string(), WcagLevel::AA, results, 100);
+        report.timestamp = DateTime::from_timestamp_millis(millis).unwrap();
+        report
+    }
+
+    #[test]
+    fn records_page_entries_and_returns_newest_oldest_first() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = HistoryStore::new(dir.path());
+        for (i, violations) in [3usize, 1, 0].into_iter().enumerate() {
+            let normalized = normalize(&report(
+                "https://example.com/about/",
+                violations,
+                1_700_000_000_000 + i as i64,
+            ))
+            .normalized;
+            store.record(&normalized).unwrap();
+        }
+
+        let trend = store.trend("https://Example.com/about", 2).unwrap();
+        assert_eq!(trend.scope, HistoryScope::Page);
+        assert_eq!(trend.entries.len(), 2);
+        assert!(trend.entries[0].timestamp < trend.entries[1].timestamp);
+        assert_eq!(trend.entries[1].finding_count, 0);
+
+        let stored = store.load_report(&trend.entries[0]).unwrap().unwrap();
+        assert_eq!(stored.url, "https://example.com/about/");
+    }
+
+    #[test]
+    fn page_keys_keep_the_path_case() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = HistoryStore::new(dir.path());
+        for (url, violations) in [
+            ("https://example.com/Docs", 2),
+            ("https://example.com/docs", 0),
+        ] {
+            store
+                .record(&normalize(&report(url, violations, 1_700_000_000_000)).normalized)
+                .unwrap();
+        }
+
+        let upper = store.trend("https://EXAMPLE.com/Docs", 10).unwrap();
+        assert_eq!(upper.entries.len(), 1);
+        assert_eq!(upper.entries[0].url, "https://example.com/Docs");
+        let lower = store.trend("https://example.com/docs/", 10).unwrap();
+        assert_eq!(lower.entries.len(), 1);
+        assert_eq!(lower.entries[0].finding_count, 0);
+    }
+
+    #[test]
+    fn batch_runs_add_a_site_entry_per_host() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = HistoryStore::new(dir.path());
+        let pages: Vec<NormalizedReport> = [
+            report("https://example.com/", 2, 1_700_000_000_000),
+            report("https://example.com/contact", 0, 1_700_000_000_001),
+        ]
+        .iter()
+        .map(|r| normalize(r).normalized)
+        .collect();
+        store.record_batch(&pages).unwrap();
+
+        let site = store.trend("example.com", 0).unwrap();
+        assert_eq!(site.scope, HistoryScope::Site);
+        assert_eq!(site.entries.len(), 1);
+        assert_eq!(site.entries[0].pages, 2);
+        assert_eq!(site.entries[0].finding_count, 1);
+        assert_eq!(
+            store
+                .trend("https://example.com/contact", 0)
+                .unwrap()
+                .entries
+                .len(),
+            1
+        );
+    }
+
+    #[test]
+    fn retention_prunes_oldest_entries_and_their_reports() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = HistoryStore::new(dir.path()).with_retention(2);
+        for i in 0..4 {
+            let normalized =
+                normalize(&report("https://example.com/", 1, 1_700_000_000_000 + i)).normalized;
+            store.record(&normalized).unwrap();
+        }
+
+        let trend = store.trend("https://example.com/", 0).unwrap();
+        assert_eq!(trend.entries.len(), 2);
+        assert_eq!(
+            trend.entries[0].timestamp.timestamp_millis(),
+            1_700_000_000_002
+        );
+        let page_dir = store.page_dir("https://example.com/");
+        let stored_reports = std::fs::read_dir(&page_dir)
+            .unwrap()
+            .filter(|e| {
+                e.as_ref()
+                    .unwrap()
+                    .path()
+                    .extension()
+                    .is_some_and(|ext| ext == "json")
+            })
+            .count();
+        assert_eq!(stored_reports, 2);
+    }
+}
@@ -9,7 +9,10 @@ pub mod batch_consistency;
 pub mod budget;
 pub mod catalog;
 mod crawl;
+mod crawl_policy;
+pub mod device;
 pub mod duplicate;
+pub mod history;
 pub mod interpretation;
 pub mod module;
 pub mod normalized;
@@ -18,24 +21,36 @@ pub mod performance_interpretation;
 mod pipeline;
 pub mod prioritization;
 mod report;
+pub mod report_diff;
 mod scoring;
+pub mod shard;
+pub mod statement;
 pub mod summary;
 pub mod template_dedup;
+pub mod url_sampling;
 pub mod verdict;
 
 pub use artifacts::{
-    cache_matches_signature, content_hash, hydrate_cached_report, load_artifacts, save_artifacts,
-    to_audit_report, AuditArtifacts, FetchArtifact, SnapshotArtifact,
+    cache_matches_signature, content_hash, default_run_dir, hydrate_cached_report, load_artifacts,
+    load_run_result, open_run_dir, save_artifacts, save_run_result, to_audit_report,
+    AuditArtifacts, FetchArtifact, RunManifest, SnapshotArtifact,
 };
-pub use baseline::{Baseline, BaselineDiff, BaselineViolation, WaivedViolation};
+pub use baseline::{
+    Baseline, BaselineDiff, BaselineViolation, WaivedViolation, Waiver, WaiverSet,
+    WAIVER_EXPIRED_RULE_ID,
+};
+pub(crate) use batch::audit_url_with_pool;
 pub use batch::{
     analyze_sitemap_diagnostics, count_sitemap_entries_shallow, parse_sitemap, read_url_file,
-    run_concurrent_batch, BatchAuditError, BatchConfig, BatchResult,
+    run_concurrent_batch, run_rendered_crawl_batch, BatchAuditError, BatchConfig, BatchResult,
 };
 pub use budget::{evaluate_budgets, BudgetSeverity, BudgetViolation};
 pub use catalog::AuditCatalog;
 pub use crawl::{analyze_crawl_links, crawl_site, CrawlNode, CrawlResult};
+pub use crawl_policy::{CrawlPolicy, RateLimiter, ROBOTS_AGENT};
+pub use device::{DeviceFinding, DeviceMatrix, DeviceProfile, DeviceResult};
 pub use duplicate::{detect_near_duplicates, DuplicatePair};
+pub use history::{HistoryEntry, HistoryScope, HistoryStore, HistoryTrend, DEFAULT_HISTORY_KEEP};
 pub use module::{AuditModule, ModuleContext, ModuleData, Viewport};
 pub use normalized::{normalize, AuditContext, NormalizedReport};
 pub use pipeline::{audit_page, run_single_audit, PipelineConfig};
@@ -43,13 +58,20 @@ pub use report::{
     compute_recurring_rules, compute_worst_risk, AccessibilitySection, AuditExecution,
     AuditQuality, AuditQualityStatus, AuditReport, AuditScope, AuditedContentState, BatchError,
     BatchReport, BatchSummary, BrokenLink, BrokenLinkSeverity, ConsentAuditState,
-    ConsentCookieSignal, ConsentPrivacySnapshot, CrawlDiagnostics, DiscoverabilitySection,
-    DualViewportResults, ExecutionEnvironment, ExecutionStatus, ExperienceSection, ModuleRun,
-    NavigationSnapshot, PageScreenshots, PerformanceResults, RecurringRule, RedirectChain,
-    SampleMetadata, ScreenshotStatus, SitemapDiagnostics, SitemapHttpIssue, SubcheckRun,
-    ThrottledPerfResult, ViewportAuditData, ViewportDefinition, ViewportScoreSet, ViewportScores,
-    ViewportScreenshot,
+    ConsentCookieSignal, ConsentPrivacySnapshot, CrawlDiagnostics, CrawlPageDiscovery,
+    DiscoverabilitySection, DualViewportResults, ExecutionEnvironment, ExecutionStatus,
+    ExperienceSection, LinkDiscovery, ModuleRun, NavigationSnapshot, PageScreenshots,
+    PerformanceResults, RecurringRule, RedirectChain, SampleMetadata, SamplingScope,
+    ScreenshotStatus, SitemapDiagnostics, SitemapHttpIssue, SubcheckRun, ThrottledPerfResult,
+    ViewportAuditData, ViewportDefinition, ViewportScoreSet, ViewportScores, ViewportScreenshot,
 };
+pub use report_diff::{diff_reports, DiffFinding, PageDiff, ReportDiff, ScoreDelta};
 pub use scoring::{AccessibilityScorer, CoverageRatio, PrincipleCoverage, ViolationStatistics};
+pub use shard::{merge_shards, Shard, ShardReport};
+pub use statement::{draft_statement, ComplianceStatus, StatementDraft};
 pub use template_dedup::{detect_template_clusters, TemplateCluster};
+pub use url_sampling::{
+    cluster_by_path_pattern, extrapolate_template_findings, plan_template_sample,
+    TemplateSamplePlan, TemplateSampling,
+};
 pub use verdict::{compute_batch_verdict, compute_verdict, Verdict, VerdictResult};
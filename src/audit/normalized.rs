@@ -94,6 +94,17 @@ pub struct NormalizedReport {
     /// rechtsrelevant.
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub interactive_findings: Vec<InteractiveFinding>,
+    /// WCAG violations accepted via the waivers file. Kept out of
+    /// `findings[]`, severity counts, risk and the verdict.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub waived_findings: Vec<crate::audit::WaivedViolation>,
+    /// WCAG findings suppressed by in-page `data-auditmysite-ignore`
+    /// annotations. Kept out of `findings[]` like waived findings.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub suppressed_findings: Vec<crate::wcag::SuppressedViolation>,
+    /// Per-device scores and breakpoint-specific findings (`[[devices]]`).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub device_results: Vec<crate::audit::DeviceResult>,
     /// Reproducible journey traces (tab walks, modal opens, …) produced by
     /// the Accessibility-Journey-Layer. `None` when `--interactive=off`.
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -2889,6 +2900,9 @@ pub fn normalize<'a>(report: &'a AuditReport) -> AuditContext<'a> {
         score_calculation_method,
         score_breakdown,
         interactive_findings,
+        waived_findings: report.accessibility.waived.clone(),
+        suppressed_findings: report.accessibility.wcag_results.suppressed.clone(),
+        device_results: report.device_results.clone(),
         accessibility_journey: report.accessibility_journey.clone(),
         screen_reader,
         interpretation: None,
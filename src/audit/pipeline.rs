@@ -25,16 +25,24 @@ use super::report::{
     AuditReport, ConsentCookieSignal, ConsentPrivacySnapshot, DualViewportResults,
     PerformanceResults, ViewportAuditData, ViewportScoreSet, ViewportScores, ViewportScreenshot,
 };
-use crate::accessibility::{enrich_violations_with_page, extract_ax_tree, AXTree};
+use crate::accessibility::annotations::{page_has_annotations, suppress_annotated};
+use crate::accessibility::{
+    apply_ignore_annotations, enrich_violations_with_page, extract_ax_tree, AXTree,
+};
+use crate::audit::baseline::WaiverSet;
+use crate::audit::device::{
+    merge_device_findings, tag_breakpoint_specific, weighted_device_accessibility, DeviceMatrix,
+    DeviceProfile, DeviceResult,
+};
 use crate::audit::scoring::AccessibilityScorer;
 use crate::best_practices::{prepare_console_collection, BestPracticesAnalysis};
 use crate::browser::{
     consent::{handle_post_navigation, inject_consent_cookies},
-    throttle, BrowserManager, ThrottleProfile,
+    throttle, BrowserManager, ThrottlePass, ThrottleProfile,
 };
 use crate::cli::{Args, WcagLevel};
 use crate::dark_mode::DarkModeAnalysis;
-use crate::error::Result;
+use crate::error::{AuditError, Result};
 use crate::interaction::stability::{settle, wait_for_page_stability};
 use crate::journey::JourneyAnalysis;
 use crate::mobile::MobileFriendliness;
@@ -42,6 +50,7 @@ use crate::performance::{prepare_coverage_collection, prepare_vitals_collection}
 use crate::security::{analyze_security, BrowserCertificateDetails, SecurityAnalysis};
 use crate::seo::SeoAnalysis;
 use crate::ux::UxAnalysis;
+use crate::wcag::DeclarativeRuleSet;
 use crate::wcag::{self, Severity, Violation, WcagResults};
 
 // ── Viewport helpers ──────────────────────────────────────────────────────────
@@ -49,32 +58,42 @@ use crate::wcag::{self, Severity, Violation, WcagResults};
 use super::catalog::AuditCatalog;
 use super::module::{ModuleContext, ModuleData, Viewport};
 
-async fn set_viewport(page: &Page, viewport: Viewport) -> Result<()> {
-    use chromiumoxide::cdp::browser_protocol::emulation::SetDeviceMetricsOverrideParams;
-
-    let params = match viewport {
-        Viewport::Desktop => SetDeviceMetricsOverrideParams::builder()
-            .mobile(false)
-            .width(1280_i64)
-            .height(800_i64)
-            .device_scale_factor(1.0_f64)
-            .build()
-            .expect("static desktop viewport params are valid"),
-        Viewport::Mobile => SetDeviceMetricsOverrideParams::builder()
-            .mobile(true)
-            .width(390_i64)
-            .height(844_i64)
-            .device_scale_factor(2.0_f64)
-            .build()
-            .expect("static mobile viewport params are valid"),
+/// Emulate `profile` on the page: viewport metrics and, when any profile of
+/// the matrix needs them, touch emulation and a user-agent override
+/// (`default_user_agent` restores the browser default for profiles without one).
+async fn set_viewport(
+    page: &Page,
+    profile: &DeviceProfile,
+    devices: &DeviceMatrix,
+    default_user_agent: Option<&str>,
+) -> Result<()> {
+    use chromiumoxide::cdp::browser_protocol::emulation::{
+        SetDeviceMetricsOverrideParams, SetTouchEmulationEnabledParams,
     };
 
-    page.execute(params)
-        .await
-        .map_err(|e| crate::error::AuditError::NavigationFailed {
-            url: "viewport-set".to_string(),
-            reason: e.to_string(),
-        })?;
+    let params = SetDeviceMetricsOverrideParams::builder()
+        .mobile(profile.is_mobile())
+        .width(profile.width as i64)
+        .height(profile.height as i64)
+        .device_scale_factor(profile.device_scale_factor)
+        .build()
+        .expect("device metrics params are complete");
+
+    let to_error = |e: chromiumoxide::error::CdpError| crate::error::AuditError::NavigationFailed {
+        url: "viewport-set".to_string(),
+        reason: e.to_string(),
+    };
+    page.execute(params).await.map_err(to_error)?;
+    if devices.uses_touch() {
+        page.execute(SetTouchEmulationEnabledParams::new(profile.touch))
+            .await
+            .map_err(to_error)?;
+    }
+    if devices.uses_user_agent() {
+        if let Some(user_agent) = profile.user_agent.as_deref().or(default_user_agent) {
+            page.set_user_agent(user_agent).await.map_err(to_error)?;
+        }
+    }
     settle(page).await?;
     Ok(())
 }
@@ -280,6 +299,22 @@ pub struct PipelineConfig {
     pub journey_budget_ms: u64,
     /// Report locale ("de" / "en") — used for i18n stopword loading.
     pub lang: String,
+    /// Accepted violations from the waivers file referenced in
+    /// `auditmysite.toml`; set by the CLI runners via `resolve_waivers`.
+    pub waivers: WaiverSet,
+    /// Declarative rules from `--custom-rules` / `[rules] custom`; set by the
+    /// CLI runners via `load_custom_rules`. The rules themselves run from the
+    /// global registry, this copy keys the audit signature.
+    pub custom_rules: DeclarativeRuleSet,
+    /// Desktop/mobile pass profiles plus extra devices from `[[devices]]`.
+    pub devices: DeviceMatrix,
+    /// Throttled performance passes: built-in profiles plus `[[throttle_profiles]]`.
+    pub throttle_passes: Vec<ThrottlePass>,
+    /// Measurement runs per throttled pass; the report keeps the medians.
+    pub perf_runs: u32,
+    /// Record rendered-DOM links and History API targets for crawl
+    /// discovery (`--crawl-render`).
+    pub capture_rendered_links: bool,
 }
 
 impl PipelineConfig {
@@ -305,6 +340,19 @@ impl PipelineConfig {
     /// requests a different scope. Excludes options that do not affect content
     /// (timeout, verbosity, persistence, screenshot capture).
     pub fn audit_signature(&self) -> String {
+        self.signature(true)
+    }
+
+    /// `audit_signature` without the waiver date, for keying checkpoint runs.
+    ///
+    /// A resumed run may continue on a later day. Restored pages keep the
+    /// waiver decisions of the day they were audited; only a changed waiver
+    /// file invalidates the run.
+    pub fn run_signature(&self) -> String {
+        self.signature(false)
+    }
+
+    fn signature(&self, dated_waivers: bool) -> String {
         // `fmt` is bumped whenever the cached `AuditReport` struct shape changes.
         // `AuditReport` has no `deny_unknown_fields`, so an old-shape cache would
         // otherwise deserialize *successfully* (unknown keys ignored, new fields
@@ -314,10 +362,41 @@ impl PipelineConfig {
         // 6 for the commerce trust-pages restructure, 7 for commerce page_kind,
         // 8 for commerce conversion signals, 9 for structured-data rule and
         // page-fit assessments, 10 for the report quality model, 11 for
-        // page-stability provenance.
-        const CACHE_FMT: u8 = 11;
+        // page-stability provenance, 12 for waived violations, 13 for the
+        // device matrix, 14 for custom throttle profiles and run statistics,
+        // 15 for annotation-suppressed findings (`WcagResults.suppressed`).
+        const CACHE_FMT: u8 = 15;
+        // Waivers change which violations survive; the date is part of the
+        // key because an expiring waiver changes the result without any edit.
+        let waivers = if self.waivers.is_empty() {
+            "none".to_string()
+        } else {
+            let hash = crate::audit::artifacts::fnv1a(
+                serde_json::to_string(&self.waivers)
+                    .unwrap_or_default()
+                    .as_bytes(),
+            );
+            if dated_waivers {
+                format!("{:016x}@{}", hash, chrono::Local::now().date_naive())
+            } else {
+                format!("{:016x}", hash)
+            }
+        };
+        // Custom rules add findings; a changed rules file must miss the cache.
+        let custom_rules = if self.custom_rules.rules.is_empty() {
+            "none".to_string()
+        } else {
+            format!(
+                "{:016x}",
+                crate::audit::artifacts::fnv1a(
+                    serde_json::to_string(&self.custom_rules)
+                        .unwrap_or_default()
+                        .as_bytes()
+                )
+            )
+        };
         format!(
-            "v={};fmt={};level={};perf={};seo={};sec={};mobile={};dark={};stack={};consent={};interactive={:?};journey_budget_ms={};lang={}",
+            "v={};fmt={};level={};perf={};seo={};sec={};mobile={};dark={};stack={};consent={};interactive={:?};journey_budget_ms={};lang={};waivers={};rules={};devices={};throttle={};runs={}",
             env!("CARGO_PKG_VERSION"),
             CACHE_FMT,
             self.wcag_level,
@@ -331,12 +410,37 @@ impl PipelineConfig {
             self.interactive,
             self.journey_budget_ms,
             self.lang,
+            waivers,
+            custom_rules,
+            self.devices.signature(),
+            self.throttle_signature(),
+            self.perf_runs,
         )
     }
+
+    fn throttle_signature(&self) -> String {
+        self.throttle_passes
+            .iter()
+            .map(|pass| {
+                let c = &pass.conditions;
+                format!(
+                    "{}:{}/{}/{}/{}",
+                    pass.label(),
+                    c.download_bps,
+                    c.upload_bps,
+                    c.latency_ms,
+                    c.cpu_slowdown
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(",")
+    }
 }
 
-impl From<&Args> for PipelineConfig {
-    fn from(args: &Args) -> Self {
+impl TryFrom<&Args> for PipelineConfig {
+    type Error = AuditError;
+
+    fn try_from(args: &Args) -> Result<Self> {
         let toml_cfg = crate::cli::config::Config::load();
         Self::from_args_and_config(args, toml_cfg.as_ref())
     }
@@ -365,15 +469,41 @@ impl PipelineConfig {
         }
     }
 
+    /// Copy for an extra device pass: WCAG rules, plus mobile friendliness on
+    /// mobile profiles. Every other module and element evidence are off.
+    pub fn for_device(&self, profile: &DeviceProfile) -> Self {
+        Self {
+            check_performance: false,
+            check_seo: false,
+            check_security: false,
+            check_mobile: self.check_mobile && profile.is_mobile(),
+            check_dark_mode: false,
+            check_stack: false,
+            capture_screenshots: false,
+            capture_element_evidence: false,
+            ..self.clone()
+        }
+    }
+
+    /// Build the pipeline config from CLI args and `auditmysite.toml`. Invalid
+    /// `[[devices]]` or `[[throttle_profiles]]` entries are a config error.
     pub fn from_args_and_config(
         args: &Args,
         toml_cfg: Option<&crate::cli::config::Config>,
-    ) -> Self {
+    ) -> Result<Self> {
         let full_audit = args.full_audit_enabled();
         let journey_budget_ms = toml_cfg
             .and_then(|c| c.interactive.journey_budget_ms)
             .unwrap_or(crate::a11y_journey::DEFAULT_BUDGET_MS);
-        Self {
+        let devices = match toml_cfg {
+            Some(c) => DeviceMatrix::from_profiles(&c.devices)?,
+            None => DeviceMatrix::default(),
+        };
+        let custom_throttle = toml_cfg
+            .map(|c| c.throttle_profiles.as_slice())
+            .unwrap_or_default();
+        let throttle_passes = throttle::throttle_passes(custom_throttle)?;
+        Ok(Self {
             wcag_level: args.level,
             timeout_secs: args.effective_timeout(),
             stability_budget_ms: args.stability_budget_ms,
@@ -387,14 +517,26 @@ impl PipelineConfig {
             check_stack: full_audit || args.stack,
             persist_artifacts: true,
             capture_screenshots: args.url.is_some()
-                && matches!(args.format, None | Some(crate::cli::OutputFormat::Pdf)),
+                && matches!(
+                    args.format,
+                    None | Some(crate::cli::OutputFormat::Pdf | crate::cli::OutputFormat::Html)
+                ),
             capture_element_evidence: args.url.is_some()
-                && matches!(args.format, None | Some(crate::cli::OutputFormat::Pdf)),
+                && matches!(
+                    args.format,
+                    None | Some(crate::cli::OutputFormat::Pdf | crate::cli::OutputFormat::Html)
+                ),
             dismiss_consent: args.dismiss_consent,
             interactive: args.interactive,
             journey_budget_ms,
             lang: args.lang.clone(),
-        }
+            waivers: WaiverSet::default(),
+            custom_rules: DeclarativeRuleSet::default(),
+            devices,
+            throttle_passes,
+            perf_runs: args.effective_runs(),
+            capture_rendered_links: args.crawl && args.crawl_render,
+        })
     }
 }
 
@@ -411,6 +553,13 @@ pub async fn run_single_audit(
 
     let page = browser.new_page().await?;
     debug!("Created new page");
+    // Read before any device pass overrides it, so the throttled passes can
+    // restore the mobile profile exactly.
+    let default_user_agent = if config.devices.uses_user_agent() {
+        page.user_agent().await.ok()
+    } else {
+        None
+    };
 
     let (mut report, snapshot) = audit_page(&page, url, config, browser).await?;
 
@@ -419,10 +568,16 @@ pub async fn run_single_audit(
             .performance
             .as_ref()
             .and_then(|p| p.content_weight.clone());
-        let (throttled, canonical) =
-            collect_throttled_performance(&page, url, browser, config, content_weight.as_ref())
-                .await;
-        attach_throttled_profile_subchecks(&mut report, &throttled);
+        let (throttled, canonical) = collect_throttled_performance(
+            &page,
+            url,
+            browser,
+            config,
+            content_weight.as_ref(),
+            default_user_agent.as_deref(),
+        )
+        .await;
+        attach_throttled_profile_subchecks(&mut report, &config.throttle_passes, &throttled);
         report.throttled_performance = throttled;
         if let Some((vitals, score)) = canonical {
             apply_canonical_perf(&mut report, vitals, score);
@@ -446,14 +601,15 @@ pub async fn run_single_audit(
 
 fn attach_throttled_profile_subchecks(
     report: &mut AuditReport,
+    passes: &[ThrottlePass],
     results: &[crate::audit::report::ThrottledPerfResult],
 ) {
-    let subchecks: Vec<crate::audit::SubcheckRun> = ThrottleProfile::AUTO_PROFILES
+    let subchecks: Vec<crate::audit::SubcheckRun> = passes
         .iter()
-        .map(|profile| {
-            let completed = results.iter().any(|result| result.profile == *profile);
+        .map(|pass| {
+            let completed = results.iter().any(|result| result.label() == pass.label());
             crate::audit::SubcheckRun {
-                subcheck: format!("throttled_profile:{}", profile.label()),
+                subcheck: format!("throttled_profile:{}", pass.label()),
                 status: if completed {
                     crate::audit::ExecutionStatus::Completed
                 } else {
@@ -509,6 +665,12 @@ pub async fn audit_page(
 
     // Enable bypassing CSP on the page
     let _ = page.execute(SetBypassCspParams::new(true)).await;
+    // Only needed to undo a per-device user-agent override between passes.
+    let default_user_agent = if config.devices.uses_user_agent() {
+        page.user_agent().await.ok()
+    } else {
+        None
+    };
     let mut security_events = if config.check_security {
         match page
             .event_listener::<EventVisibleSecurityStateChanged>()
@@ -548,7 +710,13 @@ pub async fn audit_page(
 
     // ── Desktop pass ──────────────────────────────────────────────────────────
     info!("Desktop pass starting for {}", url);
-    set_viewport(page, Viewport::Desktop).await?;
+    set_viewport(
+        page,
+        &config.devices.desktop,
+        &config.devices,
+        default_user_agent.as_deref(),
+    )
+    .await?;
     if config.check_performance {
         if let Err(e) = prepare_vitals_collection(page).await {
             warn!("Vitals observer injection failed (desktop): {}", e);
@@ -560,6 +728,11 @@ pub async fn audit_page(
             warn!("Console collection setup failed (desktop): {}", e);
         }
     }
+    if config.capture_rendered_links {
+        if let Err(e) = crate::audit::crawl::install_route_observer(page).await {
+            warn!("{}", e);
+        }
+    }
     browser.navigate(page, url).await?;
     if let (Some(sec), Some(details)) = (
         &mut security,
@@ -602,7 +775,13 @@ pub async fn audit_page(
 
     // ── Mobile pass ───────────────────────────────────────────────────────────
     info!("Mobile pass starting for {}", url);
-    set_viewport(page, Viewport::Mobile).await?;
+    set_viewport(
+        page,
+        &config.devices.mobile,
+        &config.devices,
+        default_user_agent.as_deref(),
+    )
+    .await?;
     if config.check_performance {
         if let Err(e) = prepare_vitals_collection(page).await {
             warn!("Vitals observer injection failed (mobile): {}", e);
@@ -655,7 +834,14 @@ pub async fn audit_page(
         mobile_wcag.rule_outcomes.push(reflow_outcome);
         mobile_wcag.extend_findings(reflow_findings);
         // check_reflow_with_page leaves the viewport at 320px — restore mobile
-        if let Err(e) = set_viewport(page, Viewport::Mobile).await {
+        if let Err(e) = set_viewport(
+            page,
+            &config.devices.mobile,
+            &config.devices,
+            default_user_agent.as_deref(),
+        )
+        .await
+        {
             warn!(
                 "Failed to restore mobile viewport after reflow check: {}",
                 e
@@ -778,6 +964,11 @@ pub async fn audit_page(
     let pattern_analysis = crate::patterns::analyze(&primary_snap.ax_tree);
     let mut pattern_violations = pattern_analysis.violations.clone();
     enrich_violations_with_page(page, &mut pattern_violations, &primary_snap.ax_tree).await;
+    if page_has_annotations(page).await {
+        let suppressed =
+            suppress_annotated(page, &mut pattern_violations, &primary_snap.ax_tree).await;
+        merged_wcag.suppressed.extend(suppressed);
+    }
     let (kept_patterns, demoted_patterns): (Vec<_>, Vec<_>) = pattern_violations
         .into_iter()
         .partition(|v| v.kind == crate::wcag::types::FindingKind::Violation);
@@ -800,6 +991,7 @@ pub async fn audit_page(
         browser_version: browser.chrome_version().map(str::to_string),
         headless: browser.is_headless(),
         source: "live".to_string(),
+        authentication: browser.auth_provenance().cloned(),
     };
     report.accessibility.execution.navigation = collect_navigation_snapshot(page, url).await;
     report.accessibility.execution.navigation.stability = vec![desktop_stability, mobile_stability];
@@ -823,7 +1015,8 @@ pub async fn audit_page(
     // The report-wide accessibility score is the reproducible 70/30 blend of
     // the two viewport scores shown in JSON/PDF. The merged finding union is
     // still used for evidence, counts and remediation, but must not silently
-    // create a third, lower score beside the two viewport values.
+    // create a third, lower score beside the two viewport values. Extra
+    // `[[devices]]` passes re-blend it with their own scores further down.
     report.accessibility.score = weighted_accessibility as f32;
     report.accessibility.grade =
         AccessibilityScorer::calculate_grade(report.accessibility.score).to_string();
@@ -895,6 +1088,94 @@ pub async fn audit_page(
                 });
         }
     }
+    // ── Rendered link discovery (`--crawl-render`) ───────────────────────────
+    // After the journeys so routes they push via the History API count too.
+    if config.capture_rendered_links {
+        match crate::audit::crawl::collect_rendered_links(page).await {
+            Ok(links) => report.rendered_links = links,
+            Err(e) => warn!("{}", e),
+        }
+    }
+    // ── Extra device passes ([[devices]]) ─────────────────────────────────────
+    // Last page-bound step: every pass re-navigates, which would invalidate
+    // the node ids the mobile-state work above relies on.
+    let mut extra_devices = Vec::new();
+    for profile in &config.devices.extra {
+        info!("Device pass '{}' starting for {}", profile.name, url);
+        match run_device_pass(
+            page,
+            url,
+            browser,
+            config,
+            profile,
+            default_user_agent.as_deref(),
+            &mut evidence_budget,
+        )
+        .await
+        {
+            Ok((wcag_results, mobile)) => {
+                merge_device_findings(
+                    &mut report.accessibility.wcag_results.violations,
+                    &profile.name,
+                    &wcag_results.violations,
+                );
+                let score = AccessibilityScorer::calculate_score(&wcag_results.violations);
+                extra_devices.push((
+                    profile,
+                    score.round().max(1.0) as u32,
+                    wcag_results.violations.len(),
+                    mobile.map(|m| m.score),
+                ));
+            }
+            Err(e) => warn!("Device pass '{}' failed: {}", profile.name, e),
+        }
+    }
+    if !config.devices.extra.is_empty() {
+        if let Err(e) = set_viewport(
+            page,
+            &config.devices.mobile,
+            &config.devices,
+            default_user_agent.as_deref(),
+        )
+        .await
+        {
+            warn!(
+                "Failed to restore mobile viewport after device passes: {}",
+                e
+            );
+        }
+        report.accessibility.statistics = AccessibilityScorer::calculate_statistics(
+            &report.accessibility.wcag_results.violations,
+        );
+        // Findings merged from the extra devices must move the score too, not
+        // only the finding list: re-blend with every device's score.
+        if let Some(scores) = &report.viewport_scores {
+            let extra: Vec<_> = extra_devices
+                .iter()
+                .map(|(profile, score, _, _)| (*profile, *score))
+                .collect();
+            report.accessibility.score = weighted_device_accessibility(
+                scores.desktop.accessibility,
+                scores.mobile.accessibility,
+                &extra,
+            ) as f32;
+            report.accessibility.grade =
+                AccessibilityScorer::calculate_grade(report.accessibility.score).to_string();
+            report.accessibility.certificate =
+                AccessibilityScorer::calculate_certificate(report.accessibility.score).to_string();
+        }
+    }
+    if !config.devices.is_default() {
+        tag_breakpoint_specific(
+            &mut report.accessibility.wcag_results.violations,
+            &config.devices,
+        );
+    }
+
+    apply_waivers(&mut report, &config.waivers, url);
+    if !config.devices.is_default() {
+        report.device_results = device_results(&report, config, &extra_devices);
+    }
     ensure_requested_module_runs(&mut report);
     report.accessibility.execution.module_runs =
         consolidate_module_runs(&report.accessibility.execution.module_runs);
@@ -907,6 +1188,92 @@ pub async fn audit_page(
     Ok((report, primary_snap))
 }
 
+/// Additional WCAG + contrast pass (plus mobile friendliness for mobile
+/// profiles) for one extra `[[devices]]` profile.
+async fn run_device_pass(
+    page: &Page,
+    url: &str,
+    browser: &BrowserManager,
+    config: &PipelineConfig,
+    profile: &DeviceProfile,
+    default_user_agent: Option<&str>,
+    evidence_budget: &mut crate::accessibility::ElementEvidenceBudget,
+) -> Result<(WcagResults, Option<MobileFriendliness>)> {
+    set_viewport(page, profile, &config.devices, default_user_agent).await?;
+    browser.navigate(page, url).await?;
+    let _ = handle_post_navigation(page, config.dismiss_consent).await;
+    wait_for_page_stability(page, &profile.name, config.stability_budget_ms).await;
+
+    let device_config = config.for_device(profile);
+    let snapshot = extract_snapshot(page, url, profile.viewport(), &device_config).await?;
+    let wcag_results = run_rules(
+        page,
+        &snapshot,
+        &device_config,
+        None,
+        "device",
+        evidence_budget,
+    )
+    .await;
+    Ok((wcag_results, snapshot.mobile))
+}
+
+/// One device-matrix row per audited device: the two primary passes from
+/// `dual_viewport`, then the extra passes that completed.
+fn device_results(
+    report: &AuditReport,
+    config: &PipelineConfig,
+    extra: &[(&DeviceProfile, u32, usize, Option<u32>)],
+) -> Vec<DeviceResult> {
+    let violations = &report.accessibility.wcag_results.violations;
+    let mut results = Vec::new();
+    if let (Some(dual), Some(scores)) = (&report.dual_viewport, &report.viewport_scores) {
+        results.push(DeviceResult::new(
+            &config.devices.desktop,
+            scores.desktop.accessibility,
+            dual.desktop.wcag_results.violations.len(),
+            None,
+            violations,
+        ));
+        results.push(DeviceResult::new(
+            &config.devices.mobile,
+            scores.mobile.accessibility,
+            dual.mobile.wcag_results.violations.len(),
+            dual.mobile.mobile.as_ref().map(|m| m.score),
+            violations,
+        ));
+    }
+    for (profile, score, count, mobile_score) in extra {
+        results.push(DeviceResult::new(
+            profile,
+            *score,
+            *count,
+            *mobile_score,
+            violations,
+        ));
+    }
+    results
+}
+
+/// Move waived violations out of the merged WCAG results so they never reach
+/// findings, risk or the verdict. Scores stay as measured.
+fn apply_waivers(report: &mut AuditReport, waivers: &WaiverSet, url: &str) {
+    if waivers.is_empty() {
+        return;
+    }
+    let today = chrono::Local::now().date_naive();
+    let violations = &mut report.accessibility.wcag_results.violations;
+    report.accessibility.waived = waivers.apply(url, violations, today);
+    if !report.accessibility.waived.is_empty() {
+        info!(
+            "Waived {} violation(s) on {}",
+            report.accessibility.waived.len(),
+            url
+        );
+    }
+    report.accessibility.statistics = AccessibilityScorer::calculate_statistics(violations);
+}
+
 async fn collect_navigation_snapshot(
     page: &Page,
     requested_url: &str,
@@ -1061,6 +1428,19 @@ fn merge_wcag_violations(desktop: &WcagResults, mobile: &WcagResults) -> WcagRes
     let positives = merge_aux(&desktop.positives, &mobile.positives);
     let not_testables = merge_aux(&desktop.not_testables, &mobile.not_testables);
 
+    // Annotations suppress the same element in both viewports; keep it once.
+    let mut suppressed = desktop.suppressed.clone();
+    for entry in &mobile.suppressed {
+        if !suppressed.iter().any(|s| {
+            s.rule == entry.rule
+                && s.annotated_element == entry.annotated_element
+                && (s.selector.is_some() && s.selector == entry.selector
+                    || s.node_id == entry.node_id)
+        }) {
+            suppressed.push(entry.clone());
+        }
+    }
+
     WcagResults {
         violations: merged,
         warnings,
@@ -1075,6 +1455,7 @@ fn merge_wcag_violations(desktop: &WcagResults, mobile: &WcagResults) -> WcagRes
             .chain(&mobile.rule_outcomes)
             .cloned()
             .collect(),
+        suppressed,
     }
 }
 
@@ -1256,7 +1637,24 @@ async fn run_rules(
         wcag_results.extend_findings(findings);
     }
 
+    // Declarative CSS-selector rules from a custom rules file.
+    for rule in wcag::plugin::registered_selector_rules() {
+        let meta = rule.metadata();
+        if config.wcag_level < meta.level {
+            continue;
+        }
+        let raw_findings = rule.check_with_page(page).await;
+        let (outcome, findings) =
+            page_rule_outcome(meta.axe_id, Some(meta.id), viewport_label, raw_findings);
+        if !findings.is_empty() {
+            info!("Found {} {} violations", findings.len(), meta.name);
+        }
+        wcag_results.rule_outcomes.push(outcome);
+        wcag_results.extend_findings(findings);
+    }
+
     enrich_violations_with_page(page, &mut wcag_results.violations, &snapshot.ax_tree).await;
+    apply_ignore_annotations(page, &mut wcag_results, &snapshot.ax_tree).await;
 
     if config.capture_element_evidence {
         crate::accessibility::capture_element_evidence(
@@ -1554,30 +1952,30 @@ fn audit_scope_from_config(config: &PipelineConfig) -> crate::audit::AuditScope
         interactive_mode: format!("{:?}", config.interactive).to_lowercase(),
         journey_budget_ms: config.journey_budget_ms,
         throttling_profiles: if config.check_performance {
-            ThrottleProfile::AUTO_PROFILES
+            config
+                .throttle_passes
                 .iter()
-                .map(|profile| profile.label().to_string())
+                .map(|pass| pass.label().to_string())
                 .collect()
         } else {
             Vec::new()
         },
-        viewports: vec![
-            crate::audit::ViewportDefinition {
-                name: "desktop".to_string(),
-                width: 1280,
-                height: 800,
-                device_scale_factor: 1.0,
-            },
-            crate::audit::ViewportDefinition {
-                name: "mobile".to_string(),
-                width: 390,
-                height: 844,
-                device_scale_factor: 2.0,
-            },
-        ],
+        viewports: config
+            .devices
+            .all()
+            .map(|profile| crate::audit::ViewportDefinition {
+                name: profile.name.clone(),
+                width: profile.width,
+                height: profile.height,
+                device_scale_factor: profile.device_scale_factor,
+                touch: profile.touch,
+                user_agent: profile.user_agent.clone(),
+            })
+            .collect(),
         dismiss_consent: config.dismiss_consent,
         capture_screenshots: config.capture_screenshots,
         capture_element_evidence: config.capture_element_evidence,
+        sampling: None,
     }
 }
 
@@ -1757,10 +2155,13 @@ fn update_audit_quality(report: &mut AuditReport) {
     };
 }
 
-/// Run one performance-only page load per throttle profile and return the results.
+/// Run `config.perf_runs` performance-only page loads per throttle pass and
+/// return the results.
 ///
 /// Uses the mobile viewport (most relevant for throttling scenarios).
-/// Runs sequentially; errors in individual profiles are logged and skipped.
+/// Runs sequentially; errors in individual passes or runs are logged and
+/// skipped. With more than one run the per-profile values are the medians of
+/// the successful runs (see `aggregate_vitals_runs`).
 ///
 /// Returns the per-profile summary plus the LhMobile vitals/score as the canonical
 /// throttled measurement (issue #236). LhMobile matches Lighthouse's mobile preset
@@ -1770,8 +2171,9 @@ async fn collect_throttled_performance(
     page: &Page,
     url: &str,
     browser: &BrowserManager,
-    _config: &PipelineConfig,
+    config: &PipelineConfig,
     content_weight: Option<&crate::performance::ContentWeight>,
+    default_user_agent: Option<&str>,
 ) -> (
     Vec<crate::audit::report::ThrottledPerfResult>,
     Option<(
@@ -1788,99 +2190,87 @@ async fn collect_throttled_performance(
         crate::performance::PerformanceScore,
     )> = None;
 
-    for &profile in ThrottleProfile::AUTO_PROFILES {
-        info!("Throttled perf pass: {:?}", profile);
+    for pass in &config.throttle_passes {
+        info!("Throttled perf pass: {}", pass.label());
 
-        if let Err(e) = throttle::apply_throttling(page, profile).await {
-            warn!("Throttle apply failed for {:?}: {}", profile, e);
+        if let Err(e) = throttle::apply_network_conditions(page, &pass.conditions).await {
+            warn!("Throttle apply failed for {}: {}", pass.label(), e);
             continue;
         }
 
-        if let Err(e) = throttle::apply_cpu_throttling(page, profile).await {
-            warn!("CPU throttle apply failed for {:?}: {}", profile, e);
+        if let Err(e) = throttle::apply_cpu_slowdown(page, pass.conditions.cpu_slowdown).await {
+            warn!("CPU throttle apply failed for {}: {}", pass.label(), e);
         }
 
-        if let Err(e) = throttle::disable_cache(page).await {
-            warn!("Cache disable failed for {:?}: {}", profile, e);
-        }
-
-        if let Err(e) = prepare_vitals_collection(page).await {
-            warn!("Vitals injection failed for {:?}: {}", profile, e);
-            let _ = throttle::enable_cache(page).await;
-            let _ = throttle::disable_throttling(page).await;
-            let _ = throttle::disable_cpu_throttling(page).await;
-            continue;
-        }
-
-        if let Err(e) = browser.navigate(page, url).await {
-            warn!("Navigation failed for {:?}: {}", profile, e);
-            let _ = throttle::enable_cache(page).await;
-            let _ = throttle::disable_throttling(page).await;
-            let _ = throttle::disable_cpu_throttling(page).await;
-            continue;
+        let mut samples = Vec::new();
+        for run in 1..=config.perf_runs {
+            if let Some(vitals) = measure_throttled_run(page, url, browser, pass, run).await {
+                samples.push(vitals);
+            }
         }
 
-        match crate::performance::extract_web_vitals(page).await {
-            Ok(vitals) => {
-                // Pass the headline content_weight so the size/JS/request caps
-                // apply to throttled profiles too — otherwise a throttled
-                // profile can out-score the headline (and Slow3G out-score
-                // Fast3G) purely because its caps were skipped (#456).
-                let score = calculate_performance_score(&vitals, content_weight);
-                // If LCP could not be measured under throttling (timeout or
-                // navigation pre-completion), the most important navigation
-                // metric is missing — do not let CLS/TBT alone push the score
-                // to 100. Cap to "AUSBAUFÄHIG" tier so the profile reflects
-                // that the measurement was incomplete.
-                let final_score = if vitals.lcp.is_none() {
-                    score.overall.min(50)
-                } else {
-                    score.overall
-                };
-                results.push(ThrottledPerfResult {
-                    profile,
-                    lcp_ms: vitals.lcp.as_ref().map(|v| v.value),
-                    tbt_ms: vitals.tbt.as_ref().map(|v| v.value),
-                    cls: vitals.cls.as_ref().map(|v| v.value),
-                    score: final_score,
-                });
-                // LhMobile = Lighthouse mobile preset → canonical perf measurement.
-                // Only adopt when LCP could actually be measured; otherwise fall
-                // back to the unthrottled mobile pass so we don't report a
-                // capped-to-50 score that reflects measurement failure.
-                if profile == ThrottleProfile::LhMobile && vitals.lcp.is_some() {
-                    let mut adopted_score = score.clone();
-                    adopted_score.overall = final_score;
-                    adopted_score.grade =
-                        crate::performance::PerformanceGrade::from_score(final_score);
-                    // The canonical report vitals come from this throttled pass;
-                    // tag the direct metrics so the JSON reflects that (#406).
-                    let mut throttled_vitals = vitals.clone();
-                    crate::performance::mark_throttled_mobile(&mut throttled_vitals);
-                    canonical = Some((throttled_vitals, adopted_score));
-                }
-                let _ = throttle::enable_cache(page).await;
-            }
-            Err(e) => {
-                warn!("Vitals collection failed for {:?}: {}", profile, e);
-                let _ = throttle::enable_cache(page).await;
+        // Pass the headline content_weight so the size/JS/request caps
+        // apply to throttled profiles too — otherwise a throttled
+        // profile can out-score the headline (and Slow3G out-score
+        // Fast3G) purely because its caps were skipped (#456).
+        if let Some(vitals) = crate::performance::aggregate_vitals_runs(&samples) {
+            let score = calculate_performance_score(&vitals, content_weight);
+            // If LCP could not be measured under throttling (timeout or
+            // navigation pre-completion), the most important navigation
+            // metric is missing — do not let CLS/TBT alone push the score
+            // to 100. Cap to "AUSBAUFÄHIG" tier so the profile reflects
+            // that the measurement was incomplete.
+            let final_score = if vitals.lcp.is_none() {
+                score.overall.min(50)
+            } else {
+                score.overall
+            };
+            results.push(ThrottledPerfResult {
+                profile: pass.profile,
+                profile_name: pass.name.clone(),
+                lcp_ms: vitals.lcp.as_ref().map(|v| v.value),
+                tbt_ms: vitals.tbt.as_ref().map(|v| v.value),
+                cls: vitals.cls.as_ref().map(|v| v.value),
+                score: final_score,
+                run_stats: vitals.run_stats.clone(),
+            });
+            // LhMobile = Lighthouse mobile preset → canonical perf measurement.
+            // Only adopt when LCP could actually be measured; otherwise fall
+            // back to the unthrottled mobile pass so we don't report a
+            // capped-to-50 score that reflects measurement failure.
+            if pass.profile == ThrottleProfile::LhMobile && vitals.lcp.is_some() {
+                let mut adopted_score = score.clone();
+                adopted_score.overall = final_score;
+                adopted_score.grade = crate::performance::PerformanceGrade::from_score(final_score);
+                // The canonical report vitals come from this throttled pass;
+                // tag the direct metrics so the JSON reflects that (#406).
+                let mut throttled_vitals = vitals;
+                crate::performance::mark_throttled_mobile(&mut throttled_vitals);
+                canonical = Some((throttled_vitals, adopted_score));
             }
         }
 
         if let Err(e) = throttle::disable_throttling(page).await {
-            warn!("Throttle disable failed for {:?}: {}", profile, e);
+            warn!("Throttle disable failed for {}: {}", pass.label(), e);
         }
         if let Err(e) = throttle::disable_cpu_throttling(page).await {
-            warn!("CPU throttle disable failed for {:?}: {}", profile, e);
+            warn!("CPU throttle disable failed for {}: {}", pass.label(), e);
         }
 
         if let Err(e) = settle(page).await {
-            warn!("Browser settle failed after {:?}: {}", profile, e);
+            warn!("Browser settle failed after {}: {}", pass.label(), e);
         }
     }
 
     // Restore mobile viewport for screenshot capture that follows.
-    if let Err(e) = set_viewport(page, Viewport::Mobile).await {
+    if let Err(e) = set_viewport(
+        page,
+        &config.devices.mobile,
+        &config.devices,
+        default_user_agent,
+    )
+    .await
+    {
         warn!(
             "Failed to restore mobile viewport after throttled pass: {}",
             e
@@ -1896,6 +2286,53 @@ async fn collect_throttled_performance(
     (results, canonical)
 }
 
+/// One cold-cache page load under the already-applied throttling. The cache
+/// stays disabled for the load only, so every run fetches all resources.
+async fn measure_throttled_run(
+    page: &Page,
+    url: &str,
+    browser: &BrowserManager,
+    pass: &ThrottlePass,
+    run: u32,
+) -> Option<crate::performance::WebVitals> {
+    if let Err(e) = throttle::disable_cache(page).await {
+        warn!("Cache disable failed for {}: {}", pass.label(), e);
+    }
+
+    let vitals = match prepare_vitals_collection(page).await {
+        Err(e) => {
+            warn!("Vitals injection failed for {}: {}", pass.label(), e);
+            None
+        }
+        Ok(()) => match browser.navigate(page, url).await {
+            Err(e) => {
+                warn!(
+                    "Navigation failed for {} (run {}): {}",
+                    pass.label(),
+                    run,
+                    e
+                );
+                None
+            }
+            Ok(_) => match crate::performance::extract_web_vitals(page).await {
+                Ok(vitals) => Some(vitals),
+                Err(e) => {
+                    warn!(
+                        "Vitals collection failed for {} (run {}): {}",
+                        pass.label(),
+                        run,
+                        e
+                    );
+                    None
+                }
+            },
+        },
+    };
+
+    let _ = throttle::enable_cache(page).await;
+    vitals
+}
+
 /// Replace the report's Performance vitals/score with the LhMobile (throttled)
 /// measurement and recompute viewport scores accordingly (issue #236).
 ///
@@ -2021,12 +2458,20 @@ mod tests {
             level: WcagLevel::AA,
             format: None,
             output: None,
+            compare: None,
+            vpat_edition: None,
             chrome_path: None,
             remote_debugging_port: None,
             max_pages: 0,
             crawl_depth: 2,
+            crawl_render: false,
+            template_sample: None,
+            checkpoint: None,
+            resume: None,
+            shard: None,
             concurrency: None,
             timeout: None,
+            runs: None,
             stability_budget_ms: 1500,
             no_sandbox: false,
             disable_images: false,
@@ -2043,10 +2488,17 @@ mod tests {
             stack: false,
             reuse_cache: false,
             force_refresh: false,
+            history: false,
+            history_dir: None,
+            history_keep: None,
+            custom_rules: None,
             no_sitemap_suggest: false,
             prefer_sitemap: false,
             per_page_reports: false,
             dismiss_consent: false,
+            cookie_jar: None,
+            headers: Vec::new(),
+            login_script: None,
             interactive: crate::cli::InteractiveMode::Off,
             report_level: crate::cli::ReportLevel::Standard,
             lang: "de".to_string(),
@@ -2054,12 +2506,15 @@ mod tests {
             logo: None,
             debug_typ: false,
             export_snapshot: None,
+            export_playwright: None,
+            source_root: None,
             annex: None,
+            trend: false,
             request_mode: crate::cli::RequestMode::Browser,
             report_mode: false,
         };
 
-        let config = PipelineConfig::from(&args);
+        let config = PipelineConfig::try_from(&args).unwrap();
         assert_eq!(config.wcag_level, WcagLevel::AA);
         assert_eq!(config.timeout_secs, 30);
         assert!(config.verbose);
@@ -2073,7 +2528,8 @@ mod tests {
     #[test]
     fn active_module_labels_follow_pipeline_flags() {
         let config =
-            PipelineConfig::from(&Args::parse_from(["auditmysite", "https://example.com"]));
+            PipelineConfig::try_from(&Args::parse_from(["auditmysite", "https://example.com"]))
+                .unwrap();
         let expected = vec![
             "Accessibility",
             "Accessibility Journey",
@@ -2096,12 +2552,13 @@ mod tests {
 
     #[test]
     fn active_module_labels_respect_disabled_full_modules() {
-        let config = PipelineConfig::from(&Args::parse_from([
+        let config = PipelineConfig::try_from(&Args::parse_from([
             "auditmysite",
             "https://example.com",
             "--skip-performance",
             "--skip-mobile",
-        ]));
+        ]))
+        .unwrap();
         let expected = vec![
             "Accessibility",
             "Accessibility Journey",
@@ -2132,6 +2589,12 @@ mod tests {
             interactive: crate::cli::InteractiveMode::Off,
             journey_budget_ms: crate::a11y_journey::DEFAULT_BUDGET_MS,
             lang: "de".to_string(),
+            waivers: Default::default(),
+            custom_rules: Default::default(),
+            devices: Default::default(),
+            throttle_passes: crate::browser::throttle::default_throttle_passes(),
+            perf_runs: 1,
+            capture_rendered_links: false,
         }
     }
 
@@ -2155,6 +2618,22 @@ mod tests {
         other.dismiss_consent = true;
         assert_ne!(base_sig, other.audit_signature());
 
+        // Repeated runs and custom throttle profiles change the signature.
+        let mut other = test_pipeline_config();
+        other.perf_runs = 5;
+        assert_ne!(base_sig, other.audit_signature());
+        let mut other = test_pipeline_config();
+        other.throttle_passes =
+            crate::browser::throttle::throttle_passes(&[crate::browser::CustomThrottleProfile {
+                name: "cable".to_string(),
+                download_kbps: 20_000.0,
+                upload_kbps: 5_000.0,
+                latency_ms: 10.0,
+                cpu_slowdown: 1.0,
+            }])
+            .unwrap();
+        assert_ne!(base_sig, other.audit_signature());
+
         // Interactive budget changes the possible journey findings.
         let mut other = test_pipeline_config();
         other.journey_budget_ms += 1;
@@ -2165,6 +2644,21 @@ mod tests {
         let mut other = test_pipeline_config();
         other.lang = "en".to_string();
         assert_ne!(base_sig, other.audit_signature());
+
+        // A custom rules file adds findings.
+        let mut other = test_pipeline_config();
+        other.custom_rules = toml::from_str(
+            r#"
+            [[rule]]
+            id = "no-empty-buttons"
+            wcag = "4.1.2"
+            severity = "high"
+            message = "Button without a name"
+            ax = { role = "button", name_empty = true }
+            "#,
+        )
+        .unwrap();
+        assert_ne!(base_sig, other.audit_signature());
     }
 
     #[test]
@@ -2182,6 +2676,29 @@ mod tests {
         assert_eq!(base_sig, other.audit_signature());
     }
 
+    #[test]
+    fn run_signature_tracks_waivers_but_not_the_date() {
+        let mut config = test_pipeline_config();
+        config.waivers = toml::from_str(
+            r#"
+            [[waiver]]
+            rule_id = "color-contrast"
+            reason = "Brand palette under review"
+            owner = "design"
+            expires = "2099-01-01"
+            "#,
+        )
+        .unwrap();
+        let today = chrono::Local::now().date_naive().to_string();
+
+        assert!(config.audit_signature().contains(&today));
+        assert!(!config.run_signature().contains(&today));
+        assert_ne!(
+            config.run_signature(),
+            test_pipeline_config().run_signature()
+        );
+    }
+
     #[test]
     fn pipeline_config_uses_interactive_budget_from_config() {
         let args = Args::parse_from(["auditmysite", "https://example.com"]);
@@ -2193,7 +2710,7 @@ journey_budget_ms = 1234
         )
         .unwrap();
 
-        let pipeline = PipelineConfig::from_args_and_config(&args, Some(&config));
+        let pipeline = PipelineConfig::from_args_and_config(&args, Some(&config)).unwrap();
 
         assert_eq!(pipeline.journey_budget_ms, 1234);
     }
@@ -2201,7 +2718,7 @@ journey_budget_ms = 1234
     #[test]
     fn for_viewport_desktop_disables_seo_security_mobile_stack() {
         let args = Args::parse_from(["auditmysite", "https://example.com", "--full"]);
-        let config = PipelineConfig::from_args_and_config(&args, None);
+        let config = PipelineConfig::from_args_and_config(&args, None).unwrap();
         let desktop = config.for_viewport(Viewport::Desktop);
 
         assert!(!desktop.check_seo);
@@ -2215,7 +2732,7 @@ journey_budget_ms = 1234
     #[test]
     fn for_viewport_desktop_respects_disabled_dark_mode() {
         let args = Args::parse_from(["auditmysite", "https://example.com"]);
-        let mut config = PipelineConfig::from_args_and_config(&args, None);
+        let mut config = PipelineConfig::from_args_and_config(&args, None).unwrap();
         config.check_dark_mode = false;
 
         let desktop = config.for_viewport(Viewport::Desktop);
@@ -2226,7 +2743,7 @@ journey_budget_ms = 1234
     #[test]
     fn for_viewport_mobile_disables_security_and_dark_mode() {
         let args = Args::parse_from(["auditmysite", "https://example.com", "--full"]);
-        let config = PipelineConfig::from_args_and_config(&args, None);
+        let config = PipelineConfig::from_args_and_config(&args, None).unwrap();
         let mobile = config.for_viewport(Viewport::Mobile);
 
         assert!(!mobile.check_security);
@@ -2240,7 +2757,7 @@ journey_budget_ms = 1234
     fn pipeline_config_uses_default_interactive_budget_without_config() {
         let args = Args::parse_from(["auditmysite", "https://example.com"]);
 
-        let pipeline = PipelineConfig::from_args_and_config(&args, None);
+        let pipeline = PipelineConfig::from_args_and_config(&args, None).unwrap();
 
         assert_eq!(
             pipeline.journey_budget_ms,
@@ -2270,6 +2787,7 @@ journey_budget_ms = 1234
             incomplete: 0,
             nodes_checked: 100,
             rule_outcomes: vec![],
+            suppressed: vec![],
         };
         let mobile = WcagResults {
             violations: vec![
@@ -2283,6 +2801,7 @@ journey_budget_ms = 1234
             incomplete: 0,
             nodes_checked: 90,
             rule_outcomes: vec![],
+            suppressed: vec![],
         };
 
         let merged = merge_wcag_violations(&desktop, &mobile);
@@ -2313,6 +2832,35 @@ journey_budget_ms = 1234
         assert_eq!(merged.nodes_checked, 100);
     }
 
+    #[test]
+    fn test_merge_wcag_violations_keeps_suppressed_once() {
+        use crate::wcag::{Severity, SuppressedViolation};
+
+        fn suppressed(selector: &str) -> SuppressedViolation {
+            SuppressedViolation {
+                rule_id: "color-contrast".to_string(),
+                rule: "1.4.3".to_string(),
+                node_id: format!("{selector}#0"),
+                selector: Some(selector.to_string()),
+                severity: Severity::High,
+                message: "Low contrast".to_string(),
+                annotation: "color-contrast".to_string(),
+                annotated_element: "div.chat-widget".to_string(),
+            }
+        }
+
+        let mut desktop = WcagResults::new();
+        desktop.suppressed = vec![suppressed("div.chat-widget > p")];
+        let mut mobile = WcagResults::new();
+        mobile.suppressed = vec![
+            suppressed("div.chat-widget > p"),
+            suppressed("div.chat-widget > a"),
+        ];
+
+        let merged = merge_wcag_violations(&desktop, &mobile);
+        assert_eq!(merged.suppressed.len(), 2);
+    }
+
     #[test]
     fn test_merge_wcag_violations_empty_desktop() {
         use crate::wcag::Severity;
@@ -2332,6 +2880,7 @@ journey_budget_ms = 1234
             incomplete: 0,
             nodes_checked: 0,
             rule_outcomes: vec![],
+            suppressed: vec![],
         };
         let mobile = WcagResults {
             violations: vec![make_v("1.1.1", "#img1"), make_v("1.4.3", "#text1")],
@@ -2342,6 +2891,7 @@ journey_budget_ms = 1234
             incomplete: 0,
             nodes_checked: 50,
             rule_outcomes: vec![],
+            suppressed: vec![],
         };
 
         let merged = merge_wcag_violations(&desktop, &mobile);
@@ -2373,6 +2923,7 @@ journey_budget_ms = 1234
             incomplete: 0,
             nodes_checked: 80,
             rule_outcomes: vec![],
+            suppressed: vec![],
         };
         let mobile = WcagResults {
             violations: vec![],
@@ -2383,6 +2934,7 @@ journey_budget_ms = 1234
             incomplete: 0,
             nodes_checked: 60,
             rule_outcomes: vec![],
+            suppressed: vec![],
         };
 
         let merged = merge_wcag_violations(&desktop, &mobile);
@@ -2428,6 +2980,7 @@ journey_budget_ms = 1234
             incomplete: 0,
             nodes_checked: 0,
             rule_outcomes: vec![],
+            suppressed: vec![],
         };
         let mobile = WcagResults {
             violations: vec![mobile_v],
@@ -2438,6 +2991,7 @@ journey_budget_ms = 1234
             incomplete: 0,
             nodes_checked: 0,
             rule_outcomes: vec![],
+            suppressed: vec![],
         };
 
         let merged = merge_wcag_violations(&desktop, &mobile);
@@ -2541,7 +3095,11 @@ journey_budget_ms = 1234
                 ..Default::default()
             });
 
-        attach_throttled_profile_subchecks(&mut report, &[]);
+        attach_throttled_profile_subchecks(
+            &mut report,
+            &crate::browser::throttle::default_throttle_passes(),
+            &[],
+        );
 
         let performance = report
             .accessibility
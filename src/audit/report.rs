@@ -12,7 +12,7 @@ use crate::dark_mode::DarkModeAnalysis;
 use crate::mobile::MobileFriendliness;
 use crate::performance::{
     AnimationAnalysis, ContentWeight, CoverageAnalysis, CriticalChain, MinificationAnalysis,
-    PerformanceScore, RenderBlockingAnalysis, ThirdPartyAttribution, WebVitals,
+    PerformanceScore, RenderBlockingAnalysis, ThirdPartyAttribution, VitalsRunStats, WebVitals,
 };
 use crate::security::SecurityAnalysis;
 use crate::seo::SeoAnalysis;
@@ -71,6 +71,23 @@ pub struct AuditScope {
     pub dismiss_consent: bool,
     pub capture_screenshots: bool,
     pub capture_element_evidence: bool,
+    /// Set on batch reports whose URL set was reduced by template sampling;
+    /// findings of unaudited cluster members are extrapolated, not measured.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sampling: Option<SamplingScope>,
+}
+
+/// Sampling marker in [`AuditScope`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SamplingScope {
+    /// Sampling strategy; currently always `"template"`.
+    pub strategy: String,
+    /// Always true — the marker only exists on sampled reports.
+    pub sampled: bool,
+    pub audited_urls: usize,
+    pub total_urls: usize,
+    /// `audited_urls / total_urls`.
+    pub sampling_ratio: f64,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -79,6 +96,10 @@ pub struct ViewportDefinition {
     pub width: u32,
     pub height: u32,
     pub device_scale_factor: f64,
+    #[serde(default)]
+    pub touch: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_agent: Option<String>,
 }
 
 /// Public, reproducibility-oriented browser information. Executable paths and
@@ -90,6 +111,10 @@ pub struct ExecutionEnvironment {
     pub headless: bool,
     #[serde(default)]
     pub source: String,
+    /// Cookie jar / header names / login URL used for an authenticated run.
+    /// Header and field values are never recorded.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub authentication: Option<crate::browser::AuthProvenance>,
 }
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
@@ -172,6 +197,9 @@ pub struct AuditExecution {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ThrottledPerfResult {
     pub profile: ThrottleProfile,
+    /// Name of a `[[throttle_profiles]]` entry when `profile` is `Custom`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub profile_name: Option<String>,
     /// LCP in milliseconds
     pub lcp_ms: Option<f64>,
     /// TBT in milliseconds
@@ -180,6 +208,17 @@ pub struct ThrottledPerfResult {
     pub cls: Option<f64>,
     /// Aggregate performance score (0–100)
     pub score: u32,
+    /// Per-metric spread when the profile was measured more than once; the
+    /// values above are then medians
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub run_stats: Option<VitalsRunStats>,
+}
+
+impl ThrottledPerfResult {
+    /// Profile label as used in subcheck ids (`slow-3g`, custom names).
+    pub fn label(&self) -> &str {
+        self.profile_name.as_deref().unwrap_or(self.profile.label())
+    }
 }
 
 /// Screenshot bytes captured during the audit (desktop + mobile viewports).
@@ -239,8 +278,8 @@ pub struct ViewportScreenshot {
 }
 
 /// Raw audit data for a single viewport pass.
-/// Not serialized — kept in-memory for output builders.
-#[derive(Debug, Clone)]
+/// Not part of the serialized report; batch checkpoints store it separately.
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ViewportAuditData {
     pub wcag_results: WcagResults,
     pub accessibility_score: f32,
@@ -249,13 +288,15 @@ pub struct ViewportAuditData {
     pub mobile: Option<MobileFriendliness>,
     pub ux: Option<crate::ux::UxAnalysis>,
     pub journey: Option<crate::journey::JourneyAnalysis>,
+    /// Image payload; never checkpointed.
+    #[serde(skip)]
     pub screenshot: Option<ViewportScreenshot>,
     pub module_runs: Vec<ModuleRun>,
 }
 
 /// Dual-viewport raw results — desktop and mobile passes.
-/// Not serialized — kept in-memory only.
-#[derive(Debug, Clone)]
+/// Not part of the serialized report; batch checkpoints store it separately.
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DualViewportResults {
     pub desktop: ViewportAuditData,
     pub mobile: ViewportAuditData,
@@ -326,6 +367,10 @@ pub struct AccessibilitySection {
     /// core result so older raw-report caches can default it safely.
     #[serde(default)]
     pub execution: AuditExecution,
+    /// Violations accepted via the waivers file. Already removed from
+    /// `wcag_results.violations`, so they never reach findings or the verdict.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub waived: Vec<crate::audit::baseline::WaivedViolation>,
 }
 
 /// Discoverability signals grouped together: how findable and machine-readable
@@ -392,6 +437,10 @@ pub struct AuditReport {
     /// Per-viewport scores (serialized for JSON consumers).
     #[serde(skip_serializing_if = "Option::is_none")]
     pub viewport_scores: Option<ViewportScores>,
+    /// Device-matrix rows when `[[devices]]` customizes or extends the
+    /// desktop/mobile passes.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub device_results: Vec<crate::audit::device::DeviceResult>,
     /// Performance vitals measured under different network throttle profiles.
     /// Only populated for single-page audits when performance analysis is enabled.
     #[serde(skip_serializing_if = "Vec::is_empty", default)]
@@ -430,6 +479,10 @@ pub struct AuditReport {
     /// Standalone screen-reader audit artifact. Written as sidecar JSON output.
     #[serde(skip)]
     pub screen_reader_audit: Option<crate::screen_reader::SrAuditReport>,
+    /// Absolute link targets read from the rendered DOM and the History API
+    /// during the audit. Only collected for `--crawl-render` batches.
+    #[serde(skip)]
+    pub rendered_links: Vec<String>,
 }
 
 /// Performance analysis results wrapper
@@ -493,6 +546,7 @@ impl AuditReport {
                 statistics,
                 nodes_analyzed,
                 execution: AuditExecution::default(),
+                waived: Vec::new(),
             },
             duration_ms,
             performance: None,
@@ -510,6 +564,7 @@ impl AuditReport {
             page_screenshots: None,
             dual_viewport: None,
             viewport_scores: None,
+            device_results: Vec::new(),
             throttled_performance: Vec::new(),
             patterns: None,
             screenshot_status: ScreenshotStatus::NotRequested,
@@ -522,6 +577,7 @@ impl AuditReport {
             accessibility_journey: None,
             interactive_findings: Vec::new(),
             screen_reader_audit: None,
+            rendered_links: Vec::new(),
         }
     }
 
@@ -611,7 +667,7 @@ pub struct BatchReport {
     /// Individual reports for each URL
     pub reports: Vec<AuditReport>,
     /// URLs that failed to audit (with error messages)
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub errors: Vec<BatchError>,
     /// Summary statistics
     pub summary: BatchSummary,
@@ -630,6 +686,10 @@ pub struct BatchReport {
     /// representative sample apart from full domain coverage (issue #261).
     #[serde(skip_serializing_if = "Option::is_none", default)]
     pub sample: Option<SampleMetadata>,
+    /// Template sampling plan and extrapolated cluster findings when the
+    /// batch ran with `--template-sample`.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub template_sampling: Option<crate::audit::url_sampling::TemplateSampling>,
     /// Total execution time
     pub total_duration_ms: u64,
 }
@@ -650,8 +710,9 @@ pub struct SampleMetadata {
     /// The `--max-pages` limit, when one capped the audited set.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub sample_limit: Option<usize>,
-    /// How the audited subset was chosen: `"first_n"` (sitemap/discovery order)
-    /// or `"all"` when every discovered URL was audited.
+    /// How the audited subset was chosen: `"first_n"` (sitemap/discovery order),
+    /// `"template"` (representatives per URL template), `"shard"` (one
+    /// `--shard K/N` slice) or `"all"` when every discovered URL was audited.
     pub selection: String,
     /// True when fewer URLs were audited than discovered — i.e. this is a sample,
     /// not full coverage.
@@ -732,6 +793,37 @@ pub struct CrawlDiagnostics {
     /// Links with more than 1 redirect hop
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub redirect_chains: Vec<RedirectChain>,
+    /// Per-page discovery details for browser-rendered crawls
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub discovery: Vec<CrawlPageDiscovery>,
+}
+
+/// How a crawled page was first reached.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LinkDiscovery {
+    /// The crawl seed URL
+    Seed,
+    /// Linked from a parent page's server-delivered HTML
+    Static,
+    /// Linked only from a parent page's rendered DOM or History API calls
+    Rendered,
+}
+
+/// Discovery details for one page of a browser-rendered crawl.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CrawlPageDiscovery {
+    /// Crawled page URL
+    pub url: String,
+    /// Link distance from the seed
+    pub depth: usize,
+    /// How the page itself was reached
+    pub discovered_via: LinkDiscovery,
+    /// Same-domain links present in the server-delivered HTML
+    pub static_links: usize,
+    /// Same-domain links that only appeared after rendering
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub rendered_only_links: Vec<String>,
 }
 
 /// A broken internal link found during crawl-based link checking.
@@ -972,6 +1064,16 @@ impl BatchReport {
         errors: Vec<BatchError>,
         total_duration_ms: u64,
     ) -> Self {
+        let mut reports = reports;
+        let mut pages: Vec<&mut Vec<crate::wcag::types::Violation>> = reports
+            .iter_mut()
+            .map(|r| &mut r.accessibility.wcag_results.violations)
+            .collect();
+        for page in crate::audit::baseline::report_expired_waivers_once(&mut pages) {
+            let accessibility = &mut reports[page].accessibility;
+            accessibility.statistics =
+                AccessibilityScorer::calculate_statistics(&accessibility.wcag_results.violations);
+        }
         let total_urls = reports.len();
         let normalized_reports: Vec<crate::audit::normalized::NormalizedReport> = reports
             .iter()
@@ -1081,6 +1183,7 @@ impl BatchReport {
             sitemap_diagnostics: None,
             consistency: None,
             sample: None,
+            template_sampling: None,
             total_duration_ms,
         };
         result.consistency = crate::audit::batch_consistency::analyze(&result);
@@ -1101,6 +1204,14 @@ impl BatchReport {
         self.sample = Some(sample);
         self
     }
+
+    pub fn with_template_sampling(
+        mut self,
+        sampling: crate::audit::url_sampling::TemplateSampling,
+    ) -> Self {
+        self.template_sampling = Some(sampling);
+        self
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,584 @@
+//! Comparison of two saved JSON reports (`auditmysite diff`).
+//!
+//! Works on the `UnifiedReport` envelope as written by `-f json`, so single
+//! and batch reports can be compared without re-running an audit. Findings
+//! are matched per occurrence on a stable key instead of AXTree node ids
+//! (which change on every run):
+//!
+//! - `rule_id`
+//! - location: the `dom_path` evidence when present, otherwise the selector,
+//!   both normalized like `occurrence_analysis` clusters (digits masked)
+//! - the structural shape of the raw HTML snippet (see `template_dedup`)
+//!
+//! Keys are compared as multisets: two identical occurrences before and one
+//! after yield one unchanged and one resolved finding.
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::audit::artifacts::fnv1a;
+use crate::audit::normalized::RiskLevel;
+use crate::audit::occurrence_analysis::normalize_selector_cluster;
+use crate::audit::template_dedup::snippet_shape_hash;
+use crate::error::Result;
+use crate::taxonomy::Severity;
+use crate::wcag::ViolationEvidence;
+
+/// Location used for findings without any selector (page-level rules).
+const DOCUMENT_LOCATION: &str = "document";
+
+/// Result of comparing a `before` report against an `after` report.
+#[derive(Debug, Clone, Serialize)]
+pub struct ReportDiff {
+    /// `report_type` of the baseline report (`"single"` or `"batch"`).
+    pub before_type: String,
+    /// `report_type` of the current report.
+    pub after_type: String,
+    pub summary: DiffSummary,
+    pub pages: Vec<PageDiff>,
+}
+
+/// Report-level deltas and finding totals across all pages.
+#[derive(Debug, Clone, Serialize)]
+pub struct DiffSummary {
+    pub accessibility_score: ScoreDelta,
+    pub overall_score: ScoreDelta,
+    pub risk_before: RiskLevel,
+    pub risk_after: RiskLevel,
+    /// Per-module score deltas from the report summary (batch: averages).
+    pub module_deltas: Vec<ScoreDelta>,
+    pub new_count: usize,
+    pub resolved_count: usize,
+    pub unchanged_count: usize,
+}
+
+/// A before/after score pair.
+#[derive(Debug, Clone, Serialize)]
+pub struct ScoreDelta {
+    pub label: String,
+    pub before: Option<u32>,
+    pub after: Option<u32>,
+    /// `after - before`; absent when either side is missing.
+    pub delta: Option<i64>,
+}
+
+/// Whether a page exists in both reports or only in one of them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PagePresence {
+    Both,
+    OnlyBefore,
+    OnlyAfter,
+}
+
+/// Per-page comparison.
+#[derive(Debug, Clone, Serialize)]
+pub struct PageDiff {
+    pub url: String,
+    /// Baseline URL when it differs from `url` (two single reports of
+    /// different URLs, e.g. staging vs. production).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub before_url: Option<String>,
+    pub presence: PagePresence,
+    pub accessibility_score: ScoreDelta,
+    pub overall_score: ScoreDelta,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub module_deltas: Vec<ScoreDelta>,
+    pub new_findings: Vec<DiffFinding>,
+    pub resolved_findings: Vec<DiffFinding>,
+    pub unchanged_findings: Vec<DiffFinding>,
+}
+
+/// One finding occurrence, identified by its stable fingerprint.
+#[derive(Debug, Clone, Serialize)]
+pub struct DiffFinding {
+    /// FNV-1a hash of rule id, normalized location and snippet shape.
+    pub fingerprint: String,
+    pub rule_id: String,
+    pub category: String,
+    pub title: String,
+    pub wcag_criterion: String,
+    pub severity: Severity,
+    /// Raw selector or DOM path as reported, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location: Option<String>,
+}
+
+impl ReportDiff {
+    /// True when the current report introduced findings or lost score.
+    pub fn has_regressions(&self) -> bool {
+        self.summary.new_count > 0
+            || self
+                .summary
+                .accessibility_score
+                .delta
+                .is_some_and(|d| d < 0)
+            || self.summary.overall_score.delta.is_some_and(|d| d < 0)
+    }
+}
+
+impl ScoreDelta {
+    fn new(label: impl Into<String>, before: Option<u32>, after: Option<u32>) -> Self {
+        Self {
+            label: label.into(),
+            before,
+            after,
+            delta: before
+                .zip(after)
+                .map(|(before, after)| after as i64 - before as i64),
+        }
+    }
+}
+
+// ── Parsed report subset ─────────────────────────────────────────────────────
+
+#[derive(Deserialize)]
+struct ReportFile {
+    #[serde(default)]
+    report_type: String,
+    summary: SummaryFile,
+    #[serde(default)]
+    pages: Vec<PageFile>,
+}
+
+#[derive(Deserialize)]
+struct SummaryFile {
+    accessibility_score: u32,
+    overall_score: u32,
+    #[serde(default)]
+    risk_level: RiskLevel,
+    performance_score: Option<u32>,
+    seo_score: Option<u32>,
+    security_score: Option<u32>,
+    mobile_score: Option<u32>,
+    ux_score: Option<u32>,
+    journey_score: Option<u32>,
+}
+
+#[derive(Deserialize)]
+struct PageFile {
+    url: String,
+    accessibility_score: u32,
+    overall_score: u32,
+    #[serde(default)]
+    module_scores: Vec<ModuleScoreFile>,
+    #[serde(default)]
+    findings: Vec<FindingFile>,
+}
+
+#[derive(Deserialize)]
+struct ModuleScoreFile {
+    name: String,
+    score: u32,
+}
+
+#[derive(Deserialize)]
+struct FindingFile {
+    rule_id: String,
+    #[serde(default)]
+    category: String,
+    #[serde(default)]
+    title: String,
+    #[serde(default)]
+    wcag_criterion: String,
+    severity: Severity,
+    #[serde(default)]
+    occurrences: Vec<OccurrenceFile>,
+}
+
+#[derive(Deserialize)]
+struct OccurrenceFile {
+    #[serde(default)]
+    selector: Option<String>,
+    #[serde(default)]
+    html_snippet: Option<String>,
+    #[serde(default)]
+    evidence: Vec<ViolationEvidence>,
+}
+
+impl SummaryFile {
+    fn module_scores(&self) -> [(&'static str, Option<u32>); 6] {
+        [
+            ("Performance", self.performance_score),
+            ("SEO", self.seo_score),
+            ("Security", self.security_score),
+            ("Mobile", self.mobile_score),
+            ("UX", self.ux_score),
+            ("Journey", self.journey_score),
+        ]
+    }
+}
+
+// ── Diff ─────────────────────────────────────────────────────────────────────
+
+/// Compare two JSON report documents (`before` = baseline).
+pub fn diff_reports(before: &str, after: &str) -> Result<ReportDiff> {
+    let before: ReportFile = serde_json::from_str(before)?;
+    let after: ReportFile = serde_json::from_str(after)?;
+
+    let mut pages = Vec::new();
+    if before.pages.len() == 1 && after.pages.len() == 1 {
+        pages.push(diff_page(Some(&before.pages[0]), Some(&after.pages[0])));
+    } else {
+        let before_by_url: BTreeMap<String, &PageFile> = before
+            .pages
+            .iter()
+            .map(|page| (url_key(&page.url), page))
+            .collect();
+        let after_by_url: BTreeMap<String, &PageFile> = after
+            .pages
+            .iter()
+            .map(|page| (url_key(&page.url), page))
+            .collect();
+        for page in &after.pages {
+            let baseline = before_by_url.get(&url_key(&page.url)).copied();
+            pages.push(diff_page(baseline, Some(page)));
+        }
+        for page in &before.pages {
+            if !after_by_url.contains_key(&url_key(&page.url)) {
+                pages.push(diff_page(Some(page), None));
+            }
+        }
+    }
+
+    let module_deltas = before
+        .summary
+        .module_scores()
+        .into_iter()
+        .zip(after.summary.module_scores())
+        .filter(|((_, b), (_, a))| b.is_some() || a.is_some())
+        .map(|((label, b), (_, a))| ScoreDelta::new(label, b, a))
+        .collect();
+
+    let summary = DiffSummary {
+        accessibility_score: ScoreDelta::new(
+            "Accessibility",
+            Some(before.summary.accessibility_score),
+            Some(after.summary.accessibility_score),
+        ),
+        overall_score: ScoreDelta::new(
+            "Overall",
+            Some(before.summary.overall_score),
+            Some(after.summary.overall_score),
+        ),
+        risk_before: before.summary.risk_level,
+        risk_after: after.summary.risk_level,
+        module_deltas,
+        new_count: pages.iter().map(|p| p.new_findings.len()).sum(),
+        resolved_count: pages.iter().map(|p| p.resolved_findings.len()).sum(),
+        unchanged_count: pages.iter().map(|p| p.unchanged_findings.len()).sum(),
+    };
+
+    Ok(ReportDiff {
+        before_type: before.report_type,
+        after_type: after.report_type,
+        summary,
+        pages,
+    })
+}
+
+fn diff_page(before: Option<&PageFile>, after: Option<&PageFile>) -> PageDiff {
+    let presence = match (before, after) {
+        (Some(_), Some(_)) => PagePresence::Both,
+        (Some(_), None) => PagePresence::OnlyBefore,
+        _ => PagePresence::OnlyAfter,
+    };
+    let url = after.or(before).map(|p| p.url.clone()).unwrap_or_default();
+    let before_url = before
+        .map(|p| p.url.clone())
+        .filter(|before_url| url_key(before_url) != url_key(&url));
+
+    let mut before_keys = before.map(finding_occurrences).unwrap_or_default();
+    let mut new_findings = Vec::new();
+    let mut unchanged_findings = Vec::new();
+    for (fingerprint, findings) in after.map(finding_occurrences).unwrap_or_default() {
+        let mut baseline = before_keys.remove(&fingerprint).unwrap_or_default();
+        for finding in findings {
+            if baseline.pop().is_some() {
+                unchanged_findings.push(finding);
+            } else {
+                new_findings.push(finding);
+            }
+        }
+        if !baseline.is_empty() {
+            before_keys.insert(fingerprint, baseline);
+        }
+    }
+    let mut resolved_findings: Vec<DiffFinding> = before_keys.into_values().flatten().collect();
+
+    for findings in [
+        &mut new_findings,
+        &mut resolved_findings,
+        &mut unchanged_findings,
+    ] {
+        findings.sort_by(|a, b| {
+            b.severity
+                .cmp(&a.severity)
+                .then_with(|| a.rule_id.cmp(&b.rule_id))
+                .then_with(|| a.location.cmp(&b.location))
+        });
+    }
+
+    let module_names: Vec<&str> = after
+        .into_iter()
+        .chain(before)
+        .flat_map(|p| p.module_scores.iter().map(|m| m.name.as_str()))
+        .fold(Vec::new(), |mut names, name| {
+            if !names.contains(&name) {
+                names.push(name);
+            }
+            names
+        });
+    let module_score = |page: Option<&PageFile>, name: &str| {
+        page.and_then(|p| p.module_scores.iter().find(|m| m.name == name))
+            .map(|m| m.score)
+    };
+    let module_deltas = module_names
+        .into_iter()
+        .map(|name| ScoreDelta::new(name, module_score(before, name), module_score(after, name)))
+        .collect();
+
+    PageDiff {
+        url,
+        before_url,
+        presence,
+        accessibility_score: ScoreDelta::new(
+            "Accessibility",
+            before.map(|p| p.accessibility_score),
+            after.map(|p| p.accessibility_score),
+        ),
+        overall_score: ScoreDelta::new(
+            "Overall",
+            before.map(|p| p.overall_score),
+            after.map(|p| p.overall_score),
+        ),
+        module_deltas,
+        new_findings,
+        resolved_findings,
+        unchanged_findings,
+    }
+}
+
+/// Every finding occurrence on a page, grouped by fingerprint. Findings
+/// without recorded occurrences count once, located at the document.
+fn finding_occurrences(page: &PageFile) -> BTreeMap<String, Vec<DiffFinding>> {
+    let mut grouped: BTreeMap<String, Vec<DiffFinding>> = BTreeMap::new();
+    for finding in &page.findings {
+        let occurrences: Vec<Option<&OccurrenceFile>> = if finding.occurrences.is_empty() {
+            vec![None]
+        } else {
+            finding.occurrences.iter().map(Some).collect()
+        };
+        for occurrence in occurrences {
+            let location = occurrence.and_then(occurrence_location);
+            let shape = occurrence
+                .and_then(|occ| occ.html_snippet.as_deref())
+                .map(snippet_shape_hash)
+                .unwrap_or_default();
+            let fingerprint = fingerprint(&finding.rule_id, location.as_deref(), &shape);
+            grouped
+                .entry(fingerprint.clone())
+                .or_default()
+                .push(DiffFinding {
+                    fingerprint,
+                    rule_id: finding.rule_id.clone(),
+                    category: finding.category.clone(),
+                    title: finding.title.clone(),
+                    wcag_criterion: finding.wcag_criterion.clone(),
+                    severity: finding.severity,
+                    location,
+                });
+        }
+    }
+    grouped
+}
+
+/// Prefer the multi-level DOM path over the selector shorthand: it is the
+/// more specific of the two and equally stable between runs.
+fn occurrence_location(occurrence: &OccurrenceFile) -> Option<String> {
+    occurrence
+        .evidence
+        .iter()
+        .find(|ev| ev.field.as_deref() == Some("dom_path"))
+        .and_then(|ev| ev.value.clone())
+        .or_else(|| occurrence.selector.clone())
+        .map(|location| location.trim().to_string())
+        .filter(|location| !location.is_empty())
+}
+
+/// Stable finding fingerprint: rule id + normalized location + snippet shape.
+pub fn fingerprint(rule_id: &str, location: Option<&str>, snippet_shape: &str) -> String {
+    let location = location
+        .map(normalize_selector_cluster)
+        .unwrap_or_else(|| DOCUMENT_LOCATION.to_string());
+    let input = format!("{rule_id}\u{1f}{location}\u{1f}{snippet_shape}");
+    format!("{:016x}", fnv1a(input.as_bytes()))
+}
+
+/// Page identity for matching: scheme and host are case-insensitive, path
+/// and query are not. A trailing slash is ignored.
+fn url_key(url: &str) -> String {
+    let key = match url::Url::parse(url) {
+        // `Url` already lowercases scheme and host.
+        Ok(parsed) => parsed.to_string(),
+        Err(_) => url.to_string(),
+    };
+    key.trim_end_matches('/').to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn report(report_type: &str, score: u32, pages: serde_json::Value) -> String {
+        serde_json::json!({
+            "report_type": report_type,
+            "summary": {
+                "accessibility_score": score,
+                "overall_score": score,
+                "risk_level": "medium",
+                "seo_score": 80,
+            },
+            "pages": pages,
+        })
+        .to_string()
+    }
+
+    fn page(url: &str, findings: serde_json::Value) -> serde_json::Value {
+        serde_json::json!({
+            "url": url,
+            "accessibility_score": 80,
+            "overall_score": 75,
+            "module_scores": [{ "name": "SEO", "score": 80 }],
+            "findings": findings,
+        })
+    }
+
+    fn finding(rule_id: &str, occurrences: serde_json::Value) -> serde_json::Value {
+        serde_json::json!({
+            "rule_id": rule_id,
+            "category": "wcag",
+            "title": rule_id,
+            "wcag_criterion": "1.1.1",
+            "severity": "high",
+            "occurrences": occurrences,
+        })
+    }
+
+    #[test]
+    fn matches_on_selector_and_snippet_not_node_id() {
+        let before = report(
+            "single",
+            80,
+            serde_json::json!([page(
+                "https://example.com/",
+                serde_json::json!([finding(
+                    "a11y.alt_text.missing",
+                    serde_json::json!([
+                        { "node_id": "12", "selector": "img.hero", "html_snippet": "<img class=\"hero\" src=\"a1.png\">" },
+                        { "node_id": "13", "selector": "img.logo", "html_snippet": "<img class=\"logo\">" },
+                    ])
+                )])
+            )]),
+        );
+        let after = report(
+            "single",
+            78,
+            serde_json::json!([page(
+                "https://example.com/",
+                serde_json::json!([
+                    finding(
+                        "a11y.alt_text.missing",
+                        serde_json::json!([
+                            { "node_id": "98", "selector": "img.hero", "html_snippet": "<img class=\"hero\" src=\"a2.png\">" },
+                        ])
+                    ),
+                    finding("a11y.lang.missing", serde_json::json!([])),
+                ])
+            )]),
+        );
+
+        let diff = diff_reports(&before, &after).unwrap();
+
+        assert_eq!(diff.summary.unchanged_count, 1);
+        assert_eq!(diff.summary.new_count, 1);
+        assert_eq!(diff.summary.resolved_count, 1);
+        let page = &diff.pages[0];
+        assert_eq!(page.new_findings[0].rule_id, "a11y.lang.missing");
+        assert_eq!(
+            page.resolved_findings[0].location.as_deref(),
+            Some("img.logo")
+        );
+        assert_eq!(diff.summary.accessibility_score.delta, Some(-2));
+        assert!(diff.has_regressions());
+    }
+
+    #[test]
+    fn batch_pages_are_paired_by_url() {
+        let alt = finding(
+            "a11y.alt_text.missing",
+            serde_json::json!([{ "node_id": "1", "selector": "img" }]),
+        );
+        let before = report(
+            "batch",
+            70,
+            serde_json::json!([
+                page("https://example.com/a", serde_json::json!([alt.clone()])),
+                page("https://example.com/b", serde_json::json!([alt.clone()])),
+            ]),
+        );
+        let after = report(
+            "batch",
+            90,
+            serde_json::json!([
+                page("https://example.com/a/", serde_json::json!([alt])),
+                page("https://example.com/c", serde_json::json!([])),
+            ]),
+        );
+
+        let diff = diff_reports(&before, &after).unwrap();
+
+        let presence: Vec<_> = diff.pages.iter().map(|p| p.presence).collect();
+        assert_eq!(
+            presence,
+            vec![
+                PagePresence::Both,
+                PagePresence::OnlyAfter,
+                PagePresence::OnlyBefore
+            ]
+        );
+        assert_eq!(diff.summary.unchanged_count, 1);
+        assert_eq!(diff.summary.resolved_count, 1);
+        assert_eq!(diff.summary.new_count, 0);
+        assert!(!diff.has_regressions());
+    }
+
+    #[test]
+    fn url_key_folds_host_case_but_not_path_case() {
+        assert_eq!(
+            url_key("HTTPS://Example.COM/About/"),
+            url_key("https://example.com/About")
+        );
+        assert_ne!(
+            url_key("https://example.com/About"),
+            url_key("https://example.com/about")
+        );
+        assert_ne!(
+            url_key("https://example.com/?q=A"),
+            url_key("https://example.com/?q=a")
+        );
+    }
+
+    #[test]
+    fn fingerprint_ignores_digits_in_selector() {
+        assert_eq!(
+            fingerprint("rule", Some("li:nth-child(3) > a"), ""),
+            fingerprint("rule", Some("li:nth-child(7) > a"), "")
+        );
+        assert_ne!(
+            fingerprint("rule", Some("nav a"), ""),
+            fingerprint("rule", None, "")
+        );
+    }
+}
@@ -0,0 +1,451 @@
+//! Sharded batch audits.
+//!
+//! `--shard K/N` audits a deterministic subset of a batch's URL list so a big
+//! sitemap can be spread over N CI jobs. Each job writes a [`ShardReport`]:
+//! its raw `BatchReport` plus what `merge` needs to rebuild the whole run —
+//! the full input URL list, the audit signature and the screen-reader and
+//! dual-viewport data that the serialized `AuditReport` leaves out.
+//!
+//! [`merge_shards`] then rebuilds one `BatchReport` through
+//! `BatchReport::from_reports`, so cross-page consistency, template clusters
+//! and everything the output builders derive (near-duplicates, verdict) are
+//! computed over all pages rather than per shard.
+
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::fs;
+use std::path::Path;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+use tracing::warn;
+
+use crate::audit::artifacts::fnv1a;
+use crate::audit::batch::sitemap_link_graph;
+use crate::audit::report::{BatchReport, DualViewportResults, SampleMetadata, SitemapDiagnostics};
+use crate::error::{AuditError, Result};
+use crate::screen_reader::SrAuditReport;
+
+/// Identifies a shard file among other JSON files.
+const SHARD_FORMAT: &str = "auditmysite-shard";
+
+/// One slice of a sharded batch: `index` of `count`, 1-based.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Shard {
+    pub index: usize,
+    pub count: usize,
+}
+
+impl FromStr for Shard {
+    type Err = String;
+
+    fn from_str(raw: &str) -> std::result::Result<Self, Self::Err> {
+        let (index, count) = raw
+            .split_once('/')
+            .ok_or_else(|| format!("Invalid shard '{raw}': expected K/N, e.g. 2/5"))?;
+        let index: usize = index
+            .trim()
+            .parse()
+            .map_err(|_| format!("Invalid shard index in '{raw}'"))?;
+        let count: usize = count
+            .trim()
+            .parse()
+            .map_err(|_| format!("Invalid shard count in '{raw}'"))?;
+        if count == 0 || index == 0 || index > count {
+            return Err(format!("Invalid shard '{raw}': K must be between 1 and N"));
+        }
+        Ok(Self { index, count })
+    }
+}
+
+impl fmt::Display for Shard {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}", self.index, self.count)
+    }
+}
+
+impl Shard {
+    /// The URLs of `urls` that belong to this shard, in input order.
+    ///
+    /// Assignment hashes each URL, so it does not depend on list order: jobs
+    /// that fetched the sitemap at slightly different times still split it
+    /// without overlap.
+    pub fn select(&self, urls: &[String]) -> Vec<String> {
+        urls.iter()
+            .filter(|url| self.contains(url))
+            .cloned()
+            .collect()
+    }
+
+    fn contains(&self, url: &str) -> bool {
+        fnv1a(url.as_bytes()) % self.count as u64 == (self.index - 1) as u64
+    }
+
+    /// Default output file of a shard run: `shard-K-of-N.json`.
+    pub fn file_name(&self) -> String {
+        format!("shard-{}-of-{}.json", self.index, self.count)
+    }
+}
+
+/// The result file of one shard run, input to `auditmysite merge`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ShardReport {
+    /// Always `"auditmysite-shard"`.
+    pub format: String,
+    pub tool_version: String,
+    pub shard: Shard,
+    /// `PipelineConfig::audit_signature` — shards audited with different
+    /// settings are not merged.
+    pub audit_signature: String,
+    /// The complete URL list before sharding, in discovery order.
+    pub urls: Vec<String>,
+    pub batch: BatchReport,
+    /// Screen-reader reports by page URL; `AuditReport` does not serialize
+    /// them, but the merged risk assessment depends on them.
+    #[serde(default)]
+    pub screen_reader: HashMap<String, SrAuditReport>,
+    /// Desktop/mobile pass data by page URL (without screenshots); also not
+    /// serialized by `AuditReport`, but desktop scores are derived from it.
+    #[serde(default)]
+    pub dual_viewport: HashMap<String, DualViewportResults>,
+}
+
+impl ShardReport {
+    pub fn new(
+        shard: Shard,
+        audit_signature: String,
+        urls: Vec<String>,
+        batch: BatchReport,
+    ) -> Self {
+        let screen_reader = batch
+            .reports
+            .iter()
+            .filter_map(|r| Some((r.url.clone(), r.screen_reader_audit.clone()?)))
+            .collect();
+        let dual_viewport = batch
+            .reports
+            .iter()
+            .filter_map(|r| Some((r.url.clone(), r.dual_viewport.clone()?)))
+            .collect();
+        Self {
+            format: SHARD_FORMAT.to_string(),
+            tool_version: env!("CARGO_PKG_VERSION").to_string(),
+            shard,
+            audit_signature,
+            urls,
+            batch,
+            screen_reader,
+            dual_viewport,
+        }
+    }
+
+    pub fn write(&self, path: &Path) -> Result<()> {
+        fs::write(path, serde_json::to_vec(self)?)?;
+        Ok(())
+    }
+
+    pub fn load(path: &Path) -> Result<Self> {
+        let report: Self = serde_json::from_slice(&fs::read(path)?).map_err(|e| {
+            AuditError::ConfigError(format!(
+                "{} is not a shard result (write one with --shard K/N): {e}",
+                path.display()
+            ))
+        })?;
+        if report.format != SHARD_FORMAT {
+            return Err(AuditError::ConfigError(format!(
+                "{} is not a shard result (format '{}')",
+                path.display(),
+                report.format
+            )));
+        }
+        Ok(report)
+    }
+}
+
+/// Combine the shard results of one run into a single `BatchReport`.
+///
+/// Every shard `1..=N` must be present exactly once and all shards must
+/// share the audit signature. Shards that saw different URL lists (e.g. a
+/// sitemap that changed between jobs) are merged with a warning; the run then
+/// covers the union of their lists, and URLs no shard owned count as not
+/// audited. Pages are ordered as in the original URL list. Sitemap HTTP issues are concatenated (each shard checked its own
+/// URLs) while the sitemap link graph is recomputed over all pages; crawl
+/// diagnostics come from the first shard, since every shard ran the same
+/// discovery crawl.
+pub fn merge_shards(mut shards: Vec<ShardReport>) -> Result<BatchReport> {
+    let first = shards
+        .first()
+        .ok_or_else(|| AuditError::ConfigError("No shard results to merge".to_string()))?;
+    let count = first.shard.count;
+    let signature = first.audit_signature.clone();
+
+    let mut indices = HashSet::new();
+    for shard in &shards {
+        if shard.shard.count != count {
+            return Err(AuditError::ConfigError(format!(
+                "Shard {} does not belong to a {count}-way split",
+                shard.shard
+            )));
+        }
+        if shard.audit_signature != signature {
+            return Err(AuditError::ConfigError(format!(
+                "Shard {} was audited with a different configuration ({} vs {})",
+                shard.shard, shard.audit_signature, signature
+            )));
+        }
+        if !indices.insert(shard.shard.index) {
+            return Err(AuditError::ConfigError(format!(
+                "Shard {} was given more than once",
+                shard.shard
+            )));
+        }
+    }
+    let missing: Vec<String> = (1..=count)
+        .filter(|index| !indices.contains(index))
+        .map(|index| format!("{index}/{count}"))
+        .collect();
+    if !missing.is_empty() {
+        return Err(AuditError::ConfigError(format!(
+            "Missing shard results: {}",
+            missing.join(", ")
+        )));
+    }
+
+    shards.sort_by_key(|s| s.shard.index);
+    let mut urls = shards[0].urls.clone();
+    let mut seen: HashSet<String> = urls.iter().cloned().collect();
+    for shard in &shards[1..] {
+        let own: HashSet<&str> = shard.urls.iter().map(String::as_str).collect();
+        let added: Vec<String> = shard
+            .urls
+            .iter()
+            .filter(|url| seen.insert((*url).clone()))
+            .cloned()
+            .collect();
+        let dropped = shards[0]
+            .urls
+            .iter()
+            .filter(|url| !own.contains(url.as_str()))
+            .count();
+        if !added.is_empty() || dropped > 0 {
+            warn!(
+                "Shard {} saw a different URL list than shard {} ({} extra, {} missing); \
+                 URLs only some shards listed may not have been audited",
+                shard.shard,
+                shards[0].shard,
+                added.len(),
+                dropped
+            );
+        }
+        urls.extend(added);
+    }
+    let position: HashMap<&str, usize> = urls
+        .iter()
+        .enumerate()
+        .map(|(i, url)| (url.as_str(), i))
+        .collect();
+    let rank = |url: &str| position.get(url).copied().unwrap_or(usize::MAX);
+
+    let mut reports = Vec::new();
+    let mut errors = Vec::new();
+    let mut http_issues = Vec::new();
+    let mut checked_urls = 0;
+    let mut total_duration_ms = 0;
+    let mut audited = 0;
+    for shard in &mut shards {
+        let batch = &mut shard.batch;
+        for mut report in std::mem::take(&mut batch.reports) {
+            if report.screen_reader_audit.is_none() {
+                report.screen_reader_audit = shard.screen_reader.remove(&report.url);
+            }
+            if report.dual_viewport.is_none() {
+                report.dual_viewport = shard.dual_viewport.remove(&report.url);
+            }
+            reports.push(report);
+        }
+        errors.append(&mut batch.errors);
+        if let Some(diagnostics) = batch.sitemap_diagnostics.take() {
+            checked_urls += diagnostics.checked_urls;
+            http_issues.extend(diagnostics.http_issues);
+        }
+        audited += batch.sample.as_ref().map_or(0, |s| s.audited);
+        // Shards run in parallel; the merged run took as long as the slowest.
+        total_duration_ms = total_duration_ms.max(batch.total_duration_ms);
+    }
+    // Restore the input order; URLs missing from the list go last.
+    reports.sort_by_key(|r| rank(&r.url));
+    errors.sort_by_key(|e| rank(&e.url));
+    http_issues.sort_by(|a, b| a.url.cmp(&b.url));
+
+    let template = &shards[0].batch;
+    let sample = template.sample.clone();
+    let crawl_diagnostics = template.crawl_diagnostics.clone();
+
+    let mut merged = BatchReport::from_reports(reports, errors, total_duration_ms);
+    if let Some(sample) = sample {
+        let total_discovered = urls.len();
+        let is_sample = audited < total_discovered;
+        merged = merged.with_sample(SampleMetadata {
+            total_discovered,
+            audited,
+            selection: if is_sample { "first_n" } else { "all" }.to_string(),
+            is_sample,
+            ..sample
+        });
+    }
+    if merged
+        .sample
+        .as_ref()
+        .is_some_and(|s| s.source == "sitemap")
+    {
+        let (orphan_sitemap_urls, linked_not_in_sitemap) =
+            sitemap_link_graph(&urls, &merged.reports);
+        merged = merged.with_sitemap_diagnostics(SitemapDiagnostics {
+            checked_urls,
+            http_issues,
+            orphan_sitemap_urls,
+            linked_not_in_sitemap,
+        });
+    }
+    if let Some(diagnostics) = crawl_diagnostics {
+        merged = merged.with_crawl_diagnostics(diagnostics);
+    }
+    Ok(merged)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::audit::report::AuditReport;
+    use crate::cli::WcagLevel;
+    use crate::wcag::WcagResults;
+
+    fn shard_run(shard: Shard, urls: &[String]) -> ShardReport {
+        let selected = shard.select(urls);
+        let reports = selected
+            .iter()
+            .map(|url| AuditReport::new(url.clone(), WcagLevel::AA, WcagResults::new(), 10))
+            .collect();
+        let batch =
+            BatchReport::from_reports(reports, Vec::new(), 100).with_sample(SampleMetadata {
+                source: "url_file".to_string(),
+                total_discovered: urls.len(),
+                audited: selected.len(),
+                sample_limit: None,
+                selection: "shard".to_string(),
+                is_sample: selected.len() < urls.len(),
+            });
+        ShardReport::new(shard, "level=AA".to_string(), urls.to_vec(), batch)
+    }
+
+    #[test]
+    fn parses_shard_spec() {
+        assert_eq!(
+            "2/5".parse::<Shard>().unwrap(),
+            Shard { index: 2, count: 5 }
+        );
+        assert!("0/5".parse::<Shard>().is_err());
+        assert!("6/5".parse::<Shard>().is_err());
+        assert!("2".parse::<Shard>().is_err());
+    }
+
+    #[test]
+    fn shards_partition_urls_independent_of_order() {
+        let urls: Vec<String> = (0..50)
+            .map(|i| format!("https://example.com/page-{i}"))
+            .collect();
+        let mut reversed = urls.clone();
+        reversed.reverse();
+
+        let mut covered = Vec::new();
+        for index in 1..=3 {
+            let shard = Shard { index, count: 3 };
+            let mut forward = shard.select(&urls);
+            let mut backward = shard.select(&reversed);
+            forward.sort();
+            backward.sort();
+            assert_eq!(forward, backward);
+            covered.extend(forward);
+        }
+        covered.sort();
+        let mut expected = urls.clone();
+        expected.sort();
+        assert_eq!(covered, expected);
+    }
+
+    #[test]
+    fn merge_restores_input_order_and_rejects_incomplete_sets() {
+        let urls: Vec<String> = (0..12)
+            .map(|i| format!("https://example.com/p{i}"))
+            .collect();
+        let shards: Vec<ShardReport> = (1..=3)
+            .map(|index| shard_run(Shard { index, count: 3 }, &urls))
+            .collect();
+
+        let merged = merge_shards(shards.clone()).unwrap();
+        let merged_urls: Vec<&str> = merged.reports.iter().map(|r| r.url.as_str()).collect();
+        let expected: Vec<&str> = urls.iter().map(String::as_str).collect();
+        assert_eq!(merged_urls, expected);
+        assert_eq!(merged.summary.total_urls, 12);
+        let sample = merged.sample.unwrap();
+        assert_eq!(sample.audited, 12);
+        assert!(!sample.is_sample);
+
+        assert!(merge_shards(shards[..2].to_vec()).is_err());
+        let mut duplicated = shards.clone();
+        duplicated[2] = shards[0].clone();
+        assert!(merge_shards(duplicated).is_err());
+    }
+
+    #[test]
+    fn merge_counts_the_union_of_diverging_url_lists() {
+        let urls: Vec<String> = (0..12)
+            .map(|i| format!("https://example.com/p{i}"))
+            .collect();
+        let mut shards: Vec<ShardReport> = (1..=2)
+            .map(|index| shard_run(Shard { index, count: 2 }, &urls))
+            .collect();
+        // Shard 2's sitemap copy gained a page that shard 1 never saw.
+        let mut grown = urls.clone();
+        grown.push("https://example.com/new".to_string());
+        shards[1].urls = grown;
+
+        let merged = merge_shards(shards).unwrap();
+        let sample = merged.sample.unwrap();
+        assert_eq!(sample.total_discovered, 13);
+        assert_eq!(sample.audited, 12);
+        assert!(sample.is_sample);
+    }
+
+    #[test]
+    fn merge_restores_dual_viewport_data() {
+        let urls = vec!["https://example.com/".to_string()];
+        let mut shard = shard_run(Shard { index: 1, count: 1 }, &urls);
+        let pass = |score| crate::audit::report::ViewportAuditData {
+            wcag_results: WcagResults::new(),
+            accessibility_score: score,
+            performance: None,
+            seo: None,
+            mobile: None,
+            ux: None,
+            journey: None,
+            screenshot: None,
+            module_runs: Vec::new(),
+        };
+        shard.dual_viewport.insert(
+            urls[0].clone(),
+            DualViewportResults {
+                desktop: pass(90.0),
+                mobile: pass(70.0),
+            },
+        );
+        // Round-trip through JSON like `merge` does with shard files.
+        let shard: ShardReport =
+            serde_json::from_slice(&serde_json::to_vec(&shard).unwrap()).unwrap();
+
+        let merged = merge_shards(vec![shard]).unwrap();
+        let dual = merged.reports[0].dual_viewport.as_ref().unwrap();
+        assert_eq!(dual.desktop.accessibility_score, 90.0);
+        assert_eq!(dual.mobile.accessibility_score, 70.0);
+    }
+}
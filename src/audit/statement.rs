@@ -0,0 +1,270 @@
+//! Draft accessibility statement (`auditmysite statement`).
+//!
+//! Reads a saved `-f json` report (single or batch) and derives the parts of
+//! the EU model accessibility statement (Commission Implementing Decision
+//! (EU) 2018/1523) that audit results can inform: the compliance status and
+//! the list of non-accessible content. Everything else — the responsible
+//! body, feedback contact, enforcement procedure — stays a placeholder for a
+//! human to fill in. Rendering lives in `output::statement`.
+
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::error::Result;
+use crate::taxonomy::Severity;
+use crate::wcag::en301549::{En301549Clause, EN301549_VERSION, EN301549_WEB_CLAUSES};
+
+/// Compliance status of the draft. The model statement's "fully compliant"
+/// is never derived: automated checks cannot establish it, so a run without
+/// violations yields `ReviewRequired` instead.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ComplianceStatus {
+    PartiallyCompliant,
+    NotCompliant,
+    /// No violations in the automated scope; not assessed, review required.
+    ReviewRequired,
+}
+
+/// One violated EN 301 549 clause with the rules that violated it.
+#[derive(Debug, Clone, Serialize)]
+pub struct NonAccessibleContent {
+    pub wcag_criterion: &'static str,
+    pub en_clause: &'static str,
+    pub title_en: &'static str,
+    pub title_de: &'static str,
+    pub affected_pages: usize,
+    /// Most frequent first.
+    pub issues: Vec<StatementIssue>,
+}
+
+/// Findings of one rule for a clause, summed across pages.
+#[derive(Debug, Clone, Serialize)]
+pub struct StatementIssue {
+    pub rule_id: String,
+    pub title: String,
+    pub occurrences: usize,
+    pub pages: usize,
+}
+
+/// The derived part of an accessibility statement. Always a draft.
+#[derive(Debug, Clone, Serialize)]
+pub struct StatementDraft {
+    /// Always `true`; kept in the JSON so consumers cannot mistake it for a
+    /// reviewed statement.
+    pub draft: bool,
+    /// Origin of the audited pages, e.g. `https://example.com`.
+    pub website: String,
+    pub pages: Vec<String>,
+    pub standard: &'static str,
+    pub evaluation_date: DateTime<Utc>,
+    pub tool: String,
+    pub compliance_status: ComplianceStatus,
+    /// In WCAG order.
+    pub non_accessible_content: Vec<NonAccessibleContent>,
+}
+
+// ── Parsed report subset ─────────────────────────────────────────────────────
+
+#[derive(Deserialize)]
+struct ReportFile {
+    metadata: MetadataFile,
+    #[serde(default)]
+    pages: Vec<PageFile>,
+}
+
+#[derive(Deserialize)]
+struct MetadataFile {
+    tool: String,
+    timestamp: DateTime<Utc>,
+}
+
+#[derive(Deserialize)]
+struct PageFile {
+    url: String,
+    #[serde(default)]
+    findings: Vec<FindingFile>,
+}
+
+#[derive(Deserialize)]
+struct FindingFile {
+    rule_id: String,
+    #[serde(default)]
+    category: String,
+    #[serde(default)]
+    title: String,
+    #[serde(default)]
+    wcag_criterion: String,
+    severity: Severity,
+    #[serde(default)]
+    occurrence_count: usize,
+}
+
+// ── Derivation ───────────────────────────────────────────────────────────────
+
+/// Derive a statement draft from a JSON report document. Only WCAG findings
+/// on the 50 EN 301 549 chapter-9 criteria count. The status is
+/// "not compliant" when a critical or high-severity violation occurs on more
+/// than half of the pages, "partially compliant" for any other violation and
+/// "not assessed, review required" otherwise — a manual audit decides the
+/// status before the statement may be published.
+pub fn draft_statement(report_json: &str) -> Result<StatementDraft> {
+    let report: ReportFile = serde_json::from_str(report_json)?;
+    let page_count = report.pages.len();
+
+    let mut by_clause: BTreeMap<usize, (usize, BTreeMap<String, StatementIssue>)> = BTreeMap::new();
+    let mut blocking_pages = 0;
+    for page in &report.pages {
+        let mut clauses_on_page = Vec::new();
+        let mut rules_on_page = Vec::new();
+        let mut blocking = false;
+        for finding in page.findings.iter().filter(|f| f.category == "wcag") {
+            let Some(idx) = EN301549_WEB_CLAUSES
+                .iter()
+                .position(|c| c.wcag == finding.wcag_criterion)
+            else {
+                continue;
+            };
+            blocking |= matches!(finding.severity, Severity::Critical | Severity::High);
+            let (pages, issues) = by_clause.entry(idx).or_default();
+            if !clauses_on_page.contains(&idx) {
+                clauses_on_page.push(idx);
+                *pages += 1;
+            }
+            let issue = issues
+                .entry(finding.rule_id.clone())
+                .or_insert_with(|| StatementIssue {
+                    rule_id: finding.rule_id.clone(),
+                    title: finding.title.clone(),
+                    occurrences: 0,
+                    pages: 0,
+                });
+            issue.occurrences += finding.occurrence_count.max(1);
+            if !rules_on_page.contains(&(idx, finding.rule_id.as_str())) {
+                rules_on_page.push((idx, finding.rule_id.as_str()));
+                issue.pages += 1;
+            }
+        }
+        if blocking {
+            blocking_pages += 1;
+        }
+    }
+
+    let compliance_status = if by_clause.is_empty() {
+        ComplianceStatus::ReviewRequired
+    } else if blocking_pages * 2 > page_count {
+        ComplianceStatus::NotCompliant
+    } else {
+        ComplianceStatus::PartiallyCompliant
+    };
+
+    let non_accessible_content = by_clause
+        .into_iter()
+        .map(|(idx, (affected_pages, issues))| {
+            let clause: &En301549Clause = &EN301549_WEB_CLAUSES[idx];
+            let mut issues: Vec<StatementIssue> = issues.into_values().collect();
+            issues.sort_by_key(|issue| std::cmp::Reverse(issue.occurrences));
+            NonAccessibleContent {
+                wcag_criterion: clause.wcag,
+                en_clause: clause.en_clause,
+                title_en: clause.title_en,
+                title_de: clause.title_de,
+                affected_pages,
+                issues,
+            }
+        })
+        .collect();
+
+    let pages: Vec<String> = report.pages.iter().map(|p| p.url.clone()).collect();
+    Ok(StatementDraft {
+        draft: true,
+        website: pages.first().map(|u| website_origin(u)).unwrap_or_default(),
+        pages,
+        standard: EN301549_VERSION,
+        evaluation_date: report.metadata.timestamp,
+        tool: report.metadata.tool,
+        compliance_status,
+        non_accessible_content,
+    })
+}
+
+fn website_origin(page_url: &str) -> String {
+    url::Url::parse(page_url)
+        .map(|u| u.origin().ascii_serialization())
+        .unwrap_or_else(|_| page_url.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn report(pages: serde_json::Value) -> String {
+        json!({
+            "metadata": { "tool": "auditmysite 1.0.0", "timestamp": "2026-01-15T10:00:00Z" },
+            "pages": pages,
+        })
+        .to_string()
+    }
+
+    fn finding(criterion: &str, severity: &str, occurrences: usize) -> serde_json::Value {
+        json!({
+            "rule_id": format!("rule-{criterion}"),
+            "category": "wcag",
+            "title": format!("Issue {criterion}"),
+            "wcag_criterion": criterion,
+            "severity": severity,
+            "occurrence_count": occurrences,
+        })
+    }
+
+    #[test]
+    fn derives_status_and_groups_findings_by_clause() {
+        let json = report(json!([
+            { "url": "https://example.com/", "findings": [finding("1.1.1", "medium", 3), finding("2.4.9", "high", 1)] },
+            { "url": "https://example.com/about", "findings": [finding("1.1.1", "medium", 2)] },
+            { "url": "https://example.com/contact", "findings": [] },
+        ]));
+        let draft = draft_statement(&json).unwrap();
+
+        assert_eq!(draft.website, "https://example.com");
+        assert_eq!(
+            draft.compliance_status,
+            ComplianceStatus::PartiallyCompliant
+        );
+        // 2.4.9 is AAA and outside EN 301 549 chapter 9.
+        assert_eq!(draft.non_accessible_content.len(), 1);
+        let alt = &draft.non_accessible_content[0];
+        assert_eq!(alt.en_clause, "9.1.1.1");
+        assert_eq!(alt.affected_pages, 2);
+        assert_eq!(alt.issues[0].occurrences, 5);
+    }
+
+    #[test]
+    fn widespread_blocking_violations_are_not_compliant() {
+        let json = report(json!([
+            { "url": "https://example.com/", "findings": [finding("4.1.2", "critical", 1)] },
+        ]));
+        assert_eq!(
+            draft_statement(&json).unwrap().compliance_status,
+            ComplianceStatus::NotCompliant
+        );
+    }
+
+    #[test]
+    fn zero_findings_never_claim_full_compliance() {
+        let clean = report(json!([
+            { "url": "https://example.com/", "findings": [] },
+            { "url": "https://example.com/about", "findings": [] },
+        ]));
+        let draft = draft_statement(&clean).unwrap();
+        assert_eq!(draft.compliance_status, ComplianceStatus::ReviewRequired);
+        assert!(draft.non_accessible_content.is_empty());
+        assert_eq!(
+            serde_json::to_value(&draft).unwrap()["compliance_status"],
+            "review_required"
+        );
+    }
+}
@@ -725,6 +725,9 @@ mod tests {
             score_calculation_method: "module_weighted".to_string(),
             score_breakdown: None,
             interactive_findings: Vec::new(),
+            waived_findings: Vec::new(),
+            suppressed_findings: Vec::new(),
+            device_results: Vec::new(),
             accessibility_journey: None,
             screen_reader: None,
             interpretation: None,
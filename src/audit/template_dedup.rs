@@ -220,7 +220,7 @@ fn is_specific_selector(selector: &str) -> bool {
 /// stripped, whitespace collapsed, and long attribute values masked (kills
 /// per-page URLs/hashes in `href`/`srcset`/`class` so the same component
 /// rendered with different link targets or CMS ids still hashes identically).
-fn snippet_shape_hash(html: &str) -> String {
+pub(crate) fn snippet_shape_hash(html: &str) -> String {
     // Pass 1: mask attribute values longer than ~24 chars.
     let mut masked = String::with_capacity(html.len());
     let mut chars = html.chars().peekable();
@@ -381,6 +381,9 @@ mod tests {
             score_calculation_method: "module_weighted".to_string(),
             score_breakdown: None,
             interactive_findings: Vec::new(),
+            waived_findings: Vec::new(),
+            suppressed_findings: Vec::new(),
+            device_results: Vec::new(),
             accessibility_journey: None,
             screen_reader: None,
             interpretation: None,
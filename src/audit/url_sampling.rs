@@ -0,0 +1,547 @@
+//! Template-aware URL sampling before a batch audit.
+//!
+//! `template_dedup` proves shared-template findings only after every page
+//! has been audited. On a 20k-URL shop most of those audits repeat the same
+//! product or category template. This module picks representatives up front:
+//!
+//! 1. URLs are clustered by path pattern. A path segment becomes a
+//!    placeholder (`{slug}`, or `{id}` when numeric) once enough sibling
+//!    URLs differ only there, so `/product/red-shoe` and `/product/blue-hat`
+//!    share `/product/{slug}`. The first segment stays literal unless it is
+//!    numeric — top-level pages like `/about` and `/contact` are usually
+//!    distinct templates.
+//! 2. Larger clusters are checked for a common HTML shape (tag and class
+//!    structure of a few probe pages). A cluster whose probes diverge is
+//!    audited in full rather than sampled.
+//! 3. After the audit, findings of the representatives are extrapolated to
+//!    the whole cluster and reported as estimates, never as measured pages.
+
+use std::collections::{HashMap, HashSet};
+
+use html5ever::{parse_document, tendril::TendrilSink};
+use markup5ever_rcdom::{Handle, NodeData, RcDom};
+use serde::{Deserialize, Serialize};
+use tracing::info;
+use url::Url;
+
+use crate::audit::crawl::fetch_static_html;
+use crate::audit::crawl_policy::RateLimiter;
+use crate::audit::normalized::NormalizedReport;
+use crate::taxonomy::Severity;
+
+/// Distinct sibling values at a path position before it becomes a
+/// placeholder.
+const VARIABLE_SEGMENT_MIN: usize = 5;
+
+/// Pages fetched per cluster to compare HTML shapes (at least the number of
+/// representatives).
+const SHAPE_PROBES: usize = 3;
+
+/// Minimum Jaccard similarity of a probe's shape to the first probe for the
+/// cluster to count as one template.
+const SHAPE_SIMILARITY_MIN: f64 = 0.8;
+
+/// URLs sharing one path pattern, in input order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UrlCluster {
+    /// Host plus path pattern, e.g. `shop.example/product/{slug}`.
+    pub pattern: String,
+    pub urls: Vec<String>,
+}
+
+/// The URLs selected for auditing and how each cluster was treated.
+#[derive(Debug, Clone)]
+pub struct TemplateSamplePlan {
+    /// Representatives per configured cluster.
+    pub per_cluster: usize,
+    /// Every input URL.
+    pub total_urls: usize,
+    /// URLs to audit: representatives of sampled clusters plus every member
+    /// of unsampled ones, in input order.
+    pub selected: Vec<String>,
+    pub clusters: Vec<PlannedCluster>,
+}
+
+/// One cluster of the plan.
+#[derive(Debug, Clone)]
+pub struct PlannedCluster {
+    pub pattern: String,
+    pub total_urls: usize,
+    /// URLs audited for this cluster.
+    pub representatives: Vec<String>,
+    /// True when only `representatives` are audited.
+    pub sampled: bool,
+    /// Whether probe pages could be fetched and compared.
+    pub shape_checked: bool,
+}
+
+/// Template sampling summary attached to a batch report.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TemplateSampling {
+    /// Representatives audited per sampled cluster.
+    pub per_cluster: usize,
+    /// URLs before sampling.
+    pub total_urls: usize,
+    /// URLs actually audited: `--max-pages` truncation and failed pages do
+    /// not count.
+    pub audited_urls: usize,
+    /// `audited_urls / total_urls`.
+    pub sampling_ratio: f64,
+    /// Clusters that were sampled; every other URL was audited directly.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub clusters: Vec<SampledCluster>,
+    /// Patterns of sampled clusters without a single audited representative.
+    /// Nothing is extrapolated for them: they were not measured.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub not_measured: Vec<String>,
+}
+
+/// A sampled cluster with its extrapolated findings.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SampledCluster {
+    pub pattern: String,
+    pub total_urls: usize,
+    /// Representatives that were audited successfully.
+    pub audited_urls: Vec<String>,
+    /// `audited_urls / total_urls`.
+    pub sampling_ratio: f64,
+    /// Whether the cluster's HTML shape was verified; `false` means the
+    /// path pattern alone grouped it (probe pages could not be fetched).
+    pub shape_checked: bool,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub extrapolated_findings: Vec<ExtrapolatedFinding>,
+}
+
+/// A finding seen on representatives, scaled to the cluster size.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExtrapolatedFinding {
+    pub rule_id: String,
+    pub title: String,
+    pub wcag_criterion: String,
+    pub severity: Severity,
+    /// Representatives on which the finding occurred.
+    pub affected_representatives: usize,
+    /// `total_urls × affected_representatives / audited representatives`,
+    /// rounded. An estimate, not a measurement.
+    pub estimated_affected_pages: usize,
+}
+
+/// Group `urls` by host and path pattern. Query strings and fragments are
+/// ignored. Unparseable URLs form their own single-URL clusters.
+pub fn cluster_by_path_pattern(urls: &[String]) -> Vec<UrlCluster> {
+    let parsed: Vec<Option<(String, Vec<String>)>> = urls
+        .iter()
+        .map(|raw| {
+            let url = Url::parse(raw).ok()?;
+            let segments = url
+                .path_segments()
+                .map(|s| s.filter(|seg| !seg.is_empty()).map(String::from).collect())
+                .unwrap_or_default();
+            Some((url.host_str().unwrap_or_default().to_string(), segments))
+        })
+        .collect();
+
+    let mut patterns: Vec<Vec<String>> = parsed
+        .iter()
+        .map(|p| p.as_ref().map(|(_, segs)| segs.clone()).unwrap_or_default())
+        .collect();
+    let max_depth = patterns.iter().map(Vec::len).max().unwrap_or(0);
+
+    for depth in 0..max_depth {
+        // Siblings: same host, same segment count, same pattern so far.
+        let mut groups: HashMap<(String, usize, String), Vec<usize>> = HashMap::new();
+        for (idx, entry) in parsed.iter().enumerate() {
+            let Some((host, segments)) = entry else {
+                continue;
+            };
+            if segments.len() > depth {
+                let prefix = patterns[idx][..depth].join("/");
+                groups
+                    .entry((host.clone(), segments.len(), prefix))
+                    .or_default()
+                    .push(idx);
+            }
+        }
+        for members in groups.values() {
+            let values: HashSet<&str> = members
+                .iter()
+                .map(|&idx| patterns[idx][depth].as_str())
+                .collect();
+            let all_numeric = values.iter().all(|v| is_numeric(v));
+            let variable = depth > 0 && values.len() >= VARIABLE_SEGMENT_MIN;
+            for &idx in members {
+                let segment = &mut patterns[idx][depth];
+                if is_numeric(segment) {
+                    *segment = "{id}".to_string();
+                } else if variable {
+                    *segment = if all_numeric { "{id}" } else { "{slug}" }.to_string();
+                }
+            }
+        }
+    }
+
+    let mut order: Vec<String> = Vec::new();
+    let mut clusters: HashMap<String, Vec<String>> = HashMap::new();
+    for (idx, raw) in urls.iter().enumerate() {
+        let pattern = match &parsed[idx] {
+            Some((host, _)) => format!("{host}/{}", patterns[idx].join("/")),
+            None => raw.clone(),
+        };
+        let members = clusters.entry(pattern.clone()).or_default();
+        if members.is_empty() {
+            order.push(pattern);
+        }
+        members.push(raw.clone());
+    }
+    order
+        .into_iter()
+        .map(|pattern| UrlCluster {
+            urls: clusters.remove(&pattern).unwrap_or_default(),
+            pattern,
+        })
+        .collect()
+}
+
+fn is_numeric(segment: &str) -> bool {
+    !segment.is_empty() && segment.chars().all(|c| c.is_ascii_digit())
+}
+
+/// Cluster `urls` and choose `per_cluster` representatives for every
+/// cluster that is larger than that and passes the HTML-shape check. Probe
+/// fetches go through `limiter`.
+pub async fn plan_template_sample(
+    urls: &[String],
+    per_cluster: usize,
+    limiter: &RateLimiter,
+) -> TemplateSamplePlan {
+    let per_cluster = per_cluster.max(1);
+    let mut clusters = Vec::new();
+    let mut selected_set: HashSet<String> = HashSet::new();
+
+    for cluster in cluster_by_path_pattern(urls) {
+        if cluster.urls.len() <= per_cluster {
+            selected_set.extend(cluster.urls.iter().cloned());
+            clusters.push(PlannedCluster {
+                pattern: cluster.pattern,
+                total_urls: cluster.urls.len(),
+                representatives: cluster.urls,
+                sampled: false,
+                shape_checked: false,
+            });
+            continue;
+        }
+
+        let probes = spread(&cluster.urls, per_cluster.max(SHAPE_PROBES));
+        let mut shapes = Vec::new();
+        for probe in &probes {
+            if let Some(html) = fetch_static_html(probe, limiter).await {
+                shapes.push(html_shape(&html));
+            }
+        }
+        let shape_checked = shapes.len() >= 2;
+        let homogeneous = !shape_checked
+            || shapes[1..]
+                .iter()
+                .all(|shape| shape_similarity(&shapes[0], shape) >= SHAPE_SIMILARITY_MIN);
+
+        let (representatives, sampled) = if homogeneous {
+            (probes.into_iter().take(per_cluster).collect(), true)
+        } else {
+            info!(
+                "Template sampling: {} has diverging page shapes, auditing all {} URLs",
+                cluster.pattern,
+                cluster.urls.len()
+            );
+            (cluster.urls.clone(), false)
+        };
+        selected_set.extend(representatives.iter().cloned());
+        clusters.push(PlannedCluster {
+            pattern: cluster.pattern,
+            total_urls: cluster.urls.len(),
+            representatives,
+            sampled,
+            shape_checked,
+        });
+    }
+
+    let selected = urls
+        .iter()
+        .filter(|url| selected_set.remove(*url))
+        .cloned()
+        .collect();
+    TemplateSamplePlan {
+        per_cluster,
+        total_urls: urls.len(),
+        selected,
+        clusters,
+    }
+}
+
+/// `count` members spread evenly across `urls`, starting with the first.
+fn spread(urls: &[String], count: usize) -> Vec<String> {
+    let count = count.min(urls.len());
+    (0..count)
+        .map(|i| urls[i * urls.len() / count].clone())
+        .collect()
+}
+
+/// Structural fingerprint of a page: every element as `tag` and `tag.class`
+/// token, without text or attribute values.
+fn html_shape(html: &str) -> HashSet<String> {
+    let dom: RcDom = parse_document(RcDom::default(), Default::default()).one(html);
+    let mut shape = HashSet::new();
+    collect_shape(&dom.document, &mut shape);
+    shape
+}
+
+fn collect_shape(handle: &Handle, shape: &mut HashSet<String>) {
+    if let NodeData::Element { name, attrs, .. } = &handle.data {
+        let tag = name.local.as_ref();
+        if matches!(tag, "script" | "style" | "noscript" | "template") {
+            return;
+        }
+        shape.insert(tag.to_string());
+        if let Some(class) = attrs
+            .borrow()
+            .iter()
+            .find(|a| a.name.local.as_ref() == "class")
+        {
+            for token in class.value.split_whitespace() {
+                shape.insert(format!("{tag}.{token}"));
+            }
+        }
+    }
+    for child in handle.children.borrow().iter() {
+        collect_shape(child, shape);
+    }
+}
+
+fn shape_similarity(a: &HashSet<String>, b: &HashSet<String>) -> f64 {
+    let union = a.union(b).count();
+    if union == 0 {
+        return 1.0;
+    }
+    a.intersection(b).count() as f64 / union as f64
+}
+
+/// Scale the representatives' findings to their clusters. `reports` are the
+/// pages that were actually audited.
+pub fn extrapolate_template_findings(
+    plan: &TemplateSamplePlan,
+    reports: &[NormalizedReport],
+) -> TemplateSampling {
+    let by_url: HashMap<&str, &NormalizedReport> =
+        reports.iter().map(|r| (r.url.as_str(), r)).collect();
+
+    let mut not_measured = Vec::new();
+    let clusters = plan
+        .clusters
+        .iter()
+        .filter(|cluster| cluster.sampled)
+        .filter_map(|cluster| {
+            let audited: Vec<&NormalizedReport> = cluster
+                .representatives
+                .iter()
+                .filter_map(|url| by_url.get(url.as_str()).copied())
+                .collect();
+            if audited.is_empty() {
+                not_measured.push(cluster.pattern.clone());
+                return None;
+            }
+
+            let mut findings: Vec<ExtrapolatedFinding> = Vec::new();
+            for report in &audited {
+                let mut seen = HashSet::new();
+                for finding in &report.findings {
+                    if !seen.insert(finding.rule_id.as_str()) {
+                        continue;
+                    }
+                    match findings.iter_mut().find(|f| f.rule_id == finding.rule_id) {
+                        Some(existing) => existing.affected_representatives += 1,
+                        None => findings.push(ExtrapolatedFinding {
+                            rule_id: finding.rule_id.clone(),
+                            title: finding.title.clone(),
+                            wcag_criterion: finding.wcag_criterion.clone(),
+                            severity: finding.severity,
+                            affected_representatives: 1,
+                            estimated_affected_pages: 0,
+                        }),
+                    }
+                }
+            }
+            for finding in &mut findings {
+                finding.estimated_affected_pages =
+                    ((cluster.total_urls * finding.affected_representatives) as f64
+                        / audited.len() as f64)
+                        .round() as usize;
+            }
+            findings.sort_by(|a, b| {
+                b.estimated_affected_pages
+                    .cmp(&a.estimated_affected_pages)
+                    .then_with(|| a.rule_id.cmp(&b.rule_id))
+            });
+
+            Some(SampledCluster {
+                pattern: cluster.pattern.clone(),
+                total_urls: cluster.total_urls,
+                sampling_ratio: ratio(audited.len(), cluster.total_urls),
+                audited_urls: audited.iter().map(|r| r.url.clone()).collect(),
+                shape_checked: cluster.shape_checked,
+                extrapolated_findings: findings,
+            })
+        })
+        .collect();
+
+    TemplateSampling {
+        per_cluster: plan.per_cluster,
+        total_urls: plan.total_urls,
+        audited_urls: reports.len(),
+        sampling_ratio: ratio(reports.len(), plan.total_urls),
+        clusters,
+        not_measured,
+    }
+}
+
+/// `part / total`, rounded to three decimals.
+fn ratio(part: usize, total: usize) -> f64 {
+    if total == 0 {
+        return 0.0;
+    }
+    (part as f64 / total as f64 * 1000.0).round() / 1000.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn urls(paths: &[&str]) -> Vec<String> {
+        paths
+            .iter()
+            .map(|p| format!("https://shop.example{p}"))
+            .collect()
+    }
+
+    #[test]
+    fn clusters_variable_segments_but_keeps_top_level_pages_apart() {
+        let input = urls(&[
+            "/",
+            "/about",
+            "/contact",
+            "/imprint",
+            "/privacy",
+            "/shipping",
+            "/product/red-shoe",
+            "/product/blue-hat",
+            "/product/green-scarf?ref=home",
+            "/product/black-belt",
+            "/product/white-sock",
+            "/product/info",
+            "/blog/2024/10",
+        ]);
+        let clusters = cluster_by_path_pattern(&input);
+        let patterns: Vec<&str> = clusters.iter().map(|c| c.pattern.as_str()).collect();
+
+        assert!(patterns.contains(&"shop.example/about"));
+        assert!(patterns.contains(&"shop.example/blog/{id}/{id}"));
+        let products = clusters
+            .iter()
+            .find(|c| c.pattern == "shop.example/product/{slug}")
+            .unwrap();
+        assert_eq!(products.urls.len(), 6);
+        assert_eq!(clusters.len(), 8);
+    }
+
+    #[test]
+    fn html_shape_ignores_content_but_not_layout() {
+        let product = |name: &str| {
+            format!(
+                r#"<body><header class="site"></header><main class="pdp"><h1>{name}</h1><div class="price"></div></main></body>"#
+            )
+        };
+        let a = html_shape(&product("Red shoe"));
+        let b = html_shape(&product("Blue hat"));
+        let listing = html_shape(
+            r#"<body><header class="site"></header><main class="plp"><ul class="grid"><li class="tile"></li></ul></main></body>"#,
+        );
+
+        assert_eq!(shape_similarity(&a, &b), 1.0);
+        assert!(shape_similarity(&a, &listing) < SHAPE_SIMILARITY_MIN);
+    }
+
+    #[test]
+    fn spread_picks_evenly_spaced_members() {
+        let input: Vec<String> = (0..10).map(|i| i.to_string()).collect();
+        assert_eq!(spread(&input, 3), vec!["0", "3", "6"]);
+        assert_eq!(spread(&input[..2], 3), vec!["0", "1"]);
+    }
+
+    #[test]
+    fn extrapolation_counts_only_audited_pages_and_skips_unmeasured_clusters() {
+        use crate::audit::{normalize, AuditReport};
+        use crate::cli::WcagLevel;
+        use crate::wcag::{Severity, Violation, WcagResults};
+
+        let cluster =
+            |pattern: &str, total_urls: usize, representatives: &[&str], sampled| PlannedCluster {
+                pattern: pattern.to_string(),
+                total_urls,
+                representatives: urls(representatives),
+                sampled,
+                shape_checked: true,
+            };
+        let plan = TemplateSamplePlan {
+            per_cluster: 2,
+            total_urls: 15,
+            selected: urls(&["/about", "/p/1", "/p/2", "/blog/1", "/blog/2"]),
+            clusters: vec![
+                cluster("shop.example/about", 1, &["/about"], false),
+                cluster("shop.example/p/{id}", 10, &["/p/1", "/p/2"], true),
+                cluster("shop.example/blog/{id}", 4, &["/blog/1", "/blog/2"], true),
+            ],
+        };
+        let page = |path: &str, violations: usize| {
+            let mut results = WcagResults::new();
+            for i in 0..violations {
+                results.add_violation(
+                    Violation::new(
+                        "1.1.1",
+                        "Non-text Content",
+                        WcagLevel::A,
+                        Severity::High,
+                        "Image without alt",
+                        format!("node-{i}"),
+                    )
+                    .with_rule_id("image-alt"),
+                );
+            }
+            let report = AuditReport::new(
+                format!("https://shop.example{path}"),
+                WcagLevel::AA,
+                results,
+                100,
+            );
+            normalize(&report).normalized
+        };
+        // /p/2 failed and both blog representatives were cut by --max-pages.
+        let reports = vec![page("/about", 0), page("/p/1", 2)];
+
+        let sampling = extrapolate_template_findings(&plan, &reports);
+
+        assert_eq!(sampling.audited_urls, 2);
+        assert_eq!(sampling.sampling_ratio, 0.133);
+        assert_eq!(sampling.not_measured, vec!["shop.example/blog/{id}"]);
+        assert_eq!(sampling.clusters.len(), 1);
+        let products = &sampling.clusters[0];
+        assert_eq!(products.audited_urls, urls(&["/p/1"]));
+        assert_eq!(products.sampling_ratio, 0.1);
+        assert_eq!(products.extrapolated_findings.len(), 1);
+        assert_eq!(
+            products.extrapolated_findings[0].affected_representatives,
+            1
+        );
+        assert_eq!(
+            products.extrapolated_findings[0].estimated_affected_pages,
+            10
+        );
+    }
+}
@@ -0,0 +1,759 @@
+//! Authenticated audits.
+//!
+//! Three independent mechanisms, combinable:
+//! 1. Cookie jar: a JSON (CDP / Playwright `storageState`) or Netscape
+//!    `cookies.txt` file loaded into the browser cookie store before the first
+//!    navigation.
+//! 2. Extra HTTP headers (e.g. `Authorization: Bearer …`), added to requests
+//!    for the audited origins only (see [`AuthConfig::header_origins`]).
+//! 3. A declarative login step: navigate, fill fields, submit, wait for a
+//!    selector that only exists when logged in.
+//!
+//! Cookies live in the browser's default context, so a session established
+//! once in `BrowserManager::with_options` is shared by every `BrowserPool`
+//! tab. Headers are injected per tab through `Fetch` request interception,
+//! set up in `BrowserManager::new_page`, so third-party requests the page
+//! makes never see them.
+//!
+//! Secret values never reach the report: `AuthProvenance` records only which
+//! mechanisms were used, header *names* and cookie counts.
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use chromiumoxide::cdp::browser_protocol::fetch::{
+    ContinueRequestParams, EnableParams, EventRequestPaused, HeaderEntry, RequestPattern,
+    RequestStage,
+};
+use chromiumoxide::cdp::browser_protocol::network::{
+    CookieParam, CookieSameSite, SetCookiesParams, TimeSinceEpoch,
+};
+use chromiumoxide::Page;
+use futures::StreamExt;
+use serde::{Deserialize, Serialize};
+use tracing::{debug, info, warn};
+
+use crate::error::{AuditError, Result};
+
+/// Prefix that makes a configured value read from an environment variable,
+/// so secrets can stay out of `auditmysite.toml` and shell history.
+const ENV_PREFIX: &str = "env:";
+
+/// Default budget for the login step's `wait_for` selector.
+const DEFAULT_LOGIN_TIMEOUT_SECS: u64 = 30;
+
+/// Authentication settings for a browser session.
+///
+/// `Debug` is implemented by hand so header and field values never end up in
+/// `--verbose` logs (`BrowserManager` debug-prints its options).
+#[derive(Clone, Default, Deserialize)]
+pub struct AuthConfig {
+    /// Cookie jar file (JSON array, `{ "cookies": [...] }` or Netscape format).
+    #[serde(default)]
+    pub cookie_jar: Option<PathBuf>,
+    /// Static extra HTTP headers, sent only to `header_origins`.
+    #[serde(default)]
+    pub headers: BTreeMap<String, String>,
+    /// Origins (`https://app.example.com`) whose requests get the extra
+    /// headers. The CLI adds the audited URLs' origins; the login URL's
+    /// origin is always included. List further origins, e.g. an API host.
+    #[serde(default)]
+    pub header_origins: Vec<String>,
+    /// Declarative login step run once after browser launch.
+    #[serde(default)]
+    pub login: Option<LoginStep>,
+}
+
+/// Navigate → fill → submit → wait. Runs once per browser session.
+#[derive(Debug, Clone, Deserialize)]
+pub struct LoginStep {
+    /// Login form URL.
+    pub url: String,
+    /// Fields to fill, in order.
+    #[serde(default)]
+    pub fields: Vec<LoginField>,
+    /// CSS selector of the submit control. When omitted, the form owning the
+    /// last filled field is submitted.
+    #[serde(default)]
+    pub submit: Option<String>,
+    /// CSS selector that only exists once the login succeeded.
+    #[serde(default)]
+    pub wait_for: Option<String>,
+    /// Budget for `wait_for` in seconds (default 30).
+    #[serde(default)]
+    pub timeout_secs: Option<u64>,
+}
+
+/// One form field of a `LoginStep`.
+#[derive(Clone, Deserialize)]
+pub struct LoginField {
+    /// CSS selector of the input.
+    pub selector: String,
+    /// Value to type. `env:NAME` reads the value from `$NAME`.
+    pub value: String,
+}
+
+impl std::fmt::Debug for LoginField {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("LoginField")
+            .field("selector", &self.selector)
+            .field("value", &"[redacted]")
+            .finish()
+    }
+}
+
+impl std::fmt::Debug for AuthConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AuthConfig")
+            .field("cookie_jar", &self.cookie_jar)
+            .field("headers", &self.headers.keys().collect::<Vec<_>>())
+            .field("header_origins", &self.header_origins)
+            .field("login", &self.login)
+            .finish()
+    }
+}
+
+/// Report-safe summary of the authentication applied to a run. Part of the
+/// `execution_environment` block; carries no secret values.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AuthProvenance {
+    /// Number of cookies loaded from the cookie jar (0 = no jar).
+    #[serde(default)]
+    pub cookie_jar_cookies: usize,
+    /// Names of the extra HTTP headers; values are never recorded.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub header_names: Vec<String>,
+    /// Login URL without query string or fragment, if a login step ran.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub login_url: Option<String>,
+}
+
+impl AuthProvenance {
+    /// Short human-readable description, e.g. for the PDF methodology table.
+    pub fn describe(&self, en: bool) -> String {
+        let mut parts = Vec::new();
+        if self.cookie_jar_cookies > 0 {
+            parts.push(if en {
+                format!("cookie jar ({} cookies)", self.cookie_jar_cookies)
+            } else {
+                format!("Cookie-Jar ({} Cookies)", self.cookie_jar_cookies)
+            });
+        }
+        if !self.header_names.is_empty() {
+            parts.push(if en {
+                format!("headers: {}", self.header_names.join(", "))
+            } else {
+                format!("Header: {}", self.header_names.join(", "))
+            });
+        }
+        if let Some(url) = &self.login_url {
+            parts.push(if en {
+                format!("login via {url}")
+            } else {
+                format!("Login über {url}")
+            });
+        }
+        parts.join("; ")
+    }
+}
+
+impl AuthConfig {
+    pub fn is_empty(&self) -> bool {
+        self.cookie_jar.is_none() && self.headers.is_empty() && self.login.is_none()
+    }
+
+    /// Load the cookie jar and run the login step on `page`. Call once per
+    /// browser session, before any audit tab navigates; the resulting cookies
+    /// are shared browser-wide. `page` must come from
+    /// `BrowserManager::new_page`, which already applied the extra headers.
+    pub async fn establish(
+        &self,
+        page: &Page,
+        navigation_timeout_secs: u64,
+    ) -> Result<AuthProvenance> {
+        let mut provenance = AuthProvenance {
+            header_names: self.headers.keys().cloned().collect(),
+            ..Default::default()
+        };
+
+        if let Some(path) = &self.cookie_jar {
+            let cookies = load_cookie_jar(path)?;
+            provenance.cookie_jar_cookies = cookies.len();
+            if !cookies.is_empty() {
+                page.execute(SetCookiesParams::new(cookies))
+                    .await
+                    .map_err(|e| AuditError::AuthenticationFailed {
+                        reason: format!("could not load cookie jar: {e}"),
+                    })?;
+            }
+            info!(
+                "Loaded {} cookies from {}",
+                provenance.cookie_jar_cookies,
+                path.display()
+            );
+        }
+
+        if let Some(login) = &self.login {
+            run_login(page, login, navigation_timeout_secs).await?;
+            provenance.login_url = Some(redacted_url(&login.url));
+            info!("Login step completed at {}", redacted_url(&login.url));
+        }
+
+        Ok(provenance)
+    }
+
+    /// Allow the extra headers for the origin of `url`. Unparseable URLs and
+    /// opaque origins are ignored.
+    pub fn allow_header_origin(&mut self, url: &str) {
+        if let Some(origin) = origin_of(url) {
+            if !self.header_origins.contains(&origin) {
+                self.header_origins.push(origin);
+            }
+        }
+    }
+
+    /// Origins that receive the extra headers: the configured ones plus the
+    /// login URL's origin.
+    fn scoped_origins(&self) -> Vec<String> {
+        let mut origins: Vec<String> = self
+            .header_origins
+            .iter()
+            .filter_map(|o| origin_of(o))
+            .collect();
+        if let Some(origin) = self.login.as_ref().and_then(|l| origin_of(&l.url)) {
+            if !origins.contains(&origin) {
+                origins.push(origin);
+            }
+        }
+        origins
+    }
+
+    /// Inject the extra HTTP headers into one tab's requests to the scoped
+    /// origins. `Fetch` interception is per-target, so every new pool page
+    /// needs it; requests to any other origin pass through untouched.
+    pub async fn apply_headers(&self, page: &Page) -> Result<()> {
+        if self.headers.is_empty() {
+            return Ok(());
+        }
+        let origins = self.scoped_origins();
+        if origins.is_empty() {
+            warn!("Extra HTTP headers are configured but no origin to send them to; skipping them");
+            return Ok(());
+        }
+        let mut headers = Vec::with_capacity(self.headers.len());
+        for (name, value) in &self.headers {
+            headers.push((name.clone(), resolve_secret(value)?));
+        }
+
+        let fail = |e: chromiumoxide::error::CdpError| AuditError::AuthenticationFailed {
+            reason: format!("could not set up extra HTTP headers: {e}"),
+        };
+        let mut paused = page
+            .event_listener::<EventRequestPaused>()
+            .await
+            .map_err(fail)?;
+        let patterns: Vec<RequestPattern> = origins
+            .iter()
+            .map(|origin| {
+                RequestPattern::builder()
+                    .url_pattern(format!("{origin}/*"))
+                    .request_stage(RequestStage::Request)
+                    .build()
+            })
+            .collect();
+        page.execute(EnableParams::builder().patterns(patterns).build())
+            .await
+            .map_err(fail)?;
+
+        let page = page.clone();
+        tokio::spawn(async move {
+            while let Some(event) = paused.next().await {
+                let mut params = ContinueRequestParams::new(event.request_id.clone());
+                params.headers = scoped_headers(
+                    &event.request.url,
+                    event.request.headers.inner(),
+                    &origins,
+                    &headers,
+                );
+                if let Err(e) = page.execute(params).await {
+                    debug!("Could not continue intercepted request: {}", e);
+                }
+            }
+        });
+        Ok(())
+    }
+}
+
+/// `scheme://host[:port]` of a URL, or `None` for unparseable URLs and
+/// opaque origins.
+fn origin_of(url: &str) -> Option<String> {
+    let origin = url::Url::parse(url).ok()?.origin();
+    origin.is_tuple().then(|| origin.ascii_serialization())
+}
+
+/// Request headers with the extra headers merged in (replacing same-named
+/// ones), or `None` when `request_url` is outside `origins`. The URL
+/// pattern already limits interception; this re-checks the exact origin.
+fn scoped_headers(
+    request_url: &str,
+    request_headers: &serde_json::Value,
+    origins: &[String],
+    extra: &[(String, String)],
+) -> Option<Vec<HeaderEntry>> {
+    let origin = origin_of(request_url)?;
+    if !origins.contains(&origin) {
+        return None;
+    }
+    let mut entries: Vec<HeaderEntry> = request_headers
+        .as_object()
+        .into_iter()
+        .flatten()
+        .filter(|(name, _)| !extra.iter().any(|(n, _)| n.eq_ignore_ascii_case(name)))
+        .filter_map(|(name, value)| Some(HeaderEntry::new(name.clone(), value.as_str()?)))
+        .collect();
+    entries.extend(
+        extra
+            .iter()
+            .map(|(name, value)| HeaderEntry::new(name.clone(), value.clone())),
+    );
+    Some(entries)
+}
+
+/// Parse a `Name: value` header argument.
+pub fn parse_header(raw: &str) -> std::result::Result<(String, String), String> {
+    let (name, value) = raw
+        .split_once(':')
+        .ok_or_else(|| format!("Invalid header '{raw}': expected 'Name: value'"))?;
+    let name = name.trim();
+    if name.is_empty() || name.chars().any(|c| c.is_whitespace()) {
+        return Err(format!("Invalid header name in '{raw}'"));
+    }
+    Ok((name.to_string(), value.trim().to_string()))
+}
+
+/// Resolve `env:NAME` indirection; any other value is returned verbatim.
+fn resolve_secret(value: &str) -> Result<String> {
+    match value.strip_prefix(ENV_PREFIX) {
+        Some(var) => std::env::var(var).map_err(|_| AuditError::AuthenticationFailed {
+            reason: format!("environment variable {var} is not set"),
+        }),
+        None => Ok(value.to_string()),
+    }
+}
+
+/// Strip query and fragment so one-time tokens in a login URL stay out of
+/// the report.
+fn redacted_url(raw: &str) -> String {
+    match url::Url::parse(raw) {
+        Ok(mut url) => {
+            url.set_query(None);
+            url.set_fragment(None);
+            let _ = url.set_password(None);
+            let _ = url.set_username("");
+            url.to_string()
+        }
+        Err(_) => raw.split(['?', '#']).next().unwrap_or(raw).to_string(),
+    }
+}
+
+// ── Cookie jar ────────────────────────────────────────────────────────────────
+
+/// JSON cookie as written by CDP `Network.getCookies`, Playwright
+/// `storageState()` and most browser export extensions.
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct JsonCookie {
+    name: String,
+    value: String,
+    #[serde(default)]
+    domain: Option<String>,
+    #[serde(default)]
+    path: Option<String>,
+    #[serde(default)]
+    secure: Option<bool>,
+    #[serde(default)]
+    http_only: Option<bool>,
+    #[serde(default)]
+    same_site: Option<String>,
+    #[serde(default, alias = "expirationDate")]
+    expires: Option<f64>,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum JsonJar {
+    Cookies(Vec<JsonCookie>),
+    StorageState { cookies: Vec<JsonCookie> },
+}
+
+/// Load a cookie jar file. JSON is detected by a leading `[` or `{`;
+/// everything else is parsed as Netscape `cookies.txt`.
+pub fn load_cookie_jar(path: &Path) -> Result<Vec<CookieParam>> {
+    let content = std::fs::read_to_string(path).map_err(|e| AuditError::FileError {
+        path: path.to_path_buf(),
+        reason: e.to_string(),
+    })?;
+    parse_cookie_jar(&content).map_err(|reason| AuditError::FileError {
+        path: path.to_path_buf(),
+        reason,
+    })
+}
+
+fn parse_cookie_jar(content: &str) -> std::result::Result<Vec<CookieParam>, String> {
+    let trimmed = content.trim_start();
+    if trimmed.starts_with('[') || trimmed.starts_with('{') {
+        let jar: JsonJar =
+            serde_json::from_str(trimmed).map_err(|e| format!("invalid JSON cookie jar: {e}"))?;
+        let cookies = match jar {
+            JsonJar::Cookies(cookies) | JsonJar::StorageState { cookies } => cookies,
+        };
+        return Ok(cookies.into_iter().map(json_cookie_param).collect());
+    }
+    parse_netscape_jar(content)
+}
+
+fn json_cookie_param(cookie: JsonCookie) -> CookieParam {
+    let mut param = CookieParam::new(cookie.name, cookie.value);
+    param.domain = cookie.domain;
+    param.path = cookie.path.or_else(|| Some("/".to_string()));
+    param.secure = cookie.secure;
+    param.http_only = cookie.http_only;
+    param.same_site = cookie.same_site.as_deref().and_then(parse_same_site);
+    // Session cookies are exported as -1 (CDP) or omitted.
+    param.expires = cookie
+        .expires
+        .filter(|expires| *expires > 0.0)
+        .map(TimeSinceEpoch::new);
+    param
+}
+
+fn parse_same_site(value: &str) -> Option<CookieSameSite> {
+    match value.to_ascii_lowercase().as_str() {
+        "strict" => Some(CookieSameSite::Strict),
+        "lax" => Some(CookieSameSite::Lax),
+        "none" | "no_restriction" => Some(CookieSameSite::None),
+        _ => None,
+    }
+}
+
+/// Netscape format: `domain  include_subdomains  path  secure  expires  name  value`,
+/// tab-separated; `#HttpOnly_` prefixes mark http-only cookies.
+fn parse_netscape_jar(content: &str) -> std::result::Result<Vec<CookieParam>, String> {
+    let mut cookies = Vec::new();
+    for (index, line) in content.lines().enumerate() {
+        let (line, http_only) = match line.strip_prefix("#HttpOnly_") {
+            Some(rest) => (rest, true),
+            None => (line, false),
+        };
+        if line.trim().is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let columns: Vec<&str> = line.split('\t').collect();
+        if columns.len() < 7 {
+            return Err(format!(
+                "line {}: expected 7 tab-separated columns, found {}",
+                index + 1,
+                columns.len()
+            ));
+        }
+        let mut param = CookieParam::new(columns[5].to_string(), columns[6].to_string());
+        param.domain = Some(columns[0].to_string());
+        param.path = Some(columns[2].to_string());
+        param.secure = Some(columns[3].eq_ignore_ascii_case("TRUE"));
+        param.http_only = Some(http_only);
+        param.expires = columns[4]
+            .parse::<f64>()
+            .ok()
+            .filter(|expires| *expires > 0.0)
+            .map(TimeSinceEpoch::new);
+        cookies.push(param);
+    }
+    Ok(cookies)
+}
+
+// ── Login step ────────────────────────────────────────────────────────────────
+
+async fn run_login(page: &Page, login: &LoginStep, navigation_timeout_secs: u64) -> Result<()> {
+    let goto = tokio::time::timeout(
+        Duration::from_secs(navigation_timeout_secs),
+        page.goto(login.url.as_str()),
+    )
+    .await;
+    match goto {
+        Ok(Ok(_)) => {}
+        Ok(Err(e)) => {
+            return Err(AuditError::AuthenticationFailed {
+                reason: format!("could not open login page: {e}"),
+            })
+        }
+        Err(_) => {
+            return Err(AuditError::AuthenticationFailed {
+                reason: format!("login page did not load within {navigation_timeout_secs}s"),
+            })
+        }
+    }
+
+    for field in &login.fields {
+        let value = resolve_secret(&field.value)?;
+        let filled = evaluate_bool(page, &fill_field_script(&field.selector, &value)).await;
+        if !filled {
+            return Err(AuditError::AuthenticationFailed {
+                reason: format!("login field '{}' not found", field.selector),
+            });
+        }
+    }
+
+    let last_field = login.fields.last().map(|field| field.selector.as_str());
+    let submitted = evaluate_bool(page, &submit_script(login.submit.as_deref(), last_field)).await;
+    if !submitted {
+        return Err(AuditError::AuthenticationFailed {
+            reason: match &login.submit {
+                Some(selector) => format!("submit control '{selector}' not found"),
+                None => "no form to submit (set `submit` in the login step)".to_string(),
+            },
+        });
+    }
+
+    let Some(selector) = &login.wait_for else {
+        // Without a success marker, give the post-submit navigation a moment
+        // to set its session cookies before the first audit tab navigates.
+        tokio::time::sleep(Duration::from_secs(2)).await;
+        return Ok(());
+    };
+    let budget = Duration::from_secs(login.timeout_secs.unwrap_or(DEFAULT_LOGIN_TIMEOUT_SECS));
+    let probe = format!(
+        "document.querySelector({}) !== null",
+        serde_json::to_string(selector).unwrap_or_default()
+    );
+    let appeared = tokio::time::timeout(budget, async {
+        loop {
+            if evaluate_bool(page, &probe).await {
+                return;
+            }
+            tokio::time::sleep(Duration::from_millis(250)).await;
+        }
+    })
+    .await
+    .is_ok();
+    if !appeared {
+        return Err(AuditError::AuthenticationFailed {
+            reason: format!(
+                "'{selector}' did not appear within {}s after submitting the login form",
+                budget.as_secs()
+            ),
+        });
+    }
+    debug!("Login success marker '{}' found", selector);
+    Ok(())
+}
+
+async fn evaluate_bool(page: &Page, script: &str) -> bool {
+    page.evaluate(script)
+        .await
+        .ok()
+        .and_then(|result| result.value().and_then(|v| v.as_bool()))
+        .unwrap_or(false)
+}
+
+/// Set the value through the native setter so framework-controlled inputs
+/// (React, Vue) observe the change, then fire `input` + `change`.
+fn fill_field_script(selector: &str, value: &str) -> String {
+    format!(
+        r#"(() => {{
+            const el = document.querySelector({selector});
+            if (!el) return false;
+            el.focus();
+            const proto = Object.getPrototypeOf(el);
+            const setter = Object.getOwnPropertyDescriptor(proto, 'value');
+            if (setter && setter.set) {{ setter.set.call(el, {value}); }} else {{ el.value = {value}; }}
+            el.dispatchEvent(new Event('input', {{ bubbles: true }}));
+            el.dispatchEvent(new Event('change', {{ bubbles: true }}));
+            return true;
+        }})()"#,
+        selector = serde_json::to_string(selector).unwrap_or_default(),
+        value = serde_json::to_string(value).unwrap_or_default(),
+    )
+}
+
+fn submit_script(submit: Option<&str>, last_field: Option<&str>) -> String {
+    let json = |s: Option<&str>| {
+        s.map(|s| serde_json::to_string(s).unwrap_or_default())
+            .unwrap_or_else(|| "null".to_string())
+    };
+    format!(
+        r#"(() => {{
+            const submit = {submit};
+            if (submit) {{
+                const el = document.querySelector(submit);
+                if (!el) return false;
+                el.click();
+                return true;
+            }}
+            const last = {last};
+            const field = last ? document.querySelector(last) : null;
+            const form = field ? field.form : document.querySelector('form');
+            if (!form) return false;
+            if (form.requestSubmit) {{ form.requestSubmit(); }} else {{ form.submit(); }}
+            return true;
+        }})()"#,
+        submit = json(submit),
+        last = json(last_field),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_header_splits_on_first_colon() {
+        let (name, value) = parse_header("Authorization: Bearer a:b").unwrap();
+        assert_eq!(name, "Authorization");
+        assert_eq!(value, "Bearer a:b");
+        assert!(parse_header("no-colon").is_err());
+        assert!(parse_header("Bad Name: x").is_err());
+    }
+
+    #[test]
+    fn json_jar_accepts_array_and_storage_state() {
+        let array = r#"[{"name":"sid","value":"abc","domain":".example.com","path":"/","secure":true,"httpOnly":true,"sameSite":"Lax","expires":-1}]"#;
+        let cookies = parse_cookie_jar(array).unwrap();
+        assert_eq!(cookies.len(), 1);
+        assert_eq!(cookies[0].name, "sid");
+        assert_eq!(cookies[0].http_only, Some(true));
+        assert!(
+            cookies[0].expires.is_none(),
+            "session cookie keeps no expiry"
+        );
+        assert!(matches!(cookies[0].same_site, Some(CookieSameSite::Lax)));
+
+        let state = r#"{"cookies":[{"name":"a","value":"1","domain":"example.com","expires":1999999999}],"origins":[]}"#;
+        let cookies = parse_cookie_jar(state).unwrap();
+        assert_eq!(cookies.len(), 1);
+        assert_eq!(cookies[0].path.as_deref(), Some("/"));
+        assert!(cookies[0].expires.is_some());
+    }
+
+    #[test]
+    fn netscape_jar_parses_http_only_prefix_and_comments() {
+        let jar = "# Netscape HTTP Cookie File\n\
+                   .example.com\tTRUE\t/\tTRUE\t0\tsid\tsecret\n\
+                   #HttpOnly_example.com\tFALSE\t/app\tFALSE\t1999999999\ttoken\txyz\n";
+        let cookies = parse_cookie_jar(jar).unwrap();
+        assert_eq!(cookies.len(), 2);
+        assert_eq!(cookies[0].secure, Some(true));
+        assert_eq!(cookies[0].http_only, Some(false));
+        assert!(cookies[0].expires.is_none());
+        assert_eq!(cookies[1].name, "token");
+        assert_eq!(cookies[1].http_only, Some(true));
+        assert_eq!(cookies[1].path.as_deref(), Some("/app"));
+        assert!(parse_cookie_jar("example.com\tTRUE\n").is_err());
+    }
+
+    #[test]
+    fn debug_output_never_contains_secret_values() {
+        let mut headers = BTreeMap::new();
+        headers.insert("Authorization".to_string(), "Bearer s3cret".to_string());
+        let config = AuthConfig {
+            cookie_jar: None,
+            headers,
+            header_origins: vec![],
+            login: Some(LoginStep {
+                url: "https://example.com/login".to_string(),
+                fields: vec![LoginField {
+                    selector: "#password".to_string(),
+                    value: "hunter2".to_string(),
+                }],
+                submit: None,
+                wait_for: None,
+                timeout_secs: None,
+            }),
+        };
+        let debug = format!("{config:?}");
+        assert!(debug.contains("Authorization"));
+        assert!(!debug.contains("s3cret"));
+        assert!(!debug.contains("hunter2"));
+    }
+
+    #[test]
+    fn extra_headers_only_reach_scoped_origins() {
+        let origins = vec!["https://app.example.com".to_string()];
+        let extra = vec![("Authorization".to_string(), "Bearer t".to_string())];
+        let request = serde_json::json!({"Accept": "*/*", "authorization": "old"});
+
+        let merged = scoped_headers(
+            "https://app.example.com/dashboard",
+            &request,
+            &origins,
+            &extra,
+        )
+        .unwrap();
+        assert_eq!(merged.len(), 2);
+        assert!(merged
+            .iter()
+            .any(|h| h.name == "Authorization" && h.value == "Bearer t"));
+
+        for foreign in [
+            "https://cdn.example.net/app.js",
+            "https://app.example.com.evil.test/",
+            "http://app.example.com/",
+            "https://app.example.com:8443/",
+        ] {
+            assert!(scoped_headers(foreign, &request, &origins, &extra).is_none());
+        }
+    }
+
+    #[test]
+    fn login_origin_is_always_scoped() {
+        let mut config = AuthConfig {
+            login: Some(LoginStep {
+                url: "https://sso.example.com/login?next=/".to_string(),
+                fields: vec![],
+                submit: None,
+                wait_for: None,
+                timeout_secs: None,
+            }),
+            ..Default::default()
+        };
+        config.allow_header_origin("https://app.example.com/some/page");
+        config.allow_header_origin("https://app.example.com/other");
+        config.allow_header_origin("not a url");
+        assert_eq!(
+            config.scoped_origins(),
+            vec!["https://app.example.com", "https://sso.example.com"]
+        );
+    }
+
+    #[test]
+    fn redacted_url_drops_query_and_credentials() {
+        assert_eq!(
+            redacted_url("https://user:pw@example.com/login?token=abc#x"),
+            "https://example.com/login"
+        );
+    }
+
+    #[test]
+    fn env_prefix_resolves_from_environment() {
+        std::env::set_var("AUDITMYSITE_TEST_AUTH_SECRET", "from-env");
+        assert_eq!(
+            resolve_secret("env:AUDITMYSITE_TEST_AUTH_SECRET").unwrap(),
+            "from-env"
+        );
+        assert_eq!(resolve_secret("literal").unwrap(), "literal");
+        assert!(resolve_secret("env:AUDITMYSITE_TEST_AUTH_UNSET_VAR").is_err());
+    }
+
+    #[test]
+    fn provenance_describes_without_values() {
+        let provenance = AuthProvenance {
+            cookie_jar_cookies: 3,
+            header_names: vec!["Authorization".to_string()],
+            login_url: Some("https://example.com/login".to_string()),
+        };
+        let text = provenance.describe(true);
+        assert!(text.contains("3 cookies"));
+        assert!(text.contains("Authorization"));
+        assert!(text.contains("login via https://example.com/login"));
+    }
+}
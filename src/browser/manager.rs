@@ -14,6 +14,7 @@ use futures::StreamExt;
 use tokio::sync::Mutex;
 use tracing::{debug, info, warn};
 
+use super::auth::{AuthConfig, AuthProvenance};
 use super::detection::{verify_executable, ChromeInfo};
 use super::resolver::{self, BrowserResolveOptions};
 use crate::error::{AuditError, Result};
@@ -41,6 +42,8 @@ pub struct BrowserOptions {
     pub verbose: bool,
     /// Override the user-agent string (None = use default browser simulation UA)
     pub user_agent_override: Option<String>,
+    /// Cookie jar, extra headers and login step for authenticated audits
+    pub auth: Option<AuthConfig>,
 }
 
 impl Default for BrowserOptions {
@@ -55,6 +58,7 @@ impl Default for BrowserOptions {
             timeout_secs: 30,
             verbose: false,
             user_agent_override: None,
+            auth: None,
         }
     }
 }
@@ -65,6 +69,8 @@ pub struct BrowserManager {
     chrome_info: ChromeInfo,
     options: BrowserOptions,
     user_data_dir: PathBuf,
+    /// Redacted summary of the session's authentication, set once at launch
+    auth_provenance: Option<AuthProvenance>,
     _handler: Arc<Mutex<Option<tokio::task::JoinHandle<()>>>>,
 }
 
@@ -156,13 +162,34 @@ impl BrowserManager {
             plan.label
         );
 
-        Ok(Self {
+        let mut manager = Self {
             browser,
             chrome_info,
             options,
             user_data_dir,
+            auth_provenance: None,
             _handler: Arc::new(Mutex::new(Some(handler_task))),
-        })
+        };
+        manager.authenticate().await?;
+        Ok(manager)
+    }
+
+    /// Establish the configured authentication once per browser session.
+    ///
+    /// Cookies (from the jar and from the login step) land in the default
+    /// browser context and are therefore shared by every tab the pool opens
+    /// later; headers are re-applied per tab in `new_page`.
+    async fn authenticate(&mut self) -> Result<()> {
+        let Some(auth) = self.options.auth.clone().filter(|auth| !auth.is_empty()) else {
+            return Ok(());
+        };
+        let page = self.new_page().await?;
+        let established = auth.establish(&page, self.options.timeout_secs).await;
+        if let Err(e) = page.close().await {
+            warn!("Failed to close login page: {}", e);
+        }
+        self.auth_provenance = Some(established?);
+        Ok(())
     }
 
     /// Build Chrome launch arguments based on options
@@ -325,6 +352,10 @@ impl BrowserManager {
             ))
             .await;
 
+        if let Some(auth) = &self.options.auth {
+            auth.apply_headers(&page).await?;
+        }
+
         Ok(page)
     }
 
@@ -432,6 +463,11 @@ impl BrowserManager {
         self.options.headless
     }
 
+    /// Redacted summary of the authentication applied to this session, if any.
+    pub fn auth_provenance(&self) -> Option<&AuthProvenance> {
+        self.auth_provenance.as_ref()
+    }
+
     /// Close the browser gracefully
     pub async fn close(mut self) -> Result<()> {
         info!("Closing browser...");
@@ -10,7 +10,9 @@
 //! - installer: Explicit browser installation (no auto-download)
 //! - manager: Browser launch and CDP connection
 //! - pool: Concurrent page management
+//! - auth: Cookie jars, extra HTTP headers and login steps
 
+pub mod auth;
 pub mod consent;
 mod detection;
 pub mod installer;
@@ -24,6 +26,7 @@ pub mod throttle;
 pub mod types;
 
 // New API
+pub use auth::{AuthConfig, AuthProvenance, LoginField, LoginStep};
 pub use consent::{handle_post_navigation, inject_consent_cookies, ConsentResult};
 pub use detection::detect_all_browsers;
 pub use installer::BrowserInstaller;
@@ -36,4 +39,4 @@ pub use types::{
 pub use detection::{detect_chrome, find_chrome, ChromeInfo};
 pub use manager::{BrowserManager, BrowserOptions};
 pub use pool::{BrowserPool, PoolConfig, PoolStats, PooledPage};
-pub use throttle::ThrottleProfile;
+pub use throttle::{CustomThrottleProfile, ThrottleConditions, ThrottlePass, ThrottleProfile};
@@ -2,6 +2,9 @@
 //!
 //! Network: `Network.emulateNetworkConditions` (deprecated in CDP but still functional).
 //! CPU: `Emulation.setCPUThrottlingRate` — required to simulate realistic mobile LCP/TBT.
+//!
+//! The built-in profiles run in every audit; `[[throttle_profiles]]` in
+//! `auditmysite.toml` adds user-defined network/CPU conditions.
 
 use chromiumoxide::cdp::browser_protocol::emulation::SetCpuThrottlingRateParams;
 #[allow(deprecated)]
@@ -11,9 +14,9 @@ use chromiumoxide::Page;
 use serde::{Deserialize, Serialize};
 use tracing::debug;
 
-use crate::error::Result;
+use crate::error::{AuditError, Result};
 
-/// Fixed throttle profiles used for automatic performance comparison.
+/// Throttle profiles used for automatic performance comparison.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum ThrottleProfile {
     /// No throttling — baseline measurement
@@ -24,6 +27,9 @@ pub enum ThrottleProfile {
     Fast3G,
     /// Lighthouse mobile preset — 1.6 Mbps / 150 ms latency
     LhMobile,
+    /// User-defined profile from `[[throttle_profiles]]`; the name travels
+    /// alongside (see `ThrottlePass::name`).
+    Custom,
 }
 
 impl ThrottleProfile {
@@ -40,6 +46,25 @@ impl ThrottleProfile {
             ThrottleProfile::Slow3G => "slow-3g",
             ThrottleProfile::Fast3G => "fast-3g",
             ThrottleProfile::LhMobile => "lh-mobile",
+            ThrottleProfile::Custom => "custom",
+        }
+    }
+
+    /// Network and CPU conditions of a built-in profile. `Custom` carries no
+    /// conditions of its own and reports none.
+    pub fn conditions(self) -> ThrottleConditions {
+        // All values in bytes/sec (1 kbps = 125 B/s, 1 Mbps = 125 000 B/s)
+        let (download_bps, upload_bps, latency_ms) = match self {
+            ThrottleProfile::Unthrottled | ThrottleProfile::Custom => (-1.0, -1.0, 0.0),
+            ThrottleProfile::Slow3G => (62_500.0, 62_500.0, 400.0),
+            ThrottleProfile::Fast3G => (187_500.0, 93_750.0, 40.0),
+            ThrottleProfile::LhMobile => (200_000.0, 96_000.0, 150.0),
+        };
+        ThrottleConditions {
+            download_bps,
+            upload_bps,
+            latency_ms,
+            cpu_slowdown: self.cpu_slowdown(),
         }
     }
 
@@ -49,7 +74,7 @@ impl ThrottleProfile {
     /// unrealistically low because the browser executes JS at full dev-machine speed.
     pub fn cpu_slowdown(self) -> f64 {
         match self {
-            ThrottleProfile::Unthrottled => 1.0,
+            ThrottleProfile::Unthrottled | ThrottleProfile::Custom => 1.0,
             ThrottleProfile::Slow3G => 6.0,
             ThrottleProfile::Fast3G => 4.0,
             ThrottleProfile::LhMobile => 4.0,
@@ -57,35 +82,169 @@ impl ThrottleProfile {
     }
 }
 
-/// Apply a network throttle profile to the given page.
+/// Network throughput/latency and CPU slowdown applied for one pass.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ThrottleConditions {
+    /// Download throughput in bytes/sec (`-1` = unlimited)
+    pub download_bps: f64,
+    /// Upload throughput in bytes/sec (`-1` = unlimited)
+    pub upload_bps: f64,
+    /// Added round-trip latency in milliseconds
+    pub latency_ms: f64,
+    /// CPU slowdown factor (1 = no slowdown)
+    pub cpu_slowdown: f64,
+}
+
+/// A `[[throttle_profiles]]` entry from `auditmysite.toml`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CustomThrottleProfile {
+    /// Shown in reports and used in subcheck ids (`throttled_profile:<name>`)
+    pub name: String,
+    /// Download throughput in kbit/s
+    pub download_kbps: f64,
+    /// Upload throughput in kbit/s
+    pub upload_kbps: f64,
+    /// Added round-trip latency in milliseconds
+    #[serde(default)]
+    pub latency_ms: f64,
+    /// CPU slowdown factor (1 = no slowdown)
+    #[serde(default = "default_cpu_slowdown")]
+    pub cpu_slowdown: f64,
+}
+
+fn default_cpu_slowdown() -> f64 {
+    1.0
+}
+
+impl CustomThrottleProfile {
+    pub fn conditions(&self) -> ThrottleConditions {
+        ThrottleConditions {
+            download_bps: self.download_kbps * 125.0,
+            upload_bps: self.upload_kbps * 125.0,
+            latency_ms: self.latency_ms,
+            cpu_slowdown: self.cpu_slowdown,
+        }
+    }
+
+    fn validate(&self) -> std::result::Result<(), String> {
+        if self.name.trim().is_empty() {
+            return Err("throttle profile name must not be empty".to_string());
+        }
+        if self.download_kbps <= 0.0 || self.upload_kbps <= 0.0 {
+            return Err(format!(
+                "throttle profile '{}': throughput must be positive",
+                self.name
+            ));
+        }
+        if self.latency_ms < 0.0 {
+            return Err(format!(
+                "throttle profile '{}': latency must not be negative",
+                self.name
+            ));
+        }
+        if self.cpu_slowdown < 1.0 {
+            return Err(format!(
+                "throttle profile '{}': cpu_slowdown must be at least 1",
+                self.name
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// One throttled measurement pass: a built-in profile or a custom one.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ThrottlePass {
+    pub profile: ThrottleProfile,
+    /// Profile name for `ThrottleProfile::Custom`, `None` for built-ins
+    pub name: Option<String>,
+    pub conditions: ThrottleConditions,
+}
+
+impl ThrottlePass {
+    pub fn label(&self) -> &str {
+        self.name.as_deref().unwrap_or(self.profile.label())
+    }
+}
+
+/// One pass per built-in `AUTO_PROFILES` entry.
+pub fn default_throttle_passes() -> Vec<ThrottlePass> {
+    ThrottleProfile::AUTO_PROFILES
+        .iter()
+        .map(|&profile| ThrottlePass {
+            profile,
+            name: None,
+            conditions: profile.conditions(),
+        })
+        .collect()
+}
+
+/// The passes a performance audit runs: the built-in `AUTO_PROFILES` followed
+/// by the custom profiles in configuration order.
+///
+/// Custom names must be unique and must not shadow a built-in label.
+pub fn throttle_passes(custom: &[CustomThrottleProfile]) -> Result<Vec<ThrottlePass>> {
+    let mut passes = default_throttle_passes();
+    for profile in custom {
+        profile.validate().map_err(AuditError::ConfigError)?;
+        if passes.iter().any(|pass| pass.label() == profile.name)
+            || profile.name == ThrottleProfile::Unthrottled.label()
+        {
+            return Err(AuditError::ConfigError(format!(
+                "duplicate throttle profile name '{}'",
+                profile.name
+            )));
+        }
+        passes.push(ThrottlePass {
+            profile: ThrottleProfile::Custom,
+            name: Some(profile.name.clone()),
+            conditions: profile.conditions(),
+        });
+    }
+    Ok(passes)
+}
+
+/// Apply a built-in network throttle profile to the given page.
 ///
 /// Must be called before navigation so the initial page load is throttled.
-#[allow(deprecated)]
+/// `Custom` profiles carry their conditions in [`ThrottlePass`]; apply those
+/// with [`apply_network_conditions`].
 pub async fn apply_throttling(page: &Page, profile: ThrottleProfile) -> Result<()> {
-    // All values in bytes/sec (1 kbps = 125 B/s, 1 Mbps = 125 000 B/s)
-    let (download, upload, latency_ms) = match profile {
-        ThrottleProfile::Unthrottled => (-1.0, -1.0, 0.0),
-        ThrottleProfile::Slow3G => (62_500.0, 62_500.0, 400.0),
-        ThrottleProfile::Fast3G => (187_500.0, 93_750.0, 40.0),
-        ThrottleProfile::LhMobile => (200_000.0, 96_000.0, 150.0),
-    };
+    apply_network_conditions(page, &builtin_conditions(profile)?).await
+}
 
+/// Conditions of a built-in profile; an error for `Custom`, which has none.
+fn builtin_conditions(profile: ThrottleProfile) -> Result<ThrottleConditions> {
+    if profile == ThrottleProfile::Custom {
+        return Err(AuditError::ConfigError(
+            "custom throttle profiles must be applied from their ThrottlePass conditions"
+                .to_string(),
+        ));
+    }
+    Ok(profile.conditions())
+}
+
+/// Apply the network part of `conditions` to the given page.
+///
+/// Must be called before navigation so the initial page load is throttled.
+#[allow(deprecated)]
+pub async fn apply_network_conditions(page: &Page, conditions: &ThrottleConditions) -> Result<()> {
     debug!(
-        "Applying network throttle {:?}: down={:.0} B/s, up={:.0} B/s, latency={:.0} ms",
-        profile, download, upload, latency_ms
+        "Applying network throttle: down={:.0} B/s, up={:.0} B/s, latency={:.0} ms",
+        conditions.download_bps, conditions.upload_bps, conditions.latency_ms
     );
 
     page.execute(
         EmulateNetworkConditionsParams::builder()
             .offline(false)
-            .latency(latency_ms)
-            .download_throughput(download)
-            .upload_throughput(upload)
+            .latency(conditions.latency_ms)
+            .download_throughput(conditions.download_bps)
+            .upload_throughput(conditions.upload_bps)
             .build()
             .unwrap(),
     )
     .await
-    .map_err(|e| crate::error::AuditError::NavigationFailed {
+    .map_err(|e| AuditError::NavigationFailed {
         url: "network-throttle".to_string(),
         reason: e.to_string(),
     })?;
@@ -97,11 +256,15 @@ pub async fn apply_throttling(page: &Page, profile: ThrottleProfile) -> Result<(
 ///
 /// Must be called before navigation; resets to 1x with `disable_cpu_throttling`.
 pub async fn apply_cpu_throttling(page: &Page, profile: ThrottleProfile) -> Result<()> {
-    let rate = profile.cpu_slowdown();
-    debug!("Applying CPU throttle {:?}: {:.0}x slowdown", profile, rate);
+    apply_cpu_slowdown(page, builtin_conditions(profile)?.cpu_slowdown).await
+}
+
+/// Apply a CPU slowdown factor. Must be called before navigation.
+pub async fn apply_cpu_slowdown(page: &Page, rate: f64) -> Result<()> {
+    debug!("Applying CPU throttle: {:.0}x slowdown", rate);
     page.execute(SetCpuThrottlingRateParams::new(rate))
         .await
-        .map_err(|e| crate::error::AuditError::NavigationFailed {
+        .map_err(|e| AuditError::NavigationFailed {
             url: "cpu-throttle".to_string(),
             reason: e.to_string(),
         })?;
@@ -112,7 +275,7 @@ pub async fn apply_cpu_throttling(page: &Page, profile: ThrottleProfile) -> Resu
 pub async fn disable_cpu_throttling(page: &Page) -> Result<()> {
     page.execute(SetCpuThrottlingRateParams::new(1.0_f64))
         .await
-        .map_err(|e| crate::error::AuditError::NavigationFailed {
+        .map_err(|e| AuditError::NavigationFailed {
             url: "cpu-throttle-disable".to_string(),
             reason: e.to_string(),
         })?;
@@ -124,7 +287,7 @@ pub async fn disable_cpu_throttling(page: &Page) -> Result<()> {
 pub async fn disable_cache(page: &Page) -> Result<()> {
     page.execute(SetCacheDisabledParams::new(true))
         .await
-        .map_err(|e| crate::error::AuditError::NavigationFailed {
+        .map_err(|e| AuditError::NavigationFailed {
             url: "cache-disable".to_string(),
             reason: e.to_string(),
         })?;
@@ -135,7 +298,7 @@ pub async fn disable_cache(page: &Page) -> Result<()> {
 pub async fn enable_cache(page: &Page) -> Result<()> {
     page.execute(SetCacheDisabledParams::new(false))
         .await
-        .map_err(|e| crate::error::AuditError::NavigationFailed {
+        .map_err(|e| AuditError::NavigationFailed {
             url: "cache-enable".to_string(),
             reason: e.to_string(),
         })?;
@@ -155,10 +318,55 @@ pub async fn disable_throttling(page: &Page) -> Result<()> {
             .unwrap(),
     )
     .await
-    .map_err(|e| crate::error::AuditError::NavigationFailed {
+    .map_err(|e| AuditError::NavigationFailed {
         url: "network-throttle-disable".to_string(),
         reason: e.to_string(),
     })?;
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn custom(name: &str) -> CustomThrottleProfile {
+        CustomThrottleProfile {
+            name: name.to_string(),
+            download_kbps: 10_000.0,
+            upload_kbps: 2_000.0,
+            latency_ms: 20.0,
+            cpu_slowdown: 2.0,
+        }
+    }
+
+    #[test]
+    fn custom_profiles_follow_builtins_and_convert_kbps() {
+        let passes = throttle_passes(&[custom("cable")]).unwrap();
+        assert_eq!(passes.len(), ThrottleProfile::AUTO_PROFILES.len() + 1);
+        let cable = passes.last().unwrap();
+        assert_eq!(cable.profile, ThrottleProfile::Custom);
+        assert_eq!(cable.label(), "cable");
+        assert_eq!(cable.conditions.download_bps, 1_250_000.0);
+        assert_eq!(cable.conditions.cpu_slowdown, 2.0);
+        assert_eq!(passes[0].label(), "slow-3g");
+    }
+
+    #[test]
+    fn rejects_shadowed_duplicate_and_invalid_profiles() {
+        assert!(throttle_passes(&[custom("fast-3g")]).is_err());
+        assert!(throttle_passes(&[custom("cable"), custom("cable")]).is_err());
+        let mut slow_cpu = custom("cable");
+        slow_cpu.cpu_slowdown = 0.5;
+        assert!(throttle_passes(&[slow_cpu]).is_err());
+    }
+
+    #[test]
+    fn custom_profile_has_no_builtin_conditions() {
+        assert!(builtin_conditions(ThrottleProfile::Custom).is_err());
+        assert_eq!(
+            builtin_conditions(ThrottleProfile::Slow3G).unwrap(),
+            ThrottleProfile::Slow3G.conditions()
+        );
+    }
+}
@@ -81,6 +81,16 @@ pub struct Args {
     #[arg(short = 'o', long, value_name = "FILE", global = true)]
     pub output: Option<PathBuf>,
 
+    /// Baseline JSON report (`-f json`) for `-f markdown`: list only new and
+    /// resolved findings instead of the top findings
+    #[arg(long, value_name = "FILE")]
+    pub compare: Option<PathBuf>,
+
+    /// VPAT edition for `-f vpat` / `-f vpat-json`: WCAG 2.1 rows or
+    /// EN 301 549 clauses [default: wcag]
+    #[arg(long, value_enum, value_name = "EDITION")]
+    pub vpat_edition: Option<VpatEdition>,
+
     /// Custom browser binary path (overrides auto-detection)
     ///
     /// Can also be set via AUDITMYSITE_BROWSER or CHROME_PATH env var.
@@ -115,6 +125,51 @@ pub struct Args {
     #[arg(long, default_value = "2", value_name = "NUM")]
     pub crawl_depth: usize,
 
+    /// Discover crawl links from the rendered DOM instead of raw HTML.
+    ///
+    /// For client-rendered sites (React, Next, Vue, Angular). Each page's
+    /// links — including router links and `history.pushState` targets — are
+    /// collected while it is audited and fed back into the crawl queue.
+    /// Requires --crawl.
+    #[arg(long)]
+    pub crawl_render: bool,
+
+    /// Audit only N representatives per URL template in batch runs.
+    ///
+    /// Sitemap, crawl or URL-file URLs are clustered by path pattern
+    /// (e.g. `/product/{slug}`) and HTML shape before auditing; the
+    /// representatives' findings are extrapolated to the rest of each
+    /// cluster and marked as sampled in the report.
+    #[arg(long, value_name = "N")]
+    pub template_sample: Option<usize>,
+
+    /// Checkpoint every completed page of a batch run into a run directory
+    /// so an interrupted run can be resumed (default:
+    /// ~/.auditmysite/runs/<timestamp>-<pid>, e.g. `--checkpoint=./run`).
+    #[arg(
+        long,
+        value_name = "DIR",
+        num_args = 0..=1,
+        require_equals = true
+    )]
+    pub checkpoint: Option<Option<PathBuf>>,
+
+    /// Resume an interrupted batch run from its checkpoint directory.
+    ///
+    /// Run the command that was started with `--checkpoint` again with
+    /// `--resume <dir>` to skip completed URLs and retry failed ones.
+    #[arg(long, value_name = "RUN_DIR")]
+    pub resume: Option<PathBuf>,
+
+    /// Audit only shard K of N of a batch's URL list (e.g. `2/5`).
+    ///
+    /// URLs are assigned to shards by hash, so N jobs with the same input
+    /// cover every URL exactly once. A shard run writes only its partial
+    /// result (`shard-K-of-N.json`, or `-o`); combine the results with
+    /// `auditmysite merge`.
+    #[arg(long, value_name = "K/N")]
+    pub shard: Option<crate::audit::Shard>,
+
     /// Number of concurrent browser tabs [default: 3]
     #[arg(short = 'c', long, value_name = "NUM")]
     pub concurrency: Option<usize>,
@@ -123,6 +178,12 @@ pub struct Args {
     #[arg(short = 't', long, value_name = "SECS")]
     pub timeout: Option<u64>,
 
+    /// Measure each throttled performance profile N times and report the
+    /// median (plus p75 and standard deviation) per metric. Single-page
+    /// audits only [default: 1]
+    #[arg(long, value_name = "N", value_parser = clap::value_parser!(u32).range(1..=20))]
+    pub runs: Option<u32>,
+
     /// Maximum wait for late hydration and DOM stabilization after navigation.
     #[arg(long, default_value = "1500", value_name = "MS", global = true)]
     pub stability_budget_ms: u64,
@@ -187,6 +248,26 @@ pub struct Args {
     #[arg(long)]
     pub dismiss_consent: bool,
 
+    /// Cookie jar to load before auditing (JSON array, Playwright
+    /// storage state, or Netscape cookies.txt).
+    ///
+    /// Cookies are set once per browser session and shared by all tabs.
+    #[arg(long, value_name = "FILE", global = true)]
+    pub cookie_jar: Option<PathBuf>,
+
+    /// Extra HTTP header sent with every request (repeatable).
+    ///
+    /// Example: --header "Authorization: Bearer env:STAGING_TOKEN"
+    /// Values prefixed with `env:` are read from the environment.
+    /// Header values are never written to reports.
+    #[arg(long = "header", value_name = "NAME: VALUE", global = true)]
+    pub headers: Vec<String>,
+
+    /// TOML file describing a login step (url, fields, submit, wait_for)
+    /// performed once before the audit starts.
+    #[arg(long, value_name = "FILE", global = true)]
+    pub login_script: Option<PathBuf>,
+
     /// Run the Accessibility-Journey-Layer for interactive checks
     /// (tab walk, modal focus trap, skip-link verification, …).
     ///
@@ -211,6 +292,24 @@ pub struct Args {
     #[arg(long)]
     pub force_refresh: bool,
 
+    /// Record this run in the local audit history (off by default)
+    #[arg(long)]
+    pub history: bool,
+
+    /// Audit history directory (default: ~/.auditmysite/history)
+    #[arg(long, value_name = "DIR", global = true)]
+    pub history_dir: Option<PathBuf>,
+
+    /// Runs kept per page and per site in the audit history; older runs are
+    /// pruned (default: 30, 0 = keep everything)
+    #[arg(long, value_name = "N")]
+    pub history_keep: Option<usize>,
+
+    /// Declarative custom rules file (TOML or JSON), evaluated next to the
+    /// built-in WCAG rules (overrides `[rules] custom` in the config)
+    #[arg(long, value_name = "FILE", global = true)]
+    pub custom_rules: Option<PathBuf>,
+
     /// Do not suggest scanning a discovered sitemap for base URLs
     #[arg(long)]
     pub no_sitemap_suggest: bool,
@@ -260,6 +359,28 @@ pub struct Args {
     #[arg(long, value_name = "PATH")]
     pub export_snapshot: Option<PathBuf>,
 
+    /// Write Playwright regression specs generated from the journey traces.
+    ///
+    /// One `<page>.a11y.spec.ts` per audited page replays the tab walk, modal
+    /// and disclosure journeys and asserts the recorded focus targets and
+    /// state changes. Journeys with failing checks are marked `test.fail()`.
+    ///
+    /// Example: --export-playwright tests/a11y
+    #[arg(long, value_name = "DIR")]
+    pub export_playwright: Option<PathBuf>,
+
+    /// Local template sources (HTML, JSX, Vue, Svelte, Astro) to map
+    /// attribute fixes back to.
+    ///
+    /// Findings whose suggested fix only adds an attribute (`alt`,
+    /// `aria-label`, `lang`, …) are located in the sources by snippet
+    /// matching and written as a `git apply`-able `<report>-fixes.patch`
+    /// next to the report. Inserted values are placeholders to review.
+    ///
+    /// Example: --source-root ./src
+    #[arg(long, value_name = "DIR")]
+    pub source_root: Option<PathBuf>,
+
     /// Include an additional regulatory appendix section in the PDF report.
     ///
     /// Opt-in only — this section is not part of the default report ("Zusatz").
@@ -272,6 +393,12 @@ pub struct Args {
     #[arg(long, value_enum)]
     pub annex: Option<AnnexKind>,
 
+    /// Add a score trend chart from the local audit history to the
+    /// single-page PDF (needs at least two recorded runs of the URL). Batch
+    /// runs accept it only together with `--per-page-reports`.
+    #[arg(long)]
+    pub trend: bool,
+
     /// How the browser should identify itself when making requests.
     ///
     /// In interactive mode this is prompted automatically.
@@ -320,6 +447,99 @@ pub enum Command {
         #[arg(long)]
         typst_source: Option<PathBuf>,
     },
+    /// Compare two saved JSON reports (single or batch) and list new,
+    /// resolved and unchanged findings plus score deltas. Exits non-zero on
+    /// regressions.
+    Diff {
+        /// Baseline JSON report
+        before: PathBuf,
+        /// Current JSON report
+        after: PathBuf,
+        /// Rendering of the comparison (default: table)
+        #[arg(long, value_enum, default_value = "table")]
+        diff_format: DiffFormat,
+    },
+    /// Re-audit a local dev server whenever files change and print the
+    /// finding delta against the previous run. Stop with Ctrl-C.
+    Watch {
+        /// Page to audit, e.g. `http://localhost:3000`
+        url: String,
+        /// File or directory to watch (repeatable; default: current directory)
+        #[arg(long = "path", value_name = "PATH", default_value = ".")]
+        paths: Vec<PathBuf>,
+        /// Quiet period after the last change before re-auditing (ms)
+        #[arg(long, default_value_t = 500)]
+        debounce_ms: u64,
+    },
+    /// Serve audits as a local JSON API: submit jobs over HTTP, follow their
+    /// progress events and fetch Studio or unified JSON results.
+    Serve {
+        /// Port to listen on
+        #[arg(long, default_value_t = 8787)]
+        port: u16,
+        /// Address to bind (use 0.0.0.0 to accept remote connections)
+        #[arg(long, default_value = "127.0.0.1")]
+        host: String,
+        /// Audits running at the same time on the shared browser pool
+        #[arg(long, default_value_t = 2)]
+        concurrency: usize,
+        /// Jobs that may wait for a free worker before new ones are rejected
+        #[arg(long, default_value_t = 32)]
+        queue_size: usize,
+    },
+    /// Show score, risk and finding-count trends from the local audit
+    /// history for a URL, or for a host (`example.com`) across batch runs.
+    History {
+        /// Page URL or bare host
+        target: String,
+        /// Number of most recent runs to show (0 = all)
+        #[arg(long, default_value_t = 20)]
+        limit: usize,
+        /// Rendering of the trend (default: table)
+        #[arg(long, value_enum, default_value = "table")]
+        history_format: HistoryFormat,
+    },
+    /// Merge the results of `--shard K/N` runs into one batch report and
+    /// render it with --format / --output. Exits with the batch verdict.
+    Merge {
+        /// Shard result files, e.g. `shard-*.json`
+        #[arg(required = true)]
+        inputs: Vec<PathBuf>,
+    },
+    /// Draft an accessibility statement following the EU model statement
+    /// from a saved JSON report (single or batch). Language follows --lang.
+    Statement {
+        /// JSON report (`-f json`)
+        input: PathBuf,
+        /// Rendering of the draft (default: markdown)
+        #[arg(long, value_enum, default_value = "markdown")]
+        statement_format: StatementFormat,
+    },
+}
+
+/// Rendering for the `history` subcommand.
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+#[value(rename_all = "lowercase")]
+pub enum HistoryFormat {
+    Table,
+    Json,
+}
+
+/// Rendering for the `statement` subcommand.
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+#[value(rename_all = "lowercase")]
+pub enum StatementFormat {
+    Markdown,
+    Json,
+}
+
+/// Rendering for the `diff` subcommand.
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+#[value(rename_all = "lowercase")]
+pub enum DiffFormat {
+    Table,
+    Json,
+    Markdown,
 }
 
 /// `--fail-on` threshold for the `report-lint` subcommand.
@@ -415,6 +635,19 @@ pub enum AnnexKind {
     En301549,
 }
 
+/// VPAT 2.x edition generated by `-f vpat` / `-f vpat-json`.
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum VpatEdition {
+    /// WCAG edition: WCAG 2.1 Level A and AA success criteria.
+    #[default]
+    #[value(name = "wcag")]
+    Wcag,
+    /// EU edition: EN 301 549 chapter 9 clauses plus the other chapters.
+    #[value(name = "en301549")]
+    En301549,
+}
+
 impl std::fmt::Display for WcagLevel {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -446,6 +679,30 @@ pub enum OutputFormat {
     /// SARIF 2.1.0 output for GitHub Code Scanning and other SARIF consumers
     #[value(name = "sarif")]
     Sarif,
+    /// Self-contained interactive HTML report (offline, filterable)
+    #[value(name = "html")]
+    Html,
+    /// JUnit XML for CI test dashboards (one test suite per URL)
+    #[value(name = "junit")]
+    Junit,
+    /// GitLab Code Quality JSON for merge-request widgets
+    #[value(name = "gitlab-codequality")]
+    GitlabCodequality,
+    /// CSV, one row per finding occurrence (spreadsheet triage)
+    #[value(name = "csv")]
+    Csv,
+    /// Markdown summary for pull-request comments (GitHub/GitLab)
+    #[value(name = "markdown", alias = "md")]
+    Markdown,
+    /// W3C EARL JSON-LD with ACT rule ids, for exchanging results with other tools
+    #[value(name = "earl")]
+    Earl,
+    /// Draft VPAT / Accessibility Conformance Report (Markdown)
+    #[value(name = "vpat")]
+    Vpat,
+    /// Draft VPAT / Accessibility Conformance Report (JSON)
+    #[value(name = "vpat-json")]
+    VpatJson,
 }
 
 /// Report detail level for PDF reports
@@ -493,6 +750,14 @@ impl std::fmt::Display for OutputFormat {
             OutputFormat::Ai => write!(f, "ai"),
             OutputFormat::Summary => write!(f, "summary"),
             OutputFormat::Sarif => write!(f, "sarif"),
+            OutputFormat::Html => write!(f, "html"),
+            OutputFormat::Junit => write!(f, "junit"),
+            OutputFormat::GitlabCodequality => write!(f, "gitlab-codequality"),
+            OutputFormat::Csv => write!(f, "csv"),
+            OutputFormat::Markdown => write!(f, "markdown"),
+            OutputFormat::Earl => write!(f, "earl"),
+            OutputFormat::Vpat => write!(f, "vpat"),
+            OutputFormat::VpatJson => write!(f, "vpat-json"),
         }
     }
 }
@@ -503,6 +768,11 @@ impl Args {
         self.timeout.unwrap_or(30)
     }
 
+    /// Returns the measurement runs per throttled profile (CLI value or default 1).
+    pub fn effective_runs(&self) -> u32 {
+        self.runs.unwrap_or(1)
+    }
+
     /// Returns the effective concurrency (CLI value or default 3).
     pub fn effective_concurrency(&self) -> usize {
         self.concurrency.unwrap_or(3)
@@ -585,6 +855,124 @@ impl Args {
             }
         }
 
+        if let Some(baseline) = &self.compare {
+            if self.effective_format() != OutputFormat::Markdown {
+                return Err("--compare requires --format markdown".to_string());
+            }
+            if self.per_page_reports {
+                return Err("--compare cannot be combined with --per-page-reports".to_string());
+            }
+            if !baseline.exists() {
+                return Err(format!("Baseline report not found: {:?}", baseline));
+            }
+        }
+
+        if self.vpat_edition.is_some()
+            && !matches!(
+                self.effective_format(),
+                OutputFormat::Vpat | OutputFormat::VpatJson
+            )
+        {
+            return Err("--vpat-edition requires --format vpat or vpat-json".to_string());
+        }
+
+        if self.export_playwright.is_some() && !self.interactive.is_enabled() {
+            return Err(
+                "--export-playwright needs journey traces; it cannot be combined with --interactive off"
+                    .to_string(),
+            );
+        }
+
+        if self.crawl_render && !self.crawl {
+            return Err("--crawl-render requires --crawl".to_string());
+        }
+
+        if let Some(per_cluster) = self.template_sample {
+            if per_cluster == 0 {
+                return Err("--template-sample must be at least 1".to_string());
+            }
+            if self.sitemap.is_none() && self.url_file.is_none() && !self.crawl {
+                return Err(
+                    "--template-sample requires --sitemap, --url-file or --crawl".to_string(),
+                );
+            }
+            if self.crawl_render {
+                return Err(
+                    "--template-sample cannot be combined with --crawl-render; rendered crawls discover URLs while auditing"
+                        .to_string(),
+                );
+            }
+        }
+
+        if self.checkpoint.is_some() {
+            if self.sitemap.is_none() && self.url_file.is_none() && !self.crawl {
+                return Err("--checkpoint requires --sitemap, --url-file or --crawl".to_string());
+            }
+            if self.crawl_render {
+                return Err("--checkpoint cannot be combined with --crawl-render".to_string());
+            }
+            if let (Some(Some(dir)), Some(resume)) = (&self.checkpoint, &self.resume) {
+                if dir != resume {
+                    return Err(
+                        "--checkpoint and --resume name different run directories".to_string()
+                    );
+                }
+            }
+        }
+
+        if let Some(run_dir) = &self.resume {
+            if self.sitemap.is_none() && self.url_file.is_none() && !self.crawl {
+                return Err(
+                    "--resume requires the original --sitemap, --url-file or --crawl input"
+                        .to_string(),
+                );
+            }
+            if self.crawl_render {
+                return Err("--resume cannot be combined with --crawl-render".to_string());
+            }
+            if !run_dir.join("run.json").is_file() {
+                return Err(format!("Not a batch run directory: {:?}", run_dir));
+            }
+        }
+
+        if self.shard.is_some() {
+            if self.sitemap.is_none() && self.url_file.is_none() && !self.crawl {
+                return Err("--shard requires --sitemap, --url-file or --crawl".to_string());
+            }
+            if self.crawl_render {
+                return Err("--shard cannot be combined with --crawl-render".to_string());
+            }
+            if self.template_sample.is_some() {
+                return Err("--shard cannot be combined with --template-sample".to_string());
+            }
+            if self.per_page_reports {
+                return Err(
+                    "--shard writes a partial result for `auditmysite merge`; it cannot be combined with --per-page-reports"
+                        .to_string(),
+                );
+            }
+        }
+
+        if let Some(root) = &self.source_root {
+            if !root.is_dir() {
+                return Err(format!("Source root is not a directory: {:?}", root));
+            }
+        }
+
+        for raw in &self.headers {
+            crate::browser::auth::parse_header(raw)?;
+        }
+        for (flag, path) in [
+            ("Cookie jar", &self.cookie_jar),
+            ("Login script", &self.login_script),
+        ] {
+            if let Some(path) = path {
+                if !path.exists() {
+                    return Err(format!("{} not found: {:?}", flag, path));
+                }
+            }
+        }
+
         // Validate concurrency
         let concurrency = self.effective_concurrency();
         if concurrency == 0 {
@@ -670,8 +1058,8 @@ mod tests {
         assert!(help.contains(
             "Default single-URL behavior: generate a PDF report in the current directory."
         ));
-        assert!(!help.contains("html"));
-        assert!(!help.contains("markdown"));
+        assert!(help.contains("html"));
+        assert!(help.contains("markdown"));
         assert!(!help.contains("--urls"));
     }
 
@@ -685,12 +1073,20 @@ mod tests {
             level: WcagLevel::AA,
             format: None,
             output: None,
+            compare: None,
+            vpat_edition: None,
             chrome_path: None,
             remote_debugging_port: None,
             max_pages: 0,
             crawl_depth: 2,
+            crawl_render: false,
+            template_sample: None,
+            checkpoint: None,
+            resume: None,
+            shard: None,
             concurrency: None,
             timeout: None,
+            runs: None,
             stability_budget_ms: 1500,
             no_sandbox: false,
             disable_images: false,
@@ -707,10 +1103,17 @@ mod tests {
             stack: false,
             reuse_cache: false,
             force_refresh: false,
+            history: false,
+            history_dir: None,
+            history_keep: None,
+            custom_rules: None,
             no_sitemap_suggest: false,
             prefer_sitemap: false,
             per_page_reports: false,
             dismiss_consent: false,
+            cookie_jar: None,
+            headers: Vec::new(),
+            login_script: None,
             interactive: InteractiveMode::Off,
             report_level: ReportLevel::Standard,
             lang: "de".to_string(),
@@ -718,7 +1121,10 @@ mod tests {
             logo: None,
             debug_typ: false,
             export_snapshot: None,
+            export_playwright: None,
+            source_root: None,
             annex: None,
+            trend: false,
             request_mode: RequestMode::Browser,
             report_mode: false,
         }
@@ -747,6 +1153,84 @@ mod tests {
         assert!(args.validate().is_err());
     }
 
+    #[test]
+    fn test_validate_compare_requires_markdown_and_existing_baseline() {
+        let mut args = test_args(Some("https://example.com"));
+        args.compare = Some(PathBuf::from("Cargo.toml"));
+        assert!(args.validate().is_err());
+
+        args.format = Some(OutputFormat::Markdown);
+        assert!(args.validate().is_ok());
+
+        args.compare = Some(PathBuf::from("does-not-exist.json"));
+        assert!(args.validate().is_err());
+    }
+
+    #[test]
+    fn test_validate_vpat_edition_requires_vpat_format() {
+        let mut args = test_args(Some("https://example.com"));
+        args.vpat_edition = Some(VpatEdition::En301549);
+        assert!(args.validate().is_err());
+
+        args.format = Some(OutputFormat::VpatJson);
+        assert!(args.validate().is_ok());
+    }
+
+    #[test]
+    fn test_validate_export_playwright_requires_interactive() {
+        let mut args = test_args(Some("https://example.com"));
+        args.export_playwright = Some(PathBuf::from("tests/a11y"));
+        assert!(args.validate().is_err());
+
+        args.interactive = InteractiveMode::Basic;
+        assert!(args.validate().is_ok());
+    }
+
+    #[test]
+    fn test_validate_crawl_render_requires_crawl() {
+        let mut args = test_args(Some("https://example.com"));
+        args.crawl_render = true;
+        assert!(args.validate().is_err());
+
+        args.crawl = true;
+        assert!(args.validate().is_ok());
+    }
+
+    #[test]
+    fn test_validate_shard_needs_batch_source() {
+        let mut args = test_args(Some("https://example.com"));
+        args.shard = Some("2/5".parse().unwrap());
+        assert!(args.validate().is_err());
+
+        args.crawl = true;
+        assert!(args.validate().is_ok());
+
+        args.template_sample = Some(2);
+        assert!(args.validate().is_err());
+    }
+
+    #[test]
+    fn test_checkpoint_is_opt_in_with_optional_dir() {
+        let args = Args::parse_from(["auditmysite", "--sitemap", "https://example.com/s.xml"]);
+        assert!(args.checkpoint.is_none());
+        let args = Args::parse_from([
+            "auditmysite",
+            "--sitemap",
+            "https://example.com/s.xml",
+            "--checkpoint",
+        ]);
+        assert_eq!(args.checkpoint, Some(None));
+        assert!(args.validate().is_ok());
+
+        let mut args = test_args(Some("https://example.com"));
+        args.checkpoint = Some(Some(PathBuf::from("run-a")));
+        assert!(args.validate().is_err());
+        args.crawl = true;
+        assert!(args.validate().is_ok());
+        args.resume = Some(PathBuf::from("run-b"));
+        assert!(args.validate().is_err());
+    }
+
     #[test]
     fn test_validate_reuse_and_force_refresh_conflict() {
         let mut args = test_args(Some("https://example.com"));
@@ -1,34 +1,87 @@
 //! CLI subcommand handlers.
 //!
-//! Handles the browser subcommands, the plan dry-run command, and the
-//! legacy --detect-chrome flag. No mode-runner or audit logic here.
+//! Handles the browser subcommands, the plan dry-run command, report-lint,
+//! report diff, audit history, accessibility statement drafts, and the legacy
+//! --detect-chrome flag. The watch, serve and merge subcommands are dispatched
+//! from here to their runners; no mode-runner or audit logic lives here.
 
 use colored::Colorize;
 
+use auditmysite::audit::{diff_reports, draft_statement, HistoryStore};
 use auditmysite::browser::{
     detect_all_browsers, find_chrome, resolve_browser, BrowserInstaller, BrowserResolveOptions,
     InstallTarget,
 };
-use auditmysite::cli::{Args, BrowserAction, Command, ReportLintFailOn};
+use auditmysite::cli::{
+    Args, BrowserAction, Command, Config, DiffFormat, HistoryFormat, ReportLintFailOn,
+    StatementFormat,
+};
 use auditmysite::error::{AuditError, Result};
+use auditmysite::i18n::I18n;
 use auditmysite::lint::lint;
+use auditmysite::output::{
+    format_diff_markdown, format_diff_table, format_history_table, format_statement_markdown,
+};
 use auditmysite::taxonomy::Severity;
+use auditmysite::Verdict;
 
+use crate::output_paths::output_text;
 use crate::plan::{print_banner, print_batch_audit_plan, print_single_audit_plan};
+use crate::runners::run_merge_mode;
+use crate::serve::run_serve_mode;
+use crate::watch::run_watch_mode;
 
-pub async fn handle_command(command: &Command, args: &Args) -> Result<f64> {
+/// Run a subcommand. Every subcommand is dispatched here, including the
+/// long-running watch and serve runners. `diff` and `merge` return a failing
+/// verdict instead of an error, so they exit 2 rather than 3.
+pub async fn handle_command(
+    command: &Command,
+    args: &Args,
+    config: &Option<Config>,
+) -> Result<Verdict> {
     match command {
-        Command::Browser { action } => handle_browser_command(action).await,
+        Command::Browser { action } => handle_browser_command(action).await.map(|_| Verdict::Pass),
         Command::Doctor => {
             auditmysite::cli::doctor::run_doctor();
-            Ok(0.0)
+            Ok(Verdict::Pass)
         }
-        Command::Plan { url } => run_plan_command(args, url.as_deref()),
+        Command::Plan { url } => run_plan_command(args, url.as_deref()).map(|_| Verdict::Pass),
         Command::ReportLint {
             input,
             fail_on,
             typst_source,
-        } => run_report_lint_command(input, *fail_on, typst_source.as_deref()),
+        } => {
+            run_report_lint_command(input, *fail_on, typst_source.as_deref()).map(|_| Verdict::Pass)
+        }
+        Command::Diff {
+            before,
+            after,
+            diff_format,
+        } => run_diff_command(args, before, after, *diff_format),
+        Command::History {
+            target,
+            limit,
+            history_format,
+        } => run_history_command(args, target, *limit, *history_format).map(|_| Verdict::Pass),
+        Command::Statement {
+            input,
+            statement_format,
+        } => run_statement_command(args, input, *statement_format).map(|_| Verdict::Pass),
+        Command::Watch {
+            url,
+            paths,
+            debounce_ms,
+        } => {
+            let debounce = std::time::Duration::from_millis(*debounce_ms);
+            run_watch_mode(args, config, url, paths, debounce).await
+        }
+        Command::Serve {
+            host,
+            port,
+            concurrency,
+            queue_size,
+        } => run_serve_mode(args, config, host, *port, *concurrency, *queue_size).await,
+        Command::Merge { inputs } => run_merge_mode(args, config, inputs),
     }
 }
 
@@ -45,7 +98,7 @@ fn run_report_lint_command(
     input: &std::path::Path,
     fail_on: Option<ReportLintFailOn>,
     typst_source: Option<&std::path::Path>,
-) -> Result<f64> {
+) -> Result<()> {
     let text = std::fs::read_to_string(input).map_err(|e| AuditError::FileError {
         path: input.to_path_buf(),
         reason: e.to_string(),
@@ -88,11 +141,102 @@ fn run_report_lint_command(
         Some(worst) if worst >= threshold => Err(AuditError::ConfigError(format!(
             "report-lint found a {worst:?} finding, at or above --fail-on {threshold:?}"
         ))),
-        _ => Ok(0.0),
+        _ => Ok(()),
     }
 }
 
-fn run_plan_command(args: &Args, url: Option<&str>) -> Result<f64> {
+fn read_report_file(path: &std::path::Path) -> Result<String> {
+    std::fs::read_to_string(path).map_err(|e| AuditError::FileError {
+        path: path.to_path_buf(),
+        reason: e.to_string(),
+    })
+}
+
+fn run_diff_command(
+    args: &Args,
+    before: &std::path::Path,
+    after: &std::path::Path,
+    diff_format: DiffFormat,
+) -> Result<Verdict> {
+    let diff = diff_reports(&read_report_file(before)?, &read_report_file(after)?)?;
+
+    let output = match diff_format {
+        DiffFormat::Table => format_diff_table(&diff, args.output.is_none()),
+        DiffFormat::Json => serde_json::to_string_pretty(&diff)?,
+        DiffFormat::Markdown => format_diff_markdown(&diff),
+    };
+    output_text(&output, &args.output, "Diff", args.quiet)?;
+
+    if !diff.has_regressions() {
+        return Ok(Verdict::Pass);
+    }
+    // New findings fail the comparison; a score drop alone only warns.
+    let verdict = if diff.summary.new_count > 0 {
+        Verdict::Fail
+    } else {
+        Verdict::Warn
+    };
+    if !args.quiet {
+        let label = match verdict {
+            Verdict::Fail => "FAIL".red().bold(),
+            _ => "WARN".yellow().bold(),
+        };
+        eprintln!(
+            "{} diff found {} new finding(s); accessibility score {:+}, overall score {:+}",
+            label,
+            diff.summary.new_count,
+            diff.summary.accessibility_score.delta.unwrap_or(0),
+            diff.summary.overall_score.delta.unwrap_or(0),
+        );
+    }
+    Ok(verdict)
+}
+
+fn run_history_command(
+    args: &Args,
+    target: &str,
+    limit: usize,
+    history_format: HistoryFormat,
+) -> Result<()> {
+    let store = HistoryStore::open(args.history_dir.as_deref()).ok_or_else(|| {
+        AuditError::ConfigError(
+            "no history directory: pass --history-dir or set [history].dir".to_string(),
+        )
+    })?;
+    let trend = store.trend(target, limit)?;
+
+    let output = match history_format {
+        HistoryFormat::Table => format_history_table(&trend, args.output.is_none()),
+        HistoryFormat::Json => serde_json::to_string_pretty(&trend)?,
+    };
+    output_text(&output, &args.output, "History", args.quiet)?;
+    Ok(())
+}
+
+fn run_statement_command(
+    args: &Args,
+    input: &std::path::Path,
+    statement_format: StatementFormat,
+) -> Result<()> {
+    let draft = draft_statement(&read_report_file(input)?)?;
+    let output = match statement_format {
+        StatementFormat::Markdown => {
+            let i18n = I18n::new(&args.lang).map_err(|e| AuditError::OutputError {
+                reason: e.to_string(),
+            })?;
+            format_statement_markdown(&draft, &i18n)
+        }
+        StatementFormat::Json => serde_json::to_string_pretty(&draft)?,
+    };
+    output_text(
+        &output,
+        &args.output,
+        "Accessibility statement draft",
+        args.quiet,
+    )
+}
+
+fn run_plan_command(args: &Args, url: Option<&str>) -> Result<()> {
     let mut effective = args.clone();
     if let Some(u) = url {
         effective.url = Some(u.to_string());
@@ -122,10 +266,10 @@ fn run_plan_command(args: &Args, url: Option<&str>) -> Result<f64> {
         print_single_audit_plan(&effective, single_url);
     }
 
-    Ok(0.0)
+    Ok(())
 }
 
-async fn handle_browser_command(action: &BrowserAction) -> Result<f64> {
+async fn handle_browser_command(action: &BrowserAction) -> Result<()> {
     match action {
         BrowserAction::Detect => {
             println!("{}", "Detecting browsers...".cyan().bold());
@@ -199,7 +343,7 @@ async fn handle_browser_command(action: &BrowserAction) -> Result<f64> {
                 }
             }
 
-            Ok(0.0)
+            Ok(())
         }
 
         BrowserAction::Install {
@@ -213,7 +357,7 @@ async fn handle_browser_command(action: &BrowserAction) -> Result<f64> {
                 InstallTarget::ChromeForTesting
             };
             BrowserInstaller::install(target, version.as_deref(), *force).await?;
-            Ok(0.0)
+            Ok(())
         }
 
         BrowserAction::Remove { all } => {
@@ -222,7 +366,7 @@ async fn handle_browser_command(action: &BrowserAction) -> Result<f64> {
             } else {
                 BrowserInstaller::remove(InstallTarget::ChromeForTesting)?;
             }
-            Ok(0.0)
+            Ok(())
         }
 
         BrowserAction::Path => {
@@ -236,12 +380,12 @@ async fn handle_browser_command(action: &BrowserAction) -> Result<f64> {
                     std::process::exit(1);
                 }
             }
-            Ok(0.0)
+            Ok(())
         }
     }
 }
 
-pub fn detect_chrome_command(args: &Args) -> Result<f64> {
+pub fn detect_chrome_command(args: &Args) -> Result<()> {
     println!("{}", "Searching for Chrome/Chromium...".cyan().bold());
     println!();
 
@@ -254,7 +398,7 @@ pub fn detect_chrome_command(args: &Args) -> Result<f64> {
                 info.version.as_deref().unwrap_or("unknown")
             );
             println!("  Methode: {:?}", info.detection_method);
-            Ok(0.0)
+            Ok(())
         }
         Err(e) => {
             println!("{}", e);
@@ -7,6 +7,12 @@ use std::path::PathBuf;
 use tracing::info;
 
 use super::args::{Args, InteractiveMode, OutputFormat, WcagLevel};
+use crate::audit::baseline::WaiverSet;
+use crate::audit::device::DeviceProfile;
+use crate::browser::auth::{parse_header, AuthConfig, LoginStep};
+use crate::browser::throttle::CustomThrottleProfile;
+use crate::error::{AuditError, Result};
+use crate::wcag::DeclarativeRuleSet;
 
 const CONFIG_FILENAME: &str = "auditmysite.toml";
 
@@ -29,6 +35,70 @@ pub struct Config {
     pub budgets: BudgetConfig,
     #[serde(default)]
     pub interactive: InteractiveConfig,
+    /// Cookie jar, extra headers and login step for authenticated audits.
+    #[serde(default)]
+    pub auth: AuthConfig,
+    /// Waivers file for accepted violations.
+    #[serde(default)]
+    pub waivers: WaiversConfig,
+    /// Local audit history store.
+    #[serde(default)]
+    pub history: HistoryConfig,
+    /// Device profiles: `desktop` / `mobile` replace the built-in passes,
+    /// any other name adds a device to the matrix.
+    #[serde(default)]
+    pub devices: Vec<DeviceProfile>,
+    /// Additional network/CPU profiles measured next to the built-in ones.
+    #[serde(default)]
+    pub throttle_profiles: Vec<CustomThrottleProfile>,
+    /// URL scope and politeness for `--crawl`, link and sitemap checks.
+    #[serde(default)]
+    pub crawl: CrawlConfig,
+}
+
+/// `[crawl]` section: which discovered URLs a crawl visits and how fast.
+#[derive(Debug, Deserialize, Default, Clone)]
+pub struct CrawlConfig {
+    /// Only crawl URLs whose path (plus query) matches one of these. Globs
+    /// (`/docs/*`) or regexes prefixed with `re:`. Empty = everything.
+    #[serde(default)]
+    pub include: Vec<String>,
+    /// Never crawl URLs whose path (plus query) matches one of these.
+    #[serde(default)]
+    pub exclude: Vec<String>,
+    /// Query parameters removed from discovered URLs, as name globs
+    /// (`utm_*`, `fbclid`); `*` drops the whole query.
+    #[serde(default)]
+    pub strip_query_params: Vec<String>,
+    /// Sort the remaining query parameters so reordered duplicates collapse.
+    #[serde(default)]
+    pub sort_query_params: bool,
+    /// Request cap per host, shared by the crawl, link checks and sitemap
+    /// diagnostics. A longer robots.txt `Crawl-delay` still wins.
+    pub requests_per_second: Option<f64>,
+    /// Set to `false` to ignore robots.txt. Default: true.
+    pub respect_robots: Option<bool>,
+}
+
+/// `[waivers]` section: points at the file listing accepted violations.
+#[derive(Debug, Deserialize, Default, Clone)]
+pub struct WaiversConfig {
+    /// TOML (`[[waiver]]` tables) or JSON file. Relative paths resolve
+    /// against the directory containing `auditmysite.toml`.
+    pub file: Option<PathBuf>,
+}
+
+/// `[history]` section: where runs are recorded, or whether at all.
+#[derive(Debug, Deserialize, Default, Clone)]
+pub struct HistoryConfig {
+    /// History directory, e.g. a project-local `.auditmysite/history`.
+    /// Relative paths resolve against the directory containing
+    /// `auditmysite.toml`. Defaults to `~/.auditmysite/history`.
+    pub dir: Option<PathBuf>,
+    /// Set to `true` to record every run (same as `--history`).
+    pub enabled: Option<bool>,
+    /// Runs kept per page and per site (same as `--history-keep`).
+    pub keep: Option<usize>,
 }
 
 /// Accessibility-Journey-Layer configuration.
@@ -49,6 +119,8 @@ pub struct AuditConfig {
     pub timeout: Option<u64>,
     /// Number of concurrent browser tabs
     pub concurrency: Option<usize>,
+    /// Measurement runs per throttled performance profile
+    pub runs: Option<u32>,
 }
 
 #[derive(Debug, Deserialize, Default)]
@@ -81,6 +153,8 @@ pub struct RulesConfig {
     /// If non-empty, only run these rules (by axe_id)
     #[serde(default)]
     pub enabled_only: Vec<String>,
+    /// Declarative custom rules file (TOML or JSON), relative to this config
+    pub custom: Option<PathBuf>,
 }
 
 #[derive(Debug, Deserialize, Default)]
@@ -150,7 +224,23 @@ impl Config {
 
         let content = std::fs::read_to_string(&path).ok()?;
         match toml::from_str::<Config>(&content) {
-            Ok(config) => Some(config),
+            Ok(mut config) => {
+                if let Some(dir) = path.parent() {
+                    for file in [
+                        config.waivers.file.as_mut(),
+                        config.history.dir.as_mut(),
+                        config.rules.custom.as_mut(),
+                    ]
+                    .into_iter()
+                    .flatten()
+                    {
+                        if file.is_relative() {
+                            *file = dir.join(&*file);
+                        }
+                    }
+                }
+                Some(config)
+            }
             Err(e) => {
                 tracing::warn!("Failed to parse {}: {}", path.display(), e);
                 None
@@ -195,6 +285,15 @@ impl Config {
             }
         }
 
+        if let Some(runs) = self.audit.runs {
+            if args.runs.is_none() {
+                match runs {
+                    1..=20 => args.runs = Some(runs),
+                    _ => tracing::warn!("Invalid runs in config (1-20): {}", runs),
+                }
+            }
+        }
+
         // Output settings
         if let Some(ref fmt) = self.output.format {
             if args.format.is_none() {
@@ -240,6 +339,17 @@ impl Config {
                 args.mobile = true;
             }
         }
+
+        // History store
+        if args.history_dir.is_none() {
+            args.history_dir = self.history.dir.clone();
+        }
+        if self.history.enabled == Some(true) {
+            args.history = true;
+        }
+        if args.history_keep.is_none() {
+            args.history_keep = self.history.keep;
+        }
     }
 
     /// Returns the effective verdict config, merging the `[verdict]` section with the
@@ -255,6 +365,66 @@ impl Config {
     }
 }
 
+/// Merge the `[auth]` section with `--cookie-jar`, `--header` and
+/// `--login-script`. CLI values replace the jar and login step and extend
+/// (or override, by name) the configured headers.
+///
+/// Returns `None` when neither source configures authentication.
+pub fn resolve_auth(args: &Args, config: &Option<Config>) -> Result<Option<AuthConfig>> {
+    let mut auth = config.as_ref().map(|c| c.auth.clone()).unwrap_or_default();
+
+    if let Some(ref jar) = args.cookie_jar {
+        auth.cookie_jar = Some(jar.clone());
+    }
+    for raw in &args.headers {
+        let (name, value) = parse_header(raw).map_err(AuditError::ConfigError)?;
+        auth.headers.insert(name, value);
+    }
+    if let Some(ref path) = args.login_script {
+        let content = std::fs::read_to_string(path).map_err(|e| AuditError::FileError {
+            path: path.clone(),
+            reason: e.to_string(),
+        })?;
+        let login: LoginStep = toml::from_str(&content).map_err(|e| {
+            AuditError::ConfigError(format!("Invalid login script {}: {}", path.display(), e))
+        })?;
+        auth.login = Some(login);
+    }
+    // Extra headers only go to the audited origin (plus `header_origins`);
+    // batch runs add the origin of every URL they audit.
+    if let Some(ref url) = args.url {
+        auth.allow_header_origin(url);
+    }
+
+    Ok((!auth.is_empty()).then_some(auth))
+}
+
+/// Load the waivers file named in `[waivers]`, or an empty set when none is
+/// configured. A configured but unreadable or invalid file is an error.
+pub fn resolve_waivers(config: &Option<Config>) -> Result<WaiverSet> {
+    match config.as_ref().and_then(|c| c.waivers.file.as_deref()) {
+        Some(path) => WaiverSet::load(path),
+        None => Ok(WaiverSet::default()),
+    }
+}
+
+/// Load the declarative rules file named by `--custom-rules` or
+/// `[rules] custom`, or an empty set when none is configured. A named but
+/// unreadable or invalid file is an error.
+pub fn load_custom_rules(args: &Args, config: &Option<Config>) -> Result<DeclarativeRuleSet> {
+    let configured = config.as_ref().and_then(|c| c.rules.custom.as_deref());
+    match args.custom_rules.as_deref().or(configured) {
+        Some(path) => DeclarativeRuleSet::load(path),
+        None => Ok(DeclarativeRuleSet::default()),
+    }
+}
+
+/// Register the declarative rules file named by `--custom-rules` or
+/// `[rules] custom`, if any.
+pub fn resolve_custom_rules(args: &Args, config: &Option<Config>) -> Result<()> {
+    load_custom_rules(args, config)?.register()
+}
+
 /// Search for config file starting from current dir, walking up to root.
 fn find_config_file() -> Option<PathBuf> {
     let mut dir = std::env::current_dir().ok()?;
@@ -391,4 +561,169 @@ seo = true
         assert!(config.modules.seo.unwrap());
         assert!(config.modules.performance.is_none());
     }
+
+    #[test]
+    fn test_auth_config_merges_cli_headers() {
+        let config: Config = toml::from_str(
+            r##"
+[auth.headers]
+Authorization = "env:STAGING_TOKEN"
+X-Env = "staging"
+
+[auth.login]
+url = "https://example.com/login"
+submit = "button[type=submit]"
+
+[[auth.login.fields]]
+selector = "#user"
+value = "env:AUDIT_USER"
+"##,
+        )
+        .unwrap();
+        let args = Args::parse_from([
+            "auditmysite",
+            "https://example.com",
+            "--header",
+            "X-Env: preview",
+        ]);
+
+        let auth = resolve_auth(&args, &Some(config)).unwrap().unwrap();
+
+        assert_eq!(
+            auth.headers.get("X-Env").map(String::as_str),
+            Some("preview")
+        );
+        assert!(auth.headers.contains_key("Authorization"));
+        assert_eq!(auth.login.unwrap().fields.len(), 1);
+    }
+
+    #[test]
+    fn test_no_auth_resolves_to_none() {
+        let args = Args::parse_from(["auditmysite", "https://example.com"]);
+        assert!(resolve_auth(&args, &None).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_missing_waivers_file_is_an_error() {
+        let config: Config = toml::from_str(
+            r#"
+[waivers]
+file = "/nonexistent/auditmysite-waivers.toml"
+"#,
+        )
+        .unwrap();
+        assert!(resolve_waivers(&Some(config)).is_err());
+        assert!(resolve_waivers(&None).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_history_section_applies_to_args() {
+        let config: Config = toml::from_str(
+            r#"
+[history]
+dir = "/tmp/project-history"
+enabled = true
+keep = 5
+"#,
+        )
+        .unwrap();
+        let mut args = Args::parse_from(["auditmysite", "https://example.com"]);
+        config.apply_to_args_with_sources(&mut args, false);
+        assert_eq!(
+            args.history_dir.as_deref(),
+            Some(std::path::Path::new("/tmp/project-history"))
+        );
+        assert!(args.history);
+        assert_eq!(args.history_keep, Some(5));
+    }
+
+    #[test]
+    fn test_devices_section_builds_matrix() {
+        let config: Config = toml::from_str(
+            r#"
+[[devices]]
+name = "tablet"
+width = 820
+height = 1180
+dpr = 2.0
+touch = true
+
+[[devices]]
+name = "desktop"
+width = 3840
+height = 2160
+"#,
+        )
+        .unwrap();
+        let args = Args::parse_from(["auditmysite", "https://example.com"]);
+        let pipeline =
+            crate::audit::PipelineConfig::from_args_and_config(&args, Some(&config)).unwrap();
+        assert_eq!(pipeline.devices.desktop.width, 3840);
+        assert_eq!(pipeline.devices.extra.len(), 1);
+        assert_eq!(pipeline.devices.extra[0].device_scale_factor, 2.0);
+        assert!(pipeline.audit_signature().contains("tablet:820x1180@2"));
+    }
+
+    #[test]
+    fn test_invalid_devices_or_throttle_profiles_are_config_errors() {
+        let args = Args::parse_from(["auditmysite", "https://example.com"]);
+        let devices: Config = toml::from_str(
+            r#"
+[[devices]]
+name = "tablet"
+width = 0
+height = 1180
+"#,
+        )
+        .unwrap();
+        assert!(matches!(
+            crate::audit::PipelineConfig::from_args_and_config(&args, Some(&devices)),
+            Err(crate::error::AuditError::ConfigError(_))
+        ));
+
+        let throttle: Config = toml::from_str(
+            r#"
+[[throttle_profiles]]
+name = "fast-3g"
+download_kbps = 1000
+upload_kbps = 500
+"#,
+        )
+        .unwrap();
+        assert!(matches!(
+            crate::audit::PipelineConfig::from_args_and_config(&args, Some(&throttle)),
+            Err(crate::error::AuditError::ConfigError(_))
+        ));
+    }
+
+    #[test]
+    fn test_runs_and_throttle_profiles_reach_pipeline() {
+        let config: Config = toml::from_str(
+            r#"
+[audit]
+runs = 5
+
+[[throttle_profiles]]
+name = "cable"
+download_kbps = 20000
+upload_kbps = 5000
+"#,
+        )
+        .unwrap();
+        let mut args = Args::parse_from(["auditmysite", "https://example.com"]);
+        config.apply_to_args(&mut args);
+        assert_eq!(args.runs, Some(5));
+
+        let pipeline =
+            crate::audit::PipelineConfig::from_args_and_config(&args, Some(&config)).unwrap();
+        assert_eq!(pipeline.perf_runs, 5);
+        let cable = pipeline.throttle_passes.last().unwrap();
+        assert_eq!(cable.label(), "cable");
+        assert_eq!(cable.conditions.cpu_slowdown, 1.0);
+
+        // The CLI flag wins over the config value.
+        let mut args = Args::parse_from(["auditmysite", "https://example.com", "--runs", "2"]);
+        config.apply_to_args(&mut args);
+        assert_eq!(args.runs, Some(2));
+    }
 }
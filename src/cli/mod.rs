@@ -7,7 +7,8 @@ pub mod config;
 pub mod doctor;
 
 pub use args::{
-    AnnexKind, Args, BrowserAction, Command, InteractiveMode, OutputFormat, ReportLevel,
-    ReportLintFailOn, RequestMode, WcagLevel,
+    AnnexKind, Args, BrowserAction, Command, DiffFormat, HistoryFormat, InteractiveMode,
+    OutputFormat, ReportLevel, ReportLintFailOn, RequestMode, StatementFormat, VpatEdition,
+    WcagLevel,
 };
 pub use config::Config;
@@ -103,6 +103,17 @@ pub fn default_screen_reader_json_output_path(primary_path: &Path) -> PathBuf {
     path
 }
 
+/// `--source-root` patch file written next to the primary report.
+pub fn default_fix_patch_output_path(primary_path: &Path) -> PathBuf {
+    let mut path = primary_path.to_path_buf();
+    let stem = primary_path
+        .file_stem()
+        .and_then(|stem| stem.to_str())
+        .unwrap_or("audit-report");
+    path.set_file_name(format!("{stem}-fixes.patch"));
+    path
+}
+
 /// Directory used for per-page batch output files.
 pub fn per_page_output_directory(args: &Args) -> PathBuf {
     match args.output.as_ref() {
@@ -128,6 +139,16 @@ pub fn per_page_output_path(
         OutputFormat::Ai => PathBuf::from(format!("{subject}-{date}-single-report-ai.json")),
         OutputFormat::Summary => PathBuf::from(format!("{subject}-{date}-summary.json")),
         OutputFormat::Sarif => PathBuf::from(format!("{subject}-{date}-single-report.sarif")),
+        OutputFormat::Html => PathBuf::from(format!("{subject}-{date}-single-report.html")),
+        OutputFormat::Junit => PathBuf::from(format!("{subject}-{date}-single-report.junit.xml")),
+        OutputFormat::GitlabCodequality => {
+            PathBuf::from(format!("{subject}-{date}-single-report.codequality.json"))
+        }
+        OutputFormat::Csv => PathBuf::from(format!("{subject}-{date}-single-report.csv")),
+        OutputFormat::Markdown => PathBuf::from(format!("{subject}-{date}-single-report.md")),
+        OutputFormat::Earl => PathBuf::from(format!("{subject}-{date}-single-report.earl.jsonld")),
+        OutputFormat::Vpat => PathBuf::from(format!("{subject}-{date}-vpat.md")),
+        OutputFormat::VpatJson => PathBuf::from(format!("{subject}-{date}-vpat.json")),
     };
     match filename.file_name() {
         Some(name) => base_dir.join(name),
@@ -246,6 +267,15 @@ mod tests {
         );
     }
 
+    #[test]
+    fn default_fix_patch_output_path_adds_suffix() {
+        let json = Path::new("reports/casoon-2026-01-01-single-report.json");
+        assert_eq!(
+            default_fix_patch_output_path(json),
+            PathBuf::from("reports/casoon-2026-01-01-single-report-fixes.patch")
+        );
+    }
+
     #[test]
     fn atomic_write_replaces_complete_files_without_leaving_partial_output() {
         let directory = tempfile::tempdir().unwrap();
@@ -107,7 +107,15 @@ pub fn planned_single_outputs(args: &Args, url: &str) -> Vec<String> {
         | OutputFormat::Ai
         | OutputFormat::Table
         | OutputFormat::Summary
-        | OutputFormat::Sarif => {
+        | OutputFormat::Sarif
+        | OutputFormat::Html
+        | OutputFormat::Junit
+        | OutputFormat::GitlabCodequality
+        | OutputFormat::Csv
+        | OutputFormat::Markdown
+        | OutputFormat::Earl
+        | OutputFormat::Vpat
+        | OutputFormat::VpatJson => {
             let mut outputs = match args.output.as_ref() {
                 Some(path) => vec![path.display().to_string()],
                 None => vec!["stdout".to_string()],
@@ -145,7 +153,15 @@ pub fn planned_batch_outputs(args: &Args) -> Vec<String> {
         | OutputFormat::Ai
         | OutputFormat::Table
         | OutputFormat::Summary
-        | OutputFormat::Sarif => match args.output.as_ref() {
+        | OutputFormat::Sarif
+        | OutputFormat::Html
+        | OutputFormat::Junit
+        | OutputFormat::GitlabCodequality
+        | OutputFormat::Csv
+        | OutputFormat::Markdown
+        | OutputFormat::Earl
+        | OutputFormat::Vpat
+        | OutputFormat::VpatJson => match args.output.as_ref() {
             Some(path) => vec![path.display().to_string()],
             None => vec!["stdout".to_string()],
         },
@@ -155,7 +171,11 @@ pub fn planned_batch_outputs(args: &Args) -> Vec<String> {
 // ─── Module label ─────────────────────────────────────────────────────────────
 
 pub fn active_modules_label(args: &Args) -> String {
-    PipelineConfig::from(args).active_module_labels().join(", ")
+    // Module flags come from `args` alone; invalid `[[devices]]` or
+    // `[[throttle_profiles]]` are reported when the run builds its pipeline.
+    PipelineConfig::try_from(args)
+        .map(|config| config.active_module_labels().join(", "))
+        .unwrap_or_default()
 }
 
 // ─── Tests ────────────────────────────────────────────────────────────────────
@@ -5,24 +5,31 @@
 
 use colored::Colorize;
 
+use auditmysite::accessibility::suggest_source_patches;
 use auditmysite::audit::normalize;
+use auditmysite::audit::normalized::NormalizedReport;
 use auditmysite::audit::VerdictResult;
 use auditmysite::cli::{Args, OutputFormat};
 use auditmysite::error::{AuditError, Result};
 #[cfg(feature = "pdf")]
 use auditmysite::output::report_model::ReportConfig;
 use auditmysite::output::{
-    export_snapshot_yaml, export_sr_audit, format_ai_json, format_batch_table, format_sarif,
-    format_summary, print_batch_table, print_report, UnifiedReport,
+    export_playwright_spec, export_snapshot_yaml, export_sr_audit, format_ai_json,
+    format_batch_table, format_csv, format_earl, format_gitlab_codequality, format_html,
+    format_html_batch, format_junit, format_junit_batch, format_markdown, format_markdown_batch,
+    format_sarif, format_summary, format_vpat_json, format_vpat_markdown, print_batch_table,
+    print_report, UnifiedReport,
 };
 #[cfg(feature = "pdf")]
 use auditmysite::output::{generate_batch_pdf, generate_batch_typ, generate_pdf, generate_typ};
+use auditmysite::wcag::vpat::derive_vpat;
 
 #[cfg(feature = "pdf")]
-use crate::output_paths::output_bytes;
+use crate::output_paths::default_single_json_output_path;
 #[cfg(feature = "pdf")]
-use crate::output_paths::{default_batch_pdf_output_path, default_single_json_output_path};
+use crate::output_paths::output_bytes;
 use crate::output_paths::{
+    default_batch_pdf_output_path, default_fix_patch_output_path,
     default_screen_reader_json_output_path, default_single_pdf_output_path, output_text,
     per_page_output_directory, per_page_output_path,
 };
@@ -73,6 +80,7 @@ pub fn output_single_report(
                     logo_path: args.logo.clone(),
                     locale: args.lang.clone(),
                     annex: args.annex,
+                    history: load_trend_history(report.url.as_str(), args),
                 };
                 let pdf_bytes = generate_pdf(report, &config).map_err(|e| {
                     AuditError::ReportGenerationFailed {
@@ -135,11 +143,175 @@ pub fn output_single_report(
                 })?;
             output_text(&output, &args.output, "SARIF", args.quiet)?;
         }
+        OutputFormat::Html => {
+            let output = format_html(report)?;
+            output_text(&output, &args.output, "HTML", args.quiet)?;
+        }
+        OutputFormat::Junit => {
+            let output = format_junit(report);
+            output_text(&output, &args.output, "JUnit XML", args.quiet)?;
+        }
+        OutputFormat::GitlabCodequality => {
+            let normalized = normalize(report);
+            let output = format_gitlab_codequality(&[&normalized.normalized]).map_err(|e| {
+                AuditError::OutputError {
+                    reason: e.to_string(),
+                }
+            })?;
+            output_text(&output, &args.output, "GitLab Code Quality", args.quiet)?;
+        }
+        OutputFormat::Csv => {
+            let normalized = normalize(report);
+            let output = format_csv(&[&normalized.normalized]);
+            output_text(&output, &args.output, "CSV", args.quiet)?;
+        }
+        OutputFormat::Markdown => {
+            let baseline = read_compare_baseline(args)?;
+            let output = format_markdown(report, verdict, baseline.as_deref())?;
+            output_text(&output, &args.output, "Markdown", args.quiet)?;
+        }
+        OutputFormat::Earl => {
+            let normalized = normalize(report);
+            let output =
+                format_earl(&[&normalized.normalized]).map_err(|e| AuditError::OutputError {
+                    reason: e.to_string(),
+                })?;
+            output_text(&output, &args.output, "EARL", args.quiet)?;
+        }
+        OutputFormat::Vpat | OutputFormat::VpatJson => {
+            let normalized = normalize(report);
+            output_vpat(&[&normalized.normalized], args)?;
+        }
     }
     output_screen_reader_sidecar(report, args)?;
+    output_playwright_spec(report, args)?;
+    if args.source_root.is_some() {
+        let normalized = normalize(report);
+        let primary_output_path = args.output.clone().unwrap_or_else(|| {
+            default_single_pdf_output_path(report.url.as_str(), args.report_level)
+        });
+        output_source_patches(&[&normalized.normalized], &primary_output_path, args)?;
+    }
     Ok(())
 }
 
+/// `--source-root`: write attribute fixes located in the local templates as
+/// a `git apply`-able patch next to the primary report.
+fn output_source_patches(
+    reports: &[&NormalizedReport],
+    primary_output_path: &std::path::Path,
+    args: &Args,
+) -> Result<()> {
+    let Some(root) = args.source_root.as_ref() else {
+        return Ok(());
+    };
+    let patches = suggest_source_patches(reports, root)?;
+    if patches.is_empty() {
+        if !args.quiet {
+            println!(
+                "{} No fix could be mapped to a template under {} ({} unmatched)",
+                "Info:".cyan().bold(),
+                root.display(),
+                patches.unmatched
+            );
+        }
+        return Ok(());
+    }
+    let path = default_fix_patch_output_path(primary_output_path);
+    if let Some(dir) = path.parent().filter(|d| !d.as_os_str().is_empty()) {
+        std::fs::create_dir_all(dir)?;
+    }
+    std::fs::write(&path, patches.to_unified_diff()).map_err(|e| AuditError::FileError {
+        path: path.clone(),
+        reason: e.to_string(),
+    })?;
+    if !args.quiet {
+        println!(
+            "{} {} fix suggestion(s) saved to {} ({} unmatched) — review before `git apply`",
+            "Done:".green().bold(),
+            patches.suggestions.len(),
+            path.display(),
+            patches.unmatched
+        );
+    }
+    Ok(())
+}
+
+/// `--export-playwright`: write the page's journey regression spec.
+pub(crate) fn output_playwright_spec(report: &auditmysite::AuditReport, args: &Args) -> Result<()> {
+    let Some(dir) = args.export_playwright.as_ref() else {
+        return Ok(());
+    };
+    let written = export_playwright_spec(report, dir).map_err(|e| AuditError::OutputError {
+        reason: format!("Playwright spec export failed: {e}"),
+    })?;
+    if !args.quiet {
+        match written {
+            Some(path) => println!(
+                "{} Playwright spec saved to {}",
+                "Done:".green().bold(),
+                path.display()
+            ),
+            None => println!(
+                "{} No replayable journeys for {}; no Playwright spec written",
+                "Info:".cyan().bold(),
+                report.url
+            ),
+        }
+    }
+    Ok(())
+}
+
+/// Draft VPAT over the audited pages, as Markdown or JSON per `-f`.
+fn output_vpat(reports: &[&NormalizedReport], args: &Args) -> Result<()> {
+    let vpat = derive_vpat(reports, args.vpat_edition.unwrap_or_default());
+    if args.effective_format() == OutputFormat::VpatJson {
+        let output = format_vpat_json(&vpat).map_err(|e| AuditError::OutputError {
+            reason: e.to_string(),
+        })?;
+        output_text(&output, &args.output, "VPAT JSON", args.quiet)
+    } else {
+        output_text(
+            &format_vpat_markdown(&vpat),
+            &args.output,
+            "VPAT",
+            args.quiet,
+        )
+    }
+}
+
+/// Text of the `--compare` baseline report, if one was given.
+fn read_compare_baseline(args: &Args) -> Result<Option<String>> {
+    args.compare
+        .as_ref()
+        .map(|path| {
+            std::fs::read_to_string(path).map_err(|e| AuditError::FileError {
+                path: path.clone(),
+                reason: e.to_string(),
+            })
+        })
+        .transpose()
+}
+
+/// History entries for the `--trend` chart; empty when the flag is off or the
+/// store cannot be read (the chart is then simply omitted).
+#[cfg(feature = "pdf")]
+fn load_trend_history(url: &str, args: &Args) -> Vec<auditmysite::audit::HistoryEntry> {
+    if !args.trend {
+        return Vec::new();
+    }
+    let Some(store) = auditmysite::audit::HistoryStore::open(args.history_dir.as_deref()) else {
+        return Vec::new();
+    };
+    match store.trend(url, 0) {
+        Ok(trend) => trend.entries,
+        Err(e) => {
+            tracing::warn!("Could not load audit history for trend chart: {}", e);
+            Vec::new()
+        }
+    }
+}
+
 pub(crate) fn output_screen_reader_sidecar(
     report: &auditmysite::AuditReport,
     args: &Args,
@@ -194,6 +366,7 @@ pub fn output_batch_report(
                     logo_path: args.logo.clone(),
                     locale: args.lang.clone(),
                     annex: args.annex,
+                    history: Vec::new(),
                 };
                 let pdf_bytes = generate_batch_pdf(batch_report, &config).map_err(|e| {
                     AuditError::ReportGenerationFailed {
@@ -258,6 +431,62 @@ pub fn output_batch_report(
             })?;
             output_text(&output, &args.output, "SARIF batch", args.quiet)?;
         }
+        OutputFormat::Html => {
+            let output = format_html_batch(batch_report)?;
+            output_text(&output, &args.output, "HTML batch", args.quiet)?;
+        }
+        OutputFormat::Junit => {
+            let output = format_junit_batch(batch_report);
+            output_text(&output, &args.output, "JUnit XML batch", args.quiet)?;
+        }
+        OutputFormat::GitlabCodequality => {
+            let normalized_reports: Vec<_> = batch_report.reports.iter().map(normalize).collect();
+            let refs: Vec<_> = normalized_reports.iter().map(|n| &n.normalized).collect();
+            let output = format_gitlab_codequality(&refs).map_err(|e| AuditError::OutputError {
+                reason: e.to_string(),
+            })?;
+            output_text(
+                &output,
+                &args.output,
+                "GitLab Code Quality batch",
+                args.quiet,
+            )?;
+        }
+        OutputFormat::Csv => {
+            let normalized_reports: Vec<_> = batch_report.reports.iter().map(normalize).collect();
+            let refs: Vec<_> = normalized_reports.iter().map(|n| &n.normalized).collect();
+            output_text(&format_csv(&refs), &args.output, "CSV batch", args.quiet)?;
+        }
+        OutputFormat::Markdown => {
+            let baseline = read_compare_baseline(args)?;
+            let output = format_markdown_batch(batch_report, verdict, baseline.as_deref())?;
+            output_text(&output, &args.output, "Markdown batch", args.quiet)?;
+        }
+        OutputFormat::Earl => {
+            let normalized_reports: Vec<_> = batch_report.reports.iter().map(normalize).collect();
+            let refs: Vec<_> = normalized_reports.iter().map(|n| &n.normalized).collect();
+            let output = format_earl(&refs).map_err(|e| AuditError::OutputError {
+                reason: e.to_string(),
+            })?;
+            output_text(&output, &args.output, "EARL batch", args.quiet)?;
+        }
+        OutputFormat::Vpat | OutputFormat::VpatJson => {
+            let normalized_reports: Vec<_> = batch_report.reports.iter().map(normalize).collect();
+            let refs: Vec<_> = normalized_reports.iter().map(|n| &n.normalized).collect();
+            output_vpat(&refs, args)?;
+        }
+    }
+    for report in &batch_report.reports {
+        output_playwright_spec(report, args)?;
+    }
+    if args.source_root.is_some() {
+        let normalized_reports: Vec<_> = batch_report.reports.iter().map(normalize).collect();
+        let refs: Vec<_> = normalized_reports.iter().map(|n| &n.normalized).collect();
+        let primary_output_path = args
+            .output
+            .clone()
+            .unwrap_or_else(|| default_batch_pdf_output_path(args));
+        output_source_patches(&refs, &primary_output_path, args)?;
     }
     Ok(())
 }
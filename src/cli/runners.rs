@@ -4,6 +4,7 @@
 //! plus the interactive sitemap-suggestion flow. Extracted from main.rs.
 
 use std::io::{self, IsTerminal};
+use std::path::PathBuf;
 use std::sync::Arc;
 
 use colored::Colorize;
@@ -14,11 +15,14 @@ use tracing::info;
 use auditmysite::audit::normalize;
 use auditmysite::audit::{
     analyze_crawl_links, analyze_sitemap_diagnostics, cache_matches_signature,
-    compute_batch_verdict, compute_verdict, crawl_site, hydrate_cached_report, load_artifacts,
-    parse_sitemap, read_url_file, run_concurrent_batch, run_single_audit, to_audit_report,
-    BatchConfig, CrawlResult, PipelineConfig, Verdict,
+    compute_batch_verdict, compute_verdict, crawl_site, extrapolate_template_findings,
+    hydrate_cached_report, load_artifacts, merge_shards, parse_sitemap, plan_template_sample,
+    read_url_file, run_concurrent_batch, run_rendered_crawl_batch, run_single_audit,
+    to_audit_report, BatchConfig, BatchReport, CrawlPolicy, CrawlResult, HistoryStore,
+    NormalizedReport, PipelineConfig, ShardReport, Verdict, DEFAULT_HISTORY_KEEP,
 };
 use auditmysite::browser::{BrowserManager, BrowserOptions};
+use auditmysite::cli::config::{load_custom_rules, resolve_auth, resolve_waivers};
 use auditmysite::cli::{Args, OutputFormat, RequestMode};
 use auditmysite::error::{AuditError, Result};
 use auditmysite::util::truncate_url;
@@ -58,8 +62,12 @@ pub async fn run_single_mode(
 
     info!("Starting audit for: {}", url);
 
+    let mut pipeline_config = PipelineConfig::try_from(args)?;
+    pipeline_config.waivers = resolve_waivers(config)?;
+    pipeline_config.custom_rules = load_custom_rules(args, config)?;
+
     if args.reuse_cache && !args.force_refresh {
-        let expected_signature = PipelineConfig::from(args).audit_signature();
+        let expected_signature = pipeline_config.audit_signature();
         match load_artifacts(url)? {
             Some(cached) if cache_matches_signature(&cached.meta, &expected_signature) => {
                 if !args.quiet {
@@ -103,7 +111,15 @@ pub async fn run_single_mode(
                     | OutputFormat::Pdf
                     | OutputFormat::Ai
                     | OutputFormat::Summary
-                    | OutputFormat::Sarif => {
+                    | OutputFormat::Sarif
+                    | OutputFormat::Html
+                    | OutputFormat::Junit
+                    | OutputFormat::GitlabCodequality
+                    | OutputFormat::Csv
+                    | OutputFormat::Markdown
+                    | OutputFormat::Earl
+                    | OutputFormat::Vpat
+                    | OutputFormat::VpatJson => {
                         output_single_report(&report, args, Some(&verdict_result))?;
                     }
                 }
@@ -123,18 +139,7 @@ pub async fn run_single_mode(
         }
     }
 
-    let browser_options = BrowserOptions {
-        chrome_path: args.chrome_path.clone(),
-        headless: true,
-        disable_gpu: true,
-        no_sandbox: args.no_sandbox,
-        disable_images: args.disable_images,
-        window_size: (1920, 1080),
-        timeout_secs: args.effective_timeout(),
-        verbose: args.verbose,
-        user_agent_override: (args.request_mode == RequestMode::Bot)
-            .then(|| BOT_USER_AGENT.to_string()),
-    };
+    let browser_options = single_browser_options(args, config)?;
 
     if !args.quiet {
         println!("{}", "Starting browser...".dimmed());
@@ -151,7 +156,6 @@ pub async fn run_single_mode(
         println!("{} {}", "Auditing:".cyan().bold(), url);
     }
 
-    let pipeline_config = PipelineConfig::from(args);
     let audit_result = run_single_audit(url, &browser, &pipeline_config).await;
     let close_result = browser.close().await;
     let mut report = audit_result?;
@@ -195,6 +199,7 @@ pub async fn run_single_mode(
     }
 
     let normalized = normalize(&report).normalized;
+    record_single_history(&normalized, args);
     let verdict_cfg = config
         .as_ref()
         .map(|c| c.effective_verdict_config())
@@ -205,6 +210,26 @@ pub async fn run_single_mode(
     Ok(verdict_result.verdict)
 }
 
+/// Browser launch options for single-page runs (also used by watch mode).
+pub(crate) fn single_browser_options(
+    args: &Args,
+    config: &Option<auditmysite::cli::Config>,
+) -> Result<BrowserOptions> {
+    Ok(BrowserOptions {
+        chrome_path: args.chrome_path.clone(),
+        headless: true,
+        disable_gpu: true,
+        no_sandbox: args.no_sandbox,
+        disable_images: args.disable_images,
+        window_size: (1920, 1080),
+        timeout_secs: args.effective_timeout(),
+        verbose: args.verbose,
+        user_agent_override: (args.request_mode == RequestMode::Bot)
+            .then(|| BOT_USER_AGENT.to_string()),
+        auth: resolve_auth(args, config)?,
+    })
+}
+
 async fn maybe_offer_sitemap_scan(
     args: &Args,
     url: &str,
@@ -325,7 +350,22 @@ pub async fn run_batch_mode(
     args: &Args,
     config: &Option<auditmysite::cli::Config>,
 ) -> Result<Verdict> {
+    if args.effective_runs() > 1 {
+        return Err(AuditError::ConfigError(
+            "--runs only applies to single-page audits; batch runs measure each page once"
+                .to_string(),
+        ));
+    }
+    if args.trend && !args.per_page_reports {
+        return Err(AuditError::ConfigError(
+            "--trend draws a per-URL chart and needs --per-page-reports in batch mode".to_string(),
+        ));
+    }
+
     let mut crawl_result: Option<CrawlResult> = None;
+    // One policy (and rate limiter) for the crawl, link checks and sitemap
+    // diagnostics of this run.
+    let mut crawl_policy = CrawlPolicy::new(config.as_ref().map(|c| &c.crawl), args.request_mode)?;
 
     let url_source: &str;
     let urls = if let Some(ref sitemap_url) = args.sitemap {
@@ -340,21 +380,36 @@ pub async fn run_batch_mode(
             .url
             .as_deref()
             .ok_or_else(|| AuditError::ConfigError("No crawl seed URL specified".to_string()))?;
-        if !args.quiet {
-            println!("{} {}", "Crawling site:".cyan().bold(), seed_url);
-        }
-        let crawl = crawl_site(seed_url, args.max_pages, args.crawl_depth).await?;
-        if !args.quiet {
-            println!(
-                "{} {} pages discovered at depth <= {}",
-                "Discovered:".cyan().bold(),
-                crawl.pages.len(),
-                args.crawl_depth
-            );
+        crawl_policy.load_robots(seed_url).await;
+        if args.crawl_render {
+            // Links are discovered while auditing; the queue starts at the seed.
+            if !args.quiet {
+                println!(
+                    "{} {} (rendered DOM, depth <= {})",
+                    "Crawling site:".cyan().bold(),
+                    seed_url,
+                    args.crawl_depth
+                );
+            }
+            vec![seed_url.to_string()]
+        } else {
+            if !args.quiet {
+                println!("{} {}", "Crawling site:".cyan().bold(), seed_url);
+            }
+            let crawl =
+                crawl_site(seed_url, args.max_pages, args.crawl_depth, &crawl_policy).await?;
+            if !args.quiet {
+                println!(
+                    "{} {} pages discovered at depth <= {}",
+                    "Discovered:".cyan().bold(),
+                    crawl.pages.len(),
+                    args.crawl_depth
+                );
+            }
+            let urls = crawl.urls();
+            crawl_result = Some(crawl);
+            urls
         }
-        let urls = crawl.urls();
-        crawl_result = Some(crawl);
-        urls
     } else if let Some(ref url_file) = args.url_file {
         url_source = "url_file";
         if !args.quiet {
@@ -378,11 +433,76 @@ pub async fn run_batch_mode(
         return Ok(Verdict::Warn);
     }
 
+    let rendered_crawl = url_source == "crawl" && args.crawl_render;
     let total_discovered = urls.len();
+
+    // Cloned before `urls` is sampled and moved into `run_concurrent_batch`
+    // below. Must stay the *full* discovered list, not just the audited
+    // sample: sitemap diagnostics compares it against crawled internal links,
+    // and a sampled subset floods `linked_not_in_sitemap` with pages that are
+    // genuinely in the sitemap, just outside the sample (#514).
+    let mut full_sitemap_urls: Vec<String> = if url_source == "sitemap" {
+        urls.clone()
+    } else {
+        Vec::new()
+    };
+
+    let template_plan = match args.template_sample {
+        Some(per_cluster) => {
+            if !args.quiet {
+                println!(
+                    "{} clustering {} URLs by template",
+                    "Template sampling:".cyan().bold(),
+                    total_discovered
+                );
+            }
+            let plan = plan_template_sample(&urls, per_cluster, &crawl_policy.limiter).await;
+            if !args.quiet {
+                println!(
+                    "{} {} templates, {} sampled, {} of {} URLs selected",
+                    "Template sampling:".cyan().bold(),
+                    plan.clusters.len(),
+                    plan.clusters.iter().filter(|c| c.sampled).count(),
+                    plan.selected.len(),
+                    total_discovered
+                );
+            }
+            Some(plan)
+        }
+        None => None,
+    };
+    let urls = match &template_plan {
+        Some(plan) => plan.selected.clone(),
+        None => urls,
+    };
+
+    // A shard keeps the full list for `merge` and audits its own slice. Its
+    // sitemap HTTP checks cover the slice too; `merge` recomputes the link
+    // graph over all shards.
+    let (urls, shard_input) = match args.shard {
+        Some(shard) => {
+            let selected = shard.select(&urls);
+            if !args.quiet {
+                println!(
+                    "{} {} — {} of {} URLs",
+                    "Shard:".cyan().bold(),
+                    shard,
+                    selected.len(),
+                    total_discovered
+                );
+            }
+            if url_source == "sitemap" {
+                full_sitemap_urls = selected.clone();
+            }
+            (selected, Some((shard, urls)))
+        }
+        None => (urls, None),
+    };
+
     let total_urls = if args.max_pages > 0 {
-        args.max_pages.min(total_discovered)
+        args.max_pages.min(urls.len())
     } else {
-        total_discovered
+        urls.len()
     };
 
     let sample = auditmysite::audit::SampleMetadata {
@@ -390,7 +510,11 @@ pub async fn run_batch_mode(
         total_discovered,
         audited: total_urls,
         sample_limit: (args.max_pages > 0).then_some(args.max_pages),
-        selection: if total_urls < total_discovered {
+        selection: if template_plan.is_some() {
+            "template".to_string()
+        } else if shard_input.is_some() {
+            "shard".to_string()
+        } else if total_urls < total_discovered {
             "first_n".to_string()
         } else {
             "all".to_string()
@@ -398,19 +522,8 @@ pub async fn run_batch_mode(
         is_sample: total_urls < total_discovered,
     };
 
-    // Cloned before `urls` is moved into `run_concurrent_batch` below. Must
-    // stay the *full* discovered list, not just the audited sample: sitemap
-    // diagnostics compares it against crawled internal links, and a sampled
-    // subset floods `linked_not_in_sitemap` with pages that are genuinely in
-    // the sitemap, just outside the sample (#514).
-    let full_sitemap_urls: Vec<String> = if url_source == "sitemap" {
-        urls.clone()
-    } else {
-        Vec::new()
-    };
-
     if !args.quiet {
-        if sample.is_sample {
+        if sample.is_sample && template_plan.is_none() && shard_input.is_none() {
             println!(
                 "{} auditing {} of {} discovered URLs ({} order, first {})",
                 "Sample:".yellow().bold(),
@@ -420,16 +533,44 @@ pub async fn run_batch_mode(
                 total_urls
             );
         }
-        println!(
-            "{} {} URLs with {} parallel workers\n",
-            "Auditing:".cyan().bold(),
-            total_urls,
-            args.effective_concurrency()
-        );
+        if rendered_crawl {
+            println!(
+                "{} pages as they are discovered with {} parallel workers\n",
+                "Auditing:".cyan().bold(),
+                args.effective_concurrency()
+            );
+        } else {
+            println!(
+                "{} {} URLs with {} parallel workers\n",
+                "Auditing:".cyan().bold(),
+                total_urls,
+                args.effective_concurrency()
+            );
+        }
         print_batch_audit_plan(args, total_urls);
     }
 
-    let batch_config = BatchConfig::from(args);
+    let mut batch_config = BatchConfig::try_from(args)?;
+    batch_config.pool_config.browser_options.auth = resolve_auth(args, config)?;
+    if let Some(auth) = batch_config.pool_config.browser_options.auth.as_mut() {
+        for url in &urls {
+            auth.allow_header_origin(url);
+        }
+    }
+    batch_config.pipeline.waivers = resolve_waivers(config)?;
+    batch_config.pipeline.custom_rules = load_custom_rules(args, config)?;
+    if let (Some(dir), false) = (&batch_config.run_dir, args.quiet) {
+        println!(
+            "{} {}{}",
+            "Checkpoints:".cyan().bold(),
+            dir.display(),
+            if args.resume.is_some() {
+                " (resuming)"
+            } else {
+                ""
+            }
+        );
+    }
 
     let progress_bar = if !args.quiet {
         let pb = ProgressBar::new(total_urls as u64);
@@ -448,7 +589,9 @@ pub async fn run_batch_mode(
     let progress: Option<Arc<dyn Fn(usize, usize, &str, Option<&str>) + Send + Sync>> =
         if let Some(ref pb) = progress_bar {
             let pb_clone = pb.clone();
-            Some(Arc::new(move |current, _total, url, error| {
+            Some(Arc::new(move |current, total, url, error| {
+                // A rendered crawl learns its page count while it runs.
+                pb_clone.set_length(total as u64);
                 pb_clone.set_position(current as u64);
                 if let Some(err) = error {
                     pb_clone.println(format!("  ✗ {url}\n    {err}"));
@@ -460,12 +603,79 @@ pub async fn run_batch_mode(
             None
         };
 
-    let mut batch_report = run_concurrent_batch(urls, &batch_config, progress).await?;
-    batch_report = batch_report.with_sample(sample);
+    let mut batch_report = if rendered_crawl {
+        let (report, crawl) = run_rendered_crawl_batch(
+            &urls[0],
+            args.crawl_depth,
+            &batch_config,
+            &crawl_policy,
+            progress,
+        )
+        .await?;
+        if !args.quiet {
+            let rendered_only: usize = crawl
+                .pages
+                .iter()
+                .map(|p| p.rendered_only_links.len())
+                .sum();
+            println!(
+                "{} {} pages discovered at depth <= {} ({} links only after rendering)",
+                "Discovered:".cyan().bold(),
+                crawl.pages.len(),
+                args.crawl_depth,
+                rendered_only
+            );
+        }
+        crawl_result = Some(crawl);
+        let audited = report.reports.len() + report.errors.len();
+        report.with_sample(auditmysite::audit::SampleMetadata {
+            total_discovered: audited,
+            audited,
+            ..sample
+        })
+    } else {
+        run_concurrent_batch(urls, &batch_config, progress)
+            .await?
+            .with_sample(sample)
+    };
+
+    if let Some(plan) = &template_plan {
+        let pages: Vec<NormalizedReport> = batch_report
+            .reports
+            .iter()
+            .map(|r| normalize(r).normalized)
+            .collect();
+        let sampling = extrapolate_template_findings(plan, &pages);
+        if !args.quiet {
+            println!(
+                "{} {} findings extrapolated across {} sampled templates (ratio {:.3})",
+                "Template sampling:".cyan().bold(),
+                sampling
+                    .clusters
+                    .iter()
+                    .map(|c| c.extrapolated_findings.len())
+                    .sum::<usize>(),
+                sampling.clusters.len(),
+                sampling.sampling_ratio
+            );
+        }
+        if !args.quiet && !sampling.not_measured.is_empty() {
+            println!(
+                "{} no representative audited for {}",
+                "Not measured:".yellow().bold(),
+                sampling.not_measured.join(", ")
+            );
+        }
+        batch_report = batch_report.with_template_sampling(sampling);
+    }
 
     if url_source == "sitemap" {
-        let diagnostics =
-            analyze_sitemap_diagnostics(&full_sitemap_urls, &batch_report.reports).await;
+        let diagnostics = analyze_sitemap_diagnostics(
+            &full_sitemap_urls,
+            &batch_report.reports,
+            &crawl_policy.limiter,
+        )
+        .await;
         if !args.quiet {
             println!(
                 "{} {} URLs checked, {} sitemap issues",
@@ -480,7 +690,7 @@ pub async fn run_batch_mode(
     }
 
     if let Some(ref crawl) = crawl_result {
-        let diagnostics = analyze_crawl_links(crawl).await;
+        let diagnostics = analyze_crawl_links(crawl, &crawl_policy).await;
         if !args.quiet {
             println!(
                 "{} {} internal links checked, {} broken",
@@ -507,23 +717,143 @@ pub async fn run_batch_mode(
         );
     }
 
+    if let Some((shard, all_urls)) = shard_input {
+        let path = args
+            .output
+            .clone()
+            .unwrap_or_else(|| PathBuf::from(shard.file_name()));
+        ShardReport::new(
+            shard,
+            batch_config.pipeline.audit_signature(),
+            all_urls,
+            batch_report.clone(),
+        )
+        .write(&path)?;
+        if let Some(dir) = &batch_config.run_dir {
+            finish_run_dir(dir, &batch_report, args.quiet);
+        }
+        if !args.quiet {
+            println!(
+                "{} shard {} written to {} — combine all shards with `auditmysite merge`",
+                "Shard:".green().bold(),
+                shard,
+                path.display()
+            );
+        }
+        // The verdict belongs to the merged run.
+        return Ok(Verdict::Pass);
+    }
+
     let verdict_cfg = config
         .as_ref()
         .map(|c| c.effective_verdict_config())
         .unwrap_or_default();
     let verdict_result = compute_batch_verdict(&batch_report.summary, &verdict_cfg);
+    record_batch_history(&batch_report, args);
 
     if args.per_page_reports {
         output_batch_as_single_reports(&batch_report, args)?;
-        print_verdict(&verdict_result, args.quiet);
-        return Ok(verdict_result.verdict);
+    } else {
+        output_batch_report(&batch_report, args, Some(&verdict_result))?;
+    }
+    if let Some(dir) = &batch_config.run_dir {
+        finish_run_dir(dir, &batch_report, args.quiet);
+    }
+    print_verdict(&verdict_result, args.quiet);
+    Ok(verdict_result.verdict)
+}
+
+/// `auditmysite merge`: combine `--shard` results into one batch report and
+/// write it like an unsharded run, including history and verdict.
+pub fn run_merge_mode(
+    args: &Args,
+    config: &Option<auditmysite::cli::Config>,
+    inputs: &[PathBuf],
+) -> Result<Verdict> {
+    let shards = inputs
+        .iter()
+        .map(|path| ShardReport::load(path))
+        .collect::<Result<Vec<_>>>()?;
+    let shard_count = shards.len();
+    let batch_report = merge_shards(shards)?;
+
+    if !args.quiet {
+        println!(
+            "{} {} shards, {}/{} passed, {} violations",
+            "Merged:".green().bold(),
+            shard_count,
+            batch_report.summary.passed,
+            batch_report.summary.total_urls,
+            batch_report.summary.total_violations
+        );
     }
 
+    let verdict_cfg = config
+        .as_ref()
+        .map(|c| c.effective_verdict_config())
+        .unwrap_or_default();
+    let verdict_result = compute_batch_verdict(&batch_report.summary, &verdict_cfg);
+    record_batch_history(&batch_report, args);
+
     output_batch_report(&batch_report, args, Some(&verdict_result))?;
     print_verdict(&verdict_result, args.quiet);
     Ok(verdict_result.verdict)
 }
 
+/// Drop the checkpoints of a run whose reports were written and whose URLs
+/// all succeeded; otherwise keep them so the failed URLs can be retried.
+fn finish_run_dir(dir: &std::path::Path, batch: &BatchReport, quiet: bool) {
+    if batch.errors.is_empty() {
+        if let Err(e) = std::fs::remove_dir_all(dir) {
+            tracing::warn!("Could not remove run directory {}: {}", dir.display(), e);
+        }
+        return;
+    }
+    if !quiet {
+        println!(
+            "{} {} URLs failed; retry them with --resume {}",
+            "Checkpoints kept:".yellow().bold(),
+            batch.errors.len(),
+            dir.display()
+        );
+    }
+}
+
+/// Append a fresh single-page run to the local history. Recording is
+/// best-effort: a failure is logged and never fails the audit.
+fn record_single_history(normalized: &NormalizedReport, args: &Args) {
+    let Some(store) = open_history(args) else {
+        return;
+    };
+    if let Err(e) = store.record(normalized) {
+        tracing::warn!("Could not record audit history: {}", e);
+    }
+}
+
+/// Append every page of a batch run plus one site-level entry per host.
+fn record_batch_history(batch: &BatchReport, args: &Args) {
+    let Some(store) = open_history(args) else {
+        return;
+    };
+    let pages: Vec<NormalizedReport> = batch
+        .reports
+        .iter()
+        .map(|r| normalize(r).normalized)
+        .collect();
+    if let Err(e) = store.record_batch(&pages) {
+        tracing::warn!("Could not record audit history: {}", e);
+    }
+}
+
+/// The history store this run records into, or `None` without `--history`.
+fn open_history(args: &Args) -> Option<HistoryStore> {
+    if !args.history {
+        return None;
+    }
+    HistoryStore::open(args.history_dir.as_deref())
+        .map(|store| store.with_retention(args.history_keep.unwrap_or(DEFAULT_HISTORY_KEEP)))
+}
+
 fn print_verdict(vr: &auditmysite::VerdictResult, quiet: bool) {
     if quiet {
         return;
@@ -0,0 +1,54 @@
+//! `auditmysite serve` — run the JSON API server.
+//!
+//! Builds the baseline pipeline and browser pool from the global flags and
+//! `auditmysite.toml`, then hands over to `auditmysite::server`.
+
+use std::net::{IpAddr, SocketAddr};
+
+use colored::Colorize;
+
+use auditmysite::audit::{BatchConfig, PipelineConfig};
+use auditmysite::cli::config::{load_custom_rules, resolve_waivers};
+use auditmysite::cli::{Args, Config};
+use auditmysite::error::{AuditError, Result};
+use auditmysite::server::{serve, ServerConfig};
+use auditmysite::Verdict;
+
+/// Serve until the process is interrupted (Ctrl-C).
+pub async fn run_serve_mode(
+    args: &Args,
+    config: &Option<Config>,
+    host: &str,
+    port: u16,
+    concurrency: usize,
+    queue_size: usize,
+) -> Result<Verdict> {
+    let ip: IpAddr = host.parse().map_err(|_| {
+        AuditError::ConfigError(format!("--host must be an IP address, got '{host}'"))
+    })?;
+
+    let mut pipeline = PipelineConfig::from_args_and_config(args, config.as_ref())?;
+    pipeline.waivers = resolve_waivers(config)?;
+    pipeline.custom_rules = load_custom_rules(args, config)?;
+    let pool = BatchConfig::try_from(args)?.pool_config;
+
+    let addr = SocketAddr::new(ip, port);
+    if !args.quiet {
+        println!(
+            "{} http://{} ({} concurrent audits, queue of {})",
+            "Serving:".cyan().bold(),
+            addr,
+            concurrency.max(1),
+            queue_size.max(1)
+        );
+    }
+    serve(ServerConfig {
+        addr,
+        concurrency,
+        queue_size,
+        pipeline,
+        pool,
+    })
+    .await?;
+    Ok(Verdict::Pass)
+}
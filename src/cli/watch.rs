@@ -0,0 +1,277 @@
+//! `auditmysite watch` — re-audit a local dev server on file change.
+//!
+//! Keeps one browser warm for the whole session, polls the watched paths for
+//! modified files (mtime + size), waits for a quiet period and re-runs the
+//! single-page pipeline. Only the finding delta against the previous run is
+//! printed; findings are matched on the same stable keys as `auditmysite diff`.
+
+use std::collections::BTreeMap;
+use std::io::{self, IsTerminal};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant, SystemTime};
+
+use colored::Colorize;
+
+use auditmysite::audit::{
+    diff_reports, normalize, run_single_audit, AuditContext, AuditReport, PipelineConfig,
+    ReportDiff,
+};
+use auditmysite::browser::BrowserManager;
+use auditmysite::cli::config::{load_custom_rules, resolve_waivers};
+use auditmysite::cli::{Args, Config};
+use auditmysite::error::{AuditError, Result};
+use auditmysite::output::{format_diff_delta, UnifiedReport};
+use auditmysite::Verdict;
+
+use crate::runners::single_browser_options;
+
+/// How often the watched paths are scanned for changes.
+const POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+/// Directories skipped while scanning (besides hidden ones): dependencies and
+/// build output that a dev server rewrites on every rebuild.
+const IGNORED_DIRS: &[&str] = &["node_modules", "target", "dist", "build"];
+
+/// Modification time and size per watched file.
+type FileStamps = BTreeMap<PathBuf, (Option<SystemTime>, u64)>;
+
+/// Run the watch loop until the process is interrupted (Ctrl-C).
+pub async fn run_watch_mode(
+    args: &Args,
+    config: &Option<Config>,
+    url: &str,
+    paths: &[PathBuf],
+    debounce: Duration,
+) -> Result<Verdict> {
+    if let Err(e) = url::Url::parse(url) {
+        return Err(AuditError::InvalidUrl {
+            url: url.to_string(),
+            reason: e.to_string(),
+        });
+    }
+    if let Some(missing) = paths.iter().find(|p| !p.exists()) {
+        return Err(AuditError::FileError {
+            path: missing.clone(),
+            reason: "watch path does not exist".to_string(),
+        });
+    }
+
+    let mut pipeline_config = PipelineConfig::try_from(args)?;
+    pipeline_config.waivers = resolve_waivers(config)?;
+    pipeline_config.custom_rules = load_custom_rules(args, config)?;
+    // Iterations are throwaway; don't fill the artifact cache on every save.
+    pipeline_config.persist_artifacts = false;
+
+    if !args.quiet {
+        println!("{}", "Starting browser...".dimmed());
+    }
+    let mut options = single_browser_options(args, config)?;
+    if let Some(auth) = options.auth.as_mut() {
+        auth.allow_header_origin(url);
+    }
+    let browser = BrowserManager::with_options(options).await?;
+
+    let use_color = io::stdout().is_terminal();
+    let mut stamps = scan(paths);
+    if !args.quiet {
+        println!(
+            "{} {} ({} file{} under {})",
+            "Watching:".cyan().bold(),
+            url,
+            stamps.len(),
+            if stamps.len() == 1 { "" } else { "s" },
+            paths
+                .iter()
+                .map(|p| p.display().to_string())
+                .collect::<Vec<_>>()
+                .join(", ")
+        );
+    }
+
+    let mut previous: Option<String> = None;
+    let mut run = 1usize;
+    loop {
+        let started = Instant::now();
+        match run_single_audit(url, &browser, &pipeline_config).await {
+            Ok(report) => {
+                let context = normalize(&report);
+                if !args.quiet {
+                    println!(
+                        "\n{} run #{} ({} ms)",
+                        format!("[{}]", chrono::Local::now().format("%H:%M:%S")).dimmed(),
+                        run,
+                        started.elapsed().as_millis()
+                    );
+                }
+                // A failed comparison only costs this run's delta; the session
+                // keeps the previous run as the comparison point.
+                match delta_against(previous.as_deref(), &context, &report) {
+                    Ok((json, Some(diff))) => {
+                        print!("{}", format_diff_delta(&diff, use_color));
+                        previous = Some(json);
+                    }
+                    Ok((json, None)) => {
+                        if !args.quiet {
+                            println!(
+                                "  {} {}   {} {}   {} findings   {}",
+                                "Accessibility".bold(),
+                                context.normalized.score,
+                                "Overall".bold(),
+                                context.normalized.overall_score,
+                                context.normalized.occurrence_counts.total,
+                                "baseline".dimmed()
+                            );
+                        }
+                        previous = Some(json);
+                    }
+                    Err(e) => eprintln!("{} {}", "Diff failed:".red().bold(), e),
+                }
+            }
+            // A dev server mid-rebuild often fails a single load; keep the
+            // previous run as the comparison point and wait for the next change.
+            Err(e) => eprintln!("{} {}", "Audit failed:".red().bold(), e),
+        }
+        run += 1;
+
+        let changed = wait_for_change(paths, &mut stamps, debounce).await;
+        if !args.quiet {
+            println!(
+                "\n{} {}{}",
+                "Changed:".yellow().bold(),
+                changed[0].display(),
+                if changed.len() > 1 {
+                    format!(" (+{} more)", changed.len() - 1)
+                } else {
+                    String::new()
+                }
+            );
+        }
+    }
+}
+
+/// JSON of the current run, plus its delta when there is a previous run.
+fn delta_against(
+    previous: Option<&str>,
+    context: &AuditContext,
+    report: &AuditReport,
+) -> Result<(String, Option<ReportDiff>)> {
+    let json = UnifiedReport::single(context, report).to_json(false)?;
+    let diff = previous
+        .map(|before| diff_reports(before, &json))
+        .transpose()?;
+    Ok((json, diff))
+}
+
+/// Block until at least one file changed and the tree has then been quiet
+/// for `debounce`. Returns the changed paths and updates `stamps`.
+async fn wait_for_change(
+    paths: &[PathBuf],
+    stamps: &mut FileStamps,
+    debounce: Duration,
+) -> Vec<PathBuf> {
+    loop {
+        tokio::time::sleep(POLL_INTERVAL).await;
+        let mut latest = scan(paths);
+        let mut changed = changed_files(stamps, &latest);
+        if changed.is_empty() {
+            continue;
+        }
+        loop {
+            tokio::time::sleep(debounce).await;
+            let next = scan(paths);
+            let more = changed_files(&latest, &next);
+            if more.is_empty() {
+                break;
+            }
+            changed.extend(more);
+            latest = next;
+        }
+        *stamps = latest;
+        changed.sort();
+        changed.dedup();
+        return changed;
+    }
+}
+
+fn scan(paths: &[PathBuf]) -> FileStamps {
+    let mut stamps = FileStamps::new();
+    for path in paths {
+        collect_stamps(path, &mut stamps);
+    }
+    stamps
+}
+
+fn collect_stamps(path: &Path, stamps: &mut FileStamps) {
+    let Ok(metadata) = std::fs::metadata(path) else {
+        return;
+    };
+    if !metadata.is_dir() {
+        stamps.insert(
+            path.to_path_buf(),
+            (metadata.modified().ok(), metadata.len()),
+        );
+        return;
+    }
+    let Ok(entries) = std::fs::read_dir(path) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let name = entry.file_name();
+        let name = name.to_string_lossy();
+        if name.starts_with('.') || IGNORED_DIRS.contains(&name.as_ref()) {
+            continue;
+        }
+        collect_stamps(&entry.path(), stamps);
+    }
+}
+
+/// Files added, removed or modified between two scans.
+fn changed_files(before: &FileStamps, after: &FileStamps) -> Vec<PathBuf> {
+    let mut changed: Vec<PathBuf> = after
+        .iter()
+        .filter(|(path, stamp)| before.get(*path) != Some(stamp))
+        .map(|(path, _)| path.clone())
+        .collect();
+    changed.extend(
+        before
+            .keys()
+            .filter(|path| !after.contains_key(*path))
+            .cloned(),
+    );
+    changed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn detects_added_modified_and_removed_files_but_skips_ignored_dirs() {
+        let root = std::env::temp_dir().join(format!("auditmysite-watch-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&root);
+        std::fs::create_dir_all(root.join("node_modules")).unwrap();
+        std::fs::write(root.join("app.css"), "a{}").unwrap();
+        std::fs::write(root.join("old.html"), "<p>").unwrap();
+        let before = scan(std::slice::from_ref(&root));
+        assert_eq!(before.len(), 2);
+
+        std::fs::write(root.join("app.css"), "a{color:red}").unwrap();
+        std::fs::remove_file(root.join("old.html")).unwrap();
+        std::fs::write(root.join("new.html"), "<p>").unwrap();
+        std::fs::write(root.join("node_modules").join("dep.js"), "x").unwrap();
+        let after = scan(std::slice::from_ref(&root));
+
+        let mut changed = changed_files(&before, &after);
+        changed.sort();
+        assert_eq!(
+            changed,
+            vec![
+                root.join("app.css"),
+                root.join("new.html"),
+                root.join("old.html")
+            ]
+        );
+        assert!(changed_files(&after, &after).is_empty());
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+}
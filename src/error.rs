@@ -100,6 +100,10 @@ pub enum AuditError {
     #[error("Interaction failed: {reason}")]
     InteractionFailed { reason: String },
 
+    /// Cookie jar, extra headers or the login step could not be applied.
+    #[error("Authentication failed: {reason}")]
+    AuthenticationFailed { reason: String },
+
     /// Per-page audit exceeded the total time budget.
     #[error("Audit timed out for '{url}' after {timeout_secs} seconds")]
     AuditTimeout { url: String, timeout_secs: u64 },
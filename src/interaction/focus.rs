@@ -3,18 +3,19 @@
 //! Builds the `FocusSnapshot` that accompanies each `AXSnapshot` in a
 //! journey. Phase 2 adds focus-indicator detection via computed style.
 
-use chromiumoxide::cdp::js_protocol::runtime::EvaluateParams;
+use chromiumoxide::cdp::browser_protocol::dom::{BackendNodeId, ResolveNodeParams};
+use chromiumoxide::cdp::js_protocol::runtime::{CallFunctionOnParams, EvaluateParams};
 use chromiumoxide::Page;
 use serde_json::Value;
 
 use crate::accessibility::{FocusIndicatorStatus, FocusSnapshot, Rect};
 use crate::error::{AuditError, Result};
 
-/// JS that returns a description of `document.activeElement`, including
-/// visibility flags used by the journey evaluator. `null` when no element
-/// has focus (or only body/documentElement, which we treat as "no focus").
-const ACTIVE_ELEMENT_JS: &str = r#"
-(function () {
+/// Body of the JS that returns a description of `document.activeElement`,
+/// including visibility flags used by the journey evaluator. `null` when no
+/// element has focus (or only body/documentElement, which we treat as "no
+/// focus"). Injected after `CSS_SELECTOR_JS`.
+const ACTIVE_ELEMENT_BODY: &str = r#"
     var el = document.activeElement;
     if (!el || el === document.body || el === document.documentElement) {
         return null;
@@ -22,28 +23,6 @@ const ACTIVE_ELEMENT_JS: &str = r#"
     var rect = el.getBoundingClientRect();
     var vw = window.innerWidth || document.documentElement.clientWidth;
     var vh = window.innerHeight || document.documentElement.clientHeight;
-    function selectorFor(node) {
-        if (!node || node.nodeType !== 1) return null;
-        if (node.id) return '#' + node.id;
-        var parts = [];
-        while (node && node.nodeType === 1 && parts.length < 6) {
-            var tag = node.nodeName.toLowerCase();
-            if (node.id) { parts.unshift(tag + '#' + node.id); break; }
-            var parent = node.parentNode;
-            if (parent) {
-                var siblings = Array.from(parent.children).filter(function (c) {
-                    return c.nodeName === node.nodeName;
-                });
-                if (siblings.length > 1) {
-                    var idx = siblings.indexOf(node) + 1;
-                    tag += ':nth-of-type(' + idx + ')';
-                }
-            }
-            parts.unshift(tag);
-            node = parent;
-        }
-        return parts.join(' > ');
-    }
     // Ancestor-chain checks: a focused element is "hidden" if anywhere on
     // the path to <html> there is aria-hidden="true" or an inert attribute.
     var ariaHiddenChain = false;
@@ -63,17 +42,16 @@ const ACTIVE_ELEMENT_JS: &str = r#"
         parseFloat(style.opacity) === 0;
     var inViewport = rect.right > 0 && rect.bottom > 0 && rect.left < vw && rect.top < vh;
     return {
-        selector: selectorFor(el),
+        selector: __amsCssSelector(el) || null,
         x: rect.x, y: rect.y, w: rect.width, h: rect.height,
         ariaHiddenChain: ariaHiddenChain,
         inertChain: inertChain,
         hiddenByStyle: hiddenByStyle,
         inViewport: inViewport,
     };
-})()
 "#;
 
-/// JS that collects all focusable elements on the page in DOM order and
+/// Body of the JS that collects all focusable elements on the page in DOM order and
 /// returns their selectors. Used by the tab-walk evaluator to detect
 /// reverse jumps in tab order.
 ///
@@ -82,30 +60,10 @@ const ACTIVE_ELEMENT_JS: &str = r#"
 /// - any element with positive or zero `tabindex`
 /// - `contenteditable` regions
 /// - excludes `disabled` controls and `tabindex="-1"` (HTMLElement.tabIndex < 0)
-const COLLECT_FOCUSABLES_JS: &str = r#"
-(function () {
-    function selectorFor(node) {
-        if (!node || node.nodeType !== 1) return null;
-        if (node.id) return '#' + node.id;
-        var parts = [];
-        while (node && node.nodeType === 1 && parts.length < 6) {
-            var tag = node.nodeName.toLowerCase();
-            if (node.id) { parts.unshift(tag + '#' + node.id); break; }
-            var parent = node.parentNode;
-            if (parent) {
-                var siblings = Array.from(parent.children).filter(function (c) {
-                    return c.nodeName === node.nodeName;
-                });
-                if (siblings.length > 1) {
-                    var idx = siblings.indexOf(node) + 1;
-                    tag += ':nth-of-type(' + idx + ')';
-                }
-            }
-            parts.unshift(tag);
-            node = parent;
-        }
-        return parts.join(' > ');
-    }
+///
+/// Injected after `CSS_SELECTOR_JS`, like [`ACTIVE_ELEMENT_BODY`], so DOM
+/// order and focus targets use the same selectors.
+const COLLECT_FOCUSABLES_BODY: &str = r#"
     var sel = 'a[href], button, input:not([type="hidden"]), select, textarea, ' +
               '[tabindex], [contenteditable=""], [contenteditable="true"]';
     var els = Array.from(document.querySelectorAll(sel)).filter(function (el) {
@@ -113,10 +71,20 @@ const COLLECT_FOCUSABLES_JS: &str = r#"
         if (typeof el.tabIndex === 'number' && el.tabIndex < 0) return false;
         return true;
     });
-    return els.map(selectorFor).filter(function (s) { return s !== null; });
-})()
+    return els.map(__amsCssSelector).filter(function (s) { return s; });
 "#;
 
+/// IIFE running `body` with `__amsCssSelector` in scope.
+fn wrap_with_selector_helper(body: &str) -> String {
+    [
+        "(function () {",
+        crate::accessibility::js_helpers::CSS_SELECTOR_JS,
+        body,
+        "})()",
+    ]
+    .concat()
+}
+
 /// Collect the in-DOM-order selectors of all focusable elements on the
 /// page. Used as the reference order by `evaluate::tab_walk_order`.
 ///
@@ -124,7 +92,7 @@ const COLLECT_FOCUSABLES_JS: &str = r#"
 /// missing DOM-order data simply means we cannot detect out-of-order jumps.
 pub async fn collect_focusable_dom_order(page: &Page) -> Vec<String> {
     let Ok(params) = EvaluateParams::builder()
-        .expression(COLLECT_FOCUSABLES_JS.to_string())
+        .expression(wrap_with_selector_helper(COLLECT_FOCUSABLES_BODY))
         .return_by_value(true)
         .build()
     else {
@@ -144,6 +112,39 @@ pub async fn collect_focusable_dom_order(page: &Page) -> Vec<String> {
         .collect()
 }
 
+/// CSS selector of a backend DOM node, so journey steps that act on a
+/// candidate element can record a replayable target instead of a CDP id.
+///
+/// Returns `None` when the node cannot be resolved (detached, stale id).
+pub async fn selector_for_backend_node(page: &Page, backend_node_id: i64) -> Option<String> {
+    let resolve = ResolveNodeParams::builder()
+        .backend_node_id(BackendNodeId::new(backend_node_id))
+        .build();
+    let resolved = page.execute(resolve).await.ok()?;
+    let object_id = resolved.result.object.object_id.clone()?;
+    let call = CallFunctionOnParams::builder()
+        .function_declaration(
+            [
+                "function () {",
+                crate::accessibility::js_helpers::CSS_SELECTOR_JS,
+                "return __amsCssSelector(this) || null; }",
+            ]
+            .concat(),
+        )
+        .object_id(object_id)
+        .return_by_value(true)
+        .build()
+        .ok()?;
+    let result = page.execute(call).await.ok()?;
+    result
+        .result
+        .result
+        .value
+        .as_ref()
+        .and_then(Value::as_str)
+        .map(|s| s.to_string())
+}
+
 /// JS that checks whether the currently focused element has a visible focus
 /// indicator via outline, box-shadow, or border changes.
 const FOCUS_INDICATOR_JS: &str = r#"
@@ -187,7 +188,7 @@ pub async fn detect_focus_indicator(page: &Page) -> Option<FocusIndicatorStatus>
 /// Fills selector, bounding_box, visibility flags, and focus-indicator status.
 pub async fn capture_focus(page: &Page) -> Result<FocusSnapshot> {
     let params = EvaluateParams::builder()
-        .expression(ACTIVE_ELEMENT_JS.to_string())
+        .expression(wrap_with_selector_helper(ACTIVE_ELEMENT_BODY))
         .return_by_value(true)
         .build()
         .map_err(|e| AuditError::InteractionFailed {
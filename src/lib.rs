@@ -43,6 +43,12 @@
 //!         interactive: auditmysite::cli::InteractiveMode::Off,
 //!         journey_budget_ms: auditmysite::a11y_journey::DEFAULT_BUDGET_MS,
 //!         lang: "de".to_string(),
+//!         waivers: Default::default(),
+//!         custom_rules: Default::default(),
+//!         devices: Default::default(),
+//!         throttle_passes: auditmysite::browser::throttle::default_throttle_passes(),
+//!         perf_runs: 1,
+//!         capture_rendered_links: false,
 //!     };
 //!
 //!     // Run audit
@@ -106,6 +112,7 @@ pub mod registry;
 pub mod screen_reader;
 pub mod security;
 pub mod seo;
+pub mod server;
 pub mod source_quality;
 pub mod studio;
 pub mod taxonomy;
@@ -123,9 +130,9 @@ pub use assessment::{
 };
 pub use audit::{
     analyze_crawl_links, audit_page, crawl_site, parse_sitemap, read_url_file,
-    run_concurrent_batch, AuditReport, BatchConfig, BatchReport, BrokenLink, BrokenLinkSeverity,
-    CrawlDiagnostics, CrawlNode, CrawlResult, PerformanceResults, PipelineConfig, RedirectChain,
-    Verdict, VerdictResult,
+    run_concurrent_batch, run_rendered_crawl_batch, AuditReport, BatchConfig, BatchReport,
+    BrokenLink, BrokenLinkSeverity, CrawlDiagnostics, CrawlNode, CrawlResult, LinkDiscovery,
+    PerformanceResults, PipelineConfig, RedirectChain, Verdict, VerdictResult,
 };
 pub use browser::{
     detect_all_browsers, resolve_browser, BrowserInstaller, BrowserKind, BrowserManager,
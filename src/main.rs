@@ -13,8 +13,12 @@ mod plan;
 mod report_writers;
 #[path = "cli/runners.rs"]
 mod runners;
+#[path = "cli/serve.rs"]
+mod serve;
 #[path = "cli/sitemap_suggest.rs"]
 mod sitemap_suggest;
+#[path = "cli/watch.rs"]
+mod watch;
 
 use commands::{detect_chrome_command, handle_command};
 use plan::print_banner;
@@ -110,14 +114,15 @@ fn setup_logging(args: &Args) {
 /// Main application logic
 async fn run(
     mut args: Args,
-    _config: &Option<auditmysite::cli::Config>,
+    config: &Option<auditmysite::cli::Config>,
     request_mode_from_cli: bool,
 ) -> Result<auditmysite::Verdict> {
-    // Handle subcommands first
+    // Custom rules must be registered before the first audit in any mode
+    auditmysite::cli::config::resolve_custom_rules(&args, config)?;
+
+    // Subcommands, including the watch/serve/merge runners
     if let Some(ref command) = args.command {
-        return handle_command(command, &args)
-            .await
-            .map(|_| auditmysite::Verdict::Pass);
+        return handle_command(command, &args, config).await;
     }
 
     if args.detect_chrome {
@@ -184,9 +189,9 @@ async fn run(
     let is_batch = args.sitemap.is_some() || args.url_file.is_some() || args.crawl;
 
     if is_batch {
-        run_batch_mode(&args, _config).await
+        run_batch_mode(&args, config).await
     } else {
-        run_single_mode(&args, _config).await
+        run_single_mode(&args, config).await
     }
 }
 
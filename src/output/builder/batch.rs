@@ -1104,9 +1104,7 @@ fn collect_batch_finding_groups(
     let mut groups: HashMap<String, NormalizedFindingAccumulator> = HashMap::new();
     for report in normalized_reports {
         for finding in &report.findings {
-            let base_severity = crate::taxonomy::rules::RULES
-                .iter()
-                .find(|r| r.id == finding.rule_id)
+            let base_severity = crate::taxonomy::RuleLookup::by_id(&finding.rule_id)
                 .map(|r| r.severity)
                 .unwrap_or(finding.severity);
             let entry = groups
@@ -256,7 +256,7 @@ pub(super) fn build_methodology(
         )
     };
 
-    MethodologyBlock {
+    let mut block = MethodologyBlock {
         scope,
         method,
         limitations,
@@ -305,7 +305,14 @@ pub(super) fn build_methodology(
         ],
         confidence_summary: build_confidence_summary(locale, normalized),
         capabilities: build_capability_matrix(locale, normalized),
+    };
+    if let Some(auth) = &normalized.execution.environment.authentication {
+        block.audit_facts.push((
+            key("Authentifizierung", "Authentication"),
+            auth.describe(en),
+        ));
     }
+    block
 }
 
 fn build_confidence_summary(locale: &str, normalized: &NormalizedReport) -> Vec<(String, String)> {
@@ -343,7 +343,10 @@ fn build_performance_details(
             .raw_throttled_performance
             .iter()
             .map(|t| ThrottledPerfEntry {
-                profile_name: format!("{:?}", t.profile),
+                profile_name: t
+                    .profile_name
+                    .clone()
+                    .unwrap_or_else(|| format!("{:?}", t.profile)),
                 lcp: t
                     .lcp_ms
                     .map(|v| format!("{:.0} ms", v))
@@ -506,6 +509,7 @@ fn build_performance_details(
             render_blocking_suggestions,
             has_render_blocking,
             throttled_profiles,
+            run_stats: p.vitals.run_stats.clone(),
             cls_attribution,
             third_party,
             critical_chain,
@@ -22,6 +22,9 @@ pub fn print_report(report: &AuditReport, level: WcagLevel) {
     if !report.accessibility.wcag_results.violations.is_empty() {
         print_violations_table(&report.accessibility.wcag_results.violations);
     }
+    if !report.device_results.is_empty() {
+        print_device_matrix_section(&report.device_results);
+    }
 
     // Optional module results
     if let Some(ref perf) = report.performance {
@@ -421,6 +424,26 @@ fn print_performance_section(perf: &PerformanceResults) {
     if let Some(ref ttfb) = perf.vitals.ttfb {
         println!("  {} {:.0}ms ({})", "TTFB:".bold(), ttfb.value, ttfb.rating);
     }
+    if let Some(stats) = perf.vitals.run_stats.as_ref().filter(|s| s.runs > 1) {
+        println!(
+            "  {}",
+            format!("Median of {} runs (p75, std. dev.):", stats.runs).dimmed()
+        );
+        for (label, metric) in stats.metrics() {
+            let (p75, std_dev) = if label == "CLS" {
+                (
+                    format!("{:.3}", metric.p75),
+                    format!("{:.3}", metric.std_dev()),
+                )
+            } else {
+                (
+                    format!("{:.0}ms", metric.p75),
+                    format!("{:.0}ms", metric.std_dev()),
+                )
+            };
+            println!("    {:<12} p75 {}  std. dev. {}", label, p75, std_dev);
+        }
+    }
     println!();
 }
 
@@ -682,6 +705,45 @@ fn print_budget_violations_section(violations: &[crate::audit::BudgetViolation])
     println!();
 }
 
+fn print_device_matrix_section(devices: &[crate::audit::DeviceResult]) {
+    println!("{}", "Device Matrix".bold().underline());
+    println!();
+
+    for device in devices {
+        println!(
+            "  {:<14} {:<22} Accessibility {}  Violations {}{}",
+            device.name.bold(),
+            format!(
+                "{}×{} @{}x{}",
+                device.width,
+                device.height,
+                device.device_scale_factor,
+                if device.touch { " touch" } else { "" }
+            )
+            .dimmed(),
+            colorize_score(
+                device.accessibility_score,
+                &device.accessibility_score.to_string()
+            ),
+            device.violation_count,
+            device
+                .mobile_score
+                .map(|s| format!("  Mobile {s}"))
+                .unwrap_or_default(),
+        );
+        for finding in &device.exclusive_findings {
+            println!(
+                "    {} {} {} {}",
+                "only here:".yellow(),
+                finding.rule.bold(),
+                finding.rule_name,
+                finding.selector.as_deref().unwrap_or("").dimmed(),
+            );
+        }
+    }
+    println!();
+}
+
 /// Print batch results as a table
 pub fn print_batch_table(batch_report: &BatchReport, level: WcagLevel) {
     print!("{}", format_batch_table(batch_report, level, true));
@@ -0,0 +1,143 @@
+//! GitLab Code Quality report (Code Climate JSON subset) for merge-request
+//! widgets.
+
+use std::collections::HashSet;
+
+use serde::Serialize;
+
+use crate::audit::artifacts::fnv1a;
+use crate::audit::normalized::NormalizedReport;
+use crate::wcag::Severity;
+
+#[derive(Debug, Serialize)]
+struct CodeQualityIssue {
+    description: String,
+    check_name: String,
+    fingerprint: String,
+    severity: &'static str,
+    location: CodeQualityLocation,
+}
+
+#[derive(Debug, Serialize)]
+struct CodeQualityLocation {
+    path: String,
+    lines: CodeQualityLines,
+}
+
+#[derive(Debug, Serialize)]
+struct CodeQualityLines {
+    begin: u32,
+}
+
+fn codequality_severity(severity: &Severity) -> &'static str {
+    match severity {
+        Severity::Critical => "critical",
+        Severity::High => "major",
+        Severity::Medium => "minor",
+        Severity::Low => "info",
+    }
+}
+
+/// Converts normalized findings across one or more pages into a GitLab Code
+/// Quality JSON array, one issue per occurrence.
+///
+/// As with SARIF there is no source file to point at, so `location.path` is
+/// the audited page URL. Fingerprints hash URL, rule and selector (falling
+/// back to the message) so an unchanged issue keeps its fingerprint between
+/// pipelines and GitLab can tell new issues from resolved ones.
+pub fn format_gitlab_codequality(reports: &[&NormalizedReport]) -> anyhow::Result<String> {
+    let mut issues = Vec::new();
+    let mut fingerprints = HashSet::new();
+
+    for report in reports {
+        for finding in &report.findings {
+            let severity = codequality_severity(&finding.severity);
+            let mut entries: Vec<(String, String)> = finding
+                .occurrences
+                .iter()
+                .map(|occurrence| match &occurrence.selector {
+                    Some(selector) => (
+                        format!("{}: {} ({selector})", finding.title, occurrence.message),
+                        selector.clone(),
+                    ),
+                    None => (
+                        format!("{}: {}", finding.title, occurrence.message),
+                        occurrence.message.clone(),
+                    ),
+                })
+                .collect();
+            if entries.is_empty() {
+                entries.push((
+                    format!("{}: {}", finding.title, finding.description),
+                    String::new(),
+                ));
+            }
+
+            for (description, anchor) in entries {
+                let key = format!("{}\n{}\n{}", report.url, finding.rule_id, anchor);
+                let mut fingerprint = format!("{:016x}", fnv1a(key.as_bytes()));
+                let mut n = 1;
+                while !fingerprints.insert(fingerprint.clone()) {
+                    fingerprint = format!("{:016x}", fnv1a(format!("{key}\n{n}").as_bytes()));
+                    n += 1;
+                }
+                issues.push(CodeQualityIssue {
+                    description,
+                    check_name: finding.rule_id.clone(),
+                    fingerprint,
+                    severity,
+                    location: CodeQualityLocation {
+                        path: report.url.clone(),
+                        lines: CodeQualityLines { begin: 1 },
+                    },
+                });
+            }
+        }
+    }
+
+    serde_json::to_string_pretty(&issues).map_err(|e| anyhow::anyhow!(e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::audit::normalized::normalize;
+    use crate::audit::AuditReport;
+    use crate::cli::WcagLevel;
+    use crate::wcag::{Violation, WcagResults};
+
+    fn sample_report(url: &str) -> AuditReport {
+        let mut results = WcagResults::new();
+        for (node, selector) in [("node-1", "img.hero"), ("node-2", "img.logo")] {
+            results.add_violation(
+                Violation::new(
+                    "1.1.1",
+                    "Images must have alternate text",
+                    WcagLevel::A,
+                    Severity::Critical,
+                    "Image is missing an alt attribute",
+                    node,
+                )
+                .with_selector(selector),
+            );
+        }
+        AuditReport::new(url.to_string(), WcagLevel::AA, results, 100)
+    }
+
+    #[test]
+    fn emits_one_issue_per_occurrence_with_stable_unique_fingerprints() {
+        let report = sample_report("https://example.com/a");
+        let ctx = normalize(&report);
+        let first = format_gitlab_codequality(&[&ctx.normalized]).unwrap();
+        let again = format_gitlab_codequality(&[&normalize(&report).normalized]).unwrap();
+        assert_eq!(first, again);
+
+        let issues: serde_json::Value = serde_json::from_str(&first).unwrap();
+        let issues = issues.as_array().unwrap();
+        assert_eq!(issues.len(), 2);
+        assert_eq!(issues[0]["severity"], "critical");
+        assert_eq!(issues[0]["location"]["path"], "https://example.com/a");
+        assert_eq!(issues[0]["location"]["lines"]["begin"], 1);
+        assert_ne!(issues[0]["fingerprint"], issues[1]["fingerprint"]);
+    }
+}
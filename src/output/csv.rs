@@ -0,0 +1,147 @@
+//! CSV export for spreadsheet triage: one row per finding occurrence.
+
+use std::fmt::Write as _;
+
+use crate::audit::normalized::NormalizedReport;
+use crate::wcag::Severity;
+
+const HEADER: [&str; 10] = [
+    "url",
+    "category",
+    "rule_id",
+    "wcag_criterion",
+    "impact",
+    "title",
+    "message",
+    "selector",
+    "html_snippet",
+    "help_url",
+];
+
+/// axe-core impact terms, which is what triage sheets are usually sorted by.
+fn impact(severity: &Severity) -> &'static str {
+    match severity {
+        Severity::Critical => "critical",
+        Severity::High => "serious",
+        Severity::Medium => "moderate",
+        Severity::Low => "minor",
+    }
+}
+
+/// Converts normalized findings across one or more pages into CSV (RFC 4180,
+/// CRLF line endings). Findings without recorded occurrences still get one
+/// row with empty element columns.
+pub fn format_csv(reports: &[&NormalizedReport]) -> String {
+    let mut out = String::new();
+    write_row(&mut out, &HEADER);
+
+    for report in reports {
+        for finding in &report.findings {
+            let impact = impact(&finding.severity);
+            let help_url = finding.help_url.as_deref().unwrap_or_default();
+            if finding.occurrences.is_empty() {
+                write_row(
+                    &mut out,
+                    &[
+                        &report.url,
+                        &finding.category,
+                        &finding.rule_id,
+                        &finding.wcag_criterion,
+                        impact,
+                        &finding.title,
+                        &finding.description,
+                        "",
+                        "",
+                        help_url,
+                    ],
+                );
+                continue;
+            }
+            for occurrence in &finding.occurrences {
+                write_row(
+                    &mut out,
+                    &[
+                        &report.url,
+                        &finding.category,
+                        &finding.rule_id,
+                        &finding.wcag_criterion,
+                        impact,
+                        &finding.title,
+                        &occurrence.message,
+                        occurrence.selector.as_deref().unwrap_or_default(),
+                        occurrence.html_snippet.as_deref().unwrap_or_default(),
+                        help_url,
+                    ],
+                );
+            }
+        }
+    }
+    out
+}
+
+fn write_row(out: &mut String, fields: &[&str]) {
+    for (i, field) in fields.iter().enumerate() {
+        if i > 0 {
+            out.push(',');
+        }
+        let _ = write!(out, "{}", escape(field));
+    }
+    out.push_str("\r\n");
+}
+
+/// Quote fields that need it, and neutralize a leading formula character so a
+/// spreadsheet shows scraped page content as text instead of evaluating it.
+fn escape(field: &str) -> String {
+    let field = if field.starts_with(['=', '+', '-', '@']) {
+        format!("'{field}")
+    } else {
+        field.to_string()
+    };
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::audit::normalized::normalize;
+    use crate::audit::AuditReport;
+    use crate::cli::WcagLevel;
+    use crate::wcag::{Violation, WcagResults};
+
+    #[test]
+    fn writes_one_quoted_row_per_occurrence() {
+        let mut results = WcagResults::new();
+        results.add_violation(
+            Violation::new(
+                "1.1.1",
+                "Images must have alternate text",
+                WcagLevel::A,
+                Severity::Critical,
+                "Image is missing an alt attribute",
+                "node-1",
+            )
+            .with_selector("img.hero, img.logo")
+            .with_html_snippet("<img src=\"a.png\">"),
+        );
+        let report = AuditReport::new("https://example.com".to_string(), WcagLevel::AA, results, 1);
+        let csv = format_csv(&[&normalize(&report).normalized]);
+        let lines: Vec<&str> = csv.split("\r\n").collect();
+
+        assert_eq!(lines[0], HEADER.join(","));
+        assert_eq!(lines.len(), 3);
+        assert!(lines[1].starts_with("https://example.com,wcag,"));
+        assert!(lines[1].contains(",critical,"));
+        assert!(lines[1].contains(",\"img.hero, img.logo\",\"<img src=\"\"a.png\"\">\","));
+    }
+
+    #[test]
+    fn neutralizes_formula_prefixes() {
+        assert_eq!(escape("=HYPERLINK(\"x\")"), "\"'=HYPERLINK(\"\"x\"\")\"");
+        assert_eq!(escape("-1"), "'-1");
+        assert_eq!(escape("plain"), "plain");
+    }
+}
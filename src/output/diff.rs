@@ -0,0 +1,347 @@
+//! Renderers for `auditmysite diff` results (terminal table and Markdown) and
+//! the compact per-run delta printed by `auditmysite watch`.
+//!
+//! JSON output is the `ReportDiff` serialization itself.
+
+use colored::control::{set_override, unset_override};
+use colored::Colorize;
+use comfy_table::{presets, Attribute, Cell, Color, ContentArrangement, Table};
+
+use crate::audit::report_diff::{DiffFinding, PagePresence, ReportDiff, ScoreDelta};
+use crate::util::truncate_url;
+
+/// Maximum findings listed per page and section in the Markdown output.
+const MARKDOWN_FINDING_LIMIT: usize = 25;
+
+/// Terminal rendering: score/module deltas, then one table per page that has
+/// new or resolved findings.
+pub fn format_diff_table(diff: &ReportDiff, use_color: bool) -> String {
+    set_override(use_color);
+    let mut output = String::new();
+    output.push('\n');
+    output.push_str(&format!(
+        "{} Report diff ({} → {})\n\n",
+        "═══".cyan(),
+        diff.before_type,
+        diff.after_type
+    ));
+
+    let mut scores = Table::new();
+    scores
+        .load_preset(presets::UTF8_FULL)
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_header(header_cells(&["Score", "Before", "After", "Δ"]));
+    for delta in [
+        &diff.summary.accessibility_score,
+        &diff.summary.overall_score,
+    ]
+    .into_iter()
+    .chain(&diff.summary.module_deltas)
+    {
+        scores.add_row(score_row(delta));
+    }
+    output.push_str(&scores.to_string());
+    output.push_str("\n\n");
+
+    output.push_str(&format!(
+        "  {} {}   {} {}   {} {}   {} {:?} → {:?}\n",
+        "New:".bold(),
+        count_colored(diff.summary.new_count, true),
+        "Resolved:".bold(),
+        count_colored(diff.summary.resolved_count, false),
+        "Unchanged:".bold(),
+        diff.summary.unchanged_count,
+        "Risk:".bold(),
+        diff.summary.risk_before,
+        diff.summary.risk_after,
+    ));
+
+    for page in &diff.pages {
+        if page.new_findings.is_empty() && page.resolved_findings.is_empty() {
+            continue;
+        }
+        output.push('\n');
+        output.push_str(&format!(
+            "  {} {}{}\n",
+            truncate_url(&page.url, 80).bold(),
+            format_delta(page.accessibility_score.delta),
+            presence_note(page.presence),
+        ));
+
+        let mut table = Table::new();
+        table
+            .load_preset(presets::UTF8_FULL)
+            .set_content_arrangement(ContentArrangement::Dynamic)
+            .set_header(header_cells(&["", "Severity", "Rule", "WCAG", "Location"]));
+        for (marker, color, findings) in [
+            ("+", Color::Red, &page.new_findings),
+            ("−", Color::Green, &page.resolved_findings),
+        ] {
+            for finding in findings {
+                table.add_row(vec![
+                    Cell::new(marker).fg(color),
+                    Cell::new(finding.severity.label_en()),
+                    Cell::new(&finding.rule_id),
+                    Cell::new(&finding.wcag_criterion),
+                    Cell::new(finding.location.as_deref().unwrap_or("document")),
+                ]);
+            }
+        }
+        output.push_str(&table.to_string());
+        output.push('\n');
+    }
+
+    unset_override();
+    output
+}
+
+/// GitHub/GitLab-flavoured Markdown, suitable for a pull-request comment.
+pub fn format_diff_markdown(diff: &ReportDiff) -> String {
+    let mut md = String::new();
+    let status = if diff.has_regressions() {
+        "❌ Regressions found"
+    } else {
+        "✅ No regressions"
+    };
+    md.push_str(&format!("## Accessibility report diff — {status}\n\n"));
+    write_diff_sections(&mut md, diff);
+    md
+}
+
+/// Counts line, score table and per-page new/resolved lists of
+/// [`format_diff_markdown`], shared with the `-f markdown --compare` comment.
+pub(crate) fn write_diff_sections(md: &mut String, diff: &ReportDiff) {
+    md.push_str(&format!(
+        "**{}** new · **{}** resolved · **{}** unchanged · risk {:?} → {:?}\n\n",
+        diff.summary.new_count,
+        diff.summary.resolved_count,
+        diff.summary.unchanged_count,
+        diff.summary.risk_before,
+        diff.summary.risk_after,
+    ));
+
+    md.push_str("| Score | Before | After | Δ |\n|---|---:|---:|---:|\n");
+    for delta in [
+        &diff.summary.accessibility_score,
+        &diff.summary.overall_score,
+    ]
+    .into_iter()
+    .chain(&diff.summary.module_deltas)
+    {
+        md.push_str(&format!(
+            "| {} | {} | {} | {} |\n",
+            delta.label,
+            score_text(delta.before),
+            score_text(delta.after),
+            format_delta(delta.delta).trim(),
+        ));
+    }
+
+    for page in &diff.pages {
+        if page.new_findings.is_empty() && page.resolved_findings.is_empty() {
+            continue;
+        }
+        md.push_str(&format!(
+            "\n### {}{}\n",
+            page.url,
+            presence_note(page.presence)
+        ));
+        markdown_findings(md, "New", &page.new_findings);
+        markdown_findings(md, "Resolved", &page.resolved_findings);
+    }
+}
+
+/// Compact delta for watch mode: one score line, then one line per new (`+`)
+/// or resolved (`−`) finding. Unchanged findings are only counted.
+pub fn format_diff_delta(diff: &ReportDiff, use_color: bool) -> String {
+    set_override(use_color);
+    let total = diff.summary.new_count + diff.summary.unchanged_count;
+    let mut output = format!(
+        "  {} {}{}   {} {}{}   {} {}",
+        "Accessibility".bold(),
+        score_text(diff.summary.accessibility_score.after),
+        format_delta(diff.summary.accessibility_score.delta),
+        "Overall".bold(),
+        score_text(diff.summary.overall_score.after),
+        format_delta(diff.summary.overall_score.delta),
+        total,
+        if total == 1 { "finding" } else { "findings" },
+    );
+    if diff.summary.new_count == 0 && diff.summary.resolved_count == 0 {
+        output.push_str(&format!("   {}\n", "no changes".dimmed()));
+        unset_override();
+        return output;
+    }
+    output.push_str(&format!(
+        "   {} new, {} resolved\n",
+        count_colored(diff.summary.new_count, true),
+        count_colored(diff.summary.resolved_count, false),
+    ));
+
+    for page in &diff.pages {
+        for (marker, findings) in [("+", &page.new_findings), ("−", &page.resolved_findings)] {
+            for finding in findings {
+                let marker = if marker == "+" {
+                    marker.red().bold()
+                } else {
+                    marker.green().bold()
+                };
+                output.push_str(&format!(
+                    "  {} {:<8} {} {} {}\n",
+                    marker,
+                    finding.severity.label_en(),
+                    finding.rule_id,
+                    finding.wcag_criterion.dimmed(),
+                    truncate_url(finding.location.as_deref().unwrap_or("document"), 80).dimmed(),
+                ));
+            }
+        }
+    }
+    unset_override();
+    output
+}
+
+fn markdown_findings(md: &mut String, heading: &str, findings: &[DiffFinding]) {
+    if findings.is_empty() {
+        return;
+    }
+    md.push_str(&format!(
+        "\n<details><summary>{heading} ({})</summary>\n\n",
+        findings.len()
+    ));
+    md.push_str("| Severity | Rule | WCAG | Location |\n|---|---|---|---|\n");
+    for finding in findings.iter().take(MARKDOWN_FINDING_LIMIT) {
+        md.push_str(&format!(
+            "| {} | `{}` | {} | `{}` |\n",
+            finding.severity.label_en(),
+            finding.rule_id,
+            finding.wcag_criterion,
+            finding
+                .location
+                .as_deref()
+                .unwrap_or("document")
+                .replace('|', "\\|")
+                .replace('`', "'"),
+        ));
+    }
+    if findings.len() > MARKDOWN_FINDING_LIMIT {
+        md.push_str(&format!(
+            "\n… and {} more\n",
+            findings.len() - MARKDOWN_FINDING_LIMIT
+        ));
+    }
+    md.push_str("\n</details>\n");
+}
+
+fn header_cells(labels: &[&str]) -> Vec<Cell> {
+    labels
+        .iter()
+        .map(|label| {
+            Cell::new(label)
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan)
+        })
+        .collect()
+}
+
+fn score_row(delta: &ScoreDelta) -> Vec<Cell> {
+    let change = Cell::new(format_delta(delta.delta).trim());
+    let change = match delta.delta {
+        Some(d) if d < 0 => change.fg(Color::Red),
+        Some(d) if d > 0 => change.fg(Color::Green),
+        _ => change,
+    };
+    vec![
+        Cell::new(&delta.label),
+        Cell::new(score_text(delta.before)),
+        Cell::new(score_text(delta.after)),
+        change,
+    ]
+}
+
+fn score_text(score: Option<u32>) -> String {
+    score.map_or_else(|| "—".to_string(), |s| s.to_string())
+}
+
+fn format_delta(delta: Option<i64>) -> String {
+    match delta {
+        Some(d) if d > 0 => format!("  +{d}"),
+        Some(d) => format!("  {d}"),
+        None => String::new(),
+    }
+}
+
+fn count_colored(count: usize, is_regression: bool) -> colored::ColoredString {
+    let text = count.to_string();
+    match (count, is_regression) {
+        (0, _) => text.normal(),
+        (_, true) => text.red().bold(),
+        (_, false) => text.green().bold(),
+    }
+}
+
+fn presence_note(presence: PagePresence) -> &'static str {
+    match presence {
+        PagePresence::Both => "",
+        PagePresence::OnlyBefore => " (only in baseline)",
+        PagePresence::OnlyAfter => " (new page)",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::audit::diff_reports;
+
+    fn sample_diff() -> ReportDiff {
+        let report = |score: u32, findings: serde_json::Value| {
+            serde_json::json!({
+                "report_type": "single",
+                "summary": { "accessibility_score": score, "overall_score": score },
+                "pages": [{
+                    "url": "https://example.com/",
+                    "accessibility_score": score,
+                    "overall_score": score,
+                    "findings": findings,
+                }],
+            })
+            .to_string()
+        };
+        let before = report(90, serde_json::json!([]));
+        let after = report(
+            85,
+            serde_json::json!([{
+                "rule_id": "a11y.button.name",
+                "wcag_criterion": "4.1.2",
+                "severity": "critical",
+                "occurrences": [{ "node_id": "1", "selector": "button.icon|x" }],
+            }]),
+        );
+        diff_reports(&before, &after).unwrap()
+    }
+
+    #[test]
+    fn markdown_lists_new_findings_in_details_block() {
+        let md = format_diff_markdown(&sample_diff());
+        assert!(md.contains("Regressions found"));
+        assert!(md.contains("<details><summary>New (1)</summary>"));
+        assert!(md.contains("| Accessibility | 90 | 85 | -5 |"));
+        assert!(md.contains("`button.icon\\|x`"), "pipes are escaped");
+    }
+
+    #[test]
+    fn delta_lists_only_changed_findings() {
+        let text = format_diff_delta(&sample_diff(), false);
+        assert!(text.contains("Accessibility 85  -5"));
+        assert!(text.contains("1 new, 0 resolved"));
+        assert!(text.contains("+ CRITICAL"));
+        assert!(text.contains("a11y.button.name"));
+    }
+
+    #[test]
+    fn table_shows_rule_and_counts() {
+        let text = format_diff_table(&sample_diff(), false);
+        assert!(text.contains("a11y.button.name"));
+        assert!(text.contains("New: 1"));
+    }
+}
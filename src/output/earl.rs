@@ -0,0 +1,253 @@
+//! W3C EARL 1.0 (Evaluation and Report Language) output as JSON-LD.
+//!
+//! Every rule outcome becomes an `earl:Assertion` about the audited page.
+//! Rules with an equivalent W3C ACT rule (see [`RuleLookup::act_rule_id`])
+//! use the ACT rule URL as the test, so results line up with other
+//! ACT-implementing tools; all other rules get a tool-scoped URN.
+
+use std::collections::BTreeSet;
+
+use serde_json::{json, Value};
+
+use crate::audit::normalized::NormalizedReport;
+use crate::taxonomy::RuleLookup;
+use crate::wcag::en301549::EN301549_WEB_CLAUSES;
+use crate::wcag::{RuleOutcome, RuleOutcomeStatus};
+
+const TOOL_NAME: &str = "auditmysite";
+const TOOL_INFO_URI: &str = "https://github.com/casoon/auditmysite";
+const TOOL_VERSION: &str = env!("CARGO_PKG_VERSION");
+const ACT_RULES_BASE: &str = "https://www.w3.org/WAI/standards-guidelines/act/rules/";
+
+fn context() -> Value {
+    json!({
+        "@vocab": "http://www.w3.org/ns/earl#",
+        "earl": "http://www.w3.org/ns/earl#",
+        "WCAG21": "https://www.w3.org/TR/WCAG21/#",
+        "dct": "http://purl.org/dc/terms/",
+        "sch": "https://schema.org/",
+        "ptr": "http://www.w3.org/2009/pointers#",
+        "title": "dct:title",
+        "description": "dct:description",
+        "identifier": "dct:identifier",
+        "date": "dct:date",
+        "source": "dct:source",
+        "hasVersion": "dct:hasVersion",
+        "isPartOf": { "@id": "dct:isPartOf", "@type": "@id" },
+        "assertedBy": { "@type": "@id" },
+        "outcome": { "@type": "@id" },
+        "mode": { "@type": "@id" },
+        "assertions": { "@reverse": "earl:subject" },
+        "expression": "ptr:expression"
+    })
+}
+
+/// EARL outcome and mode for a rule outcome. Checks that need a human to
+/// confirm them are semi-automatic `cantTell`; a check that could not run is
+/// `cantTell` as well, never `passed`.
+fn outcome_and_mode(status: RuleOutcomeStatus) -> (&'static str, &'static str) {
+    match status {
+        RuleOutcomeStatus::NoViolationDetected => ("earl:passed", "earl:automatic"),
+        RuleOutcomeStatus::ViolationsFound => ("earl:failed", "earl:automatic"),
+        RuleOutcomeStatus::NotApplicable => ("earl:inapplicable", "earl:automatic"),
+        RuleOutcomeStatus::Warning | RuleOutcomeStatus::ManualReviewRequired => {
+            ("earl:cantTell", "earl:semiAuto")
+        }
+        RuleOutcomeStatus::Failed => ("earl:cantTell", "earl:automatic"),
+        RuleOutcomeStatus::Skipped => ("earl:untested", "earl:automatic"),
+    }
+}
+
+/// `WCAG21:` fragment for an A/AA criterion, derived from its official title
+/// ("Contrast (Minimum)" → `contrast-minimum`).
+fn wcag_iri(criterion: &str) -> Option<String> {
+    let clause = EN301549_WEB_CLAUSES.iter().find(|c| c.wcag == criterion)?;
+    let slug: String = clause
+        .title_en
+        .to_ascii_lowercase()
+        .chars()
+        .filter(|c| !matches!(c, '(' | ')' | ','))
+        .collect::<String>()
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join("-");
+    Some(format!("WCAG21:{slug}"))
+}
+
+fn test_case(outcome: &RuleOutcome) -> Value {
+    let rule = RuleLookup::by_axe_id(&outcome.rule_id, outcome.wcag_criterion.as_deref());
+    let id = match rule.and_then(|r| RuleLookup::act_rule_id(r.id)) {
+        Some(act) => format!("{ACT_RULES_BASE}{act}/"),
+        None => format!("urn:{TOOL_NAME}:rule:{}", outcome.rule_id),
+    };
+    let mut test = json!({
+        "@id": id,
+        "@type": "earl:TestCase",
+        "identifier": outcome.rule_id,
+        "title": rule.map_or(outcome.rule_id.as_str(), |r| r.title_en),
+    });
+    if let Some(iri) = outcome.wcag_criterion.as_deref().and_then(wcag_iri) {
+        test["isPartOf"] = json!([iri]);
+    }
+    test
+}
+
+fn assertion(outcome: &RuleOutcome, report: &NormalizedReport) -> Value {
+    let (earl_outcome, mode) = outcome_and_mode(outcome.status);
+    let mut result = json!({
+        "@type": "earl:TestResult",
+        "outcome": earl_outcome,
+    });
+
+    let mut description = Vec::new();
+    if outcome.status == RuleOutcomeStatus::ViolationsFound {
+        description.push(format!("{} finding(s)", outcome.finding_count));
+        let selectors: BTreeSet<&str> = report
+            .findings
+            .iter()
+            .filter(|f| f.axe_id.as_deref() == Some(outcome.rule_id.as_str()))
+            .flat_map(|f| f.occurrences.iter())
+            .filter_map(|o| o.selector.as_deref())
+            .collect();
+        if !selectors.is_empty() {
+            result["pointer"] = selectors
+                .into_iter()
+                .map(|s| json!({ "@type": "ptr:CSSSelectorPointer", "expression": s }))
+                .collect();
+        }
+    }
+    if let Some(reason) = &outcome.reason_code {
+        description.push(format!("reason: {reason}"));
+    }
+    if let Some(viewport) = &outcome.viewport {
+        description.push(format!("viewport: {viewport}"));
+    }
+    if !description.is_empty() {
+        result["description"] = json!(description.join("; "));
+    }
+
+    json!({
+        "@type": "earl:Assertion",
+        "assertedBy": TOOL_INFO_URI,
+        "mode": mode,
+        "test": test_case(outcome),
+        "result": result,
+    })
+}
+
+/// Converts the rule outcomes of one or more pages into one EARL JSON-LD
+/// document: the assertor, then one `earl:TestSubject` per page with its
+/// assertions.
+pub fn format_earl(reports: &[&NormalizedReport]) -> anyhow::Result<String> {
+    let mut graph = vec![json!({
+        "@id": TOOL_INFO_URI,
+        "@type": ["earl:Assertor", "earl:Software"],
+        "title": TOOL_NAME,
+        "hasVersion": TOOL_VERSION,
+    })];
+    for report in reports {
+        let assertions: Vec<Value> = report
+            .rule_outcomes
+            .iter()
+            .map(|outcome| assertion(outcome, report))
+            .collect();
+        graph.push(json!({
+            "@type": ["earl:TestSubject", "sch:WebPage"],
+            "source": report.url,
+            "date": report.timestamp.to_rfc3339(),
+            "assertions": assertions,
+        }));
+    }
+
+    let document = json!({
+        "@context": context(),
+        "@graph": graph,
+    });
+    serde_json::to_string_pretty(&document).map_err(|e| anyhow::anyhow!(e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::audit::normalized::normalize;
+    use crate::audit::AuditReport;
+    use crate::cli::WcagLevel;
+    use crate::taxonomy::Severity;
+    use crate::wcag::{Violation, WcagResults};
+
+    fn outcome(rule_id: &str, criterion: &str, status: RuleOutcomeStatus) -> RuleOutcome {
+        RuleOutcome {
+            rule_id: rule_id.to_string(),
+            status,
+            wcag_criterion: Some(criterion.to_string()),
+            viewport: None,
+            reason_code: None,
+            finding_count: usize::from(status == RuleOutcomeStatus::ViolationsFound),
+        }
+    }
+
+    #[test]
+    fn emits_one_assertion_per_rule_outcome_with_act_tests() {
+        let mut results = WcagResults::new();
+        results.add_violation(
+            Violation::new(
+                "1.1.1",
+                "Images must have alternate text",
+                WcagLevel::A,
+                Severity::Critical,
+                "Image is missing an alt attribute",
+                "node-1",
+            )
+            .with_rule_id("image-alt")
+            .with_selector("img.hero"),
+        );
+        results.rule_outcomes = vec![
+            outcome("image-alt", "1.1.1", RuleOutcomeStatus::ViolationsFound),
+            outcome(
+                "color-contrast",
+                "1.4.3",
+                RuleOutcomeStatus::NoViolationDetected,
+            ),
+            outcome(
+                "heading-order",
+                "2.4.6",
+                RuleOutcomeStatus::ManualReviewRequired,
+            ),
+            outcome("frame-title", "2.4.1", RuleOutcomeStatus::NotApplicable),
+        ];
+        let report = AuditReport::new("https://example.com".to_string(), WcagLevel::AA, results, 1);
+        let earl = format_earl(&[&normalize(&report).normalized]).unwrap();
+        let value: Value = serde_json::from_str(&earl).unwrap();
+
+        let subject = &value["@graph"][1];
+        assert_eq!(subject["source"], "https://example.com");
+        let assertions = subject["assertions"].as_array().unwrap();
+        assert_eq!(assertions.len(), 4);
+
+        assert_eq!(
+            assertions[0]["test"]["@id"],
+            "https://www.w3.org/WAI/standards-guidelines/act/rules/23a2a8/"
+        );
+        assert_eq!(
+            assertions[0]["test"]["isPartOf"][0],
+            "WCAG21:non-text-content"
+        );
+        assert_eq!(assertions[0]["result"]["outcome"], "earl:failed");
+        assert_eq!(
+            assertions[0]["result"]["pointer"][0]["expression"],
+            "img.hero"
+        );
+        assert_eq!(assertions[1]["result"]["outcome"], "earl:passed");
+        assert_eq!(
+            assertions[1]["test"]["isPartOf"][0],
+            "WCAG21:contrast-minimum"
+        );
+        assert_eq!(assertions[2]["result"]["outcome"], "earl:cantTell");
+        assert_eq!(assertions[2]["mode"], "earl:semiAuto");
+        assert_eq!(
+            assertions[2]["test"]["@id"],
+            "urn:auditmysite:rule:heading-order"
+        );
+        assert_eq!(assertions[3]["result"]["outcome"], "earl:inapplicable");
+    }
+}
@@ -131,8 +131,7 @@ pub fn get_explanation(rule_id: &str) -> Option<&'static RuleExplanation> {
     }
     // Fallback: if a taxonomy rule_id was passed, resolve to WCAG ID via legacy map
     if rule_id.contains('.') {
-        use crate::taxonomy::rules::RULES;
-        if let Some(rule) = RULES.iter().find(|r| r.id == rule_id) {
+        if let Some(rule) = crate::taxonomy::RuleLookup::by_id(rule_id) {
             if let Some(ext_ref) = rule.external_ref {
                 // external_ref is "WCAG 1.1.1" — extract the number
                 let wcag_id = ext_ref.strip_prefix("WCAG ").unwrap_or(ext_ref);
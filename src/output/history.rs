@@ -0,0 +1,151 @@
+//! Terminal rendering for `auditmysite history` trends.
+//!
+//! JSON output is the `HistoryTrend` serialization itself.
+
+use colored::control::{set_override, unset_override};
+use colored::Colorize;
+use comfy_table::{presets, Attribute, Cell, Color, ContentArrangement, Table};
+
+use crate::audit::history::{HistoryScope, HistoryTrend};
+use crate::util::truncate_url;
+
+/// One row per recorded run (oldest first) with score deltas to the previous run.
+pub fn format_history_table(trend: &HistoryTrend, use_color: bool) -> String {
+    set_override(use_color);
+    let mut output = String::new();
+    output.push('\n');
+    let scope = match trend.scope {
+        HistoryScope::Page => "page",
+        HistoryScope::Site => "site",
+    };
+    output.push_str(&format!(
+        "{} Audit history for {} ({scope})\n\n",
+        "═══".cyan(),
+        truncate_url(&trend.target, 80).bold()
+    ));
+
+    if trend.entries.is_empty() {
+        output.push_str("  No recorded audits yet.\n");
+        unset_override();
+        return output;
+    }
+
+    let mut headers = vec![
+        "Date",
+        "Accessibility",
+        "Overall",
+        "Risk",
+        "Findings",
+        "Critical",
+        "High",
+    ];
+    if trend.scope == HistoryScope::Site {
+        headers.push("Pages");
+    }
+    let mut table = Table::new();
+    table
+        .load_preset(presets::UTF8_FULL)
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_header(headers.iter().map(|label| {
+            Cell::new(label)
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan)
+        }));
+
+    let mut previous = None;
+    for entry in &trend.entries {
+        let (acc_delta, overall_delta) = match previous {
+            Some((acc, overall)) => (
+                Some(entry.accessibility_score as i64 - acc),
+                Some(entry.overall_score as i64 - overall),
+            ),
+            None => (None, None),
+        };
+        let mut row = vec![
+            Cell::new(entry.timestamp.format("%Y-%m-%d %H:%M").to_string()),
+            score_cell(entry.accessibility_score, acc_delta),
+            score_cell(entry.overall_score, overall_delta),
+            Cell::new(format!("{:?}", entry.risk_level)),
+            Cell::new(entry.finding_count),
+            Cell::new(entry.severity_counts.critical),
+            Cell::new(entry.severity_counts.high),
+        ];
+        if trend.scope == HistoryScope::Site {
+            row.push(Cell::new(entry.pages));
+        }
+        table.add_row(row);
+        previous = Some((entry.accessibility_score as i64, entry.overall_score as i64));
+    }
+    output.push_str(&table.to_string());
+    output.push('\n');
+
+    if let (Some(first), Some(last)) = (trend.entries.first(), trend.entries.last()) {
+        if trend.entries.len() > 1 {
+            let delta = last.accessibility_score as i64 - first.accessibility_score as i64;
+            let delta = match delta {
+                d if d > 0 => format!("+{d}").green().bold(),
+                d if d < 0 => d.to_string().red().bold(),
+                d => d.to_string().normal(),
+            };
+            output.push_str(&format!(
+                "\n  {} {} → {} ({}) over {} runs, findings {} → {}\n",
+                "Accessibility:".bold(),
+                first.accessibility_score,
+                last.accessibility_score,
+                delta,
+                trend.entries.len(),
+                first.finding_count,
+                last.finding_count,
+            ));
+        }
+    }
+
+    unset_override();
+    output
+}
+
+fn score_cell(score: u32, delta: Option<i64>) -> Cell {
+    match delta {
+        Some(d) if d > 0 => Cell::new(format!("{score} (+{d})")).fg(Color::Green),
+        Some(d) if d < 0 => Cell::new(format!("{score} ({d})")).fg(Color::Red),
+        _ => Cell::new(score),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::audit::history::HistoryEntry;
+    use crate::audit::normalized::{RiskLevel, SeverityCounts};
+
+    fn entry(score: u32, findings: usize, millis: i64) -> HistoryEntry {
+        HistoryEntry {
+            url: "https://example.com/".to_string(),
+            timestamp: chrono::DateTime::from_timestamp_millis(millis).unwrap(),
+            accessibility_score: score,
+            overall_score: score,
+            risk_level: RiskLevel::Medium,
+            finding_count: findings,
+            severity_counts: SeverityCounts::default(),
+            module_scores: Default::default(),
+            pages: 1,
+            report_file: None,
+            cli_version: "test".to_string(),
+        }
+    }
+
+    #[test]
+    fn table_shows_deltas_and_summary() {
+        let trend = HistoryTrend {
+            target: "https://example.com/".to_string(),
+            scope: HistoryScope::Page,
+            entries: vec![
+                entry(70, 9, 1_700_000_000_000),
+                entry(82, 4, 1_700_086_400_000),
+            ],
+        };
+        let text = format_history_table(&trend, false);
+        assert!(text.contains("82 (+12)"));
+        assert!(text.contains("70 → 82 (+12) over 2 runs, findings 9 → 4"));
+    }
+}
@@ -0,0 +1,529 @@
+//! Self-contained interactive HTML report.
+//!
+//! One offline file: styles, the filter script and every screenshot are
+//! inlined, nothing is fetched at view time. Findings can be filtered by
+//! module, severity, WCAG criterion and (in batch reports) page; occurrence
+//! lists are collapsible. Batch reports add a page ranking from the batch
+//! presentation builder with a drill-down section per page.
+
+use std::collections::BTreeSet;
+use std::fmt::Write as _;
+
+use crate::audit::normalized::{NormalizedFinding, NormalizedReport};
+use crate::audit::{normalize, AuditReport, BatchReport, ViewportScreenshot};
+use crate::error::{AuditError, Result};
+use crate::i18n::I18n;
+use crate::output::builder::build_batch_presentation_with_normalized;
+use crate::output::renderer::ReportRenderer;
+use crate::util::base64_encode;
+
+/// Occurrences listed per finding; the rest are summarized in one line.
+const MAX_LISTED_OCCURRENCES: usize = 100;
+
+/// HTML renderer for single-page reports.
+pub struct HtmlRenderer;
+
+impl ReportRenderer for HtmlRenderer {
+    fn render_single_report(
+        &self,
+        normalized: &NormalizedReport,
+        raw: &AuditReport,
+    ) -> Result<String> {
+        let title = format!("Accessibility report – {}", normalized.url);
+        let mut body = String::new();
+        write_header(&mut body, &title, &summary_line(normalized));
+        write_scores(&mut body, normalized);
+        write_screenshots(&mut body, raw);
+        write_filters(&mut body, &[normalized], false);
+        body.push_str("<section class=\"findings\">\n");
+        write_findings(&mut body, normalized);
+        body.push_str("</section>\n");
+        Ok(document(&title, &body))
+    }
+}
+
+/// Render a single-page audit as an HTML document.
+pub fn format_html(report: &AuditReport) -> Result<String> {
+    let ctx = normalize(report);
+    HtmlRenderer.render_single_report(&ctx.normalized, report)
+}
+
+/// Render a batch audit as an HTML document with per-page drill-down.
+pub fn format_html_batch(batch: &BatchReport) -> Result<String> {
+    let normalized: Vec<NormalizedReport> = batch
+        .reports
+        .iter()
+        .map(|r| normalize(r).normalized)
+        .collect();
+    let i18n = I18n::new("en").map_err(|e| AuditError::ReportGenerationFailed {
+        reason: e.to_string(),
+    })?;
+    let presentation = build_batch_presentation_with_normalized(batch, &i18n, &normalized);
+    let portfolio = &presentation.portfolio_summary;
+
+    let title = format!("Accessibility report – {} pages", portfolio.total_urls);
+    let subtitle = format!(
+        "{} pages audited, {} passed, {} failed · average score {:.0} · {} violations · {}",
+        portfolio.total_urls,
+        portfolio.passed,
+        portfolio.failed,
+        portfolio.average_score,
+        portfolio.total_violations,
+        tool_line()
+    );
+    let mut body = String::new();
+    write_header(&mut body, &title, &subtitle);
+
+    body.push_str("<section class=\"ranking\">\n<h2>Pages</h2>\n<table>\n<thead><tr><th>Page</th><th>Score</th><th>Overall</th><th>Grade</th><th>Critical</th><th>Violations</th></tr></thead>\n<tbody>\n");
+    for row in &presentation.url_ranking {
+        let anchor = normalized
+            .iter()
+            .position(|n| n.url == row.url)
+            .map(|i| format!("#page-{i}"))
+            .unwrap_or_default();
+        let _ = writeln!(
+            body,
+            "<tr class=\"{}\"><td><a href=\"{}\">{}</a></td><td>{:.0}</td><td>{}</td><td>{}</td><td>{}</td><td>{}</td></tr>",
+            if row.passed { "passed" } else { "failed" },
+            anchor,
+            escape(&row.url),
+            row.score,
+            row.overall_score,
+            escape(&row.grade),
+            row.critical_violations,
+            row.total_violations
+        );
+    }
+    body.push_str("</tbody>\n</table>\n");
+    if !batch.errors.is_empty() {
+        body.push_str("<h3>Pages that could not be audited</h3>\n<ul class=\"errors\">\n");
+        for error in &batch.errors {
+            let _ = writeln!(
+                body,
+                "<li><strong>{}</strong>: {}</li>",
+                escape(&error.url),
+                escape(&error.error)
+            );
+        }
+        body.push_str("</ul>\n");
+    }
+    body.push_str("</section>\n");
+
+    let pages: Vec<&NormalizedReport> = normalized.iter().collect();
+    write_filters(&mut body, &pages, true);
+    body.push_str("<section class=\"findings\">\n");
+    for (i, (page, raw)) in normalized.iter().zip(&batch.reports).enumerate() {
+        let _ = writeln!(
+            body,
+            "<details class=\"page\" id=\"page-{}\" data-page=\"{}\">\n<summary><span class=\"page-url\">{}</span> <span class=\"muted\">score {} · {} findings</span></summary>",
+            i,
+            escape(&page.url),
+            escape(&page.url),
+            page.score,
+            page.findings.len()
+        );
+        write_scores(&mut body, page);
+        write_screenshots(&mut body, raw);
+        write_findings(&mut body, page);
+        body.push_str("</details>\n");
+    }
+    body.push_str("</section>\n");
+    Ok(document(&title, &body))
+}
+
+fn summary_line(normalized: &NormalizedReport) -> String {
+    format!(
+        "WCAG {} · {} · {}",
+        normalized.wcag_level,
+        normalized.timestamp.format("%Y-%m-%d %H:%M UTC"),
+        tool_line()
+    )
+}
+
+fn tool_line() -> String {
+    format!("auditmysite {}", env!("CARGO_PKG_VERSION"))
+}
+
+fn write_header(out: &mut String, title: &str, subtitle: &str) {
+    let _ = writeln!(
+        out,
+        "<header>\n<h1>{}</h1>\n<p class=\"muted\">{}</p>\n</header>",
+        escape(title),
+        escape(subtitle)
+    );
+}
+
+fn write_scores(out: &mut String, normalized: &NormalizedReport) {
+    out.push_str("<div class=\"scores\">\n");
+    let cards = [
+        ("Accessibility", normalized.score.to_string()),
+        ("Overall", normalized.overall_score.to_string()),
+        ("Grade", normalized.grade.clone()),
+        ("Certificate", normalized.certificate.clone()),
+        ("Risk", format!("{:?}", normalized.risk.level)),
+    ];
+    for (label, value) in cards {
+        let _ = writeln!(
+            out,
+            "<div class=\"card\"><span class=\"muted\">{}</span><strong>{}</strong></div>",
+            label,
+            escape(&value)
+        );
+    }
+    for module in &normalized.module_scores {
+        let _ = writeln!(
+            out,
+            "<div class=\"card module\"><span class=\"muted\">{}</span><strong>{}</strong></div>",
+            escape(&module.name),
+            module.score
+        );
+    }
+    out.push_str("</div>\n");
+}
+
+fn write_screenshots(out: &mut String, raw: &AuditReport) {
+    let mut shots: Vec<(&str, &[u8])> = Vec::new();
+    if let Some(dual) = &raw.dual_viewport {
+        let viewports = [
+            ("Desktop", &dual.desktop.screenshot),
+            ("Mobile", &dual.mobile.screenshot),
+        ];
+        for (label, shot) in viewports {
+            if let Some(ViewportScreenshot { bytes, .. }) = shot {
+                shots.push((label, bytes));
+            }
+        }
+    }
+    if shots.is_empty() {
+        if let Some(page) = &raw.page_screenshots {
+            shots.push(("Desktop", &page.desktop));
+            shots.push(("Mobile", &page.mobile));
+        }
+    }
+    if shots.is_empty() {
+        return;
+    }
+    out.push_str("<div class=\"screenshots\">\n");
+    for (label, bytes) in shots {
+        let _ = writeln!(
+            out,
+            "<figure><img src=\"{}\" alt=\"{} viewport of the audited page\"><figcaption>{}</figcaption></figure>",
+            data_uri(bytes),
+            label,
+            label
+        );
+    }
+    out.push_str("</div>\n");
+}
+
+fn write_filters(out: &mut String, pages: &[&NormalizedReport], by_page: bool) {
+    let findings = || pages.iter().flat_map(|p| p.findings.iter());
+    let modules: BTreeSet<&str> = findings().map(|f| f.dimension.as_str()).collect();
+    let mut severities: Vec<_> = findings().map(|f| f.severity).collect();
+    severities.sort_by(|a, b| b.cmp(a));
+    severities.dedup();
+    let criteria: BTreeSet<&str> = findings().map(criterion).collect();
+    let total = findings().count();
+
+    out.push_str("<form class=\"filters\" onsubmit=\"return false\">\n");
+    let mut select = |key: &str, label: &str, values: Vec<String>| {
+        let _ = write!(
+            out,
+            "<label>{label} <select data-filter=\"{key}\"><option value=\"\">All</option>"
+        );
+        for value in values {
+            let value = escape(&value);
+            let _ = write!(out, "<option value=\"{value}\">{value}</option>");
+        }
+        out.push_str("</select></label>\n");
+    };
+    select(
+        "module",
+        "Module",
+        modules.into_iter().map(str::to_string).collect(),
+    );
+    select(
+        "severity",
+        "Severity",
+        severities.iter().map(|s| s.to_string()).collect(),
+    );
+    select(
+        "wcag",
+        "WCAG",
+        criteria.into_iter().map(str::to_string).collect(),
+    );
+    if by_page {
+        select(
+            "page",
+            "Page",
+            pages.iter().map(|p| p.url.clone()).collect(),
+        );
+    }
+    let _ = writeln!(
+        out,
+        "<span class=\"muted\"><span id=\"shown\">{total}</span> of {total} findings</span>\n</form>"
+    );
+}
+
+fn write_findings(out: &mut String, normalized: &NormalizedReport) {
+    if normalized.findings.is_empty() {
+        out.push_str("<p class=\"muted\">No findings.</p>\n");
+        return;
+    }
+    for finding in &normalized.findings {
+        write_finding(out, finding, &normalized.url);
+    }
+}
+
+fn write_finding(out: &mut String, finding: &NormalizedFinding, page: &str) {
+    let severity = finding.severity.to_string();
+    let _ = writeln!(
+        out,
+        "<article class=\"finding\" data-module=\"{}\" data-severity=\"{}\" data-wcag=\"{}\" data-page=\"{}\">",
+        escape(&finding.dimension),
+        severity,
+        escape(criterion(finding)),
+        escape(page)
+    );
+    let _ = writeln!(
+        out,
+        "<h3><span class=\"sev sev-{}\">{}</span> {}</h3>",
+        severity,
+        finding.severity.label_en(),
+        escape(&finding.title)
+    );
+    let mut meta = vec![escape(&finding.dimension), escape(&finding.rule_id)];
+    if !finding.wcag_criterion.is_empty() {
+        meta.push(format!(
+            "WCAG {} ({})",
+            escape(&finding.wcag_criterion),
+            escape(&finding.wcag_level)
+        ));
+    }
+    if let Some(url) = &finding.help_url {
+        meta.push(format!(
+            "<a href=\"{}\" rel=\"noopener\">Reference</a>",
+            escape(url)
+        ));
+    }
+    let _ = writeln!(out, "<p class=\"muted\">{}</p>", meta.join(" · "));
+    if !finding.description.is_empty() {
+        let _ = writeln!(out, "<p>{}</p>", escape(&finding.description));
+    }
+    if !finding.user_impact.is_empty() {
+        let _ = writeln!(
+            out,
+            "<p><strong>Impact:</strong> {}</p>",
+            escape(&finding.user_impact)
+        );
+    }
+
+    let _ = writeln!(
+        out,
+        "<details class=\"occurrences\"><summary>{} occurrence{}</summary>\n<ol>",
+        finding.occurrence_count,
+        if finding.occurrence_count == 1 {
+            ""
+        } else {
+            "s"
+        }
+    );
+    for occurrence in finding.occurrences.iter().take(MAX_LISTED_OCCURRENCES) {
+        out.push_str("<li>");
+        if let Some(selector) = &occurrence.selector {
+            let _ = write!(out, "<code>{}</code> ", escape(selector));
+        }
+        let _ = write!(out, "{}", escape(&occurrence.message));
+        if !occurrence.tags.is_empty() {
+            let _ = write!(
+                out,
+                " <span class=\"muted\">[{}]</span>",
+                escape(&occurrence.tags.join(", "))
+            );
+        }
+        if let Some(fix) = &occurrence.fix_suggestion {
+            let _ = write!(out, "<p><strong>Fix:</strong> {}</p>", escape(fix));
+        }
+        if let Some(html) = &occurrence.html_snippet {
+            let _ = write!(out, "<pre>{}</pre>", escape(html));
+        }
+        if let Some(shot) = &occurrence.evidence_screenshot {
+            let _ = write!(
+                out,
+                "<img class=\"evidence\" loading=\"lazy\" src=\"{}\" alt=\"Affected element ({} viewport)\">",
+                data_uri(shot),
+                occurrence.evidence_viewport.unwrap_or("desktop")
+            );
+        }
+        out.push_str("</li>\n");
+    }
+    let hidden = finding
+        .occurrences
+        .len()
+        .saturating_sub(MAX_LISTED_OCCURRENCES);
+    if hidden > 0 {
+        let _ = writeln!(
+            out,
+            "<li class=\"muted\">… and {hidden} more (see the JSON report)</li>"
+        );
+    }
+    out.push_str("</ol>\n</details>\n</article>\n");
+}
+
+fn criterion(finding: &NormalizedFinding) -> &str {
+    if finding.wcag_criterion.is_empty() {
+        "none"
+    } else {
+        &finding.wcag_criterion
+    }
+}
+
+fn document(title: &str, body: &str) -> String {
+    format!(
+        "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n<meta name=\"viewport\" content=\"width=device-width, initial-scale=1\">\n<meta name=\"generator\" content=\"{}\">\n<title>{}</title>\n<style>{}</style>\n</head>\n<body>\n<main>\n{}</main>\n<script>{}</script>\n</body>\n</html>\n",
+        tool_line(),
+        escape(title),
+        STYLE,
+        body,
+        SCRIPT
+    )
+}
+
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Inline image as a `data:` URI (PNG, or JPEG by magic bytes).
+fn data_uri(bytes: &[u8]) -> String {
+    let mime = if bytes.starts_with(&[0xFF, 0xD8, 0xFF]) {
+        "image/jpeg"
+    } else {
+        "image/png"
+    };
+    format!("data:{mime};base64,{}", base64_encode(bytes))
+}
+
+const STYLE: &str = r#"
+:root { color-scheme: light dark; --muted: #667; --line: #d8dbe2; --card: #f4f5f8; }
+@media (prefers-color-scheme: dark) { :root { --muted: #99a; --line: #3a3d46; --card: #22252c; } }
+body { font: 15px/1.5 system-ui, sans-serif; margin: 0; }
+main { max-width: 1100px; margin: 0 auto; padding: 1.5rem; }
+h1 { margin-bottom: .2rem; }
+.muted { color: var(--muted); }
+.scores, .screenshots { display: flex; flex-wrap: wrap; gap: .75rem; margin: 1rem 0; }
+.card { background: var(--card); border-radius: 8px; padding: .6rem .9rem; min-width: 7rem; display: flex; flex-direction: column; }
+.card strong { font-size: 1.3rem; }
+.screenshots figure { margin: 0; }
+.screenshots img { max-height: 320px; border: 1px solid var(--line); border-radius: 6px; }
+.filters { position: sticky; top: 0; background: Canvas; padding: .6rem 0; border-bottom: 1px solid var(--line); display: flex; flex-wrap: wrap; gap: 1rem; align-items: center; z-index: 1; }
+.finding { border: 1px solid var(--line); border-radius: 8px; padding: .2rem 1rem .6rem; margin: .8rem 0; }
+.finding h3 { margin: .6rem 0 .2rem; font-size: 1.05rem; }
+.sev { font-size: .75rem; border-radius: 4px; padding: .1rem .4rem; color: #fff; vertical-align: middle; }
+.sev-critical { background: #b00020; } .sev-high { background: #d9480f; }
+.sev-medium { background: #b08800; } .sev-low { background: #5c6bc0; }
+pre { white-space: pre-wrap; word-break: break-all; background: var(--card); padding: .4rem; border-radius: 4px; font-size: .85rem; }
+code { font-size: .85rem; }
+img.evidence { display: block; max-width: 100%; max-height: 200px; border: 1px solid var(--line); margin: .3rem 0; }
+table { border-collapse: collapse; width: 100%; }
+th, td { text-align: left; padding: .3rem .5rem; border-bottom: 1px solid var(--line); }
+tr.failed td:first-child { border-left: 3px solid #b00020; }
+details.page { border-top: 1px solid var(--line); padding: .5rem 0; }
+details.page > summary { cursor: pointer; font-weight: 600; }
+"#;
+
+const SCRIPT: &str = r#"
+(function () {
+  var selects = Array.prototype.slice.call(document.querySelectorAll('[data-filter]'));
+  function apply() {
+    var active = {};
+    selects.forEach(function (s) { if (s.value) active[s.getAttribute('data-filter')] = s.value; });
+    var shown = 0;
+    document.querySelectorAll('.finding').forEach(function (f) {
+      var match = Object.keys(active).every(function (k) { return f.getAttribute('data-' + k) === active[k]; });
+      f.hidden = !match;
+      if (match) shown++;
+    });
+    document.querySelectorAll('.page').forEach(function (p) {
+      p.hidden = !!active.page && p.getAttribute('data-page') !== active.page;
+      if (active.page && !p.hidden) p.open = true;
+    });
+    var counter = document.getElementById('shown');
+    if (counter) counter.textContent = shown;
+  }
+  function openTarget() {
+    var target = location.hash && document.getElementById(location.hash.slice(1));
+    if (target && target.tagName === 'DETAILS') target.open = true;
+  }
+  selects.forEach(function (s) { s.addEventListener('change', apply); });
+  window.addEventListener('hashchange', openTarget);
+  openTarget();
+  apply();
+})();
+"#;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::WcagLevel;
+    use crate::wcag::{Severity, Violation, WcagResults};
+
+    fn sample_report(url: &str) -> AuditReport {
+        let mut results = WcagResults::new();
+        let mut violation = Violation::new(
+            "1.1.1",
+            "Images must have alternate text",
+            WcagLevel::A,
+            Severity::Critical,
+            "Image is missing an alt attribute",
+            "node-1",
+        )
+        .with_selector("img.hero")
+        .with_html_snippet("<img src=\"hero.png\" onerror=\"alert(1)\">");
+        violation.evidence_screenshot = Some(vec![0x89, b'P', b'N', b'G']);
+        results.add_violation(violation);
+        AuditReport::new(url.to_string(), WcagLevel::AA, results, 100)
+    }
+
+    #[test]
+    fn single_report_is_self_contained_and_filterable() {
+        let html = format_html(&sample_report("https://example.com/?a=1&b=<2>")).unwrap();
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        assert!(html.contains("data-severity=\"critical\""));
+        assert!(html.contains("data-wcag=\"1.1.1\""));
+        assert!(html.contains("<select data-filter=\"module\">"));
+        assert!(!html.contains("data-filter=\"page\""));
+        // Untrusted page content is escaped, evidence is inlined.
+        assert!(html.contains("&lt;img src=&quot;hero.png&quot; onerror=&quot;alert(1)&quot;&gt;"));
+        assert!(html.contains("https://example.com/?a=1&amp;b=&lt;2&gt;"));
+        assert!(html.contains("src=\"data:image/png;base64,iVBORw==\""));
+        assert!(!html.contains("src=\"http"));
+    }
+
+    #[test]
+    fn batch_report_links_ranking_rows_to_page_sections() {
+        let batch = BatchReport::from_reports(
+            vec![
+                sample_report("https://example.com/a"),
+                sample_report("https://example.com/b"),
+            ],
+            vec![],
+            200,
+        );
+        let html = format_html_batch(&batch).unwrap();
+        assert!(html.contains("<select data-filter=\"page\">"));
+        assert!(html.contains("id=\"page-1\" data-page=\"https://example.com/b\""));
+        assert!(html.contains("<a href=\"#page-0\">https://example.com/a</a>"));
+        assert_eq!(html.matches("<article class=\"finding\"").count(), 2);
+    }
+}
@@ -85,6 +85,10 @@ pub struct UnifiedReport {
     /// consumer tell a representative sample apart from full coverage (#261).
     #[serde(skip_serializing_if = "Option::is_none")]
     pub sample: Option<SampleMetadata>,
+    /// Batch only — template sampling clusters and their extrapolated
+    /// findings (`--template-sample`).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub template_sampling: Option<crate::audit::url_sampling::TemplateSampling>,
     pub pages: Vec<PageEntry>,
     /// Batch only — compact per-URL score matrix.
     #[serde(skip_serializing_if = "Vec::is_empty")]
@@ -493,6 +497,16 @@ pub struct PageEntry {
     /// Findings produced by the Accessibility-Journey-Layer (phase 2+).
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub interactive_findings: Vec<crate::audit::normalized::InteractiveFinding>,
+    /// Violations accepted via the waivers file; not part of `findings`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub waived_findings: Vec<crate::audit::WaivedViolation>,
+    /// Findings suppressed by `data-auditmysite-ignore` annotations, with the
+    /// annotation value and the annotated element; not part of `findings`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub suppressed_findings: Vec<crate::wcag::SuppressedViolation>,
+    /// Device matrix with breakpoint-specific findings (`[[devices]]`).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub device_results: Vec<crate::audit::DeviceResult>,
     /// Reproducible journey traces. Present only when `--interactive != off`.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub accessibility_journey: Option<crate::audit::normalized::AccessibilityJourney>,
@@ -1018,9 +1032,19 @@ impl UnifiedReport {
                     .unwrap_or_else(|| "mixed".to_string()),
                 execution_time_ms: batch_report.total_duration_ms,
             },
-            audit_scope: normalized_reports
-                .first()
-                .map(|report| report.execution.scope.clone()),
+            audit_scope: normalized_reports.first().map(|report| {
+                let mut scope = report.execution.scope.clone();
+                scope.sampling = batch_report.template_sampling.as_ref().map(|sampling| {
+                    crate::audit::SamplingScope {
+                        strategy: "template".to_string(),
+                        sampled: true,
+                        audited_urls: sampling.audited_urls,
+                        total_urls: sampling.total_urls,
+                        sampling_ratio: sampling.sampling_ratio,
+                    }
+                });
+                scope
+            }),
             execution_environment: normalized_reports
                 .first()
                 .map(|report| report.execution.environment.clone()),
@@ -1028,6 +1052,7 @@ impl UnifiedReport {
             metric_context: metric_context("batch"),
             summary,
             sample: batch_report.sample.clone(),
+            template_sampling: batch_report.template_sampling.clone(),
             pages,
             url_matrix: presentation.url_matrix,
             internal_comparison: Some(build_internal_comparison(&normalized_reports)),
@@ -1152,6 +1177,7 @@ impl UnifiedReport {
             metric_context: metric_context("single"),
             summary,
             sample: None,
+            template_sampling: None,
             pages: vec![page],
             url_matrix: Vec::new(),
             internal_comparison: None,
@@ -1242,6 +1268,7 @@ impl UnifiedReport {
             metric_context: metric_context("single"),
             summary,
             sample: None,
+            template_sampling: None,
             pages: vec![page],
             url_matrix: Vec::new(),
             internal_comparison: None,
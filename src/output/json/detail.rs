@@ -62,6 +62,9 @@ pub(super) fn build_page(
         consent: Some(normalized.execution.consent.clone()),
         consent_privacy: normalized.consent_privacy.clone(),
         interactive_findings: normalized.interactive_findings.clone(),
+        waived_findings: normalized.waived_findings.clone(),
+        suppressed_findings: normalized.suppressed_findings.clone(),
+        device_results: normalized.device_results.clone(),
         accessibility_journey: normalized.accessibility_journey.clone(),
         screen_reader: normalized.screen_reader.clone(),
         content_profile: None,
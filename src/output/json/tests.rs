@@ -927,6 +927,7 @@ fn test_collection_errors_serialized_when_present() {
             commerce: None,
         },
         sample: None,
+        template_sampling: None,
         pages: vec![],
         url_matrix: vec![],
         internal_comparison: None,
@@ -0,0 +1,430 @@
+//! JUnit XML output for CI test dashboards (Jenkins, GitLab, Azure DevOps).
+//!
+//! Each audited URL becomes a `<testsuite>`; each WCAG rule outcome, each
+//! performance budget and each interactive journey becomes a `<testcase>`.
+//! Violations, exceeded budgets and journey findings are failures, rule checks
+//! that could not run are errors, and manual-review, skipped and
+//! not-applicable outcomes are reported as skipped.
+
+use std::fmt::Write as _;
+
+use crate::audit::normalized::{NormalizedFinding, NormalizedReport};
+use crate::audit::occurrence_analysis::{build_pattern_clusters, build_representative_occurrences};
+use crate::audit::{normalize, AuditReport, BatchError, BatchReport};
+use crate::wcag::{RuleOutcome, RuleOutcomeStatus};
+
+enum CaseResult {
+    Passed,
+    Failure { message: String, body: String },
+    Error { message: String },
+    Skipped { message: String },
+}
+
+struct TestCase {
+    classname: String,
+    name: String,
+    result: CaseResult,
+}
+
+/// Render a single-page audit as a JUnit XML document.
+pub fn format_junit(report: &AuditReport) -> String {
+    render(&[report], &[])
+}
+
+/// Render a batch audit as a JUnit XML document, one test suite per URL.
+/// URLs that could not be audited become a suite with one errored test case.
+pub fn format_junit_batch(batch: &BatchReport) -> String {
+    let reports: Vec<&AuditReport> = batch.reports.iter().collect();
+    render(&reports, &batch.errors)
+}
+
+fn render(reports: &[&AuditReport], errors: &[BatchError]) -> String {
+    let mut suites = String::new();
+    let (mut tests, mut failures, mut errored, mut skipped) = (0, 0, 0, 0);
+    let mut total_ms = 0u64;
+
+    for report in reports {
+        let normalized = normalize(report).normalized;
+        let cases = test_cases(&normalized, report);
+        let counts = count(&cases);
+        tests += cases.len();
+        failures += counts.0;
+        errored += counts.1;
+        skipped += counts.2;
+        total_ms += normalized.duration_ms;
+        let _ = writeln!(
+            suites,
+            "  <testsuite name=\"{}\" tests=\"{}\" failures=\"{}\" errors=\"{}\" skipped=\"{}\" time=\"{}\" timestamp=\"{}\">",
+            escape(&normalized.url),
+            cases.len(),
+            counts.0,
+            counts.1,
+            counts.2,
+            seconds(normalized.duration_ms),
+            normalized.timestamp.format("%Y-%m-%dT%H:%M:%S")
+        );
+        for case in &cases {
+            write_case(&mut suites, case);
+        }
+        suites.push_str("  </testsuite>\n");
+    }
+
+    for error in errors {
+        tests += 1;
+        errored += 1;
+        let _ = writeln!(
+            suites,
+            "  <testsuite name=\"{}\" tests=\"1\" failures=\"0\" errors=\"1\" skipped=\"0\" time=\"0\">",
+            escape(&error.url)
+        );
+        write_case(
+            &mut suites,
+            &TestCase {
+                classname: "audit".to_string(),
+                name: "page audit".to_string(),
+                result: CaseResult::Error {
+                    message: error.error.clone(),
+                },
+            },
+        );
+        suites.push_str("  </testsuite>\n");
+    }
+
+    format!(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<testsuites name=\"auditmysite\" tests=\"{}\" failures=\"{}\" errors=\"{}\" skipped=\"{}\" time=\"{}\">\n{}</testsuites>\n",
+        tests,
+        failures,
+        errored,
+        skipped,
+        seconds(total_ms),
+        suites
+    )
+}
+
+fn test_cases(normalized: &NormalizedReport, raw: &AuditReport) -> Vec<TestCase> {
+    let mut cases: Vec<TestCase> = normalized
+        .rule_outcomes
+        .iter()
+        .map(|outcome| rule_case(outcome, normalized))
+        .collect();
+
+    for budget in &raw.experience.budget_violations {
+        cases.push(TestCase {
+            classname: "budget".to_string(),
+            name: budget.metric.clone(),
+            result: CaseResult::Failure {
+                message: format!(
+                    "{} is {} (budget {}, exceeded by {:.0}%)",
+                    budget.metric, budget.actual_label, budget.budget_label, budget.exceeded_by_pct
+                ),
+                body: format!("Severity: {}", budget.severity.label()),
+            },
+        });
+    }
+
+    let mut journeys: Vec<&str> = normalized
+        .accessibility_journey
+        .iter()
+        .flat_map(|journey| journey.traces.iter().map(|trace| trace.journey.as_str()))
+        .chain(
+            normalized
+                .interactive_findings
+                .iter()
+                .map(|finding| finding.journey.as_str()),
+        )
+        .collect();
+    journeys.sort_unstable();
+    journeys.dedup();
+    for journey in journeys {
+        let findings: Vec<_> = normalized
+            .interactive_findings
+            .iter()
+            .filter(|finding| finding.journey == journey)
+            .collect();
+        let result = if findings.is_empty() {
+            CaseResult::Passed
+        } else {
+            let mut body = String::new();
+            for finding in &findings {
+                let _ = writeln!(
+                    body,
+                    "[{}] {}: {}",
+                    finding.severity, finding.category, finding.message
+                );
+                if let Some(fix) = &finding.fix_suggestion {
+                    let _ = writeln!(body, "  Fix: {fix}");
+                }
+            }
+            CaseResult::Failure {
+                message: format!("{} journey finding(s)", findings.len()),
+                body,
+            }
+        };
+        cases.push(TestCase {
+            classname: "journey".to_string(),
+            name: journey.to_string(),
+            result,
+        });
+    }
+
+    cases
+}
+
+fn rule_case(outcome: &RuleOutcome, normalized: &NormalizedReport) -> TestCase {
+    let mut name = match &outcome.wcag_criterion {
+        Some(criterion) => format!("{} {}", criterion, outcome.rule_id),
+        None => outcome.rule_id.clone(),
+    };
+    if let Some(viewport) = &outcome.viewport {
+        let _ = write!(name, " [{viewport}]");
+    }
+    let reason = outcome.reason_code.as_deref();
+    let result = match outcome.status {
+        RuleOutcomeStatus::ViolationsFound => {
+            let findings: Vec<&NormalizedFinding> = normalized
+                .findings
+                .iter()
+                .filter(|f| f.axe_id.as_deref() == Some(outcome.rule_id.as_str()))
+                .collect();
+            let occurrences: usize = findings.iter().map(|f| f.occurrence_count).sum();
+            let message = match findings.first() {
+                Some(first) => format!(
+                    "{}: {} occurrence(s)",
+                    first.title,
+                    occurrences.max(outcome.finding_count)
+                ),
+                None => format!("{} finding(s)", outcome.finding_count),
+            };
+            CaseResult::Failure {
+                message,
+                body: findings.iter().map(|f| failure_body(f)).collect(),
+            }
+        }
+        RuleOutcomeStatus::Failed => CaseResult::Error {
+            message: format!("rule check failed ({})", reason.unwrap_or("unknown reason")),
+        },
+        RuleOutcomeStatus::ManualReviewRequired => CaseResult::Skipped {
+            message: "manual review required".to_string(),
+        },
+        RuleOutcomeStatus::NotApplicable => CaseResult::Skipped {
+            message: format!(
+                "not applicable ({})",
+                reason.unwrap_or("no matching content")
+            ),
+        },
+        RuleOutcomeStatus::Skipped => CaseResult::Skipped {
+            message: format!("skipped ({})", reason.unwrap_or("not run")),
+        },
+        RuleOutcomeStatus::NoViolationDetected | RuleOutcomeStatus::Warning => CaseResult::Passed,
+    };
+    TestCase {
+        classname: "wcag".to_string(),
+        name,
+        result,
+    }
+}
+
+/// Failure text for one finding: representative occurrences and recurring
+/// selector patterns, as chosen by `occurrence_analysis`.
+fn failure_body(finding: &NormalizedFinding) -> String {
+    let mut body = String::new();
+    let _ = writeln!(
+        body,
+        "{} (WCAG {}, {})",
+        finding.title, finding.wcag_criterion, finding.severity
+    );
+    if !finding.description.is_empty() {
+        let _ = writeln!(body, "{}", finding.description);
+    }
+    let _ = writeln!(body, "Occurrences: {}", finding.occurrence_count);
+
+    let clusters = build_pattern_clusters(&finding.occurrences);
+    if clusters.len() > 1 || clusters.first().is_some_and(|c| c.occurrences > 1) {
+        body.push_str("Patterns:\n");
+        for cluster in clusters {
+            let _ = writeln!(body, "  {} ({}x)", cluster.label, cluster.occurrences);
+        }
+    }
+    let examples = build_representative_occurrences(&finding.occurrences);
+    if !examples.is_empty() {
+        body.push_str("Examples:\n");
+        for example in examples {
+            let _ = writeln!(body, "  - {}: {}", example.selector, example.message);
+            if let Some(snippet) = &example.html_snippet {
+                let _ = writeln!(body, "    {}", snippet.trim());
+            }
+            if let Some(code) = &example.suggested_code {
+                let _ = writeln!(body, "    Fix: {}", code.trim());
+            }
+        }
+    }
+    if let Some(url) = &finding.help_url {
+        let _ = writeln!(body, "Help: {url}");
+    }
+    body.push('\n');
+    body
+}
+
+fn write_case(out: &mut String, case: &TestCase) {
+    let _ = write!(
+        out,
+        "    <testcase classname=\"{}\" name=\"{}\" time=\"0\"",
+        escape(&case.classname),
+        escape(&case.name)
+    );
+    match &case.result {
+        CaseResult::Passed => out.push_str("/>\n"),
+        CaseResult::Failure { message, body } => {
+            let _ = writeln!(
+                out,
+                ">\n      <failure message=\"{}\" type=\"{}\">{}</failure>\n    </testcase>",
+                escape(message),
+                escape(&case.classname),
+                escape(body.trim_end())
+            );
+        }
+        CaseResult::Error { message } => {
+            let _ = writeln!(
+                out,
+                ">\n      <error message=\"{}\"/>\n    </testcase>",
+                escape(message)
+            );
+        }
+        CaseResult::Skipped { message } => {
+            let _ = writeln!(
+                out,
+                ">\n      <skipped message=\"{}\"/>\n    </testcase>",
+                escape(message)
+            );
+        }
+    }
+}
+
+/// (failures, errors, skipped)
+fn count(cases: &[TestCase]) -> (usize, usize, usize) {
+    cases
+        .iter()
+        .fold((0, 0, 0), |(f, e, s), case| match case.result {
+            CaseResult::Failure { .. } => (f + 1, e, s),
+            CaseResult::Error { .. } => (f, e + 1, s),
+            CaseResult::Skipped { .. } => (f, e, s + 1),
+            CaseResult::Passed => (f, e, s),
+        })
+}
+
+fn seconds(ms: u64) -> String {
+    format!("{:.3}", ms as f64 / 1000.0)
+}
+
+/// Escape text for XML attributes and content, dropping characters XML 1.0
+/// cannot represent.
+fn escape(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&apos;"),
+            '\t' | '\n' | '\r' => out.push(c),
+            c if (c as u32) < 0x20 || matches!(c, '\u{FFFE}' | '\u{FFFF}') => {}
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::audit::budget::{BudgetSeverity, BudgetViolation};
+    use crate::cli::WcagLevel;
+    use crate::wcag::{Severity, Violation, WcagResults};
+
+    fn outcome(rule_id: &str, status: RuleOutcomeStatus, finding_count: usize) -> RuleOutcome {
+        RuleOutcome {
+            rule_id: rule_id.to_string(),
+            status,
+            wcag_criterion: Some("1.1.1".to_string()),
+            viewport: None,
+            reason_code: None,
+            finding_count,
+        }
+    }
+
+    fn sample_report() -> AuditReport {
+        let mut results = WcagResults::new();
+        results.add_violation(
+            Violation::new(
+                "1.1.1",
+                "Images must have alternate text",
+                WcagLevel::A,
+                Severity::Critical,
+                "Image is missing an alt attribute",
+                "node-1",
+            )
+            .with_rule_id("image-alt")
+            .with_selector("main > img.hero"),
+        );
+        results.rule_outcomes = vec![
+            outcome("image-alt", RuleOutcomeStatus::ViolationsFound, 1),
+            outcome("role-img-alt", RuleOutcomeStatus::NoViolationDetected, 0),
+            outcome("svg-img-alt", RuleOutcomeStatus::ManualReviewRequired, 0),
+            outcome("input-image-alt", RuleOutcomeStatus::NotApplicable, 0),
+            outcome("object-alt", RuleOutcomeStatus::Failed, 0),
+        ];
+        let mut report = AuditReport::new(
+            "https://example.com/?a=1&b=2".to_string(),
+            WcagLevel::AA,
+            results,
+            1500,
+        );
+        report.experience.budget_violations.push(BudgetViolation {
+            metric: "LCP".to_string(),
+            budget_label: "≤ 2500 ms".to_string(),
+            actual_label: "3200 ms".to_string(),
+            budget_value: 2500.0,
+            actual_value: 3200.0,
+            exceeded_by_pct: 28.0,
+            severity: BudgetSeverity::Warning,
+        });
+        report
+    }
+
+    #[test]
+    fn maps_rule_outcomes_and_budgets_to_test_cases() {
+        let xml = format_junit(&sample_report());
+
+        assert!(xml.starts_with("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<testsuites"));
+        assert!(xml.contains("<testsuite name=\"https://example.com/?a=1&amp;b=2\" tests=\"6\" failures=\"2\" errors=\"1\" skipped=\"2\""));
+        assert!(
+            xml.contains("<testcase classname=\"wcag\" name=\"1.1.1 role-img-alt\" time=\"0\"/>")
+        );
+        assert!(xml.contains("<skipped message=\"manual review required\"/>"));
+        assert!(xml.contains("main &gt; img.hero: Image is missing an alt attribute"));
+        assert!(xml.contains("<testcase classname=\"budget\" name=\"LCP\""));
+        assert!(xml.contains("LCP is 3200 ms (budget ≤ 2500 ms, exceeded by 28%)"));
+    }
+
+    #[test]
+    fn batch_errors_become_errored_suites() {
+        let batch = BatchReport::from_reports(
+            vec![sample_report()],
+            vec![BatchError {
+                url: "https://example.com/down".to_string(),
+                error: "navigation timeout".to_string(),
+            }],
+            2000,
+        );
+        let xml = format_junit_batch(&batch);
+
+        assert!(xml.contains("<testsuites name=\"auditmysite\" tests=\"7\" failures=\"2\" errors=\"2\" skipped=\"2\""));
+        assert!(xml.contains("<testsuite name=\"https://example.com/down\" tests=\"1\""));
+        assert!(xml.contains("<error message=\"navigation timeout\"/>"));
+        assert_eq!(
+            xml.matches("<testsuite ").count(),
+            xml.matches("</testsuite>").count()
+        );
+    }
+}
@@ -0,0 +1,440 @@
+//! Markdown summary for pull-request comments (GitHub/GitLab flavoured).
+//!
+//! Verdict, score badges, the top findings grouped by rule with collapsible
+//! occurrence lists, and budget violations. With a baseline report the
+//! findings section is replaced by the new/resolved delta from
+//! [`diff_reports`], so a bot can post the comment as is.
+
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+
+use crate::audit::normalized::{NormalizedFinding, NormalizedReport};
+use crate::audit::report_diff::diff_reports;
+use crate::audit::{normalize, AuditReport, BatchReport, Verdict, VerdictResult};
+use crate::error::Result;
+use crate::output::diff::write_diff_sections;
+use crate::output::UnifiedReport;
+
+/// Rules listed under "Top findings".
+const TOP_FINDINGS: usize = 10;
+/// Occurrences (single) or pages (batch) listed per rule.
+const LISTED_PER_FINDING: usize = 10;
+
+/// Render a single-page audit. `baseline` is the JSON text of an earlier
+/// `-f json` report; when given, only new and resolved findings are listed.
+pub fn format_markdown(
+    report: &AuditReport,
+    verdict: Option<&VerdictResult>,
+    baseline: Option<&str>,
+) -> Result<String> {
+    let ctx = normalize(report);
+    let normalized = &ctx.normalized;
+    let mut md = String::new();
+
+    write_heading(&mut md, "Accessibility audit", verdict);
+    let _ = writeln!(
+        md,
+        "**{}** · WCAG {} · {}\n",
+        normalized.url,
+        normalized.wcag_level,
+        normalized.timestamp.format("%Y-%m-%d %H:%M UTC")
+    );
+    let mut badges = vec![
+        badge("Accessibility", normalized.score),
+        badge("Overall", normalized.overall_score),
+    ];
+    badges.extend(
+        normalized
+            .module_scores
+            .iter()
+            .filter(|module| module.name != "Accessibility")
+            .map(|module| badge(&module.name, module.score)),
+    );
+    let _ = writeln!(md, "{}\n", badges.join(" "));
+    write_verdict_reasons(&mut md, verdict);
+
+    match baseline {
+        Some(before) => {
+            let after = UnifiedReport::single(&ctx, report).to_json(false)?;
+            write_diff_sections(&mut md, &diff_reports(before, &after)?);
+        }
+        None => {
+            let counts = &normalized.severity_counts;
+            let _ = writeln!(
+                md,
+                "**{}** findings · {} critical · {} high · {} medium · {} low",
+                counts.total, counts.critical, counts.high, counts.medium, counts.low
+            );
+            write_top_findings(&mut md, normalized);
+        }
+    }
+
+    let budgets: Vec<(Option<&str>, &AuditReport)> = vec![(None, report)];
+    write_budget_violations(&mut md, &budgets);
+    Ok(md)
+}
+
+/// Render a batch audit: page table, top findings aggregated by rule across
+/// pages, and budget violations per page. `baseline` as in [`format_markdown`].
+pub fn format_markdown_batch(
+    batch: &BatchReport,
+    verdict: Option<&VerdictResult>,
+    baseline: Option<&str>,
+) -> Result<String> {
+    let normalized: Vec<NormalizedReport> = batch
+        .reports
+        .iter()
+        .map(|r| normalize(r).normalized)
+        .collect();
+    let summary = &batch.summary;
+    let mut md = String::new();
+
+    write_heading(
+        &mut md,
+        &format!("Accessibility audit ({} pages)", summary.total_urls),
+        verdict,
+    );
+    let _ = writeln!(
+        md,
+        "{} passed · {} failed · {} violations\n",
+        summary.passed, summary.failed, summary.total_violations
+    );
+    let _ = writeln!(
+        md,
+        "{}\n",
+        badge("Average score", summary.average_score.round() as u32)
+    );
+    write_verdict_reasons(&mut md, verdict);
+
+    match baseline {
+        Some(before) => {
+            let after = UnifiedReport::batch(batch).to_json(false)?;
+            write_diff_sections(&mut md, &diff_reports(before, &after)?);
+        }
+        None => {
+            md.push_str("| Page | Accessibility | Overall | Findings |\n|---|---:|---:|---:|\n");
+            for page in &normalized {
+                let _ = writeln!(
+                    md,
+                    "| {} | {} | {} | {} |",
+                    cell(&page.url),
+                    page.score,
+                    page.overall_score,
+                    page.severity_counts.total
+                );
+            }
+            write_top_findings_batch(&mut md, &normalized);
+        }
+    }
+
+    if !batch.errors.is_empty() {
+        md.push_str("\n### Pages that could not be audited\n\n");
+        for error in &batch.errors {
+            let _ = writeln!(md, "- {}: {}", error.url, inline(&error.error));
+        }
+    }
+
+    let budgets: Vec<(Option<&str>, &AuditReport)> = batch
+        .reports
+        .iter()
+        .map(|r| (Some(r.url.as_str()), r))
+        .collect();
+    write_budget_violations(&mut md, &budgets);
+    Ok(md)
+}
+
+fn write_heading(md: &mut String, title: &str, verdict: Option<&VerdictResult>) {
+    match verdict.map(|v| v.verdict) {
+        Some(verdict) => {
+            let icon = match verdict {
+                Verdict::Pass => "✅",
+                Verdict::Warn => "⚠️",
+                Verdict::Fail => "❌",
+            };
+            let _ = writeln!(md, "## {icon} {title}: {}\n", verdict.label());
+        }
+        None => {
+            let _ = writeln!(md, "## {title}\n");
+        }
+    }
+}
+
+fn write_verdict_reasons(md: &mut String, verdict: Option<&VerdictResult>) {
+    let Some(verdict) = verdict.filter(|v| !v.reasons.is_empty()) else {
+        return;
+    };
+    let _ = writeln!(md, "> {}\n", inline(&verdict.reasons.join(" · ")));
+}
+
+fn write_top_findings(md: &mut String, normalized: &NormalizedReport) {
+    let mut findings: Vec<&NormalizedFinding> = normalized.findings.iter().collect();
+    if findings.is_empty() {
+        return;
+    }
+    findings.sort_by(|a, b| {
+        b.severity
+            .cmp(&a.severity)
+            .then_with(|| b.occurrence_count.cmp(&a.occurrence_count))
+    });
+    md.push_str("\n### Top findings\n");
+    for finding in findings.iter().take(TOP_FINDINGS) {
+        write_summary(
+            md,
+            finding,
+            &format!("{} occurrences", finding.occurrence_count),
+        );
+        for occurrence in finding.occurrences.iter().take(LISTED_PER_FINDING) {
+            let location = occurrence
+                .selector
+                .as_deref()
+                .unwrap_or(&occurrence.node_id);
+            let _ = writeln!(md, "- {} — {}", code(location), inline(&occurrence.message));
+        }
+        write_more(md, finding.occurrences.len());
+        write_help(md, finding);
+    }
+    write_more_rules(md, findings.len());
+}
+
+/// Most severe finding of one rule, plus `(page url, occurrences)` per page.
+type RuleAcrossPages<'a> = (&'a NormalizedFinding, Vec<(&'a str, usize)>);
+
+fn write_top_findings_batch(md: &mut String, pages: &[NormalizedReport]) {
+    let mut by_rule: BTreeMap<&str, RuleAcrossPages> = BTreeMap::new();
+    for page in pages {
+        for finding in &page.findings {
+            let entry = by_rule
+                .entry(finding.rule_id.as_str())
+                .or_insert((finding, Vec::new()));
+            if finding.severity > entry.0.severity {
+                entry.0 = finding;
+            }
+            entry.1.push((page.url.as_str(), finding.occurrence_count));
+        }
+    }
+    if by_rule.is_empty() {
+        return;
+    }
+    let mut rules: Vec<_> = by_rule.into_values().collect();
+    rules.sort_by(|a, b| {
+        b.0.severity
+            .cmp(&a.0.severity)
+            .then_with(|| b.1.len().cmp(&a.1.len()))
+    });
+    md.push_str("\n### Top findings\n");
+    for (finding, pages) in rules.iter().take(TOP_FINDINGS) {
+        let occurrences: usize = pages.iter().map(|(_, n)| n).sum();
+        write_summary(
+            md,
+            finding,
+            &format!("{} pages · {} occurrences", pages.len(), occurrences),
+        );
+        for (url, count) in pages.iter().take(LISTED_PER_FINDING) {
+            let _ = writeln!(md, "- {url} ({count})");
+        }
+        write_more(md, pages.len());
+        write_help(md, finding);
+    }
+    write_more_rules(md, rules.len());
+}
+
+fn write_summary(md: &mut String, finding: &NormalizedFinding, counts: &str) {
+    let wcag = if finding.wcag_criterion.is_empty() {
+        String::new()
+    } else {
+        format!(" · WCAG {}", finding.wcag_criterion)
+    };
+    let _ = writeln!(
+        md,
+        "\n<details><summary><b>{}</b> {} — <code>{}</code>{} · {}</summary>\n",
+        finding.severity.label_en(),
+        inline(&finding.title),
+        inline(&finding.rule_id),
+        wcag,
+        counts
+    );
+}
+
+fn write_more(md: &mut String, listed: usize) {
+    if listed > LISTED_PER_FINDING {
+        let _ = writeln!(md, "- … and {} more", listed - LISTED_PER_FINDING);
+    }
+}
+
+fn write_help(md: &mut String, finding: &NormalizedFinding) {
+    if let Some(url) = &finding.help_url {
+        let _ = writeln!(md, "\n[How to fix]({url})");
+    }
+    md.push_str("\n</details>\n");
+}
+
+fn write_more_rules(md: &mut String, total: usize) {
+    if total > TOP_FINDINGS {
+        let _ = writeln!(md, "\n… and {} more rules", total - TOP_FINDINGS);
+    }
+}
+
+/// `(page url, report)` pairs; the URL column is omitted for single reports.
+fn write_budget_violations(md: &mut String, reports: &[(Option<&str>, &AuditReport)]) {
+    let rows: Vec<_> = reports
+        .iter()
+        .flat_map(|(url, report)| {
+            report
+                .experience
+                .budget_violations
+                .iter()
+                .map(move |v| (*url, v))
+        })
+        .collect();
+    if rows.is_empty() {
+        return;
+    }
+    let with_page = rows.iter().any(|(url, _)| url.is_some());
+    md.push_str("\n### Budget violations\n\n");
+    md.push_str(if with_page {
+        "| Page | Metric | Budget | Actual | Exceeded by |\n|---|---|---:|---:|---:|\n"
+    } else {
+        "| Metric | Budget | Actual | Exceeded by |\n|---|---:|---:|---:|\n"
+    });
+    for (url, violation) in rows {
+        if let Some(url) = url {
+            let _ = write!(md, "| {} ", cell(url));
+        }
+        let _ = writeln!(
+            md,
+            "| {} | {} | {} | {:.0}% |",
+            cell(&violation.metric),
+            cell(&violation.budget_label),
+            cell(&violation.actual_label),
+            violation.exceeded_by_pct
+        );
+    }
+}
+
+/// Static shields.io badge, coloured like the report's score bands.
+fn badge(label: &str, score: u32) -> String {
+    let color = match score {
+        90.. => "brightgreen",
+        75..=89 => "green",
+        50..=74 => "yellow",
+        _ => "red",
+    };
+    let escaped = label
+        .replace('-', "--")
+        .replace('_', "__")
+        .replace(' ', "%20");
+    format!("![{label} {score}](https://img.shields.io/badge/{escaped}-{score}-{color})")
+}
+
+/// Text inside a table cell.
+fn cell(text: &str) -> String {
+    inline(text).replace('|', "\\|")
+}
+
+/// Inline code span; backticks inside are replaced so the span stays closed.
+fn code(text: &str) -> String {
+    format!("`{}`", text.replace('`', "'").replace('\n', " "))
+}
+
+/// Page-sourced text on one line, with HTML tags neutralized.
+fn inline(text: &str) -> String {
+    text.replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('\n', " ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::audit::budget::{BudgetSeverity, BudgetViolation};
+    use crate::cli::WcagLevel;
+    use crate::taxonomy::Severity;
+    use crate::wcag::{Violation, WcagResults};
+
+    fn sample_report(with_image_alt: bool) -> AuditReport {
+        let mut results = WcagResults::new();
+        if with_image_alt {
+            results.add_violation(
+                Violation::new(
+                    "1.1.1",
+                    "Images must have alternate text",
+                    WcagLevel::A,
+                    Severity::Critical,
+                    "Image is missing an alt attribute",
+                    "node-1",
+                )
+                .with_selector("img.hero"),
+            );
+        }
+        results.add_violation(
+            Violation::new(
+                "2.4.4",
+                "Link purpose",
+                WcagLevel::A,
+                Severity::Medium,
+                "Link has no accessible name",
+                "node-2",
+            )
+            .with_selector("a.icon"),
+        );
+        let mut report = AuditReport::new(
+            "https://example.com".to_string(),
+            WcagLevel::AA,
+            results,
+            10,
+        );
+        report.experience.budget_violations.push(BudgetViolation {
+            metric: "LCP".to_string(),
+            budget_label: "≤ 2500 ms".to_string(),
+            actual_label: "3200 ms".to_string(),
+            budget_value: 2500.0,
+            actual_value: 3200.0,
+            exceeded_by_pct: 28.0,
+            severity: BudgetSeverity::Warning,
+        });
+        report
+    }
+
+    #[test]
+    fn renders_verdict_badges_findings_and_budgets() {
+        let verdict = VerdictResult {
+            verdict: Verdict::Fail,
+            reasons: vec!["blocking_issues: 1".to_string()],
+        };
+        let md = format_markdown(&sample_report(true), Some(&verdict), None).unwrap();
+
+        assert!(md.starts_with("## ❌ Accessibility audit: FAIL\n"));
+        assert!(md.contains("![Accessibility "));
+        assert!(md.contains("> blocking_issues: 1"));
+        assert!(md.contains("### Top findings"));
+        assert!(md.contains("<details><summary><b>CRITICAL</b>"));
+        assert!(md.contains("- `img.hero` — Image is missing an alt attribute"));
+        assert!(md.contains("| LCP | ≤ 2500 ms | 3200 ms | 28% |"));
+    }
+
+    #[test]
+    fn baseline_lists_only_new_and_resolved_findings() {
+        let before = sample_report(true);
+        let ctx = normalize(&before);
+        let baseline = UnifiedReport::single(&ctx, &before).to_json(false).unwrap();
+
+        let md = format_markdown(&sample_report(false), None, Some(&baseline)).unwrap();
+        assert!(md.contains("**0** new · **1** resolved · **1** unchanged"));
+        assert!(md.contains("<details><summary>Resolved (1)</summary>"));
+        assert!(!md.contains("### Top findings"));
+        assert!(!md.contains("a.icon"));
+    }
+
+    #[test]
+    fn batch_groups_findings_by_rule_across_pages() {
+        let mut second = sample_report(false);
+        second.url = "https://example.com/about".to_string();
+        let batch = BatchReport::from_reports(vec![sample_report(true), second], vec![], 20);
+        let md = format_markdown_batch(&batch, None, None).unwrap();
+
+        assert!(md.starts_with("## Accessibility audit (2 pages)\n"));
+        assert!(md.contains("| https://example.com/about |"));
+        assert!(md.contains("2 pages · 2 occurrences"));
+        assert!(md.contains("| Page | Metric | Budget | Actual | Exceeded by |"));
+    }
+}
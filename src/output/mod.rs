@@ -5,31 +5,53 @@
 pub mod ai;
 pub mod builder;
 mod cli;
+pub mod codequality;
+pub mod csv;
+pub mod diff;
+pub mod earl;
 pub mod explanations;
+pub mod history;
+pub mod html;
 mod json;
+pub mod junit;
 #[cfg(feature = "pdf")]
 mod localized;
+pub mod markdown;
 pub mod module;
 #[cfg(feature = "pdf")]
 mod pdf;
+pub mod playwright;
 pub mod renderer;
 pub mod report_model;
 pub mod sarif;
 pub mod search_experience;
 pub mod snapshot_export;
 pub mod sr_audit_json;
+pub mod statement;
 pub mod summary;
+pub mod vpat;
 
 pub use ai::format_ai_json;
 pub use cli::{format_batch_table, format_violations_list, print_batch_table, print_report};
+pub use codequality::format_gitlab_codequality;
+pub use csv::format_csv;
+pub use diff::{format_diff_delta, format_diff_markdown, format_diff_table};
+pub use earl::format_earl;
+pub use history::format_history_table;
+pub use html::{format_html, format_html_batch, HtmlRenderer};
 pub use json::{format_json_batch, format_json_cached, format_json_normalized, UnifiedReport};
+pub use junit::{format_junit, format_junit_batch};
+pub use markdown::{format_markdown, format_markdown_batch};
 #[cfg(feature = "pdf")]
 pub use pdf::{generate_batch_pdf, generate_batch_typ, generate_pdf, generate_typ};
+pub use playwright::{export_playwright_spec, format_playwright_spec};
 pub use renderer::{JsonRenderer, ReportRenderer, SummaryRenderer};
 pub use sarif::format_sarif;
 pub use snapshot_export::export_snapshot_yaml;
 pub use sr_audit_json::export_sr_audit;
+pub use statement::format_statement_markdown;
 pub use summary::format_summary;
+pub use vpat::{format_vpat_json, format_vpat_markdown};
 
 #[cfg(test)]
 mod tests;
@@ -1,7 +1,9 @@
 use super::*;
+use crate::performance::VitalsRunStats;
 
 /// Human-readable label for a throttle profile shown in the throttled-network
 /// table. `ThrottledPerfEntry::profile_name` is `format!("{:?}", ThrottleProfile)`
+/// or the configured name of a custom profile
 /// (see `output::builder::single::module_details`) — "Slow3G"/"Fast3G" already
 /// read fine, but "LhMobile" is an internal preset name, not something a report
 /// reader recognizes. Presentation-only: the underlying enum/JSON value is
@@ -67,6 +69,37 @@ fn additional_vitals_table(vitals: &[(String, String, String)], i18n: &I18n) ->
     Some(table)
 }
 
+/// Median / p75 / range / standard deviation per metric for a repeated
+/// measurement. CLS is unitless; every other metric is in milliseconds.
+fn run_stats_table(stats: &VitalsRunStats, i18n: &I18n) -> AuditTable {
+    let en = is_english(i18n);
+    let mut table = AuditTable::new(vec![
+        TableColumn::new(if en { "Metric" } else { "Metrik" }).with_width("20%"),
+        TableColumn::new("Median").with_width("16%"),
+        TableColumn::new("p75").with_width("16%"),
+        TableColumn::new("Min – Max").with_width("26%"),
+        TableColumn::new(if en { "Std. dev." } else { "Std.-Abw." }).with_width("22%"),
+    ])
+    .with_title(i18n.t_args("pdf-perf-runs-title", &[("runs", stats.runs.to_string())]));
+    for (label, metric) in stats.metrics() {
+        let fmt = |v: f64| {
+            if label == "CLS" {
+                format!("{v:.3}")
+            } else {
+                format!("{v:.0} ms")
+            }
+        };
+        table = table.add_row(vec![
+            label.to_string(),
+            fmt(metric.median),
+            fmt(metric.p75),
+            format!("{} – {}", fmt(metric.min), fmt(metric.max)),
+            fmt(metric.std_dev()),
+        ]);
+    }
+    table
+}
+
 fn localized_decimal(value: f64, en: bool) -> String {
     let value = format!("{value:.1}");
     if en {
@@ -433,6 +466,10 @@ pub(in crate::output::pdf) fn render_performance(
         builder = builder.add_component(table);
     }
 
+    if let Some(stats) = perf.run_stats.as_ref().filter(|s| s.runs > 1) {
+        builder = builder.add_component(run_stats_table(stats, i18n));
+    }
+
     // CLS Attribution table
     if !perf.cls_attribution.is_empty() {
         let title = i18n.t("pdf-perf-cls-title");
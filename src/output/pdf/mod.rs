@@ -30,8 +30,8 @@ use self::appendix::{cover_logo_asset, register_cover_logo_asset};
 use self::diagnosis::{business_relevance, format_word_count, output_scope_callout};
 use self::helpers::{create_engine, extract_domain};
 use self::single_report::{
-    render_appendix_full, render_management_page, render_module_sections, render_part_divider,
-    render_root_cause_analysis, render_tech_details, render_timeframe_roadmap,
+    render_appendix_full, render_history_trend, render_management_page, render_module_sections,
+    render_part_divider, render_root_cause_analysis, render_tech_details, render_timeframe_roadmap,
 };
 use crate::audit::{normalize, AuditReport};
 use crate::cli::ReportLevel;
@@ -155,6 +155,7 @@ fn build_single_report(
 
     // --- Page 2: Management Summary (CoverPage already emits a page break) ---
     builder = render_management_page(builder, &vm, &i18n);
+    builder = render_history_trend(builder, &config.history, &i18n);
 
     if vm.meta.report_level != ReportLevel::Executive {
         // --- Page 3: Table of Contents ---
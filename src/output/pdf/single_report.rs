@@ -26,7 +26,7 @@ use super::en301549::render_en301549_annex;
 use super::findings::render_finding_technical;
 use super::helpers::map_severity;
 use super::wcag_coverage::render_wcag_coverage_section;
-use crate::audit::AuditReport;
+use crate::audit::{AuditReport, HistoryEntry};
 use crate::cli::{AnnexKind, ReportLevel};
 use crate::i18n::I18n;
 use crate::output::module::active_report_modules;
@@ -489,6 +489,68 @@ pub(super) fn render_management_page(
     builder
 }
 
+/// Score trend over the recorded history runs of this URL (`--trend`).
+/// Skipped unless there are at least two points to connect.
+pub(super) fn render_history_trend(
+    mut builder: renderreport::engine::ReportBuilder,
+    history: &[HistoryEntry],
+    i18n: &I18n,
+) -> renderreport::engine::ReportBuilder {
+    if history.len() < 2 {
+        return builder;
+    }
+    let en = i18n.locale() == "en";
+    let date = |entry: &HistoryEntry| entry.timestamp.format("%d.%m.%y").to_string();
+    let accessibility: Vec<(String, f64)> = history
+        .iter()
+        .map(|e| (date(e), e.accessibility_score as f64))
+        .collect();
+    let overall: Vec<(String, f64)> = history
+        .iter()
+        .map(|e| (date(e), e.overall_score as f64))
+        .collect();
+    builder = builder.add_component(
+        Chart::line(if en { "Score trend" } else { "Score-Verlauf" })
+            .add_series(
+                if en {
+                    "Accessibility"
+                } else {
+                    "Barrierefreiheit"
+                },
+                accessibility,
+            )
+            .add_series(if en { "Overall" } else { "Gesamt" }, overall)
+            .with_labels(if en { "Audit run" } else { "Prüflauf" }, "Score"),
+    );
+
+    let (first, last) = (&history[0], &history[history.len() - 1]);
+    let delta = last.accessibility_score as i64 - first.accessibility_score as i64;
+    let text = if en {
+        format!(
+            "{} recorded runs since {}: accessibility score {} → {} ({:+}), findings {} → {}.",
+            history.len(),
+            date(first),
+            first.accessibility_score,
+            last.accessibility_score,
+            delta,
+            first.finding_count,
+            last.finding_count
+        )
+    } else {
+        format!(
+            "{} erfasste Prüfläufe seit {}: Barrierefreiheits-Score {} → {} ({:+}), Befunde {} → {}.",
+            history.len(),
+            date(first),
+            first.accessibility_score,
+            last.accessibility_score,
+            delta,
+            first.finding_count,
+            last.finding_count
+        )
+    };
+    builder.add_component(Label::new(text))
+}
+
 /// Sections 5b + 6+ — diagnosis, findings by severity tier, module metrics, appendix.
 pub(super) fn render_tech_details(
     mut builder: renderreport::engine::ReportBuilder,
@@ -610,6 +672,7 @@ pub(super) fn render_appendix_full(
     }
 
     builder = render_assessment_and_execution_notes(builder, report, i18n);
+    builder = render_waived_findings(builder, report, i18n);
 
     // Methodology / disclaimer text blocks
     let limitations_title = i18n.t("callout-limitations-title");
@@ -730,6 +793,55 @@ fn render_assessment_and_execution_notes(
     builder
 }
 
+/// Violations accepted via the waivers file. Listed separately so the reader
+/// sees what was excluded from findings and verdict, by whom and until when.
+fn render_waived_findings(
+    mut builder: renderreport::engine::ReportBuilder,
+    report: &AuditReport,
+    i18n: &I18n,
+) -> renderreport::engine::ReportBuilder {
+    let waived = &report.accessibility.waived;
+    if waived.is_empty() {
+        return builder;
+    }
+    let en = i18n.locale() == "en";
+    builder = builder.add_component(Callout::info(if en {
+        format!(
+            "{} violation(s) are covered by an active waiver. They are not counted as findings and do not affect risk or verdict; scores still reflect the page as measured.",
+            waived.len()
+        )
+    } else {
+        format!(
+            "{} Verstöße sind durch eine gültige Ausnahme (Waiver) abgedeckt. Sie zählen nicht als Befunde und fließen nicht in Risiko und Urteil ein; die Scores bilden die Seite weiterhin wie gemessen ab.",
+            waived.len()
+        )
+    })
+    .with_title(if en { "Waived findings" } else { "Ausgenommene Befunde" }));
+
+    let mut table = AuditTable::new(vec![
+        TableColumn::new(if en { "Rule" } else { "Regel" }).with_width("18%"),
+        TableColumn::new("Element").with_width("24%"),
+        TableColumn::new(if en { "Owner" } else { "Verantwortlich" }).with_width("16%"),
+        TableColumn::new(if en { "Expires" } else { "Gültig bis" }).with_width("14%"),
+        TableColumn::new(if en { "Reason" } else { "Begründung" }).with_width("28%"),
+    ])
+    .with_title(if en {
+        "Waived findings"
+    } else {
+        "Ausgenommene Befunde"
+    });
+    for entry in waived {
+        table = table.add_row(vec![
+            format!("{} ({})", entry.rule_id, entry.rule),
+            entry.selector.clone().unwrap_or_else(|| "—".to_string()),
+            entry.owner.clone().unwrap_or_else(|| "—".to_string()),
+            entry.expires.clone().unwrap_or_else(|| "—".to_string()),
+            entry.reason.clone().unwrap_or_default(),
+        ]);
+    }
+    builder.add_component(table)
+}
+
 fn render_findings_section(
     mut builder: renderreport::engine::ReportBuilder,
     vm: &ReportViewModel,
@@ -1245,6 +1357,9 @@ fn render_dual_viewport_summary_section(
             format!("Screenshot-Erfassung fehlgeschlagen: {reason}")
         }));
     }
+    if !report.device_results.is_empty() {
+        builder = render_device_matrix(builder, &report.device_results, en);
+    }
 
     let occurrence_context = report
         .dual_viewport
@@ -1277,6 +1392,77 @@ fn render_dual_viewport_summary_section(
     )
 }
 
+/// Device matrix from `[[devices]]`: one row per audited device, then the
+/// findings that occur on a single device only.
+fn render_device_matrix(
+    mut builder: renderreport::engine::ReportBuilder,
+    devices: &[crate::audit::DeviceResult],
+    en: bool,
+) -> renderreport::engine::ReportBuilder {
+    let mut table = AuditTable::new(vec![
+        TableColumn::new(if en { "Device" } else { "Gerät" }).with_width("24%"),
+        TableColumn::new("Viewport").with_width("28%"),
+        TableColumn::new(if en {
+            "Accessibility"
+        } else {
+            "Barrierefreiheit"
+        })
+        .with_width("16%"),
+        TableColumn::new(if en { "Violations" } else { "Verstöße" }).with_width("16%"),
+        TableColumn::new(if en { "Only here" } else { "Nur hier" }).with_width("16%"),
+    ])
+    .with_title(if en { "Device matrix" } else { "Gerätematrix" });
+    for device in devices {
+        table = table.add_row(vec![
+            device.name.clone(),
+            format!(
+                "{}×{} @{}x{}",
+                device.width,
+                device.height,
+                device.device_scale_factor,
+                if device.touch { ", touch" } else { "" }
+            ),
+            format!("{} / 100", device.accessibility_score),
+            device.violation_count.to_string(),
+            device.exclusive_findings.len().to_string(),
+        ]);
+    }
+    builder = builder.add_component(table);
+
+    let exclusive: Vec<_> = devices
+        .iter()
+        .flat_map(|device| {
+            device
+                .exclusive_findings
+                .iter()
+                .map(move |finding| (device, finding))
+        })
+        .collect();
+    if exclusive.is_empty() {
+        return builder;
+    }
+    let mut table = AuditTable::new(vec![
+        TableColumn::new(if en { "Device" } else { "Gerät" }).with_width("18%"),
+        TableColumn::new("WCAG").with_width("12%"),
+        TableColumn::new(if en { "Finding" } else { "Befund" }).with_width("35%"),
+        TableColumn::new("Element").with_width("35%"),
+    ])
+    .with_title(if en {
+        "Breakpoint-specific findings"
+    } else {
+        "Breakpoint-spezifische Befunde"
+    });
+    for (device, finding) in exclusive {
+        table = table.add_row(vec![
+            device.name.clone(),
+            finding.rule.clone(),
+            finding.rule_name.clone(),
+            finding.selector.clone().unwrap_or_else(|| "—".to_string()),
+        ]);
+    }
+    builder.add_component(table)
+}
+
 fn score_range_label(score: u32, en: bool) -> &'static str {
     crate::registry::SCORE_RANGE.label(score as f32, en)
 }
@@ -724,10 +724,12 @@ mod tests {
         report.dual_viewport = Some(dual_viewport);
         report.throttled_performance = vec![crate::audit::ThrottledPerfResult {
             profile: crate::browser::ThrottleProfile::Slow3G,
+            profile_name: None,
             lcp_ms: Some(3200.0),
             tbt_ms: Some(180.0),
             cls: Some(0.03),
             score: 72,
+            run_stats: None,
         }];
         report = report.with_tech_stack(crate::tech_stack::TechStackAnalysis {
             detected: vec![crate::tech_stack::DetectedTech {
@@ -915,10 +917,12 @@ mod tests {
             });
         report.throttled_performance = vec![crate::audit::ThrottledPerfResult {
             profile: crate::browser::ThrottleProfile::Slow3G,
+            profile_name: None,
             lcp_ms: Some(3200.0),
             tbt_ms: Some(180.0),
             cls: Some(0.03),
             score: 72,
+            run_stats: None,
         }];
 
         let config = ReportConfig {
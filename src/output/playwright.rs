@@ -0,0 +1,415 @@
+//! Playwright regression specs generated from recorded accessibility journeys.
+//!
+//! Every replayable `JourneyTrace` (tab walk, modal open/close, disclosure
+//! toggle) becomes one `test()` that replays the key presses and clicks and
+//! asserts the focus targets and state changes the audit observed. A journey
+//! whose checks failed is emitted with the *expected* assertions and marked
+//! `test.fail()`: it passes while the defect exists and turns into an
+//! "unexpected pass" once it is fixed, which is the cue to drop the marker.
+
+use std::fmt::Write as _;
+use std::path::{Path, PathBuf};
+
+use anyhow::Result;
+
+use crate::audit::normalized::{JourneyStep, JourneyTrace};
+use crate::audit::AuditReport;
+
+/// Locator for open dialogs, identical to the query the modal journey uses.
+const DIALOG_LOCATOR: &str = r#"[role="dialog"], [role="alertdialog"]"#;
+
+/// Journeys with a replay mapping; other traces (menus, forms, SPA
+/// navigation, …) are left out of the spec.
+const REPLAYABLE_JOURNEYS: &[&str] = &["tab_walk", "modal_", "disclosure_"];
+
+/// Step results that record a failed expectation.
+const FAILING_RESULTS: &[&str] = &[
+    "focus_not_in_dialog",
+    "focus_escaped",
+    "dialog_still_open",
+    "expanded_unchanged",
+    "collapsed_failed",
+];
+
+/// Write the spec for `report` into `dir` (created if missing) as
+/// [`playwright_spec_file_name`]. Returns the written path, or `None` when
+/// the page has no replayable journey.
+pub fn export_playwright_spec(report: &AuditReport, dir: &Path) -> Result<Option<PathBuf>> {
+    let Some(spec) = format_playwright_spec(report) else {
+        return Ok(None);
+    };
+    std::fs::create_dir_all(dir)?;
+    let path = dir.join(playwright_spec_file_name(&report.url));
+    std::fs::write(&path, spec)?;
+    Ok(Some(path))
+}
+
+/// Render one Playwright TypeScript spec with a test per replayable journey
+/// of `report`. Returns `None` when the page has no replayable journey.
+pub fn format_playwright_spec(report: &AuditReport) -> Option<String> {
+    let journey = report.accessibility_journey.as_ref()?;
+    let traces: Vec<&JourneyTrace> = journey
+        .traces
+        .iter()
+        .filter(|t| REPLAYABLE_JOURNEYS.iter().any(|j| t.journey.starts_with(j)))
+        .filter(|t| t.steps.iter().any(|s| s.action != "start"))
+        .collect();
+    if traces.is_empty() {
+        return None;
+    }
+
+    let mut out = String::new();
+    let _ = writeln!(
+        out,
+        "// Accessibility journey regression tests for {}",
+        report.url
+    );
+    let _ = writeln!(
+        out,
+        "// Generated by auditmysite {} from the audit of {}.",
+        env!("CARGO_PKG_VERSION"),
+        report.timestamp.format("%Y-%m-%d %H:%M UTC")
+    );
+    out.push_str("// Tests marked test.fail() reproduce a journey finding: remove the marker\n");
+    out.push_str("// once Playwright reports them as unexpectedly passing.\n");
+    out.push_str("import { test, expect } from '@playwright/test';\n\n");
+    let _ = writeln!(out, "const PAGE_URL = {};", js_string(&report.url));
+    let _ = writeln!(out, "const DIALOG = {};\n", js_string(DIALOG_LOCATOR));
+    let _ = writeln!(
+        out,
+        "test.describe({}, () => {{",
+        js_string(&format!("accessibility journeys: {}", report.url))
+    );
+    out.push_str("  test.beforeEach(async ({ page }) => {\n");
+    out.push_str("    await page.goto(PAGE_URL);\n");
+    out.push_str("  });\n");
+
+    for trace in traces {
+        out.push('\n');
+        let findings: Vec<String> = report
+            .interactive_findings
+            .iter()
+            .filter(|f| f.journey == trace.journey)
+            .map(|f| format!("{}: {}", f.category, f.message))
+            .collect();
+        out.push_str(&render_trace(trace, &findings));
+    }
+    out.push_str("});\n");
+    Some(out)
+}
+
+/// File name for the spec of `url`, e.g. `example-com-contact.a11y.spec.ts`.
+pub fn playwright_spec_file_name(url: &str) -> String {
+    let parsed = url::Url::parse(url).ok();
+    let host = parsed
+        .as_ref()
+        .and_then(|u| u.host_str())
+        .map(|h| h.strip_prefix("www.").unwrap_or(h).to_string())
+        .unwrap_or_default();
+    let path = parsed.as_ref().map(|u| u.path()).unwrap_or_default();
+    let mut slug = String::new();
+    for ch in format!("{host}{path}").chars() {
+        if ch.is_ascii_alphanumeric() {
+            slug.push(ch.to_ascii_lowercase());
+        } else if !slug.ends_with('-') {
+            slug.push('-');
+        }
+    }
+    let slug = slug.trim_matches('-');
+    let slug = if slug.is_empty() { "page" } else { slug };
+    format!("{slug}.a11y.spec.ts")
+}
+
+fn render_trace(trace: &JourneyTrace, findings: &[String]) -> String {
+    let mut out = String::new();
+    let title = js_string(&trace.journey);
+
+    // Clicks recorded without a selector (CDP backend node only) cannot be
+    // replayed outside the audit's browser session.
+    if trace
+        .steps
+        .iter()
+        .any(|s| is_click(s) && replay_target(s).is_none())
+    {
+        out.push_str("  // Not replayable: a click target was recorded without a CSS selector.\n");
+        let _ = writeln!(out, "  test.skip({title}, async () => {{}});");
+        return out;
+    }
+
+    let mut body = String::new();
+    let mut failing = false;
+    let mut last_click: Option<&str> = None;
+    for step in &trace.steps {
+        failing |= render_step(&mut body, step, &mut last_click);
+    }
+
+    for finding in findings {
+        let _ = writeln!(out, "  // Finding: {}", single_line(finding));
+    }
+    let declare = if failing { "test.fail" } else { "test" };
+    let _ = writeln!(out, "  {declare}({title}, async ({{ page }}) => {{");
+    out.push_str(&body);
+    out.push_str("  });\n");
+    out
+}
+
+/// Append the replay and assertion lines for one step. Returns `true` when
+/// the step recorded a failed expectation.
+fn render_step<'a>(
+    out: &mut String,
+    step: &'a JourneyStep,
+    last_click: &mut Option<&'a str>,
+) -> bool {
+    let result = step.result.as_deref();
+    let failing = result.is_some_and(|r| FAILING_RESULTS.contains(&r));
+
+    match step.action.as_str() {
+        "start" | "wait" => return false,
+        "click" | "synthetic_click" => {
+            if let Some(target) = replay_target(step) {
+                let _ = writeln!(
+                    out,
+                    "    await page.locator({}).click();",
+                    js_string(target)
+                );
+                *last_click = Some(target);
+            }
+        }
+        "check_focus_in_dialog" => {
+            write_focus_in_dialog(out);
+            return failing;
+        }
+        "check_dialog_closed" => {
+            out.push_str("    await expect(page.locator(DIALOG).first()).toBeHidden();\n");
+            return failing;
+        }
+        "check_focus_restored" => {
+            if let Some(target) = step.target.as_deref().filter(|t| !is_document(t)) {
+                let _ = writeln!(
+                    out,
+                    "    await expect(page.locator({})).toBeFocused();",
+                    js_string(target)
+                );
+                return step.focus.as_deref() != Some(target);
+            }
+            return false;
+        }
+        "check_expanded" => {
+            if let Some(trigger) = last_click {
+                let _ = writeln!(
+                    out,
+                    "    await expect(page.locator({})).toHaveAttribute('aria-expanded', 'true');",
+                    js_string(trigger)
+                );
+            }
+            return failing;
+        }
+        "check_collapsed" => {
+            if let Some(trigger) = last_click {
+                let _ = writeln!(
+                    out,
+                    "    await expect(page.locator({})).toHaveAttribute('aria-expanded', 'false');",
+                    js_string(trigger)
+                );
+            }
+            return failing;
+        }
+        action => match key_for(action) {
+            Some(key) => {
+                let _ = writeln!(out, "    await page.keyboard.press('{key}');");
+            }
+            None => {
+                let _ = writeln!(
+                    out,
+                    "    // Unsupported step `{}` skipped.",
+                    single_line(action)
+                );
+                return false;
+            }
+        },
+    }
+
+    match result {
+        Some("focus_in_dialog" | "focus_escaped") => write_focus_in_dialog(out),
+        Some("focus_lost") => out.push_str("    // Focus left the page content here.\n"),
+        // The walk ends where focus stops moving; nothing to assert.
+        Some("focus_stuck") => {}
+        _ => {
+            if let Some(focus) = step.focus.as_deref().filter(|f| !is_document(f)) {
+                let _ = writeln!(
+                    out,
+                    "    await expect(page.locator({})).toBeFocused();",
+                    js_string(focus)
+                );
+            }
+        }
+    }
+    failing
+}
+
+fn write_focus_in_dialog(out: &mut String) {
+    out.push_str("    await expect(page.locator(DIALOG).first()).toBeVisible();\n");
+    out.push_str(
+        "    await expect(page.locator(DIALOG).first().locator(':focus')).toHaveCount(1);\n",
+    );
+}
+
+fn is_click(step: &JourneyStep) -> bool {
+    matches!(step.action.as_str(), "click" | "synthetic_click")
+}
+
+fn replay_target(step: &JourneyStep) -> Option<&str> {
+    step.target
+        .as_deref()
+        .filter(|t| !t.starts_with("backend_node:"))
+}
+
+fn is_document(selector: &str) -> bool {
+    matches!(selector.to_ascii_lowercase().as_str(), "body" | "html")
+}
+
+/// Playwright key name for a recorded keyboard action.
+fn key_for(action: &str) -> Option<String> {
+    Some(match action {
+        "tab" => "Tab".to_string(),
+        "shift_tab" => "Shift+Tab".to_string(),
+        "enter" => "Enter".to_string(),
+        "escape" => "Escape".to_string(),
+        "space" => "Space".to_string(),
+        "home" => "Home".to_string(),
+        "end" => "End".to_string(),
+        _ => {
+            let direction = action.strip_prefix("arrow_")?;
+            let mut chars = direction.chars();
+            let first = chars.next()?.to_ascii_uppercase();
+            format!("Arrow{first}{}", chars.as_str())
+        }
+    })
+}
+
+/// Single-quoted TypeScript string literal.
+fn js_string(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('\'');
+    for ch in s.chars() {
+        match ch {
+            '\\' => out.push_str("\\\\"),
+            '\'' => out.push_str("\\'"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            _ => out.push(ch),
+        }
+    }
+    out.push('\'');
+    out
+}
+
+fn single_line(s: &str) -> String {
+    s.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::audit::normalized::AccessibilityJourney;
+    use crate::cli::WcagLevel;
+    use crate::wcag::WcagResults;
+
+    fn step(
+        action: &str,
+        target: Option<&str>,
+        focus: Option<&str>,
+        result: Option<&str>,
+    ) -> JourneyStep {
+        JourneyStep {
+            action: action.to_string(),
+            target: target.map(str::to_string),
+            focus: focus.map(str::to_string),
+            result: result.map(str::to_string),
+            snapshot_label: None,
+        }
+    }
+
+    fn report_with(traces: Vec<JourneyTrace>) -> AuditReport {
+        let mut report = AuditReport::new(
+            "https://example.com/contact".to_string(),
+            WcagLevel::AA,
+            WcagResults::new(),
+            1,
+        );
+        report.accessibility_journey = Some(AccessibilityJourney {
+            traces,
+            ..Default::default()
+        });
+        report
+    }
+
+    #[test]
+    fn replays_journeys_and_marks_failing_ones() {
+        let report = report_with(vec![
+            JourneyTrace {
+                journey: "tab_walk".to_string(),
+                steps: vec![
+                    step("start", None, None, None),
+                    step("tab", None, Some("#skip"), None),
+                    step("tab", None, Some("nav > a:nth-of-type(1)"), None),
+                ],
+            },
+            JourneyTrace {
+                journey: "modal_0".to_string(),
+                steps: vec![
+                    step("synthetic_click", Some("button#open"), None, None),
+                    step(
+                        "check_focus_in_dialog",
+                        None,
+                        Some("body"),
+                        Some("focus_not_in_dialog"),
+                    ),
+                    step("escape", None, None, None),
+                    step("check_dialog_closed", None, None, Some("dialog_closed")),
+                ],
+            },
+            JourneyTrace {
+                journey: "disclosure_0".to_string(),
+                steps: vec![step("synthetic_click", Some("backend_node:42"), None, None)],
+            },
+        ]);
+        let spec = format_playwright_spec(&report).unwrap();
+
+        assert!(spec.contains("const PAGE_URL = 'https://example.com/contact';"));
+        assert!(spec.contains("  test('tab_walk', async ({ page }) => {"));
+        assert!(spec.contains("await page.keyboard.press('Tab');\n    await expect(page.locator('#skip')).toBeFocused();"));
+        assert!(spec.contains("  test.fail('modal_0', async ({ page }) => {"));
+        assert!(spec.contains("await page.locator('button#open').click();"));
+        assert!(spec.contains("await expect(page.locator(DIALOG).first()).toBeHidden();"));
+        assert!(spec.contains("test.skip('disclosure_0'"));
+    }
+
+    #[test]
+    fn disclosure_toggle_asserts_aria_expanded_and_spec_names_are_per_path() {
+        let report = report_with(vec![JourneyTrace {
+            journey: "disclosure_0".to_string(),
+            steps: vec![
+                step("synthetic_click", Some("#menu-toggle"), None, None),
+                step("check_expanded", None, None, Some("expanded_true")),
+                step("synthetic_click", Some("#menu-toggle"), None, None),
+                step("check_collapsed", None, None, Some("collapsed_true")),
+            ],
+        }]);
+        let spec = format_playwright_spec(&report).unwrap();
+        assert!(spec.contains(
+            "await expect(page.locator('#menu-toggle')).toHaveAttribute('aria-expanded', 'true');"
+        ));
+        assert!(spec.contains("toHaveAttribute('aria-expanded', 'false')"));
+        assert!(format_playwright_spec(&report_with(Vec::new())).is_none());
+
+        assert_eq!(
+            playwright_spec_file_name("https://www.example.com/about/team"),
+            "example-com-about-team.a11y.spec.ts"
+        );
+        assert_eq!(
+            playwright_spec_file_name("https://example.com/"),
+            "example-com.a11y.spec.ts"
+        );
+    }
+}
@@ -20,6 +20,9 @@ pub struct ReportConfig {
     /// Opt-in regulatory PDF appendix section (see `--annex`). `None` by
     /// default — these sections are not part of the default report.
     pub annex: Option<crate::cli::AnnexKind>,
+    /// Recorded runs of the audited URL, oldest first (see `--trend`). The
+    /// single-page PDF adds a score trend chart when at least two are given.
+    pub history: Vec<crate::audit::HistoryEntry>,
 }
 
 impl Default for ReportConfig {
@@ -29,6 +32,7 @@ impl Default for ReportConfig {
             logo_path: None,
             locale: "de".to_string(),
             annex: None,
+            history: Vec::new(),
         }
     }
 }
@@ -676,6 +680,8 @@ pub struct PerformancePresentation {
     pub has_render_blocking: bool,
     /// Throttled network performance profiles (empty if not measured)
     pub throttled_profiles: Vec<ThrottledPerfEntry>,
+    /// Spread of the headline vitals when measured with `--runs N` (N > 1)
+    pub run_stats: Option<crate::performance::VitalsRunStats>,
     /// CLS shift attribution (top 5, value + start_time + element)
     pub cls_attribution: Vec<(String, String, String)>,
     /// Third-party attribution — None if not collected
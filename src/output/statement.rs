@@ -0,0 +1,158 @@
+//! Markdown rendering of a draft accessibility statement
+//! (`auditmysite statement`), following the section order of the EU model
+//! statement. All text comes from the `statement-*` Fluent messages, so the
+//! draft exists in German and English.
+
+use std::fmt::Write as _;
+
+use crate::audit::statement::{ComplianceStatus, StatementDraft};
+use crate::i18n::I18n;
+
+/// Render a statement draft as Markdown. Review markers (`⚠️`) and bracketed
+/// placeholders show every spot a person still has to check or fill in.
+pub fn format_statement_markdown(draft: &StatementDraft, i18n: &I18n) -> String {
+    let de = i18n.locale() == "de";
+    let body = i18n.t("statement-placeholder-body");
+    let legislation = i18n.t("statement-placeholder-legislation");
+    let mut md = String::new();
+
+    let _ = writeln!(md, "# {}\n", i18n.t("statement-title"));
+    let _ = writeln!(md, "> ⚠️ **{}**\n", i18n.t("statement-draft-marker"));
+    let _ = writeln!(
+        md,
+        "{}\n",
+        i18n.t_args(
+            "statement-intro",
+            &[
+                ("body", body.as_str()),
+                ("legislation", legislation.as_str()),
+                ("website", draft.website.as_str()),
+            ],
+        )
+    );
+
+    let _ = writeln!(md, "## {}\n", i18n.t("statement-compliance-heading"));
+    let status_key = match draft.compliance_status {
+        ComplianceStatus::PartiallyCompliant => "statement-compliance-partially",
+        ComplianceStatus::NotCompliant => "statement-compliance-not",
+        ComplianceStatus::ReviewRequired => "statement-compliance-review-required",
+    };
+    let _ = writeln!(
+        md,
+        "{}\n",
+        i18n.t_args(status_key, &[("standard", draft.standard)])
+    );
+    let _ = writeln!(md, "> ⚠️ {}\n", i18n.t("statement-compliance-review"));
+
+    let _ = writeln!(md, "## {}\n", i18n.t("statement-nonaccessible-heading"));
+    let _ = writeln!(md, "{}\n", i18n.t("statement-nonaccessible-intro"));
+    let _ = writeln!(
+        md,
+        "### {}\n",
+        i18n.t_args(
+            "statement-noncompliance-heading",
+            &[("legislation", legislation.as_str())]
+        )
+    );
+    if draft.non_accessible_content.is_empty() {
+        let _ = writeln!(md, "{}\n", i18n.t("statement-noncompliance-none"));
+    } else {
+        for content in &draft.non_accessible_content {
+            let title = if de {
+                content.title_de
+            } else {
+                content.title_en
+            };
+            let issues: Vec<String> = content
+                .issues
+                .iter()
+                .map(|issue| {
+                    i18n.t_args(
+                        "statement-issue",
+                        &[
+                            ("title", issue.title.clone()),
+                            ("occurrences", issue.occurrences.to_string()),
+                            ("pages", issue.pages.to_string()),
+                        ],
+                    )
+                })
+                .collect();
+            let _ = writeln!(
+                md,
+                "- **{} {}** (EN 301 549 {}): {}",
+                content.wcag_criterion,
+                title,
+                content.en_clause,
+                issues.join("; ")
+            );
+        }
+        let _ = writeln!(md, "\n> ⚠️ {}\n", i18n.t("statement-noncompliance-review"));
+    }
+    let _ = writeln!(md, "### {}\n", i18n.t("statement-burden-heading"));
+    let _ = writeln!(md, "{}\n", i18n.t("statement-burden-placeholder"));
+    let _ = writeln!(md, "### {}\n", i18n.t("statement-scope-heading"));
+    let _ = writeln!(md, "{}\n", i18n.t("statement-scope-placeholder"));
+
+    let _ = writeln!(md, "## {}\n", i18n.t("statement-preparation-heading"));
+    let date = draft.evaluation_date.format("%Y-%m-%d").to_string();
+    let _ = writeln!(
+        md,
+        "{}\n",
+        i18n.t_args("statement-preparation-date", &[("date", date.as_str())])
+    );
+    let pages = draft.pages.len().to_string();
+    let _ = writeln!(
+        md,
+        "{}\n",
+        i18n.t_args(
+            "statement-preparation-method",
+            &[("tool", draft.tool.as_str()), ("pages", pages.as_str())],
+        )
+    );
+    let _ = writeln!(md, "{}\n", i18n.t("statement-preparation-review"));
+
+    let _ = writeln!(md, "## {}\n", i18n.t("statement-feedback-heading"));
+    let _ = writeln!(md, "{}\n", i18n.t("statement-feedback-placeholder"));
+
+    let _ = writeln!(md, "## {}\n", i18n.t("statement-enforcement-heading"));
+    let _ = writeln!(md, "{}", i18n.t("statement-enforcement-placeholder"));
+    md
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::audit::statement::draft_statement;
+
+    #[test]
+    fn renders_localized_draft_with_review_markers() {
+        let json = serde_json::json!({
+            "metadata": { "tool": "auditmysite 1.0.0", "timestamp": "2026-01-15T10:00:00Z" },
+            "pages": [{
+                "url": "https://example.com/",
+                "findings": [{
+                    "rule_id": "image-alt",
+                    "category": "wcag",
+                    "title": "Images without alt text",
+                    "wcag_criterion": "1.1.1",
+                    "severity": "medium",
+                    "occurrence_count": 4,
+                }],
+            }],
+        })
+        .to_string();
+        let draft = draft_statement(&json).unwrap();
+
+        let de = format_statement_markdown(&draft, &I18n::new("de").unwrap());
+        assert!(de.starts_with("# Erklärung zur Barrierefreiheit"));
+        assert!(de.contains("**1.1.1 Nicht-Text-Inhalt** (EN 301 549 9.1.1.1)"));
+        assert!(de.contains("https://example.com"));
+        assert!(!de.contains("statement-"), "missing Fluent key in:\n{de}");
+
+        let en = format_statement_markdown(&draft, &I18n::new("en").unwrap());
+        assert!(en.contains("partially compliant"));
+        assert!(en.contains("**1.1.1 Non-text Content**"));
+        assert!(en.contains("Images without alt text"));
+        assert!(!en.contains("statement-"), "missing Fluent key in:\n{en}");
+    }
+}
@@ -0,0 +1,167 @@
+//! Draft VPAT® / Accessibility Conformance Report rendering.
+//!
+//! The Markdown uses plain pipe tables only (no inline HTML), so it converts
+//! cleanly to HTML or DOCX with pandoc and pastes into the official template.
+//! The JSON is the serialized `wcag::vpat::Vpat`.
+
+use std::fmt::Write as _;
+
+use crate::cli::VpatEdition;
+use crate::wcag::vpat::{Vpat, VpatRow};
+
+/// Page URLs listed in the header before the list is truncated.
+const MAX_LISTED_PAGES: usize = 20;
+
+const TERMS: &[(&str, &str)] = &[
+    (
+        "Supports",
+        "The functionality of the product has at least one method that meets the criterion without known defects or meets with equivalent facilitation.",
+    ),
+    (
+        "Partially Supports",
+        "Some functionality of the product does not meet the criterion.",
+    ),
+    (
+        "Does Not Support",
+        "The majority of product functionality does not meet the criterion.",
+    ),
+    (
+        "Not Evaluated",
+        "The product has not been evaluated against the criterion.",
+    ),
+];
+
+/// Render a draft VPAT as Markdown.
+pub fn format_vpat_markdown(vpat: &Vpat) -> String {
+    let mut md = String::new();
+    let edition = match vpat.edition {
+        VpatEdition::Wcag => "WCAG Edition",
+        VpatEdition::En301549 => "EU Edition (EN 301 549)",
+    };
+    let _ = writeln!(
+        md,
+        "# Accessibility Conformance Report — {edition} (Draft)\n"
+    );
+    let _ = writeln!(md, "Based on VPAT® Version {}\n", vpat.template_version);
+    let _ = writeln!(md, "> **Draft.** {}\n", vpat.disclaimer);
+
+    md.push_str("## Evaluation\n\n");
+    if let Some(date) = vpat.evaluation_date {
+        let _ = writeln!(md, "- **Evaluation date:** {}", date.format("%Y-%m-%d"));
+    }
+    let _ = writeln!(md, "- **Standard:** {}", vpat.standard);
+    let _ = writeln!(
+        md,
+        "- **Evaluation methods:** Automated testing with {}. Criteria marked \"Manual review required\" were not evaluated.",
+        vpat.evaluation_tool
+    );
+    let _ = writeln!(md, "- **Pages evaluated:** {}", vpat.pages.len());
+    for url in vpat.pages.iter().take(MAX_LISTED_PAGES) {
+        let _ = writeln!(md, "  - <{url}>");
+    }
+    if vpat.pages.len() > MAX_LISTED_PAGES {
+        let _ = writeln!(md, "  - … and {} more", vpat.pages.len() - MAX_LISTED_PAGES);
+    }
+
+    md.push_str("\n## Terms\n\n");
+    for (term, definition) in TERMS {
+        let _ = writeln!(md, "- **{term}:** {definition}");
+    }
+
+    match vpat.edition {
+        VpatEdition::Wcag => {
+            write_table(&mut md, "Table 1: Success Criteria, Level A", vpat, "A");
+            write_table(&mut md, "Table 2: Success Criteria, Level AA", vpat, "AA");
+        }
+        VpatEdition::En301549 => {
+            md.push_str("\n## Chapter 9: Web\n\n");
+            write_rows(&mut md, vpat.criteria.iter());
+
+            md.push_str("\n## Other chapters\n\n");
+            md.push_str("| Chapter | Conformance Level | Remarks and Explanations |\n");
+            md.push_str("|---|---|---|\n");
+            for chapter in &vpat.out_of_scope_chapters {
+                let _ = writeln!(
+                    md,
+                    "| {} {} | {} | Outside the scope of a web content audit. |",
+                    chapter.chapter,
+                    chapter.title,
+                    chapter.conformance.label()
+                );
+            }
+        }
+    }
+    md
+}
+
+/// Render a draft VPAT as JSON.
+pub fn format_vpat_json(vpat: &Vpat) -> anyhow::Result<String> {
+    serde_json::to_string_pretty(vpat).map_err(|e| anyhow::anyhow!(e))
+}
+
+fn write_table(md: &mut String, heading: &str, vpat: &Vpat, level: &str) {
+    let _ = writeln!(md, "\n## {heading}\n");
+    write_rows(md, vpat.criteria.iter().filter(|r| r.level == level));
+}
+
+fn write_rows<'a>(md: &mut String, rows: impl Iterator<Item = &'a VpatRow>) {
+    md.push_str("| Criteria | Conformance Level | Remarks and Explanations |\n");
+    md.push_str("|---|---|---|\n");
+    for row in rows {
+        let criteria = match row.en_clause {
+            Some(clause) => format!(
+                "{clause} {} (WCAG {}, Level {})",
+                row.title, row.criterion, row.level
+            ),
+            None => format!("{} {} (Level {})", row.criterion, row.title, row.level),
+        };
+        let remarks = if row.manual_review {
+            format!("**Manual review required.** {}", row.remarks)
+        } else {
+            row.remarks.clone()
+        };
+        let _ = writeln!(
+            md,
+            "| {} | {} | {} |",
+            cell(&criteria),
+            row.conformance.label(),
+            cell(&remarks)
+        );
+    }
+}
+
+fn cell(text: &str) -> String {
+    text.replace('|', "\\|").replace(['\n', '\r'], " ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::audit::normalized::normalize;
+    use crate::audit::AuditReport;
+    use crate::cli::WcagLevel;
+    use crate::wcag::vpat::derive_vpat;
+    use crate::wcag::WcagResults;
+
+    #[test]
+    fn renders_level_tables_and_marks_manual_review_rows() {
+        let report = AuditReport::new(
+            "https://example.com".to_string(),
+            WcagLevel::AA,
+            WcagResults::new(),
+            1,
+        );
+        let normalized = normalize(&report).normalized;
+
+        let md = format_vpat_markdown(&derive_vpat(&[&normalized], VpatEdition::Wcag));
+        assert!(md.contains("## Table 1: Success Criteria, Level A"));
+        assert!(md.contains("## Table 2: Success Criteria, Level AA"));
+        assert!(md.contains(
+            "| 2.3.1 Three Flashes or Below Threshold (Level A) | Not Evaluated | **Manual review required.**"
+        ));
+
+        let eu = format_vpat_markdown(&derive_vpat(&[&normalized], VpatEdition::En301549));
+        assert!(eu.contains("| 9.1.1.1 Non-text Content (WCAG 1.1.1, Level A) |"));
+        assert!(eu.contains("| 5 Generic requirements | Not Evaluated |"));
+    }
+}
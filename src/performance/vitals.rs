@@ -76,6 +76,101 @@ pub struct WebVitals {
     pub dom_nodes: Option<i64>,
     /// JavaScript Heap Size (bytes)
     pub js_heap_size: Option<i64>,
+    /// Spread across repeated measurements (`--runs N`, N > 1). When present,
+    /// the metric values above are the per-metric medians.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub run_stats: Option<VitalsRunStats>,
+}
+
+/// Distribution of one metric across repeated measurement runs.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MetricRunStats {
+    /// Runs in which the metric could be measured
+    pub samples: usize,
+    pub median: f64,
+    /// 75th percentile (nearest rank) — the percentile Core Web Vitals are assessed at
+    pub p75: f64,
+    /// Sample variance (n − 1 denominator); 0 for a single sample
+    pub variance: f64,
+    pub min: f64,
+    pub max: f64,
+}
+
+impl MetricRunStats {
+    /// Sample standard deviation, in the metric's own unit. Reports show this
+    /// rather than the variance.
+    pub fn std_dev(&self) -> f64 {
+        self.variance.sqrt()
+    }
+
+    /// Statistics over the given samples, `None` when there are none.
+    pub fn from_samples(samples: &[f64]) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+        let mut sorted = samples.to_vec();
+        sorted.sort_by(f64::total_cmp);
+        let n = sorted.len();
+        let median = if n.is_multiple_of(2) {
+            (sorted[n / 2 - 1] + sorted[n / 2]) / 2.0
+        } else {
+            sorted[n / 2]
+        };
+        let p75 = sorted[(n * 3).div_ceil(4) - 1];
+        let mean = sorted.iter().sum::<f64>() / n as f64;
+        let variance = if n > 1 {
+            sorted.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / (n - 1) as f64
+        } else {
+            0.0
+        };
+        Some(Self {
+            samples: n,
+            median,
+            p75,
+            variance,
+            min: sorted[0],
+            max: sorted[n - 1],
+        })
+    }
+}
+
+/// Per-metric statistics for a repeated measurement (`--runs N`).
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct VitalsRunStats {
+    /// Number of successful measurement runs
+    pub runs: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lcp: Option<MetricRunStats>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fcp: Option<MetricRunStats>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cls: Option<MetricRunStats>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ttfb: Option<MetricRunStats>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tbt: Option<MetricRunStats>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub speed_index: Option<MetricRunStats>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tti: Option<MetricRunStats>,
+}
+
+impl VitalsRunStats {
+    /// `(label, stats)` for every metric that was measured, in display order.
+    pub fn metrics(&self) -> Vec<(&'static str, &MetricRunStats)> {
+        [
+            ("LCP", &self.lcp),
+            ("FCP", &self.fcp),
+            ("CLS", &self.cls),
+            ("TTFB", &self.ttfb),
+            ("TBT", &self.tbt),
+            ("Speed Index", &self.speed_index),
+            ("TTI", &self.tti),
+        ]
+        .into_iter()
+        .filter_map(|(label, stats)| stats.as_ref().map(|s| (label, s)))
+        .collect()
+    }
 }
 
 /// How a metric value was obtained.
@@ -99,6 +194,47 @@ pub enum MeasurementContext {
     LabThrottledMobile,
 }
 
+/// "Good" and "poor" boundaries of a rated metric, shared by a single
+/// measurement and the median of repeated runs.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct VitalThresholds {
+    pub good: f64,
+    pub poor: f64,
+}
+
+pub const LCP_THRESHOLDS: VitalThresholds = VitalThresholds {
+    good: 2500.0,
+    poor: 4000.0,
+};
+pub const FCP_THRESHOLDS: VitalThresholds = VitalThresholds {
+    good: 1800.0,
+    poor: 3000.0,
+};
+pub const CLS_THRESHOLDS: VitalThresholds = VitalThresholds {
+    good: 0.1,
+    poor: 0.25,
+};
+pub const TTFB_THRESHOLDS: VitalThresholds = VitalThresholds {
+    good: 800.0,
+    poor: 1800.0,
+};
+pub const TBT_THRESHOLDS: VitalThresholds = VitalThresholds {
+    good: 200.0,
+    poor: 600.0,
+};
+pub const INP_THRESHOLDS: VitalThresholds = VitalThresholds {
+    good: 200.0,
+    poor: 500.0,
+};
+pub const SPEED_INDEX_THRESHOLDS: VitalThresholds = VitalThresholds {
+    good: 3400.0,
+    poor: 5800.0,
+};
+pub const TTI_THRESHOLDS: VitalThresholds = VitalThresholds {
+    good: 3800.0,
+    poor: 7300.0,
+};
+
 /// Individual vital metric with value and rating
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct VitalMetric {
@@ -132,6 +268,11 @@ impl VitalMetric {
         }
     }
 
+    /// Rate `value` against a metric's [`VitalThresholds`].
+    pub fn rated(value: f64, thresholds: VitalThresholds) -> Self {
+        Self::new(value, thresholds.good, thresholds.poor)
+    }
+
     /// Mark this metric as an estimated/heuristic lab value (e.g. Speed Index,
     /// TTI, INP proxy) rather than a direct headless measurement.
     pub fn estimated(mut self) -> Self {
@@ -461,6 +602,82 @@ pub fn mark_throttled_mobile(vitals: &mut WebVitals) {
     tag(&mut vitals.tbt);
 }
 
+/// Combine repeated measurements of the same page into one vitals set.
+///
+/// Every rated metric becomes the median of the runs that measured it, re-rated
+/// against the usual thresholds; `run_stats` records the spread. Non-metric
+/// fields (CLS attribution, DOM size, heap, load timings) come from the run
+/// whose LCP is closest to the median. A single run is returned unchanged.
+pub fn aggregate_vitals_runs(runs: &[WebVitals]) -> Option<WebVitals> {
+    if runs.len() <= 1 {
+        return runs.first().cloned();
+    }
+
+    fn slot_stats(
+        runs: &[WebVitals],
+        slot: fn(&WebVitals) -> &Option<VitalMetric>,
+    ) -> Option<MetricRunStats> {
+        let samples: Vec<f64> = runs
+            .iter()
+            .filter_map(|v| slot(v).as_ref().map(|m| m.value))
+            .collect();
+        MetricRunStats::from_samples(&samples)
+    }
+
+    let stats = VitalsRunStats {
+        runs: runs.len(),
+        lcp: slot_stats(runs, |v| &v.lcp),
+        fcp: slot_stats(runs, |v| &v.fcp),
+        cls: slot_stats(runs, |v| &v.cls),
+        ttfb: slot_stats(runs, |v| &v.ttfb),
+        tbt: slot_stats(runs, |v| &v.tbt),
+        speed_index: slot_stats(runs, |v| &v.speed_index),
+        tti: slot_stats(runs, |v| &v.tti),
+    };
+
+    let representative = match &stats.lcp {
+        Some(lcp) => runs
+            .iter()
+            .filter(|v| v.lcp.is_some())
+            .min_by(|a, b| {
+                let distance = |v: &WebVitals| (v.lcp.as_ref().unwrap().value - lcp.median).abs();
+                distance(a).total_cmp(&distance(b))
+            })
+            .unwrap_or(&runs[0]),
+        None => &runs[0],
+    };
+    let mut vitals = representative.clone();
+
+    // Re-rate with the same thresholds as `extract_web_vitals`, keeping the
+    // provenance (lab / estimated) of the run the slot template comes from.
+    fn median_metric(
+        runs: &[WebVitals],
+        slot: fn(&WebVitals) -> &Option<VitalMetric>,
+        stats: &Option<MetricRunStats>,
+        thresholds: VitalThresholds,
+    ) -> Option<VitalMetric> {
+        let template = runs.iter().find_map(|v| slot(v).as_ref())?;
+        let stats = stats.as_ref()?;
+        let mut metric = VitalMetric::rated(stats.median, thresholds);
+        metric.measurement = template.measurement;
+        Some(metric)
+    }
+    vitals.lcp = median_metric(runs, |v| &v.lcp, &stats.lcp, LCP_THRESHOLDS);
+    vitals.fcp = median_metric(runs, |v| &v.fcp, &stats.fcp, FCP_THRESHOLDS);
+    vitals.cls = median_metric(runs, |v| &v.cls, &stats.cls, CLS_THRESHOLDS);
+    vitals.ttfb = median_metric(runs, |v| &v.ttfb, &stats.ttfb, TTFB_THRESHOLDS);
+    vitals.tbt = median_metric(runs, |v| &v.tbt, &stats.tbt, TBT_THRESHOLDS);
+    vitals.speed_index = median_metric(
+        runs,
+        |v| &v.speed_index,
+        &stats.speed_index,
+        SPEED_INDEX_THRESHOLDS,
+    );
+    vitals.tti = median_metric(runs, |v| &v.tti, &stats.tti, TTI_THRESHOLDS);
+    vitals.run_stats = Some(stats);
+    Some(vitals)
+}
+
 fn parse_shift_rect(val: &serde_json::Value) -> Option<ShiftRect> {
     if val.is_null() {
         return None;
@@ -585,18 +802,18 @@ pub async fn extract_web_vitals(page: &Page) -> Result<WebVitals> {
 
     // Apply pre-injected LCP and TBT when non-zero (0 = not captured / not injected)
     if preinjected.lcp > 0.0 && preinjected.lcp < 300_000.0 {
-        vitals.lcp = Some(VitalMetric::new(preinjected.lcp, 2500.0, 4000.0));
+        vitals.lcp = Some(VitalMetric::rated(preinjected.lcp, LCP_THRESHOLDS));
         debug!("LCP (preinjected): {:.0}ms", preinjected.lcp);
     }
     // TBT: 0 ms is a valid perfect score (no long tasks), always include like CLS.
-    vitals.tbt = Some(VitalMetric::new(preinjected.tbt, 200.0, 600.0));
+    vitals.tbt = Some(VitalMetric::rated(preinjected.tbt, TBT_THRESHOLDS));
     debug!("TBT (preinjected): {:.0}ms", preinjected.tbt);
     // CLS: 0.0 is a valid perfect score, always apply. Use the session-window
     // maximum (the current CWV definition) computed from the per-shift list,
     // not the cumulative sum the observer accumulates — the latter overcounts
     // pages that shift repeatedly during lazy-loading.
     let cls_session = session_window_cls(&preinjected.cls_shifts);
-    vitals.cls = Some(VitalMetric::new(cls_session, 0.1, 0.25));
+    vitals.cls = Some(VitalMetric::rated(cls_session, CLS_THRESHOLDS));
     debug!(
         "CLS session-window: {:.4} (cumulative was {:.4})",
         cls_session, preinjected.cls
@@ -609,7 +826,7 @@ pub async fn extract_web_vitals(page: &Page) -> Result<WebVitals> {
     // In headless audits interactions rarely fire, so 0 means "not measurable" and
     // we leave the field empty rather than reporting a misleading 0ms.
     if preinjected.inp_duration > 0.0 {
-        vitals.inp = Some(VitalMetric::new(preinjected.inp_duration, 200.0, 500.0).estimated());
+        vitals.inp = Some(VitalMetric::rated(preinjected.inp_duration, INP_THRESHOLDS).estimated());
         debug!("INP (event-timing): {:.0}ms", preinjected.inp_duration);
     }
 
@@ -625,7 +842,7 @@ pub async fn extract_web_vitals(page: &Page) -> Result<WebVitals> {
             "FirstContentfulPaint" => {
                 let ms = value * 1000.0;
                 if ms > 0.0 && ms < 300_000.0 {
-                    vitals.fcp = Some(VitalMetric::new(ms, 1800.0, 3000.0));
+                    vitals.fcp = Some(VitalMetric::rated(ms, FCP_THRESHOLDS));
                     debug!("FCP (CDP): {:.0}ms", ms);
                 }
             }
@@ -633,7 +850,7 @@ pub async fn extract_web_vitals(page: &Page) -> Result<WebVitals> {
                 // Only use CDP LCP as fallback when pre-injected observer missed it
                 let ms = value * 1000.0;
                 if ms > 0.0 && ms < 300_000.0 {
-                    vitals.lcp = Some(VitalMetric::new(ms, 2500.0, 4000.0));
+                    vitals.lcp = Some(VitalMetric::rated(ms, LCP_THRESHOLDS));
                     debug!("LCP (CDP fallback): {:.0}ms", ms);
                 }
             }
@@ -690,7 +907,7 @@ pub async fn extract_web_vitals(page: &Page) -> Result<WebVitals> {
         tti_ms = tti_ms.max(lcp_metric.value);
     }
     if tti_ms > 0.0 && tti_ms < 300_000.0 {
-        vitals.tti = Some(VitalMetric::new(tti_ms, 3800.0, 7300.0).estimated());
+        vitals.tti = Some(VitalMetric::rated(tti_ms, TTI_THRESHOLDS).estimated());
         debug!("TTI (approx): {:.0}ms", tti_ms);
     }
 
@@ -700,7 +917,7 @@ pub async fn extract_web_vitals(page: &Page) -> Result<WebVitals> {
     // Thresholds from Lighthouse: good ≤3400ms, poor >5800ms.
     if let (Some(fcp), Some(lcp)) = (&vitals.fcp, &vitals.lcp) {
         let si = 0.35 * fcp.value + 0.65 * lcp.value;
-        vitals.speed_index = Some(VitalMetric::new(si, 3400.0, 5800.0).estimated());
+        vitals.speed_index = Some(VitalMetric::rated(si, SPEED_INDEX_THRESHOLDS).estimated());
         debug!("Speed Index (heuristic): {:.0}ms", si);
     }
 
@@ -764,13 +981,13 @@ async fn extract_js_metrics(page: &Page) -> Result<WebVitals> {
     let mut vitals = WebVitals::default();
 
     if let Some(lcp) = parsed["lcp"].as_f64() {
-        vitals.lcp = Some(VitalMetric::new(lcp, 2500.0, 4000.0));
+        vitals.lcp = Some(VitalMetric::rated(lcp, LCP_THRESHOLDS));
     }
     if let Some(fcp) = parsed["fcp"].as_f64() {
-        vitals.fcp = Some(VitalMetric::new(fcp, 1800.0, 3000.0));
+        vitals.fcp = Some(VitalMetric::rated(fcp, FCP_THRESHOLDS));
     }
     if let Some(ttfb) = parsed["ttfb"].as_f64() {
-        vitals.ttfb = Some(VitalMetric::new(ttfb, 800.0, 1800.0));
+        vitals.ttfb = Some(VitalMetric::rated(ttfb, TTFB_THRESHOLDS));
     }
     if let Some(load_time) = parsed["loadTime"].as_f64() {
         vitals.load_time = Some(load_time);
@@ -870,6 +1087,50 @@ mod tests {
         assert!(json.contains("\"measurement\":\"estimated_lab\""));
     }
 
+    #[test]
+    fn test_metric_run_stats_median_p75_variance() {
+        let stats = MetricRunStats::from_samples(&[3000.0, 1000.0, 2000.0, 4000.0]).unwrap();
+        assert_eq!(stats.median, 2500.0);
+        assert_eq!(stats.p75, 3000.0);
+        assert!((stats.variance - 1_666_666.67).abs() < 1.0);
+        assert_eq!((stats.min, stats.max), (1000.0, 4000.0));
+
+        let single = MetricRunStats::from_samples(&[1200.0]).unwrap();
+        assert_eq!(
+            (single.median, single.p75, single.variance),
+            (1200.0, 1200.0, 0.0)
+        );
+        assert!(MetricRunStats::from_samples(&[]).is_none());
+    }
+
+    #[test]
+    fn test_aggregate_vitals_runs_uses_medians() {
+        let run = |lcp: f64, nodes: i64| WebVitals {
+            lcp: Some(VitalMetric::new(lcp, 2500.0, 4000.0).into_throttled_mobile()),
+            cls: Some(VitalMetric::new(0.05, 0.1, 0.25)),
+            dom_nodes: Some(nodes),
+            ..Default::default()
+        };
+        let vitals =
+            aggregate_vitals_runs(&[run(2000.0, 1), run(4500.0, 2), run(3000.0, 3)]).unwrap();
+        let lcp = vitals.lcp.unwrap();
+        assert_eq!(lcp.value, 3000.0);
+        assert_eq!(lcp.rating, "needs-improvement");
+        assert_eq!(lcp.measurement, MeasurementContext::LabThrottledMobile);
+        assert_eq!(
+            vitals.dom_nodes,
+            Some(3),
+            "representative run is the median-LCP one"
+        );
+        let stats = vitals.run_stats.unwrap();
+        assert_eq!(stats.runs, 3);
+        assert_eq!(stats.lcp.unwrap().p75, 4500.0);
+        assert!(stats.fcp.is_none());
+
+        let once = aggregate_vitals_runs(&[run(2000.0, 1)]).unwrap();
+        assert!(once.run_stats.is_none());
+    }
+
     #[test]
     fn test_web_vitals_good_count() {
         let vitals = WebVitals {
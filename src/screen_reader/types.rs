@@ -28,10 +28,15 @@ pub struct IgnoredReadingNode {
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct SrAuditReport {
+    // Constants of the sidecar format. They are re-filled rather than read
+    // back, so the report can be restored from batch run checkpoints.
+    #[serde(skip_deserializing, default = "sr_schema_version")]
     pub schema_version: &'static str,
+    #[serde(skip_deserializing, default = "sr_report_type")]
     pub report_type: &'static str,
     pub url: String,
     pub timestamp: chrono::DateTime<chrono::Utc>,
+    #[serde(skip_deserializing, default = "sr_tool_version")]
     pub tool_version: &'static str,
     pub summary: SrAuditSummary,
     pub reading_sequence: Vec<AnnouncedReadingItem>,
@@ -40,6 +45,18 @@ pub struct SrAuditReport {
     pub bfsg_compliance: BfsgCompliance,
 }
 
+fn sr_schema_version() -> &'static str {
+    "1.0"
+}
+
+fn sr_report_type() -> &'static str {
+    "screen_reader_audit"
+}
+
+fn sr_tool_version() -> &'static str {
+    env!("CARGO_PKG_VERSION")
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct SrAuditSummary {
     pub total_announced_nodes: usize,
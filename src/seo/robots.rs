@@ -531,6 +531,86 @@ pub fn infer_robots_policy(audit: &RobotsAudit, en: bool) -> String {
     }
 }
 
+// ─── Crawl rules ─────────────────────────────────────────────────────────────
+
+impl RobotsAudit {
+    /// Groups that apply to `agent` (a product token such as `auditmysite`,
+    /// or `*`): the groups naming the token, otherwise the `*` groups
+    /// (RFC 9309 §2.2.1).
+    fn groups_for(&self, agent: &str) -> Vec<&RobotsGroup> {
+        let named: Vec<&RobotsGroup> = self
+            .groups
+            .iter()
+            .filter(|g| g.user_agent != "*" && g.user_agent.eq_ignore_ascii_case(agent))
+            .collect();
+        if !named.is_empty() {
+            return named;
+        }
+        self.groups.iter().filter(|g| g.user_agent == "*").collect()
+    }
+
+    /// Whether `agent` may fetch `path` (path plus query). The longest
+    /// matching rule wins; `Allow` wins a tie (RFC 9309 §2.2.2).
+    pub fn is_allowed(&self, agent: &str, path: &str) -> bool {
+        let mut best: Option<(usize, bool)> = None;
+        for group in self.groups_for(agent) {
+            let rules = group
+                .allows
+                .iter()
+                .map(|r| (r, true))
+                .chain(group.disallows.iter().map(|r| (r, false)));
+            for (rule, allow) in rules {
+                if rule.is_empty() || !robots_rule_matches(rule, path) {
+                    continue;
+                }
+                let len = rule.chars().count();
+                if best.is_none_or(|(best_len, best_allow)| {
+                    len > best_len || (len == best_len && allow && !best_allow)
+                }) {
+                    best = Some((len, allow));
+                }
+            }
+        }
+        best.is_none_or(|(_, allow)| allow)
+    }
+
+    /// `Crawl-delay` in seconds for `agent`, falling back to `*`.
+    pub fn crawl_delay_for(&self, agent: &str) -> Option<u32> {
+        let delay_for = |token: &str| {
+            self.crawl_delays
+                .iter()
+                .find(|(a, _)| a.eq_ignore_ascii_case(token))
+                .map(|(_, d)| *d)
+        };
+        delay_for(agent).or_else(|| delay_for("*"))
+    }
+}
+
+/// robots.txt path pattern: prefix match where `*` matches any run of
+/// characters and a trailing `$` anchors the end.
+fn robots_rule_matches(rule: &str, path: &str) -> bool {
+    let (rule, anchored) = match rule.strip_suffix('$') {
+        Some(r) => (r, true),
+        None => (rule, false),
+    };
+    let mut parts = rule.split('*');
+    let first = parts.next().unwrap_or_default();
+    let Some(mut rest) = path.strip_prefix(first) else {
+        return false;
+    };
+    let parts: Vec<&str> = parts.collect();
+    for (i, part) in parts.iter().enumerate() {
+        if anchored && i == parts.len() - 1 {
+            return rest.ends_with(part);
+        }
+        match rest.find(part) {
+            Some(pos) => rest = &rest[pos + part.len()..],
+            None => return false,
+        }
+    }
+    !anchored || rest.is_empty()
+}
+
 fn flush_group(
     agents: &mut Vec<String>,
     allows: &mut Vec<String>,
@@ -611,4 +691,22 @@ Crawl-delay: 10
             "http://sub.example.com"
         );
     }
+
+    #[test]
+    fn test_crawl_rules_pick_agent_group_and_longest_match() {
+        let text = "User-agent: *\nDisallow: /private/\nAllow: /private/press\nDisallow: /*.pdf$\nCrawl-delay: 2\n\nUser-agent: auditmysite\nDisallow: /search\n";
+        let audit = parse_robots_txt(text, "en");
+
+        assert!(!audit.is_allowed("*", "/private/team"));
+        assert!(audit.is_allowed("*", "/private/press/2024"));
+        assert!(!audit.is_allowed("*", "/files/report.pdf"));
+        assert!(audit.is_allowed("*", "/files/report.pdf?download=1"));
+        assert!(audit.is_allowed("*", "/search?q=x"));
+
+        // A named group replaces the `*` rules entirely.
+        assert!(!audit.is_allowed("AuditMySite", "/search?q=x"));
+        assert!(audit.is_allowed("auditmysite", "/private/team"));
+
+        assert_eq!(audit.crawl_delay_for("auditmysite"), Some(2));
+    }
 }
@@ -0,0 +1,213 @@
+//! Minimal HTTP/1.1 framing for the JSON API.
+//!
+//! One request per connection: the request head and a `Content-Length` body
+//! are read, and every response is sent with `Connection: close`. Streaming
+//! responses simply omit the length and end when the connection closes.
+
+use tokio::io::{AsyncRead, AsyncReadExt};
+
+/// Upper bound for the request line plus headers.
+const MAX_HEAD_BYTES: usize = 16 * 1024;
+/// Upper bound for a request body (job submissions are tiny).
+const MAX_BODY_BYTES: usize = 1024 * 1024;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Request {
+    pub method: String,
+    /// Path without the query string
+    pub path: String,
+    pub body: Vec<u8>,
+}
+
+/// A request that could not be read, with the status to answer it with.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct HttpError {
+    pub status: u16,
+    pub message: String,
+}
+
+impl HttpError {
+    fn bad_request(message: impl Into<String>) -> Self {
+        Self {
+            status: 400,
+            message: message.into(),
+        }
+    }
+
+    fn timeout() -> Self {
+        Self {
+            status: 408,
+            message: "request not received in time".to_string(),
+        }
+    }
+}
+
+/// [`read_request`], answered with `408` when the client does not finish
+/// sending within `limit`.
+pub(crate) async fn read_request_within<R: AsyncRead + Unpin>(
+    reader: &mut R,
+    limit: std::time::Duration,
+) -> std::result::Result<Request, HttpError> {
+    tokio::time::timeout(limit, read_request(reader))
+        .await
+        .unwrap_or_else(|_| Err(HttpError::timeout()))
+}
+
+pub(crate) async fn read_request<R: AsyncRead + Unpin>(
+    reader: &mut R,
+) -> std::result::Result<Request, HttpError> {
+    let mut buf = Vec::with_capacity(1024);
+    let head_end = loop {
+        if let Some(pos) = buf.windows(4).position(|w| w == b"\r\n\r\n") {
+            break pos;
+        }
+        if buf.len() > MAX_HEAD_BYTES {
+            return Err(HttpError {
+                status: 431,
+                message: "request head too large".to_string(),
+            });
+        }
+        let mut chunk = [0u8; 4096];
+        let n = reader
+            .read(&mut chunk)
+            .await
+            .map_err(|e| HttpError::bad_request(e.to_string()))?;
+        if n == 0 {
+            return Err(HttpError::bad_request("connection closed mid-request"));
+        }
+        buf.extend_from_slice(&chunk[..n]);
+    };
+
+    let head = std::str::from_utf8(&buf[..head_end])
+        .map_err(|_| HttpError::bad_request("request head is not UTF-8"))?;
+    let mut lines = head.split("\r\n");
+    let mut request_line = lines.next().unwrap_or_default().split_whitespace();
+    let (Some(method), Some(target)) = (request_line.next(), request_line.next()) else {
+        return Err(HttpError::bad_request("malformed request line"));
+    };
+    let method = method.to_string();
+    let path = target.split('?').next().unwrap_or(target).to_string();
+
+    let mut content_length = 0usize;
+    for line in lines {
+        let Some((name, value)) = line.split_once(':') else {
+            return Err(HttpError::bad_request("malformed header"));
+        };
+        if name.trim().eq_ignore_ascii_case("content-length") {
+            content_length = value
+                .trim()
+                .parse()
+                .map_err(|_| HttpError::bad_request("invalid Content-Length"))?;
+        } else if name.trim().eq_ignore_ascii_case("transfer-encoding") {
+            return Err(HttpError {
+                status: 411,
+                message: "chunked request bodies are not supported".to_string(),
+            });
+        }
+    }
+    if content_length > MAX_BODY_BYTES {
+        return Err(HttpError {
+            status: 413,
+            message: "request body too large".to_string(),
+        });
+    }
+
+    let mut body = buf.split_off(head_end + 4);
+    if body.len() < content_length {
+        let start = body.len();
+        body.resize(content_length, 0);
+        reader
+            .read_exact(&mut body[start..])
+            .await
+            .map_err(|_| HttpError::bad_request("request body shorter than Content-Length"))?;
+    }
+    body.truncate(content_length);
+
+    Ok(Request { method, path, body })
+}
+
+/// A complete JSON response.
+pub(crate) fn json_response(status: u16, body: &serde_json::Value) -> Vec<u8> {
+    let body = body.to_string();
+    format!(
+        "HTTP/1.1 {} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        status,
+        reason_phrase(status),
+        body.len(),
+        body
+    )
+    .into_bytes()
+}
+
+/// Head of a newline-delimited JSON stream; the body ends when the
+/// connection closes.
+pub(crate) fn ndjson_stream_head() -> &'static [u8] {
+    b"HTTP/1.1 200 OK\r\nContent-Type: application/x-ndjson\r\nCache-Control: no-cache\r\nConnection: close\r\n\r\n"
+}
+
+fn reason_phrase(status: u16) -> &'static str {
+    match status {
+        200 => "OK",
+        202 => "Accepted",
+        400 => "Bad Request",
+        404 => "Not Found",
+        405 => "Method Not Allowed",
+        411 => "Length Required",
+        408 => "Request Timeout",
+        413 => "Payload Too Large",
+        431 => "Request Header Fields Too Large",
+        503 => "Service Unavailable",
+        _ => "Internal Server Error",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn reads_head_and_body_and_rejects_oversized_or_malformed_requests() {
+        let raw =
+            b"POST /audits?x=1 HTTP/1.1\r\nHost: localhost\r\ncontent-length: 7\r\n\r\n{\"a\":1}";
+        let request = read_request(&mut &raw[..]).await.unwrap();
+        assert_eq!(request.method, "POST");
+        assert_eq!(request.path, "/audits");
+        assert_eq!(request.body, b"{\"a\":1}");
+
+        let short = b"POST /audits HTTP/1.1\r\nContent-Length: 10\r\n\r\n{}";
+        assert_eq!(read_request(&mut &short[..]).await.unwrap_err().status, 400);
+
+        let big = format!(
+            "POST /audits HTTP/1.1\r\nContent-Length: {}\r\n\r\n",
+            MAX_BODY_BYTES + 1
+        );
+        assert_eq!(
+            read_request(&mut big.as_bytes()).await.unwrap_err().status,
+            413
+        );
+        assert_eq!(
+            read_request(&mut &b"GARBAGE\r\n\r\n"[..])
+                .await
+                .unwrap_err()
+                .status,
+            400
+        );
+
+        let response =
+            String::from_utf8(json_response(404, &serde_json::json!({"error": "x"}))).unwrap();
+        assert!(response.starts_with("HTTP/1.1 404 Not Found\r\n"));
+        assert!(response.ends_with("\r\n\r\n{\"error\":\"x\"}"));
+    }
+
+    #[tokio::test]
+    async fn stalled_clients_get_a_timeout() {
+        let (mut client, mut server) = tokio::io::duplex(64);
+        tokio::io::AsyncWriteExt::write_all(&mut client, b"GET /health HTTP/1.1\r\n")
+            .await
+            .unwrap();
+        let err = read_request_within(&mut server, std::time::Duration::from_millis(20))
+            .await
+            .unwrap_err();
+        assert_eq!(err.status, 408);
+    }
+}
@@ -0,0 +1,563 @@
+//! `auditmysite serve` — audits as a local JSON API.
+//!
+//! Jobs are submitted over HTTP, wait in a bounded queue and run on one
+//! shared [`BrowserPool`], at most `concurrency` at a time. Each job streams
+//! progress events and ends with a [`StudioAuditResponse`] or a
+//! [`UnifiedReport`], so Studio and dashboards consume the same contract types
+//! as the CLI.
+//!
+//! | Method | Path                  | Response                                   |
+//! |--------|-----------------------|--------------------------------------------|
+//! | GET    | `/health`             | queue and worker status                    |
+//! | POST   | `/audits`             | `202` with the job id, `503` if the queue is full |
+//! | GET    | `/audits/{id}`        | job status, plus `result` or `error` once finished |
+//! | GET    | `/audits/{id}/events` | NDJSON progress events until the job ends  |
+
+mod http;
+
+use std::collections::VecDeque;
+use std::net::SocketAddr;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use tokio::io::AsyncWriteExt;
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::{mpsc, watch, Semaphore};
+use tracing::{info, warn};
+
+use crate::audit::{audit_url_with_pool, normalize, AuditReport, PipelineConfig};
+use crate::browser::{BrowserPool, PoolConfig};
+use crate::cli::WcagLevel;
+use crate::error::{AuditError, Result};
+use crate::output::{format_json_normalized, UnifiedReport};
+use crate::studio::StudioAuditResponse;
+
+use http::{json_response, ndjson_stream_head, read_request_within, Request};
+
+/// Open connections served at once, event streams included; further
+/// clients wait in the listen backlog.
+const MAX_CONNECTIONS: usize = 256;
+/// Time a client gets to send its request head and body.
+const REQUEST_READ_TIMEOUT: Duration = Duration::from_secs(10);
+/// Pause after a failed `accept`, e.g. when file descriptors run out.
+const ACCEPT_BACKOFF: Duration = Duration::from_millis(100);
+
+/// Finished jobs kept for `GET /audits/{id}`; older ones are forgotten.
+const MAX_FINISHED_JOBS: usize = 100;
+
+/// Modules a job can select; accessibility always runs.
+const JOB_MODULES: &[&str] = &["performance", "seo", "security", "mobile", "stack"];
+
+/// Server settings. `pipeline` is the baseline every job starts from.
+#[derive(Debug, Clone)]
+pub struct ServerConfig {
+    pub addr: SocketAddr,
+    /// Audits running at the same time (browser pool size)
+    pub concurrency: usize,
+    /// Jobs allowed to wait for a worker before submissions are rejected
+    pub queue_size: usize,
+    pub pipeline: PipelineConfig,
+    pub pool: PoolConfig,
+}
+
+/// Body of `POST /audits`.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct AuditJobRequest {
+    pub url: String,
+    /// WCAG level (default: the server's `--level`)
+    #[serde(default)]
+    pub level: Option<WcagLevel>,
+    /// Modules besides accessibility, e.g. `["performance", "seo"]`
+    /// (default: the server's module selection)
+    #[serde(default)]
+    pub modules: Option<Vec<String>>,
+    #[serde(default)]
+    pub format: JobResultFormat,
+}
+
+/// Shape of a finished job's `result`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum JobResultFormat {
+    /// [`StudioAuditResponse`]
+    #[default]
+    Studio,
+    /// [`UnifiedReport`], identical to `-f json`
+    Unified,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum JobStatus {
+    Queued,
+    Running,
+    Completed,
+    Failed,
+}
+
+/// Progress event of one job, streamed as one JSON line each.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "event", rename_all = "lowercase")]
+pub enum JobEvent {
+    Queued {
+        id: String,
+        url: String,
+        position: usize,
+    },
+    Started {
+        id: String,
+        url: String,
+    },
+    Completed {
+        id: String,
+        url: String,
+        accessibility_score: u32,
+        overall_score: u32,
+        duration_ms: u64,
+    },
+    Failed {
+        id: String,
+        url: String,
+        error: String,
+    },
+}
+
+impl AuditJobRequest {
+    /// Validate the request and derive its pipeline config from `base`.
+    pub fn pipeline_config(&self, base: &PipelineConfig) -> Result<PipelineConfig> {
+        let parsed = url::Url::parse(&self.url).map_err(|e| AuditError::InvalidUrl {
+            url: self.url.clone(),
+            reason: e.to_string(),
+        })?;
+        if !matches!(parsed.scheme(), "http" | "https") {
+            return Err(AuditError::InvalidUrl {
+                url: self.url.clone(),
+                reason: "only http and https URLs can be audited".to_string(),
+            });
+        }
+
+        let mut config = base.clone();
+        if let Some(level) = self.level {
+            config.wcag_level = level;
+        }
+        if let Some(modules) = &self.modules {
+            if let Some(unknown) = modules.iter().find(|m| !JOB_MODULES.contains(&m.as_str())) {
+                return Err(AuditError::ConfigError(format!(
+                    "unknown module '{}' (expected one of: {})",
+                    unknown,
+                    JOB_MODULES.join(", ")
+                )));
+            }
+            let has = |name: &str| modules.iter().any(|m| m == name);
+            config.full_audit = JOB_MODULES.iter().all(|m| has(m));
+            config.check_performance = has("performance");
+            config.check_seo = has("seo");
+            config.check_security = has("security");
+            config.check_mobile = has("mobile");
+            config.check_stack = has("stack");
+        }
+        Ok(config)
+    }
+}
+
+struct Job {
+    id: String,
+    url: String,
+    format: JobResultFormat,
+    config: PipelineConfig,
+    state: watch::Sender<JobState>,
+}
+
+#[derive(Debug, Clone)]
+struct JobState {
+    status: JobStatus,
+    events: Vec<JobEvent>,
+    result: Option<serde_json::Value>,
+    error: Option<String>,
+}
+
+impl Job {
+    fn push(&self, status: JobStatus, event: JobEvent) {
+        self.state.send_modify(|state| {
+            state.status = status;
+            state.events.push(event);
+        });
+    }
+
+    fn summary(&self) -> serde_json::Value {
+        let state = self.state.borrow();
+        let mut body = json!({
+            "id": self.id,
+            "url": self.url,
+            "status": state.status,
+            "format": self.format,
+        });
+        if let Some(result) = &state.result {
+            body["result"] = result.clone();
+        }
+        if let Some(error) = &state.error {
+            body["error"] = json!(error);
+        }
+        body
+    }
+}
+
+struct ServerState {
+    base: PipelineConfig,
+    pool: BrowserPool,
+    queue: mpsc::Sender<Arc<Job>>,
+    jobs: Mutex<VecDeque<Arc<Job>>>,
+    next_id: AtomicU64,
+    concurrency: usize,
+}
+
+impl ServerState {
+    fn find(&self, id: &str) -> Option<Arc<Job>> {
+        self.jobs
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .iter()
+            .find(|job| job.id == id)
+            .cloned()
+    }
+
+    fn count(&self, status: JobStatus) -> usize {
+        self.jobs
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .iter()
+            .filter(|job| job.state.borrow().status == status)
+            .count()
+    }
+
+    /// Track a new job, forgetting the oldest finished ones beyond the cap.
+    fn track(&self, job: Arc<Job>) {
+        let mut jobs = self.jobs.lock().unwrap_or_else(|e| e.into_inner());
+        jobs.push_back(job);
+        let finished = |job: &Arc<Job>| {
+            matches!(
+                job.state.borrow().status,
+                JobStatus::Completed | JobStatus::Failed
+            )
+        };
+        let mut excess = jobs.iter().filter(|job| finished(job)).count();
+        while excess > MAX_FINISHED_JOBS {
+            if let Some(pos) = jobs.iter().position(finished) {
+                jobs.remove(pos);
+            }
+            excess -= 1;
+        }
+    }
+}
+
+/// Launch the browser pool and serve the API until the process is stopped.
+pub async fn serve(config: ServerConfig) -> Result<()> {
+    let concurrency = config.concurrency.max(1);
+    let pool = BrowserPool::new(PoolConfig {
+        max_pages: concurrency,
+        ..config.pool
+    })
+    .await?;
+    let listener = TcpListener::bind(config.addr).await?;
+    let (queue, receiver) = mpsc::channel(config.queue_size.max(1));
+    let state = Arc::new(ServerState {
+        base: config.pipeline,
+        pool,
+        queue,
+        jobs: Mutex::new(VecDeque::new()),
+        next_id: AtomicU64::new(1),
+        concurrency,
+    });
+
+    let receiver = Arc::new(tokio::sync::Mutex::new(receiver));
+    for _ in 0..concurrency {
+        tokio::spawn(worker(Arc::clone(&state), Arc::clone(&receiver)));
+    }
+
+    info!("Serving audits on http://{}", listener.local_addr()?);
+    let connections = Arc::new(Semaphore::new(MAX_CONNECTIONS));
+    loop {
+        let permit = Arc::clone(&connections)
+            .acquire_owned()
+            .await
+            .expect("connection semaphore is never closed");
+        let (stream, peer) = match listener.accept().await {
+            Ok(accepted) => accepted,
+            Err(e) => {
+                warn!("Accepting a connection failed: {}", e);
+                tokio::time::sleep(ACCEPT_BACKOFF).await;
+                continue;
+            }
+        };
+        let state = Arc::clone(&state);
+        tokio::spawn(async move {
+            if let Err(e) = handle_connection(stream, &state).await {
+                warn!("Connection from {} failed: {}", peer, e);
+            }
+            drop(permit);
+        });
+    }
+}
+
+async fn worker(
+    state: Arc<ServerState>,
+    receiver: Arc<tokio::sync::Mutex<mpsc::Receiver<Arc<Job>>>>,
+) {
+    loop {
+        let Some(job) = receiver.lock().await.recv().await else {
+            return;
+        };
+        run_job(&state, &job).await;
+    }
+}
+
+async fn run_job(state: &ServerState, job: &Job) {
+    let started = Instant::now();
+    job.push(
+        JobStatus::Running,
+        JobEvent::Started {
+            id: job.id.clone(),
+            url: job.url.clone(),
+        },
+    );
+
+    let outcome = audit_url_with_pool(&state.pool, &job.url, &job.config)
+        .await
+        .outcome
+        .map_err(|e| e.to_string())
+        .and_then(|report| render_result(&report, job.format).map_err(|e| e.to_string()));
+
+    match outcome {
+        Ok((result, accessibility_score, overall_score)) => {
+            job.state.send_modify(|s| s.result = Some(result));
+            job.push(
+                JobStatus::Completed,
+                JobEvent::Completed {
+                    id: job.id.clone(),
+                    url: job.url.clone(),
+                    accessibility_score,
+                    overall_score,
+                    duration_ms: started.elapsed().as_millis() as u64,
+                },
+            );
+        }
+        Err(error) => {
+            warn!("Audit job {} for {} failed: {}", job.id, job.url, error);
+            job.state.send_modify(|s| s.error = Some(error.clone()));
+            job.push(
+                JobStatus::Failed,
+                JobEvent::Failed {
+                    id: job.id.clone(),
+                    url: job.url.clone(),
+                    error,
+                },
+            );
+        }
+    }
+}
+
+/// Render the job result and pick out the scores for the completion event.
+fn render_result(
+    report: &AuditReport,
+    format: JobResultFormat,
+) -> Result<(serde_json::Value, u32, u32)> {
+    let ctx = normalize(report);
+    let scores = (ctx.normalized.score, ctx.normalized.overall_score);
+    let result = match format {
+        JobResultFormat::Studio => {
+            let json_report = format_json_normalized(&ctx, report, false)?;
+            serde_json::to_value(StudioAuditResponse::from_normalized(
+                &ctx,
+                report,
+                json_report,
+            ))?
+        }
+        JobResultFormat::Unified => serde_json::to_value(UnifiedReport::single(&ctx, report))?,
+    };
+    Ok((result, scores.0, scores.1))
+}
+
+async fn handle_connection(mut stream: TcpStream, state: &ServerState) -> Result<()> {
+    let request = match read_request_within(&mut stream, REQUEST_READ_TIMEOUT).await {
+        Ok(request) => request,
+        Err(e) => {
+            let body = json!({ "error": e.message });
+            stream.write_all(&json_response(e.status, &body)).await?;
+            return Ok(());
+        }
+    };
+
+    if request.method == "GET" {
+        if let Some(job) = request
+            .path
+            .strip_prefix("/audits/")
+            .and_then(|rest| rest.strip_suffix("/events"))
+            .and_then(|id| state.find(id))
+        {
+            return stream_events(stream, &job).await;
+        }
+    }
+
+    let (status, body) = route(&request, state);
+    stream.write_all(&json_response(status, &body)).await?;
+    Ok(())
+}
+
+fn route(request: &Request, state: &ServerState) -> (u16, serde_json::Value) {
+    let segments: Vec<&str> = request.path.trim_matches('/').split('/').collect();
+    match (request.method.as_str(), segments.as_slice()) {
+        ("GET", ["health"]) => (
+            200,
+            json!({
+                "status": "ok",
+                "version": env!("CARGO_PKG_VERSION"),
+                "concurrency": state.concurrency,
+                "queued": state.count(JobStatus::Queued),
+                "running": state.count(JobStatus::Running),
+            }),
+        ),
+        ("POST", ["audits"]) => submit(request, state),
+        ("GET", ["audits", id]) => match state.find(id) {
+            Some(job) => (200, job.summary()),
+            None => not_found(),
+        },
+        (_, ["health"] | ["audits"] | ["audits", _] | ["audits", _, "events"])
+            if !matches!(request.method.as_str(), "GET" | "POST") =>
+        {
+            (405, json!({ "error": "method not allowed" }))
+        }
+        _ => not_found(),
+    }
+}
+
+fn submit(request: &Request, state: &ServerState) -> (u16, serde_json::Value) {
+    let parsed = serde_json::from_slice::<AuditJobRequest>(&request.body)
+        .map_err(AuditError::from)
+        .and_then(|job| job.pipeline_config(&state.base).map(|config| (job, config)));
+    let (job_request, config) = match parsed {
+        Ok(parsed) => parsed,
+        Err(e) => return (400, json!({ "error": e.to_string() })),
+    };
+
+    let id = format!("job-{}", state.next_id.fetch_add(1, Ordering::Relaxed));
+    let queued = JobEvent::Queued {
+        id: id.clone(),
+        url: job_request.url.clone(),
+        position: state.count(JobStatus::Queued) + 1,
+    };
+    let (sender, _) = watch::channel(JobState {
+        status: JobStatus::Queued,
+        events: vec![queued],
+        result: None,
+        error: None,
+    });
+    let job = Arc::new(Job {
+        id: id.clone(),
+        url: job_request.url,
+        format: job_request.format,
+        config,
+        state: sender,
+    });
+
+    if state.queue.try_send(Arc::clone(&job)).is_err() {
+        return (503, json!({ "error": "audit queue is full, retry later" }));
+    }
+    state.track(Arc::clone(&job));
+    (
+        202,
+        json!({
+            "id": id,
+            "status": JobStatus::Queued,
+            "status_url": format!("/audits/{id}"),
+            "events_url": format!("/audits/{id}/events"),
+        }),
+    )
+}
+
+fn not_found() -> (u16, serde_json::Value) {
+    (404, json!({ "error": "not found" }))
+}
+
+/// Replay the job's events so far, then follow it until it finishes.
+async fn stream_events(mut stream: TcpStream, job: &Job) -> Result<()> {
+    stream.write_all(ndjson_stream_head()).await?;
+    let mut receiver = job.state.subscribe();
+    let mut sent = 0;
+    loop {
+        let (lines, finished) = {
+            let state = receiver.borrow_and_update();
+            let mut lines = String::new();
+            for event in &state.events[sent..] {
+                lines.push_str(&serde_json::to_string(event)?);
+                lines.push('\n');
+            }
+            sent = state.events.len();
+            let finished = matches!(state.status, JobStatus::Completed | JobStatus::Failed);
+            (lines, finished)
+        };
+        stream.write_all(lines.as_bytes()).await?;
+        if finished || receiver.changed().await.is_err() {
+            return Ok(());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::Args;
+    use clap::Parser;
+
+    fn base() -> PipelineConfig {
+        PipelineConfig::try_from(&Args::parse_from(["auditmysite", "serve"])).unwrap()
+    }
+
+    #[test]
+    fn job_requests_override_level_and_modules_of_the_server_baseline() {
+        let request: AuditJobRequest = serde_json::from_value(json!({
+            "url": "https://example.com",
+            "level": "AAA",
+            "modules": ["seo", "security"],
+            "format": "unified",
+        }))
+        .unwrap();
+        let config = request.pipeline_config(&base()).unwrap();
+        assert_eq!(config.wcag_level, WcagLevel::AAA);
+        assert!(config.check_seo && config.check_security);
+        assert!(!config.check_performance && !config.check_mobile && !config.full_audit);
+        assert_eq!(request.format, JobResultFormat::Unified);
+
+        let defaults: AuditJobRequest =
+            serde_json::from_value(json!({ "url": "https://example.com" })).unwrap();
+        let config = defaults.pipeline_config(&base()).unwrap();
+        assert_eq!(config.wcag_level, base().wcag_level);
+        assert_eq!(config.check_seo, base().check_seo);
+        assert_eq!(defaults.format, JobResultFormat::Studio);
+
+        let unknown: AuditJobRequest = serde_json::from_value(
+            json!({ "url": "https://example.com", "modules": ["lighthouse"] }),
+        )
+        .unwrap();
+        assert!(unknown.pipeline_config(&base()).is_err());
+        let file: AuditJobRequest =
+            serde_json::from_value(json!({ "url": "file:///etc/passwd" })).unwrap();
+        assert!(file.pipeline_config(&base()).is_err());
+    }
+
+    #[test]
+    fn events_serialize_as_tagged_json_lines() {
+        let event = JobEvent::Queued {
+            id: "job-1".to_string(),
+            url: "https://example.com".to_string(),
+            position: 2,
+        };
+        assert_eq!(
+            serde_json::to_string(&event).unwrap(),
+            r#"{"event":"queued","id":"job-1","url":"https://example.com","position":2}"#
+        );
+    }
+}
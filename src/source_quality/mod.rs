@@ -1254,6 +1254,7 @@ mod tests {
                 },
                 nodes_analyzed: 100,
                 execution: Default::default(),
+                waived: Vec::new(),
             },
             duration_ms: 1000,
             performance: None,
@@ -1271,6 +1272,7 @@ mod tests {
             page_screenshots: None,
             dual_viewport: None,
             viewport_scores: None,
+            device_results: Vec::new(),
             throttled_performance: vec![],
             patterns: None,
             screenshot_status: Default::default(),
@@ -1283,6 +1285,7 @@ mod tests {
             accessibility_journey: None,
             interactive_findings: Vec::new(),
             screen_reader_audit: None,
+            rendered_links: Vec::new(),
         }
     }
 
@@ -78,9 +78,21 @@ const VIS_TECHNICAL: ReportVisibility = ReportVisibility {
 pub struct RuleLookup;
 
 impl RuleLookup {
-    /// Regel nach ID suchen
+    /// Regel nach ID suchen (eingebaute und per Plugin registrierte Regeln)
     pub fn by_id(id: &str) -> Option<&'static Rule> {
-        RULES.iter().find(|r| r.id == id)
+        RULES.iter().find(|r| r.id == id).or_else(|| {
+            crate::wcag::plugin::registered_taxonomy()
+                .into_iter()
+                .find(|r| r.id == id)
+        })
+    }
+
+    /// Alle Regeln: das eingebaute Register, gefolgt von Plugin-Regeln
+    pub fn all() -> Vec<&'static Rule> {
+        RULES
+            .iter()
+            .chain(crate::wcag::plugin::registered_taxonomy())
+            .collect()
     }
 
     /// Regel nach externer WCAG-Referenz suchen (z.B. "1.1.1")
@@ -89,19 +101,65 @@ impl RuleLookup {
         RULES
             .iter()
             .find(|r| r.external_ref == Some(search.as_str()))
+            .or_else(|| {
+                crate::wcag::plugin::registered_taxonomy()
+                    .into_iter()
+                    .find(|r| r.external_ref == Some(search.as_str()))
+            })
     }
 
-    /// Regel nach Legacy-WCAG-ID suchen (z.B. "1.1.1")
+    /// Regel nach Legacy-WCAG-ID suchen (z.B. "1.1.1"); Plugin-Regeln
+    /// werden über ihre axe-ID gefunden
     pub fn by_legacy_wcag_id(wcag_id: &str) -> Option<&'static Rule> {
         LEGACY_WCAG_MAP
             .iter()
             .find(|(wid, _)| *wid == wcag_id)
             .and_then(|(_, rule_id)| Self::by_id(rule_id))
+            .or_else(|| {
+                crate::wcag::plugin::registered_taxonomy()
+                    .into_iter()
+                    .find(|r| r.axe_id == Some(wcag_id))
+            })
+    }
+
+    /// Regel nach axe-ID suchen; teilen sich mehrere Regeln eine axe-ID,
+    /// gewinnt die mit passendem WCAG-Kriterium (z.B. "2.4.4")
+    pub fn by_axe_id(axe_id: &str, criterion: Option<&str>) -> Option<&'static Rule> {
+        let candidates: Vec<&'static Rule> = Self::all()
+            .into_iter()
+            .filter(|r| r.axe_id == Some(axe_id))
+            .collect();
+        criterion
+            .and_then(|c| {
+                candidates
+                    .iter()
+                    .find(|r| r.external_ref.and_then(|e| e.strip_prefix("WCAG ")) == Some(c))
+            })
+            .or(candidates.first())
+            .copied()
     }
 
-    /// Alle Regeln einer Dimension
+    /// W3C-ACT-Regel-ID zu einer Regel-ID, sofern es eine entsprechende
+    /// ACT-Regel gibt
+    pub fn act_rule_id(rule_id: &str) -> Option<&'static str> {
+        ACT_RULE_MAP
+            .iter()
+            .find(|(id, _)| *id == rule_id)
+            .map(|(_, act)| *act)
+    }
+
+    /// Alle Regeln einer Dimension (inklusive Plugin-Regeln)
     pub fn by_dimension(dim: Dimension) -> Vec<&'static Rule> {
-        RULES.iter().filter(|r| r.dimension == dim).collect()
+        Self::all()
+            .into_iter()
+            .filter(|r| r.dimension == dim)
+            .collect()
+    }
+
+    /// Ob eine axe-ID zum eingebauten Register gehört (ohne Plugin-Regeln)
+    pub(crate) fn is_builtin_axe_id(axe_id: &str) -> bool {
+        RULES.iter().any(|r| r.axe_id == Some(axe_id))
+            || LEGACY_WCAG_MAP.iter().any(|(wid, _)| *wid == axe_id)
     }
 }
 
@@ -198,6 +256,45 @@ static LEGACY_WCAG_MAP: &[(&str, &str)] = &[
         "presentation-semantic-children",
         "a11y.presentation_semantic_children.invalid",
     ),
+    ("waiver-expired", "a11y.waiver.expired"),
+];
+
+/// Zuordnung Regel-ID → W3C-ACT-Regel-ID
+/// (<https://www.w3.org/WAI/standards-guidelines/act/rules/>). Nur Regeln,
+/// deren Prüfung einer ACT-Regel entspricht; mehrere Regeln können dieselbe
+/// ACT-Regel abdecken.
+static ACT_RULE_MAP: &[(&str, &str)] = &[
+    ("a11y.alt_text.missing", "23a2a8"),
+    ("a11y.captions.missing", "eac66b"),
+    ("a11y.orientation.restricted", "b33eff"),
+    ("a11y.keyboard_trap.risk", "80af7b"),
+    ("a11y.bypass_blocks.missing", "cf77f2"),
+    ("a11y.page_title.missing", "2779a5"),
+    ("a11y.link_purpose.weak", "c487ae"),
+    ("a11y.focus_visible.missing", "oj04fd"),
+    ("a11y.label_in_name.invalid", "2ee8b8"),
+    ("a11y.timing.unadjustable", "bc659a"),
+    ("a11y.input_purpose.missing", "73f2c2"),
+    ("a11y.form_labels.missing", "e086e5"),
+    ("a11y.language.missing", "b5c3f8"),
+    ("a11y.language_valid.invalid", "bf051a"),
+    ("a11y.language_mismatch.invalid", "5b7ae0"),
+    ("a11y.contrast.weak", "afw4f7"),
+    ("a11y.resize_text.weak", "b4f0c3"),
+    ("a11y.aria_hidden_focus.invalid", "6cfa84"),
+    ("a11y.aria_required_attr.missing", "4e8ab6"),
+    ("a11y.aria_required_parent.invalid", "ff89c9"),
+    ("a11y.aria_allowed_attr.invalid", "5c01ea"),
+    ("a11y.aria_valid_attr_value.invalid", "6a7281"),
+    ("a11y.aria_relationships.invalid", "5f99a7"),
+    ("a11y.aria_roles.invalid", "674b10"),
+    ("a11y.command_name.missing", "97a4e1"),
+    ("a11y.input_field_name.missing", "e086e5"),
+    ("a11y.toggle_field_name.missing", "e086e5"),
+    ("a11y.table_headers_ref.invalid", "a25f45"),
+    ("a11y.table_header_data.missing", "d0f69e"),
+    ("a11y.frame_title.missing", "cae760"),
+    ("a11y.parsing.invalid", "3ea0c8"),
 ];
 
 /// Alle Regeln im System
@@ -2171,6 +2268,29 @@ pub static RULES: &[Rule] = &[
         },
         report_visibility: VIS_ALL,
     },
+    Rule {
+        id: "a11y.waiver.expired",
+        dimension: Dimension::Accessibility,
+        subcategory: Subcategory::TechnicalRobustness,
+        issue_class: IssueClass::Risk,
+        severity: Severity::Medium,
+        external_ref: None,
+        external_level: None,
+        axe_id: Some("waiver-expired"),
+        title: "Abgelaufene Ausnahme (Waiver)",
+        title_en: "Expired waiver",
+        description: "Eine Ausnahme aus der Waiver-Datei ist abgelaufen, der zugehörige Befund besteht weiterhin.",
+        user_impact: "Eine bewusst akzeptierte Barriere wurde nicht fristgerecht behoben oder neu bewertet.",
+        user_impact_en: "A knowingly accepted barrier was not fixed or re-assessed by its deadline.",
+        technical_impact: "Waiver-Eintrag mit überschrittenem Ablaufdatum.",
+        technical_impact_en: "Waiver entry past its expiry date.",
+        score_impact: ScoreImpact {
+            base_penalty: 0.0,
+            max_penalty: 0.0,
+            occurrence_scaling: Scaling::Fixed,
+        },
+        report_visibility: VIS_ALL,
+    },
     // ═══════════════════════════════════════════════════════════════════════════
     // PERFORMANCE
     // ═══════════════════════════════════════════════════════════════════════════
@@ -2982,6 +3102,27 @@ mod tests {
         assert_eq!(modern.axe_id, Some("modern-attribute-misuse"));
     }
 
+    #[test]
+    fn test_act_rule_ids_resolve_and_axe_lookup_prefers_criterion() {
+        for (rule_id, act_id) in ACT_RULE_MAP {
+            assert!(
+                RuleLookup::by_id(rule_id).is_some(),
+                "ACT mapping refers to unknown rule {rule_id}"
+            );
+            assert_eq!(act_id.len(), 6, "ACT rule id {act_id} is not 6 characters");
+        }
+        assert_eq!(
+            RuleLookup::act_rule_id("a11y.alt_text.missing"),
+            Some("23a2a8")
+        );
+        assert_eq!(RuleLookup::act_rule_id("a11y.headings.missing"), None);
+
+        let rule = RuleLookup::by_axe_id("link-name", Some("2.4.9")).unwrap();
+        assert_eq!(rule.id, "a11y.link_purpose_only.weak");
+        let rule = RuleLookup::by_axe_id("link-name", None).unwrap();
+        assert_eq!(rule.id, "a11y.link_purpose.weak");
+    }
+
     #[test]
     fn test_all_rules_have_dimension() {
         for rule in RULES {
@@ -63,6 +63,12 @@ mod tests {
             interactive: InteractiveMode::Off,
             journey_budget_ms: crate::a11y_journey::DEFAULT_BUDGET_MS,
             lang: "de".to_string(),
+            waivers: Default::default(),
+            custom_rules: Default::default(),
+            devices: Default::default(),
+            throttle_passes: crate::browser::throttle::default_throttle_passes(),
+            perf_runs: 1,
+            capture_rendered_links: false,
         }
     }
 
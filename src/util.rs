@@ -53,6 +53,59 @@ pub fn truncate_url(s: &str, max_len: usize) -> String {
     format!("{}...", &s[..boundary])
 }
 
+/// Standard base64 (RFC 4648, with padding), e.g. for `data:` URIs.
+pub fn base64_encode(bytes: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+    let mut out = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let n = match chunk {
+            [a, b, c] => u32::from_be_bytes([0, *a, *b, *c]),
+            [a, b] => u32::from_be_bytes([0, *a, *b, 0]),
+            [a] => u32::from_be_bytes([0, *a, 0, 0]),
+            _ => unreachable!("chunks(3) yields 1..=3 bytes"),
+        };
+        for i in 0..4 {
+            if i <= chunk.len() {
+                out.push(ALPHABET[(n >> (18 - 6 * i) & 0x3F) as usize] as char);
+            } else {
+                out.push('=');
+            }
+        }
+    }
+    out
+}
+
+/// Shell-style wildcard match: `*` matches any run of characters (including
+/// none), `?` exactly one. Everything else is literal; the whole text must match.
+pub fn glob_match(pattern: &str, text: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let text: Vec<char> = text.chars().collect();
+    let (mut p, mut t) = (0, 0);
+    // Position of the last `*` and the text index it is currently absorbing up to.
+    let mut backtrack: Option<(usize, usize)> = None;
+    while t < text.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                backtrack = Some((p, t));
+                p += 1;
+            }
+            Some(&c) if c == '?' || c == text[t] => {
+                p += 1;
+                t += 1;
+            }
+            _ => match backtrack {
+                Some((star, absorbed)) => {
+                    p = star + 1;
+                    t = absorbed + 1;
+                    backtrack = Some((star, absorbed + 1));
+                }
+                None => return false,
+            },
+        }
+    }
+    pattern[p..].iter().all(|&c| c == '*')
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -78,4 +131,30 @@ mod tests {
         let headers = browser_headers();
         assert_eq!(headers["accept-encoding"], "gzip");
     }
+
+    #[test]
+    fn test_glob_match() {
+        assert!(glob_match(
+            "https://example.com/*",
+            "https://example.com/a/b"
+        ));
+        assert!(glob_match("*/checkout?", "https://shop.test/checkout/"));
+        assert!(glob_match("*", ""));
+        assert!(glob_match("nav > a.*", "nav > a.active"));
+        assert!(!glob_match("https://example.com/*", "https://example.org/"));
+        assert!(!glob_match("div.card", "div.card-title"));
+    }
+
+    #[test]
+    fn test_base64_encode_matches_rfc_4648_vectors() {
+        for (input, expected) in [
+            ("", ""),
+            ("f", "Zg=="),
+            ("fo", "Zm8="),
+            ("foo", "Zm9v"),
+            ("foobar", "Zm9vYmFy"),
+        ] {
+            assert_eq!(base64_encode(input.as_bytes()), expected);
+        }
+    }
 }
@@ -4,9 +4,12 @@
 //! automatically vs. those that fundamentally require behavioral testing.
 //! Surfaced in the report so users understand the scope of automated audit.
 
+use std::borrow::Cow;
 use std::sync::OnceLock;
 
 use crate::taxonomy::rules::RULES;
+use crate::taxonomy::Rule;
+use crate::wcag::plugin::registered_taxonomy;
 
 /// WCAG 2.1 AA totals — Level A: 30, Level AA: 20, sum 50.
 /// WCAG 2.2 adds 6 more at AA (the 2.2.x criteria).
@@ -33,24 +36,42 @@ fn wcag_id_order(id: &str) -> Vec<u32> {
 /// of a hand-maintained duplicate list — that list had drifted from the real
 /// catalog (under-counting by ~20 criteria) and mislabeled several
 /// implemented criteria (e.g. 2.5.1/2.5.2/2.5.4) as manual-review-only.
-pub fn automated_criteria() -> &'static [(&'static str, &'static str)] {
+///
+/// The built-in set is cached; criteria of rules registered through
+/// `wcag::register_rule` are merged on top when there are any.
+pub fn automated_criteria() -> Cow<'static, [(&'static str, &'static str)]> {
     static CACHE: OnceLock<Vec<(&'static str, &'static str)>> = OnceLock::new();
-    CACHE.get_or_init(|| {
-        let mut out: Vec<(&'static str, &'static str)> = Vec::new();
-        for rule in RULES {
-            let (Some(ext_ref), Some(level)) = (rule.external_ref, rule.external_level) else {
-                continue;
-            };
-            let Some(id) = ext_ref.strip_prefix("WCAG ") else {
-                continue;
-            };
-            if !out.iter().any(|(existing, _)| *existing == id) {
-                out.push((id, level));
-            }
+    let builtin = CACHE.get_or_init(|| criteria_of(RULES.iter()));
+    let plugins = registered_taxonomy();
+    if plugins.is_empty() {
+        return Cow::Borrowed(builtin.as_slice());
+    }
+    let mut merged = builtin.clone();
+    for (id, level) in criteria_of(plugins.into_iter()) {
+        if !merged.iter().any(|(existing, _)| *existing == id) {
+            merged.push((id, level));
         }
-        out.sort_by_key(|a| wcag_id_order(a.0));
-        out
-    })
+    }
+    merged.sort_by_key(|a| wcag_id_order(a.0));
+    Cow::Owned(merged)
+}
+
+/// Distinct `(criterion, level)` pairs of `rules`, in WCAG order.
+fn criteria_of<'a>(rules: impl Iterator<Item = &'a Rule>) -> Vec<(&'static str, &'static str)> {
+    let mut out: Vec<(&'static str, &'static str)> = Vec::new();
+    for rule in rules {
+        let (Some(ext_ref), Some(level)) = (rule.external_ref, rule.external_level) else {
+            continue;
+        };
+        let Some(id) = ext_ref.strip_prefix("WCAG ") else {
+            continue;
+        };
+        if !out.iter().any(|(existing, _)| *existing == id) {
+            out.push((id, level));
+        }
+    }
+    out.sort_by_key(|a| wcag_id_order(a.0));
+    out
 }
 
 /// Candidate WCAG criteria that fundamentally require behavioral / manual
@@ -79,16 +100,20 @@ const MANUAL_REVIEW_CRITERIA_RAW: &[(&str, &str, &str)] = &[
 
 /// WCAG criteria that fundamentally require behavioral / manual review and
 /// cannot be reliably verified by an automated tool.
-pub fn manual_review_criteria() -> &'static [(&'static str, &'static str, &'static str)] {
+pub fn manual_review_criteria() -> Cow<'static, [(&'static str, &'static str, &'static str)]> {
     static CACHE: OnceLock<Vec<(&'static str, &'static str, &'static str)>> = OnceLock::new();
-    CACHE.get_or_init(|| {
-        let automated = automated_criteria();
+    let filter = |automated: &[(&'static str, &'static str)]| -> Vec<_> {
         MANUAL_REVIEW_CRITERIA_RAW
             .iter()
             .filter(|(id, _, _)| !automated.iter().any(|(aid, _)| aid == id))
             .copied()
             .collect()
-    })
+    };
+    let automated = automated_criteria();
+    match automated {
+        Cow::Borrowed(builtin) => Cow::Borrowed(CACHE.get_or_init(|| filter(builtin)).as_slice()),
+        Cow::Owned(merged) => Cow::Owned(filter(&merged)),
+    }
 }
 
 /// Returns (automated_count, total_aa_criteria), scoped to WCAG 2.1's A/AA
@@ -0,0 +1,568 @@
+//! Declarative custom rules loaded from a rules file.
+//!
+//! A rules file holds `[[rule]]` tables in TOML, or `{"rules": [...]}` in
+//! JSON. Every rule has exactly one predicate:
+//!
+//! - `ax` — matched against each non-ignored accessibility-tree node by role,
+//!   accessible name and AX properties;
+//! - `css` — a selector plus attribute conditions, evaluated in the page.
+//!
+//! A node or element that satisfies the predicate is a violation. Rules are
+//! registered through the [`plugin`](super::plugin) registry, so they honour
+//! the WCAG level, get a taxonomy entry (`a11y.custom.<id>`) and reach every
+//! output format like built-in rules.
+//!
+//! ```toml
+//! [[rule]]
+//! id = "ds-icon-button-label"
+//! wcag = "4.1.2"
+//! level = "A"
+//! severity = "high"
+//! message = "Icon button has no accessible name"
+//! ax = { role = "button", name_empty = true }
+//!
+//! [[rule]]
+//! id = "ds-new-window-warning"
+//! wcag = "3.2.5"
+//! level = "AAA"
+//! severity = "low"
+//! message = "Link opens a new window without announcing it"
+//! fix = "Reference the shared 'opens in a new window' hint via aria-describedby"
+//! css = { selector = "a[target='_blank']", attributes = [{ name = "aria-describedby", present = false }] }
+//! ```
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use chromiumoxide::Page;
+use serde::{Deserialize, Serialize};
+
+use crate::accessibility::{AXNode, AXTree, AXValue};
+use crate::cli::WcagLevel;
+use crate::error::{AuditError, Result};
+use crate::taxonomy::Severity;
+use crate::util::glob_match;
+
+use super::plugin::{Rule, RuleRegistry, RuleTaxonomy};
+use super::types::{RuleMetadata, Violation, WcagResults};
+
+/// Elements a selector rule inspects per page; matches beyond this are ignored.
+const MAX_SELECTOR_ELEMENTS: usize = 1000;
+/// Violations a selector rule reports per page.
+const MAX_SELECTOR_FINDINGS: usize = 50;
+
+/// The parsed rules file.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct DeclarativeRuleSet {
+    #[serde(default, rename = "rule", alias = "rules")]
+    pub rules: Vec<DeclarativeRule>,
+}
+
+/// One rule of a rules file.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct DeclarativeRule {
+    /// Stable rule id (the `axe_id` used by filters, grouping and SARIF)
+    pub id: String,
+    /// Rule title; defaults to the id
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// WCAG success criterion, e.g. `4.1.2`
+    pub wcag: String,
+    #[serde(default = "default_level")]
+    pub level: WcagLevel,
+    pub severity: Severity,
+    /// Message of every violation the rule raises
+    pub message: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub help_url: Option<String>,
+    /// Fix suggestion attached to every violation
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fix: Option<String>,
+    /// Effect on users, shown in reports
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_impact: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ax: Option<AxPredicate>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub css: Option<CssPredicate>,
+}
+
+fn default_level() -> WcagLevel {
+    WcagLevel::A
+}
+
+/// Accessibility-tree predicate. Every condition that is set must hold.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct AxPredicate {
+    /// Role, compared case-insensitively
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub role: Option<String>,
+    /// `*`/`?` glob on the accessible name, compared case-insensitively
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// `true`: the name is missing or blank; `false`: it is not
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name_empty: Option<bool>,
+    /// AX properties that must be present with exactly this value
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub properties: BTreeMap<String, serde_json::Value>,
+}
+
+/// In-page predicate: elements matching `selector` for which every attribute
+/// condition holds.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CssPredicate {
+    pub selector: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub attributes: Vec<AttributeCondition>,
+}
+
+/// Condition on one attribute. `equals` and `matches` imply presence.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct AttributeCondition {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub present: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub equals: Option<String>,
+    /// `*`/`?` glob on the attribute value
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub matches: Option<String>,
+}
+
+impl DeclarativeRuleSet {
+    /// Load a rules file; `.json` files are parsed as JSON, anything else as TOML.
+    pub fn load(path: &Path) -> Result<Self> {
+        let content = std::fs::read_to_string(path).map_err(|e| AuditError::FileError {
+            path: path.to_path_buf(),
+            reason: e.to_string(),
+        })?;
+        let parsed: std::result::Result<Self, String> =
+            if path.extension().is_some_and(|ext| ext == "json") {
+                serde_json::from_str(&content).map_err(|e| e.to_string())
+            } else {
+                toml::from_str(&content).map_err(|e| e.to_string())
+            };
+        let set = parsed.map_err(|e| {
+            AuditError::ConfigError(format!("Invalid rules file {}: {}", path.display(), e))
+        })?;
+        for rule in &set.rules {
+            rule.validate().map_err(|e| {
+                AuditError::ConfigError(format!("Invalid rules file {}: {}", path.display(), e))
+            })?;
+        }
+        Ok(set)
+    }
+
+    /// Register every rule for all subsequent audits in this process.
+    pub fn register(&self) -> Result<()> {
+        self.register_into(RuleRegistry::global())
+    }
+
+    pub(crate) fn register_into(&self, registry: &RuleRegistry) -> Result<()> {
+        for rule in &self.rules {
+            rule.validate().map_err(AuditError::ConfigError)?;
+            let meta = rule.metadata();
+            let taxonomy = RuleTaxonomy {
+                user_impact: leak(rule.user_impact.clone().unwrap_or_default()),
+                ..RuleTaxonomy::default()
+            };
+            match (&rule.ax, &rule.css) {
+                (Some(predicate), _) => registry.register(AxRule {
+                    meta,
+                    taxonomy,
+                    predicate: predicate.clone(),
+                    message: rule.message.clone(),
+                    fix: rule.fix.clone(),
+                })?,
+                (None, Some(predicate)) => registry.register_selector_rule(
+                    SelectorRule {
+                        meta,
+                        predicate: predicate.clone(),
+                        message: rule.message.clone(),
+                        fix: rule.fix.clone(),
+                    },
+                    taxonomy,
+                )?,
+                (None, None) => unreachable!("validated above"),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl DeclarativeRule {
+    fn validate(&self) -> std::result::Result<(), String> {
+        if self.id.trim().is_empty() {
+            return Err("rule without an id".to_string());
+        }
+        if self.wcag.is_empty() || !self.wcag.chars().all(|c| c.is_ascii_digit() || c == '.') {
+            return Err(format!(
+                "rule '{}': wcag must be a success criterion like \"4.1.2\"",
+                self.id
+            ));
+        }
+        match (&self.ax, &self.css) {
+            (Some(_), Some(_)) | (None, None) => Err(format!(
+                "rule '{}' needs exactly one of `ax` or `css`",
+                self.id
+            )),
+            (Some(ax), None) if *ax == AxPredicate::default() => {
+                Err(format!("rule '{}': `ax` has no conditions", self.id))
+            }
+            (None, Some(css)) if css.selector.trim().is_empty() => {
+                Err(format!("rule '{}': `css.selector` is empty", self.id))
+            }
+            (None, Some(css)) => match css
+                .attributes
+                .iter()
+                .find(|a| a.present.is_none() && a.equals.is_none() && a.matches.is_none())
+            {
+                Some(attr) => Err(format!(
+                    "rule '{}': attribute condition on '{}' needs present, equals or matches",
+                    self.id, attr.name
+                )),
+                None => Ok(()),
+            },
+            _ => Ok(()),
+        }
+    }
+
+    /// Metadata for the registry. Strings are leaked: registration is
+    /// permanent for the process, like [`super::register_rule`].
+    fn metadata(&self) -> &'static RuleMetadata {
+        let id = leak(self.id.clone());
+        let name = leak(self.name.clone().unwrap_or_else(|| self.id.clone()));
+        let level_tag = match self.level {
+            WcagLevel::A => "wcag2a",
+            WcagLevel::AA => "wcag2aa",
+            WcagLevel::AAA => "wcag2aaa",
+        };
+        let criterion_tag = leak(format!("wcag{}", self.wcag.replace('.', "")));
+        Box::leak(Box::new(RuleMetadata {
+            id: leak(self.wcag.clone()),
+            name,
+            level: self.level,
+            severity: self.severity,
+            description: leak(self.description.clone().unwrap_or_else(|| name.to_string())),
+            help_url: leak(self.help_url.clone().unwrap_or_default()),
+            axe_id: id,
+            tags: Box::leak(vec![level_tag, criterion_tag, "custom-rule"].into_boxed_slice()),
+        }))
+    }
+}
+
+/// An `ax` rule, run by the engine next to the built-in tree rules.
+struct AxRule {
+    meta: &'static RuleMetadata,
+    taxonomy: RuleTaxonomy,
+    predicate: AxPredicate,
+    message: String,
+    fix: Option<String>,
+}
+
+impl Rule for AxRule {
+    fn metadata(&self) -> &RuleMetadata {
+        self.meta
+    }
+
+    fn check(&self, tree: &AXTree) -> WcagResults {
+        let mut results = WcagResults::new();
+        for node in tree.iter().filter(|n| !n.ignored) {
+            if self.predicate.matches(node) {
+                let mut violation = self.meta.violation(self.message.as_str(), &node.node_id);
+                violation.fix_suggestion = self.fix.clone();
+                results.add_violation(violation);
+            }
+        }
+        results
+    }
+
+    fn taxonomy(&self) -> RuleTaxonomy {
+        self.taxonomy
+    }
+}
+
+impl AxPredicate {
+    fn matches(&self, node: &AXNode) -> bool {
+        let name = node.name.as_deref().unwrap_or("").trim();
+        self.role.as_deref().is_none_or(|role| {
+            node.role
+                .as_deref()
+                .is_some_and(|r| r.eq_ignore_ascii_case(role))
+        }) && self
+            .name
+            .as_deref()
+            .is_none_or(|pattern| glob_match(&pattern.to_lowercase(), &name.to_lowercase()))
+            && self.name_empty.is_none_or(|empty| name.is_empty() == empty)
+            && self.properties.iter().all(|(key, expected)| {
+                node.properties
+                    .iter()
+                    .find(|p| p.name == *key)
+                    .is_some_and(|p| ax_value_equals(&p.value, expected))
+            })
+    }
+}
+
+fn ax_value_equals(value: &AXValue, expected: &serde_json::Value) -> bool {
+    match (value, expected) {
+        (AXValue::Bool(b), serde_json::Value::Bool(e)) => b == e,
+        (AXValue::Int(i), serde_json::Value::Number(e)) => e.as_f64() == Some(*i as f64),
+        (AXValue::Float(f), serde_json::Value::Number(e)) => e.as_f64() == Some(*f),
+        (AXValue::String(s), serde_json::Value::String(e)) => s.eq_ignore_ascii_case(e),
+        _ => false,
+    }
+}
+
+/// A `css` rule, evaluated in the page after the built-in page rules.
+pub(crate) struct SelectorRule {
+    meta: &'static RuleMetadata,
+    predicate: CssPredicate,
+    message: String,
+    fix: Option<String>,
+}
+
+/// One element the selector matched, with the attributes the conditions name.
+#[derive(Debug, Deserialize)]
+struct MatchedElement {
+    selector: String,
+    #[serde(default)]
+    attributes: BTreeMap<String, Option<String>>,
+    #[serde(default)]
+    html: Option<String>,
+}
+
+impl SelectorRule {
+    pub(crate) fn metadata(&self) -> &'static RuleMetadata {
+        self.meta
+    }
+
+    /// Evaluate the rule on `page`. An invalid selector or failed evaluation
+    /// becomes a technical rule failure.
+    pub(crate) async fn check_with_page(&self, page: &Page) -> Vec<Violation> {
+        let spec = serde_json::json!({
+            "selector": self.predicate.selector,
+            "names": self.predicate.attributes.iter().map(|a| &a.name).collect::<Vec<_>>(),
+            "cap": MAX_SELECTOR_ELEMENTS,
+        });
+        let js = [
+            "(function() {",
+            crate::accessibility::js_helpers::CSS_SELECTOR_JS,
+            &SELECTOR_RULE_BODY.replace("SPEC", &spec.to_string()),
+            "})()",
+        ]
+        .concat();
+        let val = match super::types::evaluate_or_fail(page, self.meta, &js).await {
+            Ok(v) => v,
+            Err(violations) => return violations,
+        };
+        if val.get("error").is_some() {
+            return vec![super::types::technical_rule_failure(
+                self.meta,
+                "invalid_selector",
+            )];
+        }
+        let elements: Vec<MatchedElement> = val
+            .get("elements")
+            .cloned()
+            .and_then(|v| serde_json::from_value(v).ok())
+            .unwrap_or_default();
+        self.violations(elements)
+    }
+
+    fn violations(&self, elements: Vec<MatchedElement>) -> Vec<Violation> {
+        elements
+            .into_iter()
+            .filter(|el| {
+                self.predicate.attributes.iter().all(|cond| {
+                    cond.holds(el.attributes.get(&cond.name).and_then(|v| v.as_deref()))
+                })
+            })
+            .take(MAX_SELECTOR_FINDINGS)
+            .map(|el| {
+                let mut violation = self
+                    .meta
+                    .violation(self.message.as_str(), el.selector.as_str())
+                    .with_selector(el.selector);
+                if let Some(html) = el.html {
+                    violation = violation.with_html_snippet(html);
+                }
+                violation.fix_suggestion = self.fix.clone();
+                violation
+            })
+            .collect()
+    }
+}
+
+impl AttributeCondition {
+    fn holds(&self, value: Option<&str>) -> bool {
+        self.present
+            .is_none_or(|present| value.is_some() == present)
+            && self
+                .equals
+                .as_deref()
+                .is_none_or(|expected| value == Some(expected))
+            && self
+                .matches
+                .as_deref()
+                .is_none_or(|pattern| value.is_some_and(|v| glob_match(pattern, v)))
+    }
+}
+
+const SELECTOR_RULE_BODY: &str = r#"
+  var spec = SPEC;
+  var els;
+  try {
+    els = document.querySelectorAll(spec.selector);
+  } catch (e) {
+    return { error: String(e) };
+  }
+  var elements = [];
+  for (var i = 0; i < els.length && elements.length < spec.cap; i++) {
+    var el = els[i];
+    var attributes = {};
+    for (var j = 0; j < spec.names.length; j++) {
+      attributes[spec.names[j]] = el.getAttribute(spec.names[j]);
+    }
+    elements.push({
+      selector: __amsCssSelector(el),
+      attributes: attributes,
+      html: (el.outerHTML || '').slice(0, 300)
+    });
+  }
+  return { elements: elements };
+"#;
+
+fn leak(s: String) -> &'static str {
+    Box::leak(s.into_boxed_str())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::accessibility::AXProperty;
+    use crate::wcag::engine::{run_plugin_rules, RuleFilterConfig};
+
+    const RULES: &str = r#"
+[[rule]]
+id = "decl-test-icon-button"
+wcag = "4.1.2"
+severity = "high"
+message = "Icon button has no accessible name"
+fix = "Add an aria-label"
+ax = { role = "button", name_empty = true, properties = { focusable = true } }
+
+[[rule]]
+id = "decl-test-new-window"
+wcag = "3.2.5"
+level = "AAA"
+severity = "low"
+message = "Link opens a new window without warning"
+css = { selector = "a[target='_blank']", attributes = [{ name = "aria-describedby", present = false }] }
+"#;
+
+    fn button(id: &str, name: Option<&str>) -> AXNode {
+        AXNode {
+            node_id: id.to_string(),
+            ignored: false,
+            ignored_reasons: vec![],
+            role: Some("button".to_string()),
+            name: name.map(str::to_string),
+            name_source: None,
+            description: None,
+            value: None,
+            properties: vec![AXProperty {
+                name: "focusable".to_string(),
+                value: AXValue::Bool(true),
+            }],
+            child_ids: vec![],
+            parent_id: None,
+            backend_dom_node_id: None,
+        }
+    }
+
+    #[test]
+    fn ax_rules_run_in_the_engine_and_selector_rules_filter_attributes() {
+        let set: DeclarativeRuleSet = toml::from_str(RULES).unwrap();
+        let registry = RuleRegistry::new();
+        set.register_into(&registry).unwrap();
+        assert!(set.register_into(&registry).is_err(), "duplicate ids");
+
+        let tree = AXTree::from_nodes(vec![button("1", Some("  ")), button("2", Some("Menu"))]);
+        let mut results = WcagResults::new();
+        run_plugin_rules(
+            &registry,
+            &tree,
+            WcagLevel::AA,
+            &mut results,
+            &RuleFilterConfig::default(),
+        );
+        assert_eq!(results.violations.len(), 1);
+        let violation = &results.violations[0];
+        assert_eq!(violation.node_id, "1");
+        assert_eq!(violation.rule, "4.1.2");
+        assert_eq!(violation.rule_id.as_deref(), Some("decl-test-icon-button"));
+        assert_eq!(
+            violation.fix_suggestion.as_deref(),
+            Some("Add an aria-label")
+        );
+
+        let selector_rules = registry.selector_rules();
+        assert_eq!(selector_rules.len(), 1);
+        assert_eq!(selector_rules[0].metadata().level, WcagLevel::AAA);
+        let elements: Vec<MatchedElement> = serde_json::from_value(serde_json::json!([
+            {"selector": "a.plain", "attributes": {"aria-describedby": null}},
+            {"selector": "a.hinted", "attributes": {"aria-describedby": "new-window-hint"}},
+        ]))
+        .unwrap();
+        let found = selector_rules[0].violations(elements);
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].selector.as_deref(), Some("a.plain"));
+
+        let taxonomy = registry.taxonomy();
+        assert_eq!(taxonomy.len(), 2);
+        assert_eq!(taxonomy[1].id, "a11y.custom.decl-test-new-window");
+        assert_eq!(taxonomy[1].external_ref, Some("WCAG 3.2.5"));
+    }
+
+    #[test]
+    fn rules_need_exactly_one_usable_predicate() {
+        let both = r#"
+[[rule]]
+id = "x"
+wcag = "1.1.1"
+severity = "low"
+message = "m"
+ax = { role = "img" }
+css = { selector = "img" }
+"#;
+        let set: DeclarativeRuleSet = toml::from_str(both).unwrap();
+        assert!(set.rules[0].validate().is_err());
+
+        let json: DeclarativeRuleSet = serde_json::from_str(
+            r#"{"rules": [{"id": "x", "wcag": "AA", "severity": "low", "message": "m", "ax": {"role": "img"}}]}"#,
+        )
+        .unwrap();
+        assert!(
+            json.rules[0].validate().is_err(),
+            "wcag must be a criterion"
+        );
+
+        let empty_condition = r#"
+[[rule]]
+id = "x"
+wcag = "1.1.1"
+severity = "low"
+message = "m"
+css = { selector = "img", attributes = [{ name = "alt" }] }
+"#;
+        let set: DeclarativeRuleSet = toml::from_str(empty_condition).unwrap();
+        assert!(set.rules[0].validate().is_err());
+    }
+}
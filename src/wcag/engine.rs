@@ -1,9 +1,12 @@
 //! WCAG Rule Engine - Executes all WCAG rules against an AXTree
 //!
 //! The engine loads all rules and runs them against the accessibility tree.
+//! Rules registered through [`register_rule`](super::register_rule) run after
+//! the built-in rules of the requested level.
 
 use tracing::{debug, info};
 
+use super::plugin::RuleRegistry;
 pub use super::rules::{
     check_abbreviations_with_page, check_background_audio_with_page,
     check_click_handlers_with_page, check_content_on_hover_with_page,
@@ -103,6 +106,9 @@ pub fn check_all_with_config(
         run_level_aaa_rules(tree, &mut results, filter);
     }
 
+    // Rules registered through `wcag::register_rule`
+    run_plugin_rules(RuleRegistry::global(), tree, level, &mut results, filter);
+
     info!(
         "WCAG check complete: {} violations found",
         results.violations.len()
@@ -115,30 +121,63 @@ pub fn check_all_with_config(
 macro_rules! run_if_allowed {
     ($filter:expr, $axe_id:expr, $check_fn:expr, $results:expr, $tree:expr) => {
         if $filter.should_run($axe_id) {
-            let rule_results = $check_fn($tree);
-            let finding_count = rule_results.violations.len();
-            let status = if finding_count > 0 {
-                crate::wcag::RuleOutcomeStatus::ViolationsFound
-            } else if !rule_results.warnings.is_empty() {
-                crate::wcag::RuleOutcomeStatus::Warning
-            } else if !rule_results.not_testables.is_empty() {
-                crate::wcag::RuleOutcomeStatus::ManualReviewRequired
-            } else {
-                crate::wcag::RuleOutcomeStatus::NoViolationDetected
-            };
-            $results.rule_outcomes.push(crate::wcag::RuleOutcome {
-                rule_id: $axe_id.to_string(),
-                status,
-                wcag_criterion: crate::taxonomy::criterion_for_rule($axe_id),
-                viewport: None,
-                reason_code: None,
-                finding_count,
-            });
-            $results.merge(rule_results);
+            record_rule_results($results, $axe_id, $check_fn($tree));
         }
     };
 }
 
+/// Record one rule's outcome and merge its findings into `results`.
+fn record_rule_results(results: &mut WcagResults, axe_id: &str, rule_results: WcagResults) {
+    let finding_count = rule_results.violations.len();
+    let status = if finding_count > 0 {
+        crate::wcag::RuleOutcomeStatus::ViolationsFound
+    } else if !rule_results.warnings.is_empty() {
+        crate::wcag::RuleOutcomeStatus::Warning
+    } else if !rule_results.not_testables.is_empty() {
+        crate::wcag::RuleOutcomeStatus::ManualReviewRequired
+    } else {
+        crate::wcag::RuleOutcomeStatus::NoViolationDetected
+    };
+    results.rule_outcomes.push(crate::wcag::RuleOutcome {
+        rule_id: axe_id.to_string(),
+        status,
+        wcag_criterion: crate::taxonomy::criterion_for_rule(axe_id),
+        viewport: None,
+        reason_code: None,
+        finding_count,
+    });
+    results.merge(rule_results);
+}
+
+/// Run the rules of `registry` up to `level`.
+pub(super) fn run_plugin_rules(
+    registry: &RuleRegistry,
+    tree: &AXTree,
+    level: WcagLevel,
+    results: &mut WcagResults,
+    filter: &RuleFilterConfig,
+) {
+    for rule in registry.rules() {
+        let meta = rule.metadata();
+        if meta.level > level || !filter.should_run(meta.axe_id) {
+            continue;
+        }
+        let mut rule_results = rule.check(tree);
+        for finding in rule_results
+            .violations
+            .iter_mut()
+            .chain(rule_results.warnings.iter_mut())
+            .chain(rule_results.positives.iter_mut())
+            .chain(rule_results.not_testables.iter_mut())
+        {
+            if finding.rule_id.is_none() {
+                finding.rule_id = Some(meta.axe_id.to_string());
+            }
+        }
+        record_rule_results(results, meta.axe_id, rule_results);
+    }
+}
+
 /// Run all Level A rules
 fn run_level_a_rules(tree: &AXTree, results: &mut WcagResults, filter: &RuleFilterConfig) {
     // 1.1.1 Non-text Content (Level A)
@@ -3,11 +3,15 @@
 //! Provides WCAG 2.1 rule checking against the Accessibility Tree.
 
 pub mod coverage;
+pub mod declarative;
 pub mod en301549;
 pub mod engine;
+pub mod plugin;
 pub mod rules;
 pub mod types;
+pub mod vpat;
 
+pub use declarative::DeclarativeRuleSet;
 pub use engine::{
     check_abbreviations_with_page, check_all, check_all_with_config,
     check_background_audio_with_page, check_click_handlers_with_page,
@@ -20,8 +24,9 @@ pub use engine::{
     check_timeouts_with_page, check_timing_with_page, check_use_of_color_with_page,
     check_visual_presentation_with_page, RuleFilterConfig,
 };
+pub use plugin::{register_rule, registered_rules, Rule, RuleTaxonomy};
 pub use types::{
     technical_failure_reason, technical_rule_failure, technical_rule_failure_for, FindingKind,
-    RuleMetadata, RuleOutcome, RuleOutcomeStatus, Severity, Violation, ViolationEvidence,
-    WcagResults,
+    RuleMetadata, RuleOutcome, RuleOutcomeStatus, Severity, SuppressedViolation, Violation,
+    ViolationEvidence, WcagResults,
 };
@@ -0,0 +1,385 @@
+//! Plugin API for custom WCAG rules.
+//!
+//! Built-in rules are wired into [`engine`](super::engine) by hand. Downstream
+//! crates implement [`Rule`] instead and call [`register_rule`] once at
+//! startup, before the first audit. Registered rules run after the built-in
+//! rules of the requested level, honour `RuleFilterConfig` by `axe_id`, and get
+//! a taxonomy entry (`a11y.custom.<axe_id>`), so their findings are normalized,
+//! scored, counted in WCAG coverage and exported to SARIF like built-in ones.
+//!
+//! ```no_run
+//! use auditmysite::accessibility::AXTree;
+//! use auditmysite::cli::WcagLevel;
+//! use auditmysite::wcag::{register_rule, Rule, RuleMetadata, Severity, WcagResults};
+//!
+//! struct IconButtonLabel;
+//!
+//! const META: RuleMetadata = RuleMetadata {
+//!     id: "4.1.2",
+//!     name: "Icon buttons use the design-system label",
+//!     level: WcagLevel::A,
+//!     severity: Severity::High,
+//!     description: "Icon-only buttons need an aria-label from the icon catalog",
+//!     help_url: "https://design.example.com/a11y/icon-buttons",
+//!     axe_id: "ds-icon-button-label",
+//!     tags: &["wcag2a", "wcag412", "design-system"],
+//! };
+//!
+//! impl Rule for IconButtonLabel {
+//!     fn metadata(&self) -> &RuleMetadata {
+//!         &META
+//!     }
+//!
+//!     fn check(&self, tree: &AXTree) -> WcagResults {
+//!         let mut results = WcagResults::new();
+//!         for node in tree.iter().filter(|n| n.role.as_deref() == Some("button")) {
+//!             if node.name.as_deref().is_none_or(|n| n.trim().is_empty()) {
+//!                 results.add_violation(META.violation("Icon button has no label", &node.node_id));
+//!             }
+//!         }
+//!         results
+//!     }
+//! }
+//!
+//! register_rule(IconButtonLabel).expect("unique rule id");
+//! ```
+
+use std::sync::{Arc, RwLock};
+
+use crate::accessibility::AXTree;
+use crate::error::{AuditError, Result};
+use crate::taxonomy::{
+    Dimension, IssueClass, ReportVisibility, RuleLookup, Scaling, ScoreImpact, Subcategory,
+};
+
+use super::declarative::SelectorRule;
+use super::types::{RuleMetadata, WcagResults};
+
+/// A custom WCAG rule evaluated against the accessibility tree.
+pub trait Rule: Send + Sync {
+    /// Static description of the rule. `axe_id` is the stable identifier used
+    /// by rule filters, finding grouping and SARIF; `id` is the WCAG criterion.
+    fn metadata(&self) -> &RuleMetadata;
+
+    /// Run the rule. Violations without a `rule_id` are attributed to
+    /// `metadata().axe_id` by the engine.
+    fn check(&self, tree: &AXTree) -> WcagResults;
+
+    /// Classification used for scoring and report grouping.
+    fn taxonomy(&self) -> RuleTaxonomy {
+        RuleTaxonomy::default()
+    }
+}
+
+/// Taxonomy fields a custom rule can set; everything else is derived from
+/// its [`RuleMetadata`].
+#[derive(Debug, Clone, Copy)]
+pub struct RuleTaxonomy {
+    pub subcategory: Subcategory,
+    pub issue_class: IssueClass,
+    pub score_impact: ScoreImpact,
+    /// Effect on users (English, shown in JSON and reports)
+    pub user_impact: &'static str,
+    /// Effect on the markup or implementation (English)
+    pub technical_impact: &'static str,
+}
+
+impl Default for RuleTaxonomy {
+    fn default() -> Self {
+        Self {
+            subcategory: Subcategory::TechnicalRobustness,
+            issue_class: IssueClass::Invalid,
+            score_impact: ScoreImpact {
+                base_penalty: 2.0,
+                max_penalty: 8.0,
+                occurrence_scaling: Scaling::Logarithmic,
+            },
+            user_impact: "",
+            technical_impact: "",
+        }
+    }
+}
+
+/// How a registered rule is evaluated: against the accessibility tree by the
+/// engine, or in the page next to the built-in page rules.
+enum RuleKind {
+    Tree(Arc<dyn Rule>),
+    Selector(Arc<SelectorRule>),
+}
+
+struct RegisteredRule {
+    kind: RuleKind,
+    taxonomy: &'static crate::taxonomy::Rule,
+}
+
+/// A set of custom rules. The process-wide instance behind [`register_rule`]
+/// is what audits use; separate instances keep tests isolated from it.
+pub(crate) struct RuleRegistry {
+    entries: RwLock<Vec<RegisteredRule>>,
+}
+
+static REGISTRY: RuleRegistry = RuleRegistry::new();
+
+impl RuleRegistry {
+    pub(crate) const fn new() -> Self {
+        Self {
+            entries: RwLock::new(Vec::new()),
+        }
+    }
+
+    /// The registry consulted by the engine and `RuleLookup`.
+    pub(crate) fn global() -> &'static Self {
+        &REGISTRY
+    }
+
+    pub(crate) fn register(&self, rule: impl Rule + 'static) -> Result<()> {
+        let rule: Arc<dyn Rule> = Arc::new(rule);
+        self.insert(
+            rule.metadata(),
+            rule.taxonomy(),
+            RuleKind::Tree(Arc::clone(&rule)),
+        )
+    }
+
+    /// Register a declarative CSS-selector rule, evaluated per page.
+    pub(crate) fn register_selector_rule(
+        &self,
+        rule: SelectorRule,
+        taxonomy: RuleTaxonomy,
+    ) -> Result<()> {
+        let rule = Arc::new(rule);
+        self.insert(
+            rule.metadata(),
+            taxonomy,
+            RuleKind::Selector(Arc::clone(&rule)),
+        )
+    }
+
+    fn insert(&self, meta: &RuleMetadata, taxonomy: RuleTaxonomy, kind: RuleKind) -> Result<()> {
+        let axe_id = meta.axe_id;
+        if axe_id.trim().is_empty() {
+            return Err(AuditError::ConfigError(format!(
+                "custom rule '{}' has an empty axe_id",
+                meta.name
+            )));
+        }
+        let mut entries = self.entries.write().unwrap_or_else(|e| e.into_inner());
+        if RuleLookup::is_builtin_axe_id(axe_id)
+            || entries.iter().any(|r| r.taxonomy.axe_id == Some(axe_id))
+        {
+            return Err(AuditError::ConfigError(format!(
+                "rule id '{axe_id}' is already registered"
+            )));
+        }
+
+        let taxonomy = taxonomy_entry(meta, taxonomy);
+        entries.push(RegisteredRule { kind, taxonomy });
+        Ok(())
+    }
+
+    pub(crate) fn rules(&self) -> Vec<Arc<dyn Rule>> {
+        self.entries
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .iter()
+            .filter_map(|r| match &r.kind {
+                RuleKind::Tree(rule) => Some(Arc::clone(rule)),
+                RuleKind::Selector(_) => None,
+            })
+            .collect()
+    }
+
+    pub(crate) fn selector_rules(&self) -> Vec<Arc<SelectorRule>> {
+        self.entries
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .iter()
+            .filter_map(|r| match &r.kind {
+                RuleKind::Selector(rule) => Some(Arc::clone(rule)),
+                RuleKind::Tree(_) => None,
+            })
+            .collect()
+    }
+
+    pub(crate) fn taxonomy(&self) -> Vec<&'static crate::taxonomy::Rule> {
+        self.entries
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .iter()
+            .map(|r| r.taxonomy)
+            .collect()
+    }
+}
+
+/// Register a custom rule for all subsequent audits in this process.
+///
+/// Fails when the `axe_id` is empty or already used by a built-in or a
+/// previously registered rule. Registration is permanent: the taxonomy entry
+/// is allocated once and lives for the rest of the process.
+pub fn register_rule(rule: impl Rule + 'static) -> Result<()> {
+    REGISTRY.register(rule)
+}
+
+/// All registered custom rules, in registration order.
+pub fn registered_rules() -> Vec<Arc<dyn Rule>> {
+    REGISTRY.rules()
+}
+
+/// Registered declarative selector rules (see [`super::declarative`]).
+pub(crate) fn registered_selector_rules() -> Vec<Arc<SelectorRule>> {
+    REGISTRY.selector_rules()
+}
+
+/// Taxonomy entries of the registered rules (see `RuleLookup`).
+pub(crate) fn registered_taxonomy() -> Vec<&'static crate::taxonomy::Rule> {
+    REGISTRY.taxonomy()
+}
+
+fn taxonomy_entry(meta: &RuleMetadata, taxonomy: RuleTaxonomy) -> &'static crate::taxonomy::Rule {
+    Box::leak(Box::new(crate::taxonomy::Rule {
+        id: leak(format!("a11y.custom.{}", meta.axe_id)),
+        dimension: Dimension::Accessibility,
+        subcategory: taxonomy.subcategory,
+        issue_class: taxonomy.issue_class,
+        severity: meta.severity,
+        external_ref: Some(leak(format!("WCAG {}", meta.id))),
+        external_level: Some(leak(meta.level.to_string())),
+        axe_id: Some(meta.axe_id),
+        title: meta.name,
+        title_en: meta.name,
+        description: meta.description,
+        user_impact: taxonomy.user_impact,
+        user_impact_en: taxonomy.user_impact,
+        technical_impact: taxonomy.technical_impact,
+        technical_impact_en: taxonomy.technical_impact,
+        score_impact: taxonomy.score_impact,
+        report_visibility: ReportVisibility {
+            executive: false,
+            standard: true,
+            technical: true,
+        },
+    }))
+}
+
+fn leak(s: String) -> &'static str {
+    Box::leak(s.into_boxed_str())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::accessibility::AXNode;
+    use crate::cli::WcagLevel;
+    use crate::wcag::engine::{run_plugin_rules, RuleFilterConfig};
+    use crate::wcag::Severity;
+
+    const META: RuleMetadata = RuleMetadata {
+        id: "4.1.2",
+        name: "Plugin test: marker buttons need a label",
+        level: WcagLevel::AA,
+        severity: Severity::High,
+        description: "Buttons named by the plugin test marker",
+        help_url: "https://example.com/plugin-test",
+        axe_id: "plugin-test-marker-button",
+        tags: &["wcag2aa", "wcag412"],
+    };
+
+    struct MarkerRule;
+
+    impl Rule for MarkerRule {
+        fn metadata(&self) -> &RuleMetadata {
+            &META
+        }
+
+        fn check(&self, tree: &AXTree) -> WcagResults {
+            let mut results = WcagResults::new();
+            for node in tree.iter() {
+                if node.description.as_deref() == Some("plugin-test-marker") {
+                    // No rule_id: the engine attributes it to META.axe_id.
+                    results.add_violation(crate::wcag::Violation::new(
+                        META.id,
+                        META.name,
+                        META.level,
+                        META.severity,
+                        "marker button",
+                        &node.node_id,
+                    ));
+                }
+            }
+            results
+        }
+    }
+
+    fn marker_tree() -> AXTree {
+        AXTree::from_nodes(vec![AXNode {
+            node_id: "1".to_string(),
+            ignored: false,
+            ignored_reasons: vec![],
+            role: Some("button".to_string()),
+            name: Some("Menu".to_string()),
+            name_source: None,
+            description: Some("plugin-test-marker".to_string()),
+            value: None,
+            properties: vec![],
+            child_ids: vec![],
+            parent_id: None,
+            backend_dom_node_id: None,
+        }])
+    }
+
+    #[test]
+    fn registered_rule_runs_filters_and_joins_taxonomy() {
+        let registry = RuleRegistry::new();
+        registry.register(MarkerRule).unwrap();
+        assert!(registry.register(MarkerRule).is_err(), "duplicate axe_id");
+        let tree = marker_tree();
+        let all = RuleFilterConfig::default();
+
+        let mut aa = WcagResults::new();
+        run_plugin_rules(&registry, &tree, WcagLevel::AA, &mut aa, &all);
+        assert_eq!(aa.violations.len(), 1);
+        assert_eq!(aa.violations[0].rule_id.as_deref(), Some(META.axe_id));
+        assert!(aa.rule_outcomes.iter().any(|o| o.rule_id == META.axe_id));
+
+        // Below the rule's level and when disabled, it does not run.
+        let mut a = WcagResults::new();
+        run_plugin_rules(&registry, &tree, WcagLevel::A, &mut a, &all);
+        assert!(a.rule_outcomes.is_empty());
+        let filter = RuleFilterConfig {
+            disabled_rules: vec![META.axe_id.to_string()],
+            enabled_only_rules: vec![],
+        };
+        let mut disabled = WcagResults::new();
+        run_plugin_rules(&registry, &tree, WcagLevel::AA, &mut disabled, &filter);
+        assert!(disabled.rule_outcomes.is_empty());
+
+        let taxonomy = registry.taxonomy();
+        assert_eq!(taxonomy.len(), 1);
+        assert_eq!(taxonomy[0].id, "a11y.custom.plugin-test-marker-button");
+        assert_eq!(taxonomy[0].external_ref, Some("WCAG 4.1.2"));
+        assert_eq!(taxonomy[0].external_level, Some("AA"));
+        assert_eq!(taxonomy[0].title_en, META.name);
+        // A private registry never reaches the process-wide lookups.
+        assert!(RuleLookup::by_legacy_wcag_id(META.axe_id).is_none());
+    }
+
+    #[test]
+    fn builtin_axe_ids_cannot_be_registered() {
+        struct Shadow;
+        const SHADOW: RuleMetadata = RuleMetadata {
+            axe_id: "image-alt",
+            ..META
+        };
+        impl Rule for Shadow {
+            fn metadata(&self) -> &RuleMetadata {
+                &SHADOW
+            }
+            fn check(&self, _tree: &AXTree) -> WcagResults {
+                WcagResults::new()
+            }
+        }
+        let registry = RuleRegistry::new();
+        assert!(registry.register(Shadow).is_err());
+        assert!(registry.rules().is_empty());
+    }
+}
@@ -204,7 +204,7 @@ impl ContrastRule {
         tasks: Vec<serde_json::Value>,
     ) -> SampledVerdicts {
         let mut verdicts = SampledVerdicts::new();
-        let base64_data = to_base64(&shot.bytes);
+        let base64_data = crate::util::base64_encode(&shot.bytes);
         let tasks_json = match serde_json::to_string(&tasks) {
             Ok(json) => json,
             Err(e) => {
@@ -773,38 +773,6 @@ pub fn apca_contrast(text: &Color, background: &Color) -> f64 {
     output * 100.0
 }
 
-fn to_base64(bytes: &[u8]) -> String {
-    const CHARS: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
-    let mut result = String::with_capacity(bytes.len().div_ceil(3) * 4);
-    for chunk in bytes.chunks(3) {
-        match chunk.len() {
-            3 => {
-                let n = ((chunk[0] as u32) << 16) | ((chunk[1] as u32) << 8) | (chunk[2] as u32);
-                result.push(CHARS[((n >> 18) & 63) as usize] as char);
-                result.push(CHARS[((n >> 12) & 63) as usize] as char);
-                result.push(CHARS[((n >> 6) & 63) as usize] as char);
-                result.push(CHARS[(n & 63) as usize] as char);
-            }
-            2 => {
-                let n = ((chunk[0] as u32) << 8) | (chunk[1] as u32);
-                result.push(CHARS[((n >> 10) & 63) as usize] as char);
-                result.push(CHARS[((n >> 4) & 63) as usize] as char);
-                result.push(CHARS[((n << 2) & 63) as usize] as char);
-                result.push('=');
-            }
-            1 => {
-                let n = chunk[0] as u32;
-                result.push(CHARS[((n >> 2) & 63) as usize] as char);
-                result.push(CHARS[((n << 4) & 63) as usize] as char);
-                result.push('=');
-                result.push('=');
-            }
-            _ => unreachable!(),
-        }
-    }
-    result
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -401,6 +401,24 @@ pub struct RuleMetadata {
     pub tags: &'static [&'static str],
 }
 
+impl RuleMetadata {
+    /// A violation of this rule at `node_id`, carrying the rule's criterion,
+    /// level, default severity, help URL, `axe_id` and tags.
+    pub fn violation(&self, message: impl Into<String>, node_id: impl Into<String>) -> Violation {
+        Violation::new(
+            self.id,
+            self.name,
+            self.level,
+            self.severity,
+            message,
+            node_id,
+        )
+        .with_help_url(self.help_url)
+        .with_rule_id(self.axe_id)
+        .with_tags(self.tags.iter().map(|t| t.to_string()).collect())
+    }
+}
+
 /// Result of running all WCAG checks
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct WcagResults {
@@ -425,6 +443,33 @@ pub struct WcagResults {
     /// This distinguishes a clean check from a check that could not run.
     #[serde(default)]
     pub rule_outcomes: Vec<RuleOutcome>,
+    /// Findings inside a `data-auditmysite-ignore` subtree. Kept out of
+    /// `violations`/`warnings`, so they never reach findings or the verdict.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub suppressed: Vec<SuppressedViolation>,
+}
+
+/// A violation suppressed by an in-page `data-auditmysite-ignore` annotation.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SuppressedViolation {
+    /// axe-core style rule ID
+    pub rule_id: String,
+    /// WCAG rule number (e.g., "1.4.3")
+    pub rule: String,
+    /// AXTree node ID
+    pub node_id: String,
+    /// CSS selector of the suppressed element (if known)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub selector: Option<String>,
+    /// Severity the violation would have carried
+    pub severity: Severity,
+    /// Original violation message
+    pub message: String,
+    /// Attribute value that matched, e.g. `"color-contrast,region"`
+    pub annotation: String,
+    /// Short selector of the annotated element (the element itself or an
+    /// ancestor)
+    pub annotated_element: String,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -465,6 +510,7 @@ impl WcagResults {
             incomplete: 0,
             nodes_checked: 0,
             rule_outcomes: Vec::new(),
+            suppressed: Vec::new(),
         }
     }
 
@@ -545,6 +591,7 @@ impl WcagResults {
         self.incomplete += other.incomplete;
         self.nodes_checked += other.nodes_checked;
         self.rule_outcomes.extend(other.rule_outcomes);
+        self.suppressed.extend(other.suppressed);
     }
 }
 
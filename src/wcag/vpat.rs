@@ -0,0 +1,333 @@
+//! Draft VPAT® 2.x / Accessibility Conformance Report (ACR) derivation.
+//!
+//! Projects audited pages onto the WCAG 2.1 A/AA rows of a VPAT (WCAG
+//! edition) or onto EN 301 549 chapter 9 plus the chapters this tool never
+//! assesses (EU edition). Like `en301549::derive_annex` this is a pure
+//! projection over `NormalizedReport`s; rendering lives in `output::vpat`.
+//!
+//! The result is a draft for an accessibility specialist to complete, never a
+//! finished conformance claim — see `VPAT_DISCLAIMER`.
+
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+use crate::audit::normalized::NormalizedReport;
+use crate::cli::VpatEdition;
+use crate::taxonomy::Severity;
+use crate::wcag::en301549::{
+    derive_annex, ClauseStatus, EN301549_VERSION, EN301549_WEB_CLAUSES, OUT_OF_SCOPE_CHAPTERS,
+};
+
+/// VPAT template revision the generated document follows.
+pub const VPAT_TEMPLATE_VERSION: &str = "2.5";
+
+/// Shown at the top of every generated VPAT (Markdown and JSON).
+pub const VPAT_DISCLAIMER: &str = "Draft generated from automated test results. \"Supports\" only means that automated checks found no violations on the audited pages. Rows marked for manual review were not evaluated. Review and complete every row before publishing this report.";
+
+/// VPAT conformance level terms, as defined by the VPAT 2.x template.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum ConformanceLevel {
+    #[serde(rename = "Supports")]
+    Supports,
+    #[serde(rename = "Partially Supports")]
+    PartiallySupports,
+    #[serde(rename = "Does Not Support")]
+    DoesNotSupport,
+    #[serde(rename = "Not Evaluated")]
+    NotEvaluated,
+}
+
+impl ConformanceLevel {
+    pub fn label(&self) -> &'static str {
+        match self {
+            ConformanceLevel::Supports => "Supports",
+            ConformanceLevel::PartiallySupports => "Partially Supports",
+            ConformanceLevel::DoesNotSupport => "Does Not Support",
+            ConformanceLevel::NotEvaluated => "Not Evaluated",
+        }
+    }
+}
+
+/// One success criterion (or EN 301 549 clause) row.
+#[derive(Debug, Clone, Serialize)]
+pub struct VpatRow {
+    /// WCAG success criterion id, e.g. `"1.1.1"`.
+    pub criterion: &'static str,
+    /// EN 301 549 clause number; set in the EN 301 549 edition only.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub en_clause: Option<&'static str>,
+    pub title: &'static str,
+    /// WCAG conformance level: `"A"` or `"AA"`.
+    pub level: &'static str,
+    pub conformance: ConformanceLevel,
+    /// The criterion needs a manual test; its conformance is not derived.
+    pub manual_review: bool,
+    /// Pages with at least one violation of this criterion.
+    pub affected_pages: usize,
+    pub remarks: String,
+}
+
+/// An EN 301 549 chapter outside "Web", listed as not evaluated.
+#[derive(Debug, Clone, Serialize)]
+pub struct VpatChapterRow {
+    pub chapter: &'static str,
+    pub title: &'static str,
+    pub conformance: ConformanceLevel,
+}
+
+/// A draft VPAT over one or more audited pages.
+#[derive(Debug, Clone, Serialize)]
+pub struct Vpat {
+    pub template_version: &'static str,
+    pub edition: VpatEdition,
+    pub standard: &'static str,
+    /// Timestamp of the most recent audited page.
+    pub evaluation_date: Option<DateTime<Utc>>,
+    pub evaluation_tool: String,
+    pub pages: Vec<String>,
+    pub disclaimer: &'static str,
+    pub criteria: Vec<VpatRow>,
+    /// EN 301 549 chapters 5–8 and 10–13 (EN 301 549 edition only).
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub out_of_scope_chapters: Vec<VpatChapterRow>,
+}
+
+/// Aggregate of one rule's findings for a criterion across the batch.
+struct RuleRemark<'a> {
+    title: &'a str,
+    occurrences: usize,
+    pages: usize,
+    blocking: bool,
+}
+
+/// Derive a draft VPAT from audited pages. Per criterion:
+///   - a violation on more than half of the pages, including at least one
+///     critical/high finding → `Does Not Support`;
+///   - any other violation → `Partially Supports`;
+///   - a criterion that needs manual review → `Not Evaluated` (marked);
+///   - otherwise (automated coverage, no violation) → `Supports`.
+///
+/// With no pages at all, every row is `Not Evaluated`.
+pub fn derive_vpat(reports: &[&NormalizedReport], edition: VpatEdition) -> Vpat {
+    let page_rollups: Vec<_> = reports.iter().map(|r| derive_annex(&r.findings)).collect();
+    let page_count = reports.len();
+
+    let criteria = EN301549_WEB_CLAUSES
+        .iter()
+        .enumerate()
+        .map(|(idx, clause)| {
+            let statuses: Vec<ClauseStatus> = page_rollups.iter().map(|p| p[idx].status).collect();
+            let affected_pages = statuses
+                .iter()
+                .filter(|s| matches!(s, ClauseStatus::ViolationsFound))
+                .count();
+            let manual_review = statuses
+                .iter()
+                .any(|s| matches!(s, ClauseStatus::ManualReviewRequired));
+
+            let mut rules: BTreeMap<&str, RuleRemark> = BTreeMap::new();
+            for report in reports {
+                let mut seen_on_page = Vec::new();
+                for f in report
+                    .findings
+                    .iter()
+                    .filter(|f| f.category == "wcag" && f.wcag_criterion == clause.wcag)
+                {
+                    let entry = rules.entry(&f.rule_id).or_insert(RuleRemark {
+                        title: &f.title,
+                        occurrences: 0,
+                        pages: 0,
+                        blocking: false,
+                    });
+                    entry.occurrences += f.occurrence_count;
+                    entry.blocking |= matches!(f.severity, Severity::Critical | Severity::High);
+                    if !seen_on_page.contains(&f.rule_id.as_str()) {
+                        seen_on_page.push(&f.rule_id);
+                        entry.pages += 1;
+                    }
+                }
+            }
+
+            let (conformance, remarks) = if page_count == 0 {
+                (
+                    ConformanceLevel::NotEvaluated,
+                    "No pages were audited.".to_string(),
+                )
+            } else if affected_pages > 0 {
+                let blocking = rules.values().any(|r| r.blocking);
+                let conformance = if blocking && affected_pages * 2 > page_count {
+                    ConformanceLevel::DoesNotSupport
+                } else {
+                    ConformanceLevel::PartiallySupports
+                };
+                (
+                    conformance,
+                    violation_remarks(rules.into_values().collect(), affected_pages, page_count),
+                )
+            } else if manual_review {
+                (
+                    ConformanceLevel::NotEvaluated,
+                    "Manual review required: this criterion cannot be verified by automated checks."
+                        .to_string(),
+                )
+            } else {
+                (
+                    ConformanceLevel::Supports,
+                    format!(
+                        "No violations found by automated checks on {page_count} audited page(s)."
+                    ),
+                )
+            };
+
+            VpatRow {
+                criterion: clause.wcag,
+                en_clause: (edition == VpatEdition::En301549).then_some(clause.en_clause),
+                title: clause.title_en,
+                level: clause.wcag_level,
+                conformance,
+                manual_review: manual_review && affected_pages == 0,
+                affected_pages,
+                remarks,
+            }
+        })
+        .collect();
+
+    let out_of_scope_chapters = match edition {
+        VpatEdition::Wcag => Vec::new(),
+        VpatEdition::En301549 => OUT_OF_SCOPE_CHAPTERS
+            .iter()
+            .map(|c| VpatChapterRow {
+                chapter: c.chapter,
+                title: c.title_en,
+                conformance: ConformanceLevel::NotEvaluated,
+            })
+            .collect(),
+    };
+
+    Vpat {
+        template_version: VPAT_TEMPLATE_VERSION,
+        edition,
+        standard: match edition {
+            VpatEdition::Wcag => "WCAG 2.1 Level A and AA",
+            VpatEdition::En301549 => EN301549_VERSION,
+        },
+        evaluation_date: reports.iter().map(|r| r.timestamp).max(),
+        evaluation_tool: format!("auditmysite {}", env!("CARGO_PKG_VERSION")),
+        pages: reports.iter().map(|r| r.url.clone()).collect(),
+        disclaimer: VPAT_DISCLAIMER,
+        criteria,
+        out_of_scope_chapters,
+    }
+}
+
+/// "Automated checks found violations on 2 of 5 pages: Images must have
+/// alternate text (7 occurrences on 2 pages); …", most frequent rule first.
+fn violation_remarks(
+    mut rules: Vec<RuleRemark>,
+    affected_pages: usize,
+    page_count: usize,
+) -> String {
+    rules.sort_by(|a, b| b.occurrences.cmp(&a.occurrences).then(a.title.cmp(b.title)));
+    let details: Vec<String> = rules
+        .iter()
+        .map(|r| {
+            format!(
+                "{} ({} occurrence{} on {} page{})",
+                r.title,
+                r.occurrences,
+                if r.occurrences == 1 { "" } else { "s" },
+                r.pages,
+                if r.pages == 1 { "" } else { "s" },
+            )
+        })
+        .collect();
+    format!(
+        "Automated checks found violations on {affected_pages} of {page_count} page(s): {}.",
+        details.join("; ")
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::audit::normalized::normalize;
+    use crate::audit::AuditReport;
+    use crate::cli::WcagLevel;
+    use crate::wcag::{Violation, WcagResults};
+
+    fn page(url: &str, violations: Vec<Violation>) -> NormalizedReport {
+        let mut results = WcagResults::new();
+        for v in violations {
+            results.add_violation(v);
+        }
+        let report = AuditReport::new(url.to_string(), WcagLevel::AA, results, 1);
+        normalize(&report).normalized
+    }
+
+    fn missing_alt() -> Violation {
+        Violation::new(
+            "1.1.1",
+            "Images must have alternate text",
+            WcagLevel::A,
+            Severity::Critical,
+            "Image is missing an alt attribute",
+            "node-1",
+        )
+        .with_rule_id("image-alt")
+    }
+
+    fn row<'a>(vpat: &'a Vpat, criterion: &str) -> &'a VpatRow {
+        vpat.criteria
+            .iter()
+            .find(|r| r.criterion == criterion)
+            .unwrap()
+    }
+
+    #[test]
+    fn conformance_follows_affected_pages_and_manual_review() {
+        let a = page("https://example.com/a", vec![missing_alt()]);
+        let b = page("https://example.com/b", vec![]);
+        let c = page("https://example.com/c", vec![missing_alt()]);
+
+        let partial = derive_vpat(&[&a, &b, &b], VpatEdition::Wcag);
+        let alt = row(&partial, "1.1.1");
+        assert_eq!(alt.conformance, ConformanceLevel::PartiallySupports);
+        assert_eq!(alt.affected_pages, 1);
+        assert!(alt.remarks.contains("on 1 of 3 page(s)"));
+        assert_eq!(alt.en_clause, None);
+
+        let failing = derive_vpat(&[&a, &b, &c], VpatEdition::Wcag);
+        assert_eq!(
+            row(&failing, "1.1.1").conformance,
+            ConformanceLevel::DoesNotSupport
+        );
+
+        let flashes = row(&failing, "2.3.1");
+        assert_eq!(flashes.conformance, ConformanceLevel::NotEvaluated);
+        assert!(flashes.manual_review);
+        assert_eq!(
+            row(&failing, "2.4.2").conformance,
+            ConformanceLevel::Supports
+        );
+        assert_eq!(failing.criteria.len(), EN301549_WEB_CLAUSES.len());
+        assert!(failing.out_of_scope_chapters.is_empty());
+    }
+
+    #[test]
+    fn en301549_edition_adds_clause_numbers_and_out_of_scope_chapters() {
+        let a = page("https://example.com/", vec![]);
+        let vpat = derive_vpat(&[&a], VpatEdition::En301549);
+        assert_eq!(row(&vpat, "1.1.1").en_clause, Some("9.1.1.1"));
+        assert_eq!(vpat.standard, EN301549_VERSION);
+        assert_eq!(
+            vpat.out_of_scope_chapters.len(),
+            OUT_OF_SCOPE_CHAPTERS.len()
+        );
+        assert!(vpat
+            .out_of_scope_chapters
+            .iter()
+            .all(|c| c.conformance == ConformanceLevel::NotEvaluated));
+    }
+}
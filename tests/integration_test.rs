@@ -121,6 +121,12 @@ fn default_config() -> PipelineConfig {
         interactive: auditmysite::cli::InteractiveMode::Off,
         journey_budget_ms: auditmysite::a11y_journey::DEFAULT_BUDGET_MS,
         lang: "de".to_string(),
+        waivers: Default::default(),
+        custom_rules: Default::default(),
+        devices: Default::default(),
+        throttle_passes: auditmysite::browser::throttle::default_throttle_passes(),
+        perf_runs: 1,
+        capture_rendered_links: false,
     }
 }
 
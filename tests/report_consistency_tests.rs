@@ -1237,10 +1237,12 @@ fn test_json_report_includes_report_artifact_fields() {
     }];
     report.throttled_performance = vec![auditmysite::audit::ThrottledPerfResult {
         profile: auditmysite::browser::ThrottleProfile::Slow3G,
+        profile_name: None,
         lcp_ms: Some(3200.0),
         tbt_ms: Some(180.0),
         cls: Some(0.03),
         score: 72,
+        run_stats: None,
     }];
     report.patterns = Some(auditmysite::patterns::PatternAnalysis {
         recognized: vec![auditmysite::patterns::RecognizedPattern {